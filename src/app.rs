@@ -2,17 +2,33 @@
 
 use crate::daemon::DaemonClient;
 use crate::state::{
-    AppState, ButtonPressState, DocDetailFocus, DocsListFocus, EntityType, IssueDetailFocus,
-    IssuesListFocus, LlmAction, LogoStyle, MoveEntityType, OrganizationFocus, PendingMoveAction,
-    PendingStartWorkAction, PendingWorktreeAction, PeopleListFocus, PersonDetailFocus,
-    PrDetailFocus, PressedButton, Project, PrsListFocus, ScreenBuffer, ScreenPos, SplashState,
-    UiArea, View, ViewParams, WorktreeDialogOption,
+    command_on_path, complete_path, expand_tilde, AgentsFocus, AppState, Asset, AssetPreview,
+    ButtonPressState, ConfigEditor, ConfigInputTarget, ConfigSection, ConfirmKind, DaemonControl,
+    DaemonReconnect, DocDetailFocus, DocsListFocus, EntityAction, EntityType, FieldValue,
+    InitWizard, IssueDetailFocus, IssueDetailTab, IssuesListFocus, JumpMatch, JumpQuery,
+    JumpTarget, LinkDialogStep, LlmAction, LlmConfigScope, LlmWorkSession, LocalLlmConfig,
+    LogoStyle, MoveEntityType, MoveMode, OrganizationFocus, PendingAgentAction, PendingAssetAction,
+    PendingConfirmAction, PendingJumpAction, PendingLinkAction, PendingMoveAction,
+    PendingOrgAction, PendingRegisterAction, PendingRenameAction, PendingSpawnAction,
+    PendingStartWorkAction, PendingUnlinkAction, PendingWorktreeAction, PeopleListFocus,
+    PersonDetailFocus, PrDetailFocus, PressedButton, Project, ProjectListFilter, PrsListFocus,
+    ScreenBuffer, ScreenPos, SearchFocus, SplashState, TitleScope, UiArea, User, View, ViewParams,
+    WorkspaceConfirm, WorktreeDialogOption, CONTEXT_BAR_HEIGHT,
 };
 use crate::ui::forms::get_doc_field_count;
 use crate::ui::BUTTON_HEIGHT;
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// How often active agent sessions are polled
+const LLM_WORK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// Upper bound for one project's agent session lookup, so one slow project can't hold up a pass
+const LLM_WORK_RPC_TIMEOUT: Duration = Duration::from_secs(2);
 
 /// Main application struct
 pub struct App {
@@ -32,6 +48,8 @@ pub struct App {
     pub screen_buffer: ScreenBuffer,
     /// Timestamp of last Ctrl+C press for double-tap quit
     pub last_ctrl_c: Option<Instant>,
+    /// Pending result of the agent session lookup running in the background
+    llm_work_rx: Option<oneshot::Receiver<Vec<LlmWorkSession>>>,
 }
 
 impl App {
@@ -46,10 +64,14 @@ impl App {
 
         // Check daemon connection
         state.daemon_connected = daemon.check_connection().await;
+        state.daemon_address = daemon.address().to_string();
+        if state.daemon_connected {
+            state.daemon_seen_since = Some(Instant::now());
+        }
 
         // Load projects if connected
         if state.daemon_connected {
-            if let Ok(projects) = daemon.list_projects().await {
+            if let Ok(projects) = daemon.list_projects(state.project_filter).await {
                 state.projects = projects;
             }
         }
@@ -63,6 +85,7 @@ impl App {
             terminal_size: None,
             screen_buffer: ScreenBuffer::default(),
             last_ctrl_c: None,
+            llm_work_rx: None,
         })
     }
 
@@ -188,7 +211,7 @@ impl App {
     fn calculate_sidebar_item_from_click(&self, mouse_row: u16) -> Option<usize> {
         use crate::ui::sidebar::get_local_actions;
 
-        let actions = get_local_actions(&self.state.current_view);
+        let actions = get_local_actions(&self.state);
         let item_count = actions.len();
 
         if item_count == 0 {
@@ -256,6 +279,12 @@ impl App {
             return Ok(());
         }
 
+        // Handle destructive confirmation dialog (modal)
+        if self.state.pending_confirm_action.is_some() {
+            self.handle_confirm_dialog_key(key).await?;
+            return Ok(());
+        }
+
         // Handle move dialog (modal)
         if self.state.pending_move_action.is_some() {
             self.handle_move_dialog_key(key).await?;
@@ -268,9 +297,72 @@ impl App {
             return Ok(());
         }
 
+        // Handle add link dialog (modal)
+        if self.state.pending_link_action.is_some() {
+            self.handle_link_dialog_key(key).await?;
+            return Ok(());
+        }
+
+        // Handle unlink confirmation dialog (modal)
+        if self.state.pending_unlink_action.is_some() {
+            self.handle_unlink_dialog_key(key).await?;
+            return Ok(());
+        }
+
+        // Handle asset dialog (modal)
+        if self.state.pending_asset_action.is_some() {
+            self.handle_asset_dialog_key(key).await?;
+            return Ok(());
+        }
+
+        // Handle organization dialog (modal)
+        if self.state.pending_org_action.is_some() {
+            self.handle_org_dialog_key(key).await?;
+            return Ok(());
+        }
+
+        // Handle spawn agent dialog (modal)
+        if self.state.pending_spawn_action.is_some() {
+            self.handle_spawn_dialog_key(key).await?;
+            return Ok(());
+        }
+
+        // Handle agents view dialog (modal)
+        if self.state.pending_agent_action.is_some() {
+            self.handle_agent_dialog_key(key).await?;
+            return Ok(());
+        }
+
+        // Handle project rename dialog (modal)
+        if self.state.pending_rename_action.is_some() {
+            self.handle_rename_dialog_key(key).await?;
+            return Ok(());
+        }
+
+        // Handle register project dialog (modal)
+        if self.state.pending_register_action.is_some() {
+            self.handle_register_dialog_key(key).await?;
+            return Ok(());
+        }
+
+        // Handle jump prompt (modal)
+        if self.state.pending_jump_action.is_some() {
+            self.handle_jump_dialog_key(key).await?;
+            return Ok(());
+        }
+
         // Clear any status messages on key press
         self.copy_message = None;
 
+        // Ctrl+G opens the jump prompt from any view
+        if key.code == KeyCode::Char('g')
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+            && self.state.current_view != View::Splash
+        {
+            self.state.pending_jump_action = Some(PendingJumpAction::default());
+            return Ok(());
+        }
+
         // Handle keyboard text selection (Shift+arrows)
         if key.modifiers.contains(KeyModifiers::SHIFT) {
             match key.code {
@@ -308,7 +400,27 @@ impl App {
             View::DocEdit => self.handle_doc_edit_key(key).await?,
             View::People => self.handle_people_key(key).await?,
             View::PersonDetail => self.handle_person_detail_key(key).await?,
+            View::PersonCreate | View::PersonEdit => self.handle_person_form_key(key).await?,
+            View::UserSync => self.handle_user_sync_key(key).await?,
             View::Config => self.handle_config_key(key).await?,
+            View::ConfigEdit => self.handle_config_editor_key(key).await?,
+            View::Search => self.handle_search_key(key).await?,
+            View::Assets => self.handle_assets_key(key).await?,
+            View::InitWizard => self.handle_init_wizard_key(key).await?,
+            View::Version => self.handle_version_key(key).await?,
+            View::Features => self.handle_features_key(key).await?,
+            View::CompactEdit | View::MigrationCreate => {
+                self.handle_feature_editor_key(key).await?
+            }
+            View::AgentActivity => self.handle_agent_activity_key(key).await?,
+            View::Workspaces => self.handle_workspaces_key(key).await?,
+            View::Daemon => self.handle_daemon_key(key).await?,
+            View::Agents => self.handle_agents_key(key).await?,
+            View::AgentCreate | View::AgentEdit => self.handle_agent_form_key(key).await?,
+            View::Organizations => self.handle_organizations_key(key).await?,
+            View::OrganizationCreate | View::OrganizationEdit => {
+                self.handle_org_form_key(key).await?
+            }
         }
 
         // Refresh actions if view changed to one that shows action panel
@@ -345,12 +457,46 @@ impl App {
             if matches!(view, View::Projects) {
                 self.state.selected_project_path = None;
             }
+            // Restore the entity shown by detail views (links can jump between entities)
+            if let Some(id) = &params.issue_id {
+                self.state.selected_issue_id = Some(id.clone());
+            }
+            if let Some(id) = &params.pr_id {
+                self.state.selected_pr_id = Some(id.clone());
+            }
+            if let Some(slug) = &params.doc_slug {
+                self.state.selected_doc_slug = Some(slug.clone());
+            }
             self.state.current_view = view;
             self.state.view_params = params;
             return;
         }
     }
 
+    /// Reload every issue of a project, keeping the current list on failure
+    async fn reload_issues(&mut self, path: &str) {
+        if let Ok(issues) = self.daemon.list_issues(path).await {
+            self.state.issues = issues;
+        }
+    }
+
+    /// Select a project and load its issues, PRs, docs and config
+    async fn open_project(&mut self, path: &str) {
+        self.state.selected_project_path = Some(path.to_string());
+        if let Ok(issues) = self.daemon.list_issues(path).await {
+            self.state.issues = issues;
+        }
+        if let Ok(prs) = self.daemon.list_prs(path).await {
+            self.state.prs = prs;
+        }
+        if let Ok(docs) = self.daemon.list_docs(path).await {
+            self.state.docs = docs;
+        }
+        // Don't keep the previous project's config (and custom fields) on failure
+        self.state.config = self.daemon.get_config(path).await.ok();
+        self.state.project_version = self.daemon.get_project_version(path).await.ok();
+    }
+
     /// Navigate to the detail view for a newly created item
     fn navigate_to_created_item(&mut self, view: View, params: ViewParams) {
         self.state.clear_form();
@@ -390,16 +536,7 @@ impl App {
                     .get(self.state.selected_index)
                     .map(|p| p.path.clone());
                 if let Some(path) = project_path {
-                    self.state.selected_project_path = Some(path.clone());
-                    if let Ok(issues) = self.daemon.list_issues(&path).await {
-                        self.state.issues = issues;
-                    }
-                    if let Ok(docs) = self.daemon.list_docs(&path).await {
-                        self.state.docs = docs;
-                    }
-                    if let Ok(config) = self.daemon.get_config(&path).await {
-                        self.state.config = Some(config);
-                    }
+                    self.open_project(&path).await;
                     self.navigate(View::Issues, ViewParams::default());
                 }
             }
@@ -424,496 +561,752 @@ impl App {
                     }
                 }
             }
-            KeyCode::Char('a') => {
+            KeyCode::Char('a') => self.toggle_selected_archived().await,
+            // Extra projects shown in the grid
+            KeyCode::Char('A') => {
+                self.toggle_project_filter(|f| &mut f.include_archived)
+                    .await
+            }
+            KeyCode::Char('S') => self.toggle_project_filter(|f| &mut f.include_stale).await,
+            KeyCode::Char('U') => {
+                self.toggle_project_filter(|f| &mut f.include_uninitialized)
+                    .await
+            }
+            KeyCode::Char('T') => self.toggle_project_filter(|f| &mut f.include_temp).await,
+            KeyCode::Char('x') => {
+                let confirm = self
+                    .state
+                    .selectable_projects()
+                    .get(self.state.selected_index)
+                    .map(|p| PendingConfirmAction::untrack_project(p));
+                self.state.pending_confirm_action = confirm;
+            }
+            // Uninitialized projects are hidden by default; show them first so they can be picked
+            KeyCode::Char('i') if !self.state.project_filter.include_uninitialized => {
+                self.toggle_project_filter(|f| &mut f.include_uninitialized)
+                    .await;
+                self.copy_message = Some(
+                    "Showing uninitialized projects: select one and press i to initialize"
+                        .to_string(),
+                );
+            }
+            KeyCode::Char('i') => {
                 let project_path = self
                     .state
                     .selectable_projects()
                     .get(self.state.selected_index)
                     .map(|p| p.path.clone());
                 if let Some(path) = project_path {
-                    if self.daemon.set_project_archived(&path, true).await.is_ok() {
-                        if let Ok(projects) = self.daemon.list_projects().await {
-                            self.state.projects = projects;
-                            self.state.reset_selection();
-                        }
-                    }
+                    self.start_init_wizard(path).await;
                 }
             }
-            KeyCode::Char('x') => {
-                self.state.confirm_action = Some("untrack".to_string());
+            KeyCode::Char('o') => {
+                let project = self
+                    .state
+                    .selectable_projects()
+                    .get(self.state.selected_index)
+                    .map(|p| {
+                        (
+                            p.path.clone(),
+                            p.display_name().to_string(),
+                            p.organization_slug.clone(),
+                        )
+                    });
+                if let Some((project_path, project_name, current_slug)) = project {
+                    self.open_org_picker(project_path, project_name, current_slug)
+                        .await;
+                }
+            }
+            KeyCode::Char('O') => self.open_organizations().await,
+            KeyCode::Char('W') => self.navigate(View::Workspaces, ViewParams::default()),
+            KeyCode::Char('D') => self.navigate(View::Daemon, ViewParams::default()),
+            KeyCode::Char('r') => {
+                self.state.pending_rename_action = self
+                    .state
+                    .selectable_projects()
+                    .get(self.state.selected_index)
+                    .map(|p| PendingRenameAction::new(p));
             }
+            KeyCode::Char('/') => self.open_search(),
             KeyCode::Char('n') => {
-                self.navigate(View::Projects, ViewParams::default());
+                self.state.pending_register_action = Some(PendingRegisterAction::Path {
+                    input: String::new(),
+                    error: None,
+                });
             }
             KeyCode::Char('1') => self.state.sidebar_index = 0,
-            KeyCode::Char('2') => {
-                if self.state.selected_project_path.is_some() {
-                    self.state.sidebar_index = 1;
-                    self.navigate(View::Issues, ViewParams::default());
-                }
+            KeyCode::Char('2') if self.state.selected_project_path.is_some() => {
+                self.state.sidebar_index = 1;
+                self.navigate(View::Issues, ViewParams::default());
             }
-            KeyCode::Char('3') => {
-                if self.state.selected_project_path.is_some() {
-                    self.state.sidebar_index = 2;
-                    self.navigate(View::Prs, ViewParams::default());
-                }
+            KeyCode::Char('3') if self.state.selected_project_path.is_some() => {
+                self.state.sidebar_index = 2;
+                self.navigate(View::Prs, ViewParams::default());
             }
-            KeyCode::Char('4') => {
-                if self.state.selected_project_path.is_some() {
-                    self.state.sidebar_index = 3;
-                    self.navigate(View::Docs, ViewParams::default());
-                }
+            KeyCode::Char('4') if self.state.selected_project_path.is_some() => {
+                self.state.sidebar_index = 3;
+                self.navigate(View::Docs, ViewParams::default());
             }
-            KeyCode::Char('5') => {
-                if self.state.selected_project_path.is_some() {
-                    self.state.sidebar_index = 4;
-                    self.navigate(View::People, ViewParams::default());
-                }
+            KeyCode::Char('5') if self.state.selected_project_path.is_some() => {
+                self.state.sidebar_index = 4;
+                self.navigate(View::People, ViewParams::default());
             }
-            KeyCode::Char('6') => {
-                if self.state.selected_project_path.is_some() {
-                    self.state.sidebar_index = 5;
-                    self.navigate(View::Config, ViewParams::default());
-                }
+            KeyCode::Char('6') if self.state.selected_project_path.is_some() => {
+                self.state.sidebar_index = 5;
+                self.navigate(View::Config, ViewParams::default());
             }
             _ => {}
         }
         Ok(())
     }
 
-    /// Handle keys in Organization view
-    async fn handle_organization_key(&mut self, key: KeyEvent) -> Result<()> {
+    /// Handle keys in the project rename dialog
+    async fn handle_rename_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(action) = self.state.pending_rename_action.as_mut() else {
+            return Ok(());
+        };
+
         match key.code {
-            // Toggle focus between list and action panel
-            KeyCode::Tab => {
-                self.state.organization_focus.toggle();
+            KeyCode::Esc => self.state.pending_rename_action = None,
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => action.scope.toggle(),
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                action.input_mut().clear();
             }
-            // Navigate up
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(
-                    self.state.organization_focus,
-                    OrganizationFocus::ProjectsList
-                ) {
-                    if self.state.selected_project_in_org > 0 {
-                        self.state.selected_project_in_org -= 1;
-                    }
-                } else {
-                    self.state.action_panel_up();
-                }
+            KeyCode::Backspace => {
+                action.input_mut().pop();
             }
-            // Navigate down
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(
-                    self.state.organization_focus,
-                    OrganizationFocus::ProjectsList
-                ) {
-                    let max = self.state.organization_projects.len();
-                    if max > 0 && self.state.selected_project_in_org < max - 1 {
-                        self.state.selected_project_in_org += 1;
-                    }
-                } else {
-                    self.state.action_panel_down();
-                }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                action.input_mut().push(c);
             }
-            // Enter: open selected project or execute action
             KeyCode::Enter => {
-                if matches!(
-                    self.state.organization_focus,
-                    OrganizationFocus::ActionPanel
-                ) {
-                    self.execute_selected_dynamic_action().await?;
-                } else {
-                    // Open selected project - navigate to Issues view
-                    if let Some(project) = self
-                        .state
-                        .organization_projects
-                        .get(self.state.selected_project_in_org)
-                        .cloned()
-                    {
-                        self.state.selected_project_path = Some(project.path.clone());
-                        if let Ok(issues) = self.daemon.list_issues(&project.path).await {
-                            self.state.issues = issues;
-                        }
-                        if let Ok(docs) = self.daemon.list_docs(&project.path).await {
-                            self.state.docs = docs;
-                        }
-                        if let Ok(prs) = self.daemon.list_prs(&project.path).await {
-                            self.state.prs = prs;
-                        }
-                        self.navigate(View::Issues, ViewParams::default());
-                    }
+                if let Some(action) = self.state.pending_rename_action.take() {
+                    self.rename_project(action).await;
                 }
             }
-            // Go back to projects
-            KeyCode::Esc | KeyCode::Backspace => {
-                self.state.organization_focus = OrganizationFocus::ProjectsList;
-                self.go_back();
-            }
-            // 'p' - Navigate to All Projects
-            KeyCode::Char('p') => {
-                self.navigate(View::Projects, ViewParams::default());
-            }
             _ => {}
         }
         Ok(())
     }
 
-    /// Handle mouse events in Organization view
-    async fn handle_organization_mouse(&mut self, mouse: MouseEvent) -> Result<()> {
-        match mouse.kind {
-            MouseEventKind::ScrollDown => {
-                self.state.scroll_down();
-            }
-            MouseEventKind::ScrollUp => {
-                self.state.scroll_up();
-            }
-            MouseEventKind::Down(MouseButton::Left) => {
-                // Simple click handling - could be expanded to select projects
+    /// Save every title that was edited (an empty title clears it)
+    async fn rename_project(&mut self, mut action: PendingRenameAction) {
+        let path = action.project_path.clone();
+        for (scope, title) in action.changed_titles() {
+            let result = match scope {
+                TitleScope::User => self.daemon.set_project_user_title(&path, &title).await,
+                TitleScope::Project => self.daemon.set_project_title(&path, &title).await,
+            };
+            if let Err(e) = result {
+                self.push_error(e.to_string());
+                // Titles saved before the failure still move the card
+                self.select_project_card(&path).await;
+                self.state.pending_rename_action = Some(action);
+                return;
             }
-            _ => {}
+            action.mark_saved(scope);
         }
-        Ok(())
-    }
 
-    /// Handle keys in Issues view
-    async fn handle_issues_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check for dynamic action shortcut first (when focused on list)
-        if matches!(self.state.issues_list_focus, IssuesListFocus::List) {
-            if let Some(action_idx) = self.find_action_for_key(&key) {
-                self.state.action_panel_selected_index = action_idx;
-                self.execute_selected_dynamic_action().await?;
-                return Ok(());
-            }
+        // Sections are sorted by name, so the card may move
+        self.select_project_card(&path).await;
+        if let Some(project) = self.state.projects.iter().find(|p| p.path == path) {
+            self.copy_message = Some(format!("Renamed to {}", project.display_name()));
         }
+    }
 
-        match key.code {
-            // Tab: Switch focus between list and action panel
-            KeyCode::Tab => {
-                self.state.issues_list_focus.toggle();
-            }
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.issues_list_focus, IssuesListFocus::List) {
-                    self.state
-                        .move_selection_down(self.state.sorted_issues().len());
-                } else {
-                    // Navigate down in action panel
-                    self.state.action_panel_down();
+    /// Handle keys in the register project dialog
+    async fn handle_register_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(action) = self.state.pending_register_action.as_mut() else {
+            return Ok(());
+        };
+
+        match action {
+            PendingRegisterAction::Path { input, error } => match key.code {
+                KeyCode::Esc => self.state.pending_register_action = None,
+                KeyCode::Tab => {
+                    if let Some(completed) = complete_path(input) {
+                        *input = completed;
+                    }
+                    *error = None;
                 }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.issues_list_focus, IssuesListFocus::List) {
-                    self.state.move_selection_up();
-                } else {
-                    // Navigate up in action panel
-                    self.state.action_panel_up();
+                KeyCode::Backspace => {
+                    input.pop();
+                    *error = None;
                 }
-            }
-            KeyCode::Enter => {
-                if matches!(self.state.issues_list_focus, IssuesListFocus::ActionPanel) {
-                    self.execute_selected_dynamic_action().await?;
-                } else {
-                    // Open issue detail
-                    let issue_id = self
-                        .state
-                        .sorted_issues()
-                        .get(self.state.selected_index)
-                        .map(|issue| issue.id.clone());
-                    if let Some(id) = issue_id {
-                        self.state.selected_issue_id = Some(id.clone());
-                        self.navigate(
-                            View::IssueDetail,
-                            ViewParams {
-                                issue_id: Some(id),
-                                ..Default::default()
-                            },
-                        );
-                    }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    input.push(c);
+                    *error = None;
                 }
-            }
-            KeyCode::Char('s') => {
-                self.state.cycle_issue_sort_field();
-            }
-            KeyCode::Char('S') => {
-                self.state.toggle_issue_sort_direction();
-            }
-            KeyCode::Char('a') => {
-                self.state.show_closed_issues = !self.state.show_closed_issues;
-                self.state.reset_selection();
-            }
-            // Navigation to other views
-            KeyCode::Char('3') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Prs, ViewParams::default());
+                KeyCode::Enter => {
+                    let input = input.clone();
+                    self.register_project(&input).await;
                 }
-            }
-            KeyCode::Char('4') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Docs, ViewParams::default());
+                _ => {}
+            },
+            PendingRegisterAction::ConfirmInit { project_path } => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    let path = project_path.clone();
+                    self.state.pending_register_action = None;
+                    self.start_init_wizard(path).await;
                 }
-            }
-            KeyCode::Char('5') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::People, ViewParams::default());
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state.pending_register_action = None;
                 }
-            }
-            KeyCode::Esc | KeyCode::Backspace => {
-                // Reset focus state when leaving
-                self.state.issues_list_focus = IssuesListFocus::List;
-                self.state.action_panel_selected_index = 0;
-                self.go_back();
-            }
-            _ => {}
-        }
+                _ => {}
+            },
+        }
         Ok(())
     }
 
-    /// Delete the selected issue from the issues list
-    async fn delete_selected_issue(&mut self) -> Result<()> {
-        let project_path = match &self.state.selected_project_path {
-            Some(path) => path.clone(),
-            None => {
-                self.push_error("No project selected");
-                return Ok(());
+    /// Register the typed directory, select its card and offer to initialize it
+    async fn register_project(&mut self, input: &str) {
+        let dir = expand_tilde(input.trim());
+        let error = if input.trim().is_empty() {
+            Some("Enter a directory path".to_string())
+        } else if !dir.is_dir() {
+            Some(format!("Directory does not exist: {}", dir.display()))
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            self.state.pending_register_action = Some(PendingRegisterAction::Path {
+                input: input.to_string(),
+                error: Some(error),
+            });
+            return;
+        }
+
+        let (project, org) = match self.daemon.register_project(&dir.to_string_lossy()).await {
+            Ok(registered) => registered,
+            Err(e) => {
+                self.state.pending_register_action = Some(PendingRegisterAction::Path {
+                    input: input.to_string(),
+                    error: Some(e.to_string()),
+                });
+                return;
             }
         };
 
-        // Get the selected issue ID from the sorted list
-        let issue_id = {
-            let sorted = self.state.sorted_issues();
-            sorted.get(self.state.selected_index).map(|i| i.id.clone())
+        // The daemon hides uninitialized projects unless asked, so show them to keep the new card
+        let initialized = dir.join(".centy").is_dir();
+        if !initialized {
+            self.state.project_filter.include_uninitialized = true;
+        }
+        self.select_project_card(&project.path).await;
+        self.copy_message = Some(match org.filter(|o| !o.message.is_empty()) {
+            Some(org) => format!("Registered {} ({})", project.display_name(), org.message),
+            None => format!("Registered {}", project.display_name()),
+        });
+
+        self.state.pending_register_action = if initialized {
+            None
+        } else {
+            Some(PendingRegisterAction::ConfirmInit {
+                project_path: project.path,
+            })
         };
+    }
 
-        let issue_id = match issue_id {
-            Some(id) => id,
-            None => {
-                self.push_error("No issue selected");
-                return Ok(());
+    /// Reload the projects list and select the card for `path` if it is listed
+    async fn select_project_card(&mut self, path: &str) {
+        if let Ok(projects) = self.daemon.list_projects(self.state.project_filter).await {
+            self.state.projects = projects;
+        }
+        if self.state.select_project_by_path(path) {
+            let columns = self.calculate_project_grid_columns();
+            let visible_height = self.calculate_project_grid_visible_height();
+            self.state.ensure_selected_visible(columns, visible_height);
+        }
+    }
+
+    /// Fetch the reconciliation plan for a project and open the init wizard
+    async fn start_init_wizard(&mut self, path: String) {
+        match self.daemon.get_reconciliation_plan(&path).await {
+            Ok(plan) => {
+                self.state.init_wizard = Some(InitWizard::new(path, plan));
+                self.navigate(View::InitWizard, ViewParams::default());
             }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Handle keys in the init wizard
+    async fn handle_init_wizard_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(wizard) = self.state.init_wizard.as_mut() else {
+            self.go_back();
+            return Ok(());
         };
 
-        // Delete the issue
-        match self.daemon.delete_issue(&project_path, &issue_id).await {
-            Ok(_) => {
-                // Refresh issues list
-                if let Ok(issues) = self.daemon.list_issues(&project_path).await {
-                    self.state.issues = issues;
-                    // Adjust selection if needed
-                    let max = self.state.sorted_issues().len();
-                    if self.state.selected_index >= max && max > 0 {
-                        self.state.selected_index = max - 1;
-                    }
-                }
-                // Navigate to issues list after successful deletion
-                self.navigate(View::Issues, ViewParams::default());
-            }
-            Err(e) => {
-                self.push_error(format!("Failed to delete issue: {}", e));
+        // Summary screen: any confirming key closes the wizard
+        if wizard.result.is_some() {
+            if matches!(key.code, KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q')) {
+                self.close_init_wizard().await;
             }
+            return Ok(());
         }
 
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => wizard.select_next(),
+            KeyCode::Char('k') | KeyCode::Up => wizard.select_prev(),
+            KeyCode::Char(' ') => wizard.toggle_selected(),
+            KeyCode::Enter => self.run_init_wizard().await,
+            KeyCode::Esc => self.close_init_wizard().await,
+            _ => {}
+        }
         Ok(())
     }
 
-    /// Handle keys in Issue Detail view
-    async fn handle_issue_detail_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check for dynamic action shortcut first (when focused on content)
-        if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
-            if let Some(action_idx) = self.find_action_for_key(&key) {
-                self.state.action_panel_selected_index = action_idx;
-                self.execute_selected_dynamic_action().await?;
-                return Ok(());
+    /// Handle mouse events in the init wizard (wheel moves the selection)
+    fn handle_init_wizard_mouse(&mut self, mouse: MouseEvent) {
+        let Some(wizard) = self.state.init_wizard.as_mut() else {
+            return;
+        };
+        match mouse.kind {
+            MouseEventKind::ScrollUp => wizard.select_prev(),
+            MouseEventKind::ScrollDown => wizard.select_next(),
+            _ => {}
+        }
+    }
+
+    /// Execute the reconciliation with the chosen decisions and show the summary
+    async fn run_init_wizard(&mut self) {
+        let Some(wizard) = &self.state.init_wizard else {
+            return;
+        };
+        let path = wizard.project_path.clone();
+        let result = self
+            .daemon
+            .execute_reconciliation(&path, wizard.restore.clone(), wizard.reset.clone())
+            .await;
+
+        match result {
+            Ok(result) => {
+                if let Some(wizard) = self.state.init_wizard.as_mut() {
+                    wizard.result = Some(result);
+                }
             }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Leave the init wizard; after a completed run, reload the grid and select the project
+    async fn close_init_wizard(&mut self) {
+        let completed = self
+            .state
+            .init_wizard
+            .take()
+            .filter(|wizard| wizard.result.is_some());
+        self.go_back();
+        if let Some(wizard) = completed {
+            self.select_project_card(&wizard.project_path).await;
+        }
+    }
+
+    /// Reload the organizations list, reporting failures
+    async fn refresh_organizations(&mut self) {
+        match self.daemon.list_organizations().await {
+            Ok(organizations) => self.state.set_organizations(organizations),
+            Err(e) => self.push_error(e.to_string()),
         }
+    }
+
+    /// Open the Organizations list view
+    async fn open_organizations(&mut self) {
+        self.refresh_organizations().await;
+        self.navigate(View::Organizations, ViewParams::default());
+    }
 
+    /// Open the organization picker for a project card
+    async fn open_org_picker(
+        &mut self,
+        project_path: String,
+        project_name: String,
+        current_slug: Option<String>,
+    ) {
+        self.refresh_organizations().await;
+        let selected_index = current_slug
+            .and_then(|slug| self.state.organizations.iter().position(|o| o.slug == slug))
+            .map_or(0, |idx| idx + 1);
+        self.state.pending_org_action = Some(PendingOrgAction::Assign {
+            project_path,
+            project_name,
+            selected_index,
+        });
+    }
+
+    /// Handle keys in Organizations list view
+    async fn handle_organizations_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            // Tab: Switch focus between content and action panel
-            KeyCode::Tab => {
-                self.state.issue_detail_focus.toggle();
+            KeyCode::Char('j') | KeyCode::Down => self.state.select_next_organization(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.select_prev_organization(),
+            KeyCode::Enter => {
+                if let Some(org) = self.state.selected_organization() {
+                    let slug = org.slug.clone();
+                    self.load_organization_data(slug).await;
+                }
+            }
+            KeyCode::Char('n') => {
+                self.state.clear_form();
+                self.navigate(View::OrganizationCreate, ViewParams::default());
             }
-            // Edit issue
             KeyCode::Char('e') => {
-                if let Some(issue_id) = self.state.selected_issue_id.clone() {
-                    // Load issue data into form before navigating
-                    if let Some(issue) =
-                        self.state.issues.iter().find(|i| i.id == issue_id).cloned()
-                    {
-                        self.state.load_issue_to_form(&issue);
-                    }
+                if let Some(org) = self.state.selected_organization().cloned() {
+                    self.state.clear_form();
+                    self.state.load_organization_to_form(&org);
                     self.navigate(
-                        View::IssueEdit,
+                        View::OrganizationEdit,
                         ViewParams {
-                            issue_id: Some(issue_id),
+                            organization_slug: Some(org.slug),
                             ..Default::default()
                         },
                     );
                 }
             }
-            // Navigation (j/k/Up/Down)
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
-                    self.state.scroll_down();
-                } else {
-                    // Navigate down in action panel
-                    self.state.action_panel_down();
-                }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
-                    self.state.scroll_up();
-                } else {
-                    // Navigate up in action panel
-                    self.state.action_panel_up();
-                }
-            }
-            KeyCode::Char('d') | KeyCode::PageDown => {
-                if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
-                    self.state.scroll_down_page();
+            KeyCode::Char('x') => {
+                if let Some(org) = self.state.selected_organization().cloned() {
+                    self.state.pending_org_action = Some(PendingOrgAction::Delete { org });
                 }
             }
-            KeyCode::Char('u') | KeyCode::PageUp => {
-                if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
-                    self.state.scroll_up_page();
-                }
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle keys in the organization create/edit forms
+    async fn handle_org_form_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.state.clear_form();
+                self.go_back();
             }
-            // Execute action (Enter when action panel is focused)
-            KeyCode::Enter => {
-                if matches!(self.state.issue_detail_focus, IssueDetailFocus::ActionPanel) {
-                    self.execute_selected_dynamic_action().await?;
-                }
+            // Save (Ctrl+S or Cmd+W / Ctrl+W)
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_org_form().await;
             }
-            // Go back (also reset focus and action panel index)
-            KeyCode::Esc | KeyCode::Backspace => {
-                self.state.issue_detail_focus = IssueDetailFocus::Content;
-                self.state.action_panel_selected_index = 0;
-                self.go_back();
+            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.save_org_form().await;
             }
+            KeyCode::Tab => self.state.next_form_field(),
+            KeyCode::BackTab => self.state.prev_form_field(),
+            KeyCode::Char(c) => self
+                .state
+                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
+            KeyCode::Backspace => self.state.form_backspace(),
             _ => {}
         }
         Ok(())
     }
 
-    /// Update the current issue's status
-    async fn update_issue_status(&mut self, new_status: String) -> Result<()> {
-        let project_path = match &self.state.selected_project_path {
-            Some(path) => path.clone(),
+    /// Create or update the organization from the form
+    async fn save_org_form(&mut self) {
+        let name = self.state.form_title.trim().to_string();
+        let slug = self.state.form_slug.trim().to_string();
+        let description = self.state.form_description.trim().to_string();
+        if name.is_empty() {
+            self.push_error("Organization name is required");
+            return;
+        }
+
+        let result = match self.state.view_params.organization_slug.clone() {
+            Some(current_slug) => {
+                let new_slug = (!slug.is_empty() && slug != current_slug).then_some(slug.as_str());
+                self.daemon
+                    .update_organization(&current_slug, &name, &description, new_slug)
+                    .await
+            }
             None => {
-                self.push_error("No project selected");
-                return Ok(());
+                let slug = (!slug.is_empty()).then_some(slug.as_str());
+                self.daemon
+                    .create_organization(slug, &name, &description)
+                    .await
             }
         };
 
-        let issue_id = match &self.state.selected_issue_id {
-            Some(id) => id.clone(),
-            None => {
-                self.push_error("No issue selected");
-                return Ok(());
+        match result {
+            Ok(org) => {
+                self.refresh_organizations().await;
+                if let Some(idx) = self
+                    .state
+                    .organizations
+                    .iter()
+                    .position(|o| o.slug == org.slug)
+                {
+                    self.state.selected_organization_index = idx;
+                }
+                // Project cards carry the org name and slug used for grouping
+                if let Ok(projects) = self.daemon.list_projects(self.state.project_filter).await {
+                    self.state.projects = projects;
+                }
+                self.state.clear_form();
+                self.go_back();
             }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Handle keys in the organization delete confirmation or assignment picker
+    async fn handle_org_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        let org_count = self.state.organizations.len();
+        let Some(action) = self.state.pending_org_action.as_mut() else {
+            return Ok(());
         };
 
-        // Get current issue data
-        let (title, description, priority) = {
-            let issue = self.state.issues.iter().find(|i| i.id == issue_id);
-            match issue {
-                Some(i) => (i.title.clone(), i.description.clone(), i.metadata.priority),
-                None => {
-                    self.push_error("Issue not found");
-                    return Ok(());
+        match action {
+            PendingOrgAction::Delete { org } => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    let slug = org.slug.clone();
+                    self.state.pending_org_action = None;
+                    self.delete_organization(&slug).await;
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state.pending_org_action = None;
+                }
+                _ => {}
+            },
+            PendingOrgAction::Assign { selected_index, .. } => match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    *selected_index = selected_index.saturating_sub(1);
+                }
+                // Index 0 is "no organization", so there are org_count + 1 entries
+                KeyCode::Down | KeyCode::Char('j') if *selected_index < org_count => {
+                    *selected_index += 1;
+                }
+                KeyCode::Enter => {
+                    if let Some(PendingOrgAction::Assign {
+                        project_path,
+                        selected_index,
+                        ..
+                    }) = self.state.pending_org_action.take()
+                    {
+                        self.assign_project_organization(&project_path, selected_index)
+                            .await;
+                    }
+                }
+                KeyCode::Esc => self.state.pending_org_action = None,
+                _ => {}
+            },
+        }
+        Ok(())
+    }
+
+    /// Delete an organization and refresh the lists that show it
+    async fn delete_organization(&mut self, slug: &str) {
+        match self.daemon.delete_organization(slug).await {
+            Ok(unassigned) => {
+                self.copy_message = Some(match unassigned {
+                    0 => format!("Deleted {}", slug),
+                    n => format!("Deleted {} ({} projects ungrouped)", slug, n),
+                });
+                self.refresh_organizations().await;
+                if let Ok(projects) = self.daemon.list_projects(self.state.project_filter).await {
+                    self.state.projects = projects;
                 }
             }
-        };
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Assign the project to the picked organization (index 0 removes it)
+    async fn assign_project_organization(&mut self, project_path: &str, selected_index: usize) {
+        let slug = selected_index
+            .checked_sub(1)
+            .and_then(|idx| self.state.organizations.get(idx))
+            .map(|o| o.slug.clone());
 
-        // Update the issue
         match self
             .daemon
-            .update_issue(
-                &project_path,
-                &issue_id,
-                &title,
-                &description,
-                priority,
-                &new_status,
-            )
+            .set_project_organization(project_path, slug.as_deref())
             .await
         {
-            Ok(_) => {
-                // Refresh issues list
-                if let Ok(issues) = self.daemon.list_issues(&project_path).await {
-                    self.state.issues = issues;
-                }
-            }
-            Err(e) => {
-                self.push_error(format!("Failed to update status: {}", e));
+            Ok(()) => {
+                // The card moves to another section, so follow it
+                self.select_project_card(project_path).await;
             }
+            Err(e) => self.push_error(e.to_string()),
         }
-
-        Ok(())
     }
 
-    /// Update the current PR's status
-    async fn update_pr_status(&mut self, new_status: String) -> Result<()> {
-        let project_path = match &self.state.selected_project_path {
-            Some(path) => path.clone(),
-            None => {
-                self.push_error("No project selected");
-                return Ok(());
-            }
-        };
-
-        let pr_id = match &self.state.selected_pr_id {
-            Some(id) => id.clone(),
-            None => {
-                self.push_error("No PR selected");
+    /// Handle keys in Organization view
+    async fn handle_organization_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            // Toggle focus between list and action panel
+            KeyCode::Tab => {
+                self.state.organization_focus.toggle();
+            }
+            // Navigate up
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(
+                    self.state.organization_focus,
+                    OrganizationFocus::ProjectsList
+                ) {
+                    if self.state.selected_project_in_org > 0 {
+                        self.state.selected_project_in_org -= 1;
+                    }
+                } else {
+                    self.state.action_panel_up();
+                }
+            }
+            // Navigate down
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(
+                    self.state.organization_focus,
+                    OrganizationFocus::ProjectsList
+                ) {
+                    let max = self.state.organization_projects.len();
+                    if max > 0 && self.state.selected_project_in_org < max - 1 {
+                        self.state.selected_project_in_org += 1;
+                    }
+                } else {
+                    self.state.action_panel_down();
+                }
+            }
+            // Enter: open selected project or execute action
+            KeyCode::Enter => {
+                if matches!(
+                    self.state.organization_focus,
+                    OrganizationFocus::ActionPanel
+                ) {
+                    self.execute_selected_dynamic_action().await?;
+                } else {
+                    // Open selected project - navigate to Issues view
+                    if let Some(project) = self
+                        .state
+                        .organization_projects
+                        .get(self.state.selected_project_in_org)
+                        .cloned()
+                    {
+                        self.open_project(&project.path).await;
+                        self.navigate(View::Issues, ViewParams::default());
+                    }
+                }
+            }
+            // Go back to projects
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.state.organization_focus = OrganizationFocus::ProjectsList;
+                self.go_back();
+            }
+            // 'p' - Navigate to All Projects
+            KeyCode::Char('p') => {
+                self.navigate(View::Projects, ViewParams::default());
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle mouse events in Organization view
+    async fn handle_organization_mouse(&mut self, mouse: MouseEvent) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::ScrollDown => {
+                self.state.scroll_down();
+            }
+            MouseEventKind::ScrollUp => {
+                self.state.scroll_up();
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                // Simple click handling - could be expanded to select projects
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle keys in Issues view
+    async fn handle_issues_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Check for dynamic action shortcut first (when focused on list)
+        if matches!(self.state.issues_list_focus, IssuesListFocus::List) {
+            if let Some(action_idx) = self.find_action_for_key(&key) {
+                self.state.action_panel_selected_index = action_idx;
+                self.execute_selected_dynamic_action().await?;
                 return Ok(());
             }
-        };
+        }
 
-        // Get current PR data
-        let (title, description, source_branch, target_branch) = {
-            let pr = self.state.prs.iter().find(|p| p.id == pr_id);
-            match pr {
-                Some(p) => (
-                    p.title.clone(),
-                    p.description.clone(),
-                    p.metadata.source_branch.clone(),
-                    p.metadata.target_branch.clone(),
-                ),
-                None => {
-                    self.push_error("PR not found");
-                    return Ok(());
+        match key.code {
+            // Tab: Switch focus between list and action panel
+            KeyCode::Tab => {
+                self.state.issues_list_focus.toggle();
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.issues_list_focus, IssuesListFocus::List) {
+                    self.state
+                        .move_selection_down(self.state.sorted_issues().len());
+                } else {
+                    // Navigate down in action panel
+                    self.state.action_panel_down();
                 }
             }
-        };
-
-        // Update the PR
-        match self
-            .daemon
-            .update_pr(
-                &project_path,
-                &pr_id,
-                &title,
-                &description,
-                &source_branch,
-                &target_branch,
-                &new_status,
-            )
-            .await
-        {
-            Ok(()) => {
-                // Refresh PRs list
-                if let Ok(prs) = self.daemon.list_prs(&project_path).await {
-                    self.state.prs = prs;
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.issues_list_focus, IssuesListFocus::List) {
+                    self.state.move_selection_up();
+                } else {
+                    // Navigate up in action panel
+                    self.state.action_panel_up();
                 }
             }
-            Err(e) => {
-                self.push_error(format!("Failed to update PR status: {}", e));
+            KeyCode::Enter => {
+                if matches!(self.state.issues_list_focus, IssuesListFocus::ActionPanel) {
+                    self.execute_selected_dynamic_action().await?;
+                } else {
+                    // Open issue detail
+                    let issue_id = self
+                        .state
+                        .sorted_issues()
+                        .get(self.state.selected_index)
+                        .map(|issue| issue.id.clone());
+                    if let Some(id) = issue_id {
+                        self.state.selected_issue_id = Some(id.clone());
+                        self.navigate(
+                            View::IssueDetail,
+                            ViewParams {
+                                issue_id: Some(id),
+                                ..Default::default()
+                            },
+                        );
+                    }
+                }
+            }
+            KeyCode::Char('s') => {
+                self.state.cycle_issue_sort_field();
+            }
+            KeyCode::Char('S') => {
+                self.state.toggle_issue_sort_direction();
+            }
+            KeyCode::Char('a') => {
+                self.state.show_closed_issues = !self.state.show_closed_issues;
+                self.state.reset_selection();
+            }
+            KeyCode::Char('D') => self.state.cycle_issue_draft_filter(),
+            // Navigation to other views
+            KeyCode::Char('3') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Prs, ViewParams::default());
+            }
+            KeyCode::Char('4') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Docs, ViewParams::default());
+            }
+            KeyCode::Char('5') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::People, ViewParams::default());
+            }
+            KeyCode::Char('7') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Assets, ViewParams::default());
+            }
+            KeyCode::Char('8') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Features, ViewParams::default());
+            }
+            KeyCode::Char('/') => self.open_search(),
+            KeyCode::Esc | KeyCode::Backspace => {
+                // Reset focus state when leaving
+                self.state.issues_list_focus = IssuesListFocus::List;
+                self.state.action_panel_selected_index = 0;
+                self.go_back();
             }
+            _ => {}
         }
-
         Ok(())
     }
 
-    /// Execute the "Open in VSCode" action
-    async fn execute_open_in_vscode(&mut self) -> Result<()> {
+    /// Delete the selected issue from the issues list
+    async fn delete_selected_issue(&mut self) -> Result<()> {
         let project_path = match &self.state.selected_project_path {
             Some(path) => path.clone(),
             None => {
@@ -922,664 +1315,3349 @@ impl App {
             }
         };
 
-        let issue_id = match &self.state.selected_issue_id {
-            Some(id) => id.clone(),
+        // The selected row in the list, or the open issue in detail
+        let issue_id = match self.state.action_issue().map(|i| i.id.clone()) {
+            Some(id) => id,
             None => {
                 self.push_error("No issue selected");
                 return Ok(());
             }
         };
 
-        let action = self.state.action_panel_llm_action.as_proto_value();
-
-        self.copy_message = Some("Opening in VSCode...".to_string());
-
-        match self
-            .daemon
-            .open_in_temp_vscode(&project_path, &issue_id, action, "", 0)
-            .await
-        {
-            Ok(result) => {
-                if result.vscode_opened {
-                    self.copy_message = Some(format!(
-                        "Opened #{} in VSCode (expires: {})",
-                        result.display_number,
-                        result
-                            .expires_at
-                            .split('T')
-                            .next()
-                            .unwrap_or(&result.expires_at)
-                    ));
-                } else {
-                    self.copy_message =
-                        Some(format!("Workspace created at {}", result.workspace_path));
+        // Delete the issue
+        match self.daemon.delete_issue(&project_path, &issue_id).await {
+            Ok(_) => {
+                // Refresh issues list
+                if let Ok(issues) = self.daemon.list_issues(&project_path).await {
+                    self.state.issues = issues;
+                    // Adjust selection if needed
+                    let max = self.state.sorted_issues().len();
+                    if self.state.selected_index >= max && max > 0 {
+                        self.state.selected_index = max - 1;
+                    }
                 }
+                // Navigate to issues list after successful deletion
+                self.navigate(View::Issues, ViewParams::default());
             }
             Err(e) => {
-                let error_str = e.to_string();
-                let error_lower = error_str.to_lowercase();
+                self.push_error(format!("Failed to delete issue: {}", e));
+            }
+        }
 
-                // Check if this is a worktree/folder exists error
-                if error_lower.contains("worktree") || error_lower.contains("already exists") {
-                    // Try to find existing workspace for this issue
-                    if let Ok(workspaces) = self.daemon.list_temp_workspaces(&project_path).await {
-                        // Find workspace matching this issue
-                        if let Some(existing) = workspaces.into_iter().find(|w| {
-                            w.issue_id == issue_id || w.issue_display_number.to_string() == issue_id
-                        }) {
-                            // Show dialog to let user choose action
-                            self.state.pending_worktree_action = Some(PendingWorktreeAction {
-                                project_path: project_path.clone(),
-                                issue_id: issue_id.clone(),
-                                action,
-                                existing_workspace: existing,
-                                selected_option: WorktreeDialogOption::OpenExisting,
-                            });
-                            self.copy_message = None;
-                            return Ok(());
-                        }
+        Ok(())
+    }
+
+    /// Delete the selected PR
+    async fn delete_selected_pr(&mut self) -> Result<()> {
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return Ok(());
+        };
+        let Some(pr_id) = self.state.action_pr().map(|p| p.id.clone()) else {
+            self.push_error("No PR selected");
+            return Ok(());
+        };
+
+        match self.daemon.delete_pr(&project_path, &pr_id).await {
+            Ok(_) => {
+                if let Ok(prs) = self.daemon.list_prs(&project_path).await {
+                    self.state.prs = prs;
+                    let max = self.state.sorted_prs().len();
+                    if self.state.selected_index >= max && max > 0 {
+                        self.state.selected_index = max - 1;
                     }
-                    // Fallback to error message if we couldn't find existing workspace
-                    self.push_error("Failed to create git worktree.\nTry closing other VS Code windows for this project.");
-                } else if error_str.contains("detached HEAD") {
-                    self.push_error("Repository is in detached HEAD state.\nCheckout a branch first: git checkout <branch>");
-                } else if error_lower.contains("not a git repository") {
-                    self.push_error(
-                        "This project is not a git repository.\nInitialize with: git init",
-                    );
-                } else if error_lower.contains("not found") && error_lower.contains("vscode") {
-                    self.push_error("VS Code not found.\nInstall it and add 'code' to PATH.");
-                } else if error_lower.contains("connection") {
-                    self.push_error(
-                        "Cannot connect to centy daemon.\nIs it running? Try: centy daemon start",
+                }
+                self.state.selected_pr_id = None;
+                self.navigate(View::Prs, ViewParams::default());
+            }
+            Err(e) => {
+                self.push_error(format!("Failed to delete PR: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete the selected doc
+    async fn delete_selected_doc(&mut self) -> Result<()> {
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return Ok(());
+        };
+        let Some(slug) = self.state.action_doc().map(|d| d.slug.clone()) else {
+            self.push_error("No doc selected");
+            return Ok(());
+        };
+
+        match self.daemon.delete_doc(&project_path, &slug).await {
+            Ok(_) => {
+                if let Ok(docs) = self.daemon.list_docs(&project_path).await {
+                    self.state.docs = docs;
+                    let max = self.state.docs.len();
+                    if self.state.selected_index >= max && max > 0 {
+                        self.state.selected_index = max - 1;
+                    }
+                }
+                self.state.selected_doc_slug = None;
+                self.navigate(View::Docs, ViewParams::default());
+            }
+            Err(e) => {
+                self.push_error(format!("Failed to delete doc: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle keys in Issue Detail view
+    async fn handle_issue_detail_key(&mut self, key: KeyEvent) -> Result<()> {
+        // The Assets tab has its own list keys
+        if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content)
+            && self.state.issue_detail_tab == IssueDetailTab::Assets
+            && self.handle_asset_list_key(key).await?
+        {
+            return Ok(());
+        }
+
+        // Check for dynamic action shortcut first (when focused on content)
+        if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
+            if let Some(action_idx) = self.find_action_for_key(&key) {
+                self.state.action_panel_selected_index = action_idx;
+                self.execute_selected_dynamic_action().await?;
+                return Ok(());
+            }
+        }
+
+        match key.code {
+            // Tab: Switch focus between content and action panel
+            KeyCode::Tab => {
+                self.state.issue_detail_focus.toggle();
+            }
+            // Switch between the Details and Assets tabs
+            KeyCode::Char('t') => {
+                self.state.issue_detail_tab.toggle();
+                self.state.scroll_offset = 0;
+            }
+            // Edit issue
+            KeyCode::Char('e') => {
+                if let Some(issue_id) = self.state.selected_issue_id.clone() {
+                    // Load issue data into form before navigating
+                    if let Some(issue) =
+                        self.state.issues.iter().find(|i| i.id == issue_id).cloned()
+                    {
+                        self.state.load_issue_to_form(&issue);
+                    }
+                    self.navigate(
+                        View::IssueEdit,
+                        ViewParams {
+                            issue_id: Some(issue_id),
+                            ..Default::default()
+                        },
                     );
+                }
+            }
+            // Navigation (j/k/Up/Down)
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
+                    self.state.scroll_down();
                 } else {
-                    // Clean up nested error prefixes for unknown errors
-                    let user_msg = error_str
-                        .replace("Git error: ", "")
-                        .replace("Worktree error: ", "");
-                    self.push_error(user_msg);
+                    // Navigate down in action panel
+                    self.state.action_panel_down();
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
+                    self.state.scroll_up();
+                } else {
+                    // Navigate up in action panel
+                    self.state.action_panel_up();
+                }
+            }
+            KeyCode::Char('d') | KeyCode::PageDown => {
+                if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
+                    self.state.scroll_down_page();
                 }
             }
+            KeyCode::Char('u') | KeyCode::PageUp => {
+                if matches!(self.state.issue_detail_focus, IssueDetailFocus::Content) {
+                    self.state.scroll_up_page();
+                }
+            }
+            // Execute action (Enter when action panel is focused), otherwise open selected link
+            KeyCode::Enter => {
+                if matches!(self.state.issue_detail_focus, IssueDetailFocus::ActionPanel) {
+                    self.execute_selected_dynamic_action().await?;
+                } else {
+                    self.open_selected_link().await?;
+                }
+            }
+            // Links
+            KeyCode::Char(']') => self.state.select_next_link(),
+            KeyCode::Char('[') => self.state.select_prev_link(),
+            KeyCode::Char('L') => self.start_add_link().await,
+            KeyCode::Char('X') => self.start_remove_link(),
+            // Spawn an agent on this issue, or watch agent activity
+            KeyCode::Char('A') => {
+                let issue = self.state.selected_issue_id.clone().unwrap_or_default();
+                self.open_spawn_dialog(&issue).await;
+            }
+            KeyCode::Char('w') => self.navigate(View::AgentActivity, ViewParams::default()),
+            KeyCode::Char('P') => self.publish_selected_draft().await,
+            // Go back (also reset focus and action panel index)
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.state.issue_detail_focus = IssueDetailFocus::Content;
+                self.state.issue_detail_tab = IssueDetailTab::Details;
+                self.state.action_panel_selected_index = 0;
+                self.go_back();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Update the current issue's status
+    async fn update_issue_status(&mut self, new_status: String) -> Result<()> {
+        let project_path = match &self.state.selected_project_path {
+            Some(path) => path.clone(),
+            None => {
+                self.push_error("No project selected");
+                return Ok(());
+            }
+        };
+
+        let issue_id = match &self.state.selected_issue_id {
+            Some(id) => id.clone(),
+            None => {
+                self.push_error("No issue selected");
+                return Ok(());
+            }
+        };
+
+        // Get current issue data
+        let (title, description, priority) = {
+            let issue = self.state.issues.iter().find(|i| i.id == issue_id);
+            match issue {
+                Some(i) => (i.title.clone(), i.description.clone(), i.metadata.priority),
+                None => {
+                    self.push_error("Issue not found");
+                    return Ok(());
+                }
+            }
+        };
+
+        // Update the issue
+        match self
+            .daemon
+            .update_issue(
+                &project_path,
+                &issue_id,
+                &title,
+                &description,
+                priority,
+                &new_status,
+                None,
+                HashMap::new(),
+            )
+            .await
+        {
+            Ok(_) => {
+                // Refresh issues list
+                self.reload_issues(&project_path).await;
+            }
+            Err(e) => {
+                self.push_error(format!("Failed to update status: {}", e));
+            }
         }
 
         Ok(())
     }
 
-    /// Execute the "Open in Terminal" action
-    async fn execute_open_in_terminal(&mut self) -> Result<()> {
+    /// Publish the selected draft issue
+    async fn publish_selected_draft(&mut self) {
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
+        let Some(issue) = self.state.action_issue() else {
+            self.push_error("No issue selected");
+            return;
+        };
+        if !issue.metadata.draft {
+            self.push_error(format!("#{} is not a draft", issue.display_number));
+            return;
+        }
+        let (issue_id, display_number) = (issue.id.clone(), issue.display_number);
+
+        // Empty fields and priority 0 are left unchanged by the daemon
+        match self
+            .daemon
+            .update_issue(
+                &project_path,
+                &issue_id,
+                "",
+                "",
+                0,
+                "",
+                Some(false),
+                HashMap::new(),
+            )
+            .await
+        {
+            Ok(()) => {
+                self.reload_issues(&project_path).await;
+                self.copy_message = Some(format!("Published #{}", display_number));
+                self.refresh_current_actions().await;
+            }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Update the current PR's status
+    async fn update_pr_status(&mut self, new_status: String) -> Result<()> {
         let project_path = match &self.state.selected_project_path {
             Some(path) => path.clone(),
             None => {
                 self.push_error("No project selected");
                 return Ok(());
             }
-        };
-
-        let issue_id = match &self.state.selected_issue_id {
-            Some(id) => id.clone(),
-            None => {
-                self.push_error("No issue selected");
-                return Ok(());
+        };
+
+        let pr_id = match &self.state.selected_pr_id {
+            Some(id) => id.clone(),
+            None => {
+                self.push_error("No PR selected");
+                return Ok(());
+            }
+        };
+
+        // Get current PR data
+        let (title, description, source_branch, target_branch) = {
+            let pr = self.state.prs.iter().find(|p| p.id == pr_id);
+            match pr {
+                Some(p) => (
+                    p.title.clone(),
+                    p.description.clone(),
+                    p.metadata.source_branch.clone(),
+                    p.metadata.target_branch.clone(),
+                ),
+                None => {
+                    self.push_error("PR not found");
+                    return Ok(());
+                }
+            }
+        };
+
+        // Update the PR
+        match self
+            .daemon
+            .update_pr(
+                &project_path,
+                &pr_id,
+                &title,
+                &description,
+                &source_branch,
+                &target_branch,
+                &new_status,
+                Vec::new(),
+                0,
+                HashMap::new(),
+            )
+            .await
+        {
+            Ok(()) => {
+                // Refresh PRs list
+                if let Ok(prs) = self.daemon.list_prs(&project_path).await {
+                    self.state.prs = prs;
+                }
+            }
+            Err(e) => {
+                self.push_error(format!("Failed to update PR status: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Execute the "Open in VSCode" action
+    async fn execute_open_in_vscode(&mut self) -> Result<()> {
+        let project_path = match &self.state.selected_project_path {
+            Some(path) => path.clone(),
+            None => {
+                self.push_error("No project selected");
+                return Ok(());
+            }
+        };
+
+        let issue_id = match &self.state.selected_issue_id {
+            Some(id) => id.clone(),
+            None => {
+                self.push_error("No issue selected");
+                return Ok(());
+            }
+        };
+
+        let action = self.state.action_panel_llm_action.as_proto_value();
+
+        self.copy_message = Some("Opening in VSCode...".to_string());
+
+        match self
+            .daemon
+            .open_in_temp_vscode(&project_path, &issue_id, action, "", 0)
+            .await
+        {
+            Ok(result) => {
+                if result.vscode_opened {
+                    self.copy_message = Some(format!(
+                        "Opened #{} in VSCode (expires: {})",
+                        result.display_number,
+                        result
+                            .expires_at
+                            .split('T')
+                            .next()
+                            .unwrap_or(&result.expires_at)
+                    ));
+                } else {
+                    self.copy_message =
+                        Some(format!("Workspace created at {}", result.workspace_path));
+                }
+            }
+            Err(e) => {
+                let error_str = e.to_string();
+                let error_lower = error_str.to_lowercase();
+
+                // Check if this is a worktree/folder exists error
+                if error_lower.contains("worktree") || error_lower.contains("already exists") {
+                    // Try to find existing workspace for this issue
+                    if let Ok(workspaces) =
+                        self.daemon.list_temp_workspaces(&project_path, false).await
+                    {
+                        // Find workspace matching this issue
+                        if let Some(existing) = workspaces.into_iter().find(|w| {
+                            w.issue_id == issue_id || w.issue_display_number.to_string() == issue_id
+                        }) {
+                            // Show dialog to let user choose action
+                            self.state.pending_worktree_action = Some(PendingWorktreeAction {
+                                project_path: project_path.clone(),
+                                issue_id: issue_id.clone(),
+                                action,
+                                existing_workspace: existing,
+                                selected_option: WorktreeDialogOption::OpenExisting,
+                            });
+                            self.copy_message = None;
+                            return Ok(());
+                        }
+                    }
+                    // Fallback to error message if we couldn't find existing workspace
+                    self.push_error("Failed to create git worktree.\nTry closing other VS Code windows for this project.");
+                } else if error_str.contains("detached HEAD") {
+                    self.push_error("Repository is in detached HEAD state.\nCheckout a branch first: git checkout <branch>");
+                } else if error_lower.contains("not a git repository") {
+                    self.push_error(
+                        "This project is not a git repository.\nInitialize with: git init",
+                    );
+                } else if error_lower.contains("not found") && error_lower.contains("vscode") {
+                    self.push_error("VS Code not found.\nInstall it and add 'code' to PATH.");
+                } else if error_lower.contains("connection") {
+                    self.push_error(
+                        "Cannot connect to centy daemon.\nIs it running? Try: centy daemon start",
+                    );
+                } else {
+                    // Clean up nested error prefixes for unknown errors
+                    let user_msg = error_str
+                        .replace("Git error: ", "")
+                        .replace("Worktree error: ", "");
+                    self.push_error(user_msg);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Execute the "Open in Terminal" action
+    async fn execute_open_in_terminal(&mut self) -> Result<()> {
+        let project_path = match &self.state.selected_project_path {
+            Some(path) => path.clone(),
+            None => {
+                self.push_error("No project selected");
+                return Ok(());
+            }
+        };
+
+        let issue_id = match &self.state.selected_issue_id {
+            Some(id) => id.clone(),
+            None => {
+                self.push_error("No issue selected");
+                return Ok(());
+            }
+        };
+
+        self.copy_message = Some("Opening in terminal...".to_string());
+
+        match self
+            .daemon
+            .open_agent_in_terminal(&project_path, &issue_id, "", 0, 0)
+            .await
+        {
+            Ok(result) => {
+                if result.terminal_opened {
+                    self.copy_message = Some(format!(
+                        "Opened #{} in terminal with {}",
+                        result.display_number, result.agent_command
+                    ));
+                } else {
+                    self.copy_message =
+                        Some(format!("Agent ready at {}", result.working_directory));
+                }
+            }
+            Err(e) => {
+                self.push_error(format!("Failed to open: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    // =========== Dynamic Actions ===========
+
+    /// Fetch entity actions from daemon
+    pub async fn fetch_entity_actions(&mut self, entity_type: EntityType, entity_id: Option<&str>) {
+        let project_path = match &self.state.selected_project_path {
+            Some(path) => path.clone(),
+            None => return,
+        };
+
+        self.state.actions_loading = true;
+        self.state.actions_error = None;
+
+        match self
+            .daemon
+            .get_entity_actions(&project_path, entity_type, entity_id)
+            .await
+        {
+            Ok(response) => {
+                self.state.current_actions = response;
+                self.state.actions_loading = false;
+                self.state.action_panel_selected_index = 0;
+            }
+            Err(e) => {
+                self.state.actions_error = Some(e.to_string());
+                self.state.actions_loading = false;
+                self.state.current_actions = Default::default();
+            }
+        }
+    }
+
+    /// Refresh actions for current view (and links for detail views)
+    pub async fn refresh_current_actions(&mut self) {
+        match self.state.current_view {
+            View::Issues => {
+                let entity_id = self
+                    .state
+                    .sorted_issues()
+                    .get(self.state.selected_index)
+                    .map(|i| i.id.clone());
+                self.fetch_entity_actions(EntityType::Issue, entity_id.as_deref())
+                    .await;
+            }
+            View::IssueDetail => {
+                let entity_id = self.state.selected_issue_id.clone();
+                self.fetch_entity_actions(EntityType::Issue, entity_id.as_deref())
+                    .await;
+                self.fetch_entity_links(EntityType::Issue, entity_id.as_deref())
+                    .await;
+                self.fetch_assets().await;
+            }
+            View::Prs => {
+                let entity_id = self
+                    .state
+                    .sorted_prs()
+                    .get(self.state.selected_index)
+                    .map(|p| p.id.clone());
+                self.fetch_entity_actions(EntityType::Pr, entity_id.as_deref())
+                    .await;
+            }
+            View::PrDetail => {
+                let entity_id = self.state.selected_pr_id.clone();
+                self.fetch_entity_actions(EntityType::Pr, entity_id.as_deref())
+                    .await;
+                self.fetch_entity_links(EntityType::Pr, entity_id.as_deref())
+                    .await;
+                self.ensure_project_users().await;
+            }
+            View::Docs => {
+                let entity_id = self
+                    .state
+                    .docs
+                    .get(self.state.selected_index)
+                    .map(|d| d.slug.clone());
+                self.fetch_entity_actions(EntityType::Doc, entity_id.as_deref())
+                    .await;
+            }
+            View::DocDetail => {
+                let entity_id = self.state.selected_doc_slug.clone();
+                self.fetch_entity_actions(EntityType::Doc, entity_id.as_deref())
+                    .await;
+                self.fetch_entity_links(EntityType::Doc, entity_id.as_deref())
+                    .await;
+            }
+            View::Assets => self.fetch_assets().await,
+            View::Version => self.fetch_version_info().await,
+            View::Features => self.fetch_features().await,
+            View::Agents => self.fetch_agents().await,
+            View::AgentActivity => self.refresh_llm_work(),
+            View::Workspaces => self.refresh_workspaces().await,
+            View::Daemon => self.fetch_daemon_status().await,
+            View::IssueCreate => {
+                self.state.load_form_templates();
+                self.state.prefill_custom_fields();
+            }
+            View::PrCreate => {
+                self.state.load_form_templates();
+                self.state.prefill_custom_fields();
+                self.ensure_project_users().await;
+            }
+            View::PrEdit => self.ensure_project_users().await,
+            View::People => self.refresh_users().await,
+            View::UserSync => self.sync_users(true).await,
+            _ => {}
+        }
+    }
+
+    // =========== Entity Links ===========
+
+    /// Fetch links and available link types for an entity from daemon
+    pub async fn fetch_entity_links(&mut self, entity_type: EntityType, entity_id: Option<&str>) {
+        let project_path = match &self.state.selected_project_path {
+            Some(path) => path.clone(),
+            None => return,
+        };
+
+        self.state.current_links = match entity_id {
+            Some(id) => match self.daemon.list_links(&project_path, id, entity_type).await {
+                Ok(links) => links,
+                Err(e) => {
+                    // Links from the previous entity would be misleading here
+                    self.push_error(format!("Failed to load links: {}", e));
+                    Vec::new()
+                }
+            },
+            None => Vec::new(),
+        };
+
+        if let Ok(link_types) = self.daemon.get_available_link_types(&project_path).await {
+            self.state.available_link_types = link_types;
+        }
+
+        if self.state.selected_link_index >= self.state.current_links.len() {
+            self.state.selected_link_index = 0;
+        }
+    }
+
+    /// Get the entity shown in the current detail view as a link source
+    fn current_link_source(&self) -> Option<(String, EntityType)> {
+        match self.state.current_view {
+            View::IssueDetail => self
+                .state
+                .selected_issue_id
+                .clone()
+                .map(|id| (id, EntityType::Issue)),
+            View::PrDetail => self
+                .state
+                .selected_pr_id
+                .clone()
+                .map(|id| (id, EntityType::Pr)),
+            View::DocDetail => self
+                .state
+                .selected_doc_slug
+                .clone()
+                .map(|slug| (slug, EntityType::Doc)),
+            _ => None,
+        }
+    }
+
+    /// Start the add link flow for the entity in the current detail view
+    async fn start_add_link(&mut self) {
+        let project_path = match &self.state.selected_project_path {
+            Some(p) => p.clone(),
+            None => {
+                self.push_error("No project selected");
+                return;
+            }
+        };
+
+        let Some((source_id, source_type)) = self.current_link_source() else {
+            self.push_error("Nothing selected to link from");
+            return;
+        };
+
+        if self.state.link_types().is_empty() {
+            self.push_error("No link types available");
+            return;
+        }
+
+        // PRs are not loaded with the project, fetch them so they can be picked as targets
+        if let Ok(prs) = self.daemon.list_prs(&project_path).await {
+            self.state.prs = prs;
+        }
+
+        let source_display = self.state.link_target_display(&source_id, source_type);
+        self.state.pending_link_action = Some(PendingLinkAction {
+            project_path,
+            source_id,
+            source_type,
+            source_display,
+            step: LinkDialogStep::SelectType,
+            selected_type_index: 0,
+            search_filter: String::new(),
+            selected_target_index: 0,
+        });
+    }
+
+    /// Start the remove flow for the selected link (asks for confirmation)
+    fn start_remove_link(&mut self) {
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+        let Some((source_id, source_type)) = self.current_link_source() else {
+            return;
+        };
+        let Some(link) = self.state.selected_link().cloned() else {
+            self.push_error("No link selected");
+            return;
+        };
+
+        let target_display = self
+            .state
+            .link_target_display(&link.target_id, link.target_type);
+        self.state.pending_unlink_action = Some(PendingUnlinkAction {
+            project_path,
+            source_id,
+            source_type,
+            link,
+            target_display,
+        });
+    }
+
+    /// Navigate to the entity targeted by the selected link
+    async fn open_selected_link(&mut self) -> Result<()> {
+        let Some(link) = self.state.selected_link().cloned() else {
+            return Ok(());
+        };
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            return Ok(());
+        };
+
+        let view_before = self.state.current_view.clone();
+        let target_id = link.target_id.clone();
+
+        match link.target_type {
+            EntityType::Issue => {
+                if !self.state.issues.iter().any(|i| i.id == target_id) {
+                    self.reload_issues(&project_path).await;
+                }
+                if !self.state.issues.iter().any(|i| i.id == target_id) {
+                    self.push_error("Linked issue not found");
+                    return Ok(());
+                }
+                self.state.selected_issue_id = Some(target_id.clone());
+                self.navigate(
+                    View::IssueDetail,
+                    ViewParams {
+                        issue_id: Some(target_id),
+                        ..Default::default()
+                    },
+                );
+            }
+            EntityType::Pr => {
+                if !self.state.prs.iter().any(|p| p.id == target_id) {
+                    if let Ok(prs) = self.daemon.list_prs(&project_path).await {
+                        self.state.prs = prs;
+                    }
+                }
+                if !self.state.prs.iter().any(|p| p.id == target_id) {
+                    self.push_error("Linked PR not found");
+                    return Ok(());
+                }
+                self.state.selected_pr_id = Some(target_id.clone());
+                self.navigate(
+                    View::PrDetail,
+                    ViewParams {
+                        pr_id: Some(target_id),
+                        ..Default::default()
+                    },
+                );
+            }
+            EntityType::Doc => {
+                if !self.state.docs.iter().any(|d| d.slug == target_id) {
+                    if let Ok(docs) = self.daemon.list_docs(&project_path).await {
+                        self.state.docs = docs;
+                    }
+                }
+                if !self.state.docs.iter().any(|d| d.slug == target_id) {
+                    self.push_error("Linked doc not found");
+                    return Ok(());
+                }
+                self.state.selected_doc_slug = Some(target_id.clone());
+                self.navigate(
+                    View::DocDetail,
+                    ViewParams {
+                        doc_slug: Some(target_id),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
+        self.state.scroll_offset = 0;
+        self.state.selected_link_index = 0;
+
+        // Jumping between two entities of the same kind keeps the view, so refresh here
+        if view_before == self.state.current_view {
+            self.refresh_current_actions().await;
+        }
+
+        Ok(())
+    }
+
+    /// Handle keys for the add link dialog
+    async fn handle_link_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        let step = self
+            .state
+            .pending_link_action
+            .as_ref()
+            .map(|a| a.step)
+            .unwrap_or_default();
+
+        match step {
+            LinkDialogStep::SelectType => {
+                let type_count = self.state.link_types().len();
+                match key.code {
+                    KeyCode::Esc => {
+                        self.state.pending_link_action = None;
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        if let Some(ref mut action) = self.state.pending_link_action {
+                            action.selected_type_index =
+                                action.selected_type_index.saturating_sub(1);
+                        }
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        if let Some(ref mut action) = self.state.pending_link_action {
+                            if action.selected_type_index < type_count.saturating_sub(1) {
+                                action.selected_type_index += 1;
+                            }
+                        }
+                    }
+                    KeyCode::Enter | KeyCode::Tab => {
+                        if let Some(ref mut action) = self.state.pending_link_action {
+                            action.step = LinkDialogStep::SelectTarget;
+                            action.selected_target_index = 0;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            LinkDialogStep::SelectTarget => {
+                let target_count = self.get_filtered_link_targets().len();
+                match key.code {
+                    KeyCode::Esc | KeyCode::BackTab => {
+                        // Go back to link type picker
+                        if let Some(ref mut action) = self.state.pending_link_action {
+                            action.step = LinkDialogStep::SelectType;
+                        }
+                    }
+                    KeyCode::Up => {
+                        if let Some(ref mut action) = self.state.pending_link_action {
+                            action.selected_target_index =
+                                action.selected_target_index.saturating_sub(1);
+                        }
+                    }
+                    KeyCode::Down => {
+                        if let Some(ref mut action) = self.state.pending_link_action {
+                            if action.selected_target_index < target_count.saturating_sub(1) {
+                                action.selected_target_index += 1;
+                            }
+                        }
+                    }
+                    KeyCode::Char(c) => {
+                        if let Some(ref mut action) = self.state.pending_link_action {
+                            action.search_filter.push(c);
+                            action.selected_target_index = 0;
+                        }
+                    }
+                    KeyCode::Backspace => {
+                        if let Some(ref mut action) = self.state.pending_link_action {
+                            action.search_filter.pop();
+                            action.selected_target_index = 0;
+                        }
+                    }
+                    KeyCode::Enter => {
+                        self.execute_create_link().await;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Get link targets matching the add link dialog's search filter
+    fn get_filtered_link_targets(&self) -> Vec<crate::state::LinkTarget> {
+        match &self.state.pending_link_action {
+            Some(action) => self
+                .state
+                .link_target_candidates(&action.source_id, &action.search_filter),
+            None => Vec::new(),
+        }
+    }
+
+    /// Create the link chosen in the add link dialog
+    async fn execute_create_link(&mut self) {
+        let Some(action) = self.state.pending_link_action.clone() else {
+            return;
+        };
+        let Some(link_type) = self
+            .state
+            .link_types()
+            .get(action.selected_type_index)
+            .map(|t| t.name.clone())
+        else {
+            return;
+        };
+        let Some(target) = self
+            .get_filtered_link_targets()
+            .into_iter()
+            .nth(action.selected_target_index)
+        else {
+            return;
+        };
+
+        self.state.pending_link_action = None;
+
+        match self
+            .daemon
+            .create_link(
+                &action.project_path,
+                &action.source_id,
+                action.source_type,
+                &target.id,
+                target.entity_type,
+                &link_type,
+            )
+            .await
+        {
+            Ok(()) => {
+                self.copy_message = Some(format!("Linked: {} {}", link_type, target.display));
+                self.fetch_entity_links(action.source_type, Some(&action.source_id))
+                    .await;
+            }
+            Err(e) => {
+                self.push_error(e.to_string());
+            }
+        }
+    }
+
+    /// Handle keys for the unlink confirmation dialog
+    async fn handle_unlink_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
+                self.state.pending_unlink_action = None;
+            }
+            KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
+                if let Some(action) = self.state.pending_unlink_action.take() {
+                    match self
+                        .daemon
+                        .delete_link(
+                            &action.project_path,
+                            &action.source_id,
+                            action.source_type,
+                            &action.link.target_id,
+                            action.link.target_type,
+                            &action.link.link_type,
+                        )
+                        .await
+                    {
+                        Ok(_) => {
+                            self.copy_message = Some(format!(
+                                "Removed link: {} {}",
+                                action.link.link_type, action.target_display
+                            ));
+                            self.fetch_entity_links(action.source_type, Some(&action.source_id))
+                                .await;
+                        }
+                        Err(e) => {
+                            self.push_error(e.to_string());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // =========== Assets ===========
+
+    /// Issue owning the asset list on screen (None for the shared library)
+    fn asset_issue_id(&self) -> Option<String> {
+        if self.state.current_view == View::IssueDetail {
+            self.state.selected_issue_id.clone()
+        } else {
+            None
+        }
+    }
+
+    /// Issue owning an existing asset (None for shared assets)
+    fn asset_owner(&self, asset: &Asset) -> Option<String> {
+        if asset.is_shared {
+            None
+        } else {
+            self.asset_issue_id()
+        }
+    }
+
+    /// Fetch the issue's assets (shared ones included), or the shared library outside issue detail
+    async fn fetch_assets(&mut self) {
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+
+        let assets = match self.asset_issue_id() {
+            Some(issue_id) => self.daemon.list_assets(&project_path, &issue_id).await,
+            None => self.daemon.list_shared_assets(&project_path).await,
+        };
+        match assets {
+            Ok(assets) => self.state.set_assets(assets),
+            Err(e) => self.push_error(format!("Failed to load assets: {}", e)),
+        }
+    }
+
+    /// Handle keys in Shared Assets view
+    async fn handle_assets_key(&mut self, key: KeyEvent) -> Result<()> {
+        if self.handle_asset_list_key(key).await? {
+            return Ok(());
+        }
+
+        match key.code {
+            KeyCode::Char('2') => self.navigate(View::Issues, ViewParams::default()),
+            KeyCode::Char('3') => self.navigate(View::Prs, ViewParams::default()),
+            KeyCode::Char('4') => self.navigate(View::Docs, ViewParams::default()),
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle asset list keys shared by the issue Assets tab and the shared library.
+    /// Returns true if the key was handled.
+    async fn handle_asset_list_key(&mut self, key: KeyEvent) -> Result<bool> {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.state.select_next_asset(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.select_prev_asset(),
+            // Scroll the preview
+            KeyCode::Char('d') | KeyCode::PageDown => self.state.scroll_down_page(),
+            KeyCode::Char('u') | KeyCode::PageUp => self.state.scroll_up_page(),
+            KeyCode::Enter => self.toggle_asset_preview().await,
+            KeyCode::Char('a') => {
+                let issue_id = self.asset_issue_id();
+                self.state.pending_asset_action = Some(PendingAssetAction::Attach {
+                    path: String::new(),
+                    shared: issue_id.is_none(),
+                    issue_id,
+                });
+            }
+            KeyCode::Char('s') => {
+                if let Some(asset) = self.state.selected_asset().cloned() {
+                    let path = asset.filename.clone();
+                    self.state.pending_asset_action =
+                        Some(PendingAssetAction::Save { asset, path });
+                }
+            }
+            KeyCode::Char('x') => {
+                if let Some(asset) = self.state.selected_asset().cloned() {
+                    self.state.pending_asset_action = Some(PendingAssetAction::Delete { asset });
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Show or hide the inline preview of the selected text asset
+    async fn toggle_asset_preview(&mut self) {
+        let Some(asset) = self.state.selected_asset().cloned() else {
+            return;
+        };
+        if self
+            .state
+            .asset_preview
+            .as_ref()
+            .is_some_and(|p| p.filename == asset.filename)
+        {
+            self.state.asset_preview = None;
+            return;
+        }
+        if !asset.is_text() {
+            self.copy_message = Some(format!(
+                "No preview for {} ({})",
+                asset.filename, asset.mime_type
+            ));
+            return;
+        }
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+
+        let issue_id = self.asset_owner(&asset);
+        match self
+            .daemon
+            .get_asset(&project_path, issue_id.as_deref(), &asset.filename)
+            .await
+        {
+            Ok(data) => {
+                self.state.asset_preview = Some(AssetPreview {
+                    filename: asset.filename,
+                    content: String::from_utf8_lossy(&data).into_owned(),
+                });
+                self.state.scroll_offset = 0;
+            }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Handle keys in the asset dialog (path input or delete confirmation)
+    async fn handle_asset_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(action) = self.state.pending_asset_action.as_mut() else {
+            return Ok(());
+        };
+
+        match action {
+            PendingAssetAction::Delete { asset } => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    let asset = asset.clone();
+                    self.state.pending_asset_action = None;
+                    self.delete_asset(&asset).await;
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state.pending_asset_action = None;
+                }
+                _ => {}
+            },
+            PendingAssetAction::Overwrite { asset, target } => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    let (asset, target) = (asset.clone(), PathBuf::from(target.as_str()));
+                    self.state.pending_asset_action = None;
+                    self.save_asset(&asset, &target).await;
+                }
+                // Back to the path input to pick another name
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    let (asset, path) = (asset.clone(), target.clone());
+                    self.state.pending_asset_action =
+                        Some(PendingAssetAction::Save { asset, path });
+                }
+                _ => {}
+            },
+            // Switch between the issue and the shared library
+            PendingAssetAction::Attach {
+                issue_id: Some(_),
+                shared,
+                ..
+            } if key.code == KeyCode::Char('t')
+                && key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                *shared = !*shared;
+            }
+            PendingAssetAction::Attach { path, .. } | PendingAssetAction::Save { path, .. } => {
+                match key.code {
+                    KeyCode::Esc => self.state.pending_asset_action = None,
+                    KeyCode::Tab => {
+                        if let Some(completed) = complete_path(path) {
+                            *path = completed;
+                        }
+                    }
+                    KeyCode::Backspace => {
+                        path.pop();
+                    }
+                    KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        path.push(c);
+                    }
+                    KeyCode::Enter => {
+                        if let Some(action) = self.state.pending_asset_action.take() {
+                            match action {
+                                PendingAssetAction::Attach {
+                                    path,
+                                    issue_id,
+                                    shared,
+                                } => {
+                                    let issue_id = issue_id.filter(|_| !shared);
+                                    self.attach_asset(&path, issue_id.as_deref()).await
+                                }
+                                PendingAssetAction::Save { asset, path } => {
+                                    let target = asset_save_target(&asset, &path);
+                                    if target.exists() {
+                                        self.state.pending_asset_action =
+                                            Some(PendingAssetAction::Overwrite {
+                                                asset,
+                                                target: target.display().to_string(),
+                                            });
+                                    } else {
+                                        self.save_asset(&asset, &target).await
+                                    }
+                                }
+                                PendingAssetAction::Overwrite { .. }
+                                | PendingAssetAction::Delete { .. } => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Read a local file and attach it to the issue (None for the shared library)
+    async fn attach_asset(&mut self, path: &str, issue_id: Option<&str>) {
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
+
+        let file_path = expand_tilde(path.trim());
+        let Some(filename) = file_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(str::to_string)
+        else {
+            self.push_error(format!("Not a file: {}", path));
+            return;
+        };
+        let data = match std::fs::read(&file_path) {
+            Ok(data) => data,
+            Err(e) => {
+                self.push_error(format!("Failed to read {}: {}", file_path.display(), e));
+                return;
+            }
+        };
+
+        match self
+            .daemon
+            .add_asset(&project_path, issue_id, &filename, data)
+            .await
+        {
+            Ok(asset) => {
+                self.copy_message = Some(format!("Attached {}", asset.filename));
+                self.fetch_assets().await;
+                if let Some(idx) = self
+                    .state
+                    .assets
+                    .iter()
+                    .position(|a| a.filename == asset.filename)
+                {
+                    self.state.selected_asset_index = idx;
+                }
+            }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Download an asset and write it to `target` (already confirmed if it exists)
+    async fn save_asset(&mut self, asset: &Asset, target: &Path) {
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
+
+        let issue_id = self.asset_owner(asset);
+        let data = match self
+            .daemon
+            .get_asset(&project_path, issue_id.as_deref(), &asset.filename)
+            .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                self.push_error(e.to_string());
+                return;
+            }
+        };
+
+        match std::fs::write(target, data) {
+            Ok(()) => self.copy_message = Some(format!("Saved to {}", target.display())),
+            Err(e) => self.push_error(format!("Failed to write {}: {}", target.display(), e)),
+        }
+    }
+
+    /// Delete an asset and refresh the list
+    async fn delete_asset(&mut self, asset: &Asset) {
+        let Some(project_path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
+
+        let issue_id = self.asset_owner(asset);
+        match self
+            .daemon
+            .delete_asset(&project_path, issue_id.as_deref(), &asset.filename)
+            .await
+        {
+            Ok(()) => {
+                self.copy_message = Some(format!("Deleted {}", asset.filename));
+                self.fetch_assets().await;
+            }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    // =========== Search ===========
+
+    /// Open the search view with the query input focused
+    fn open_search(&mut self) {
+        self.state.search_focus = SearchFocus::Query;
+        self.navigate(View::Search, ViewParams::default());
+    }
+
+    /// Handle keys in Search view
+    async fn handle_search_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Ctrl+A toggles the scope from either focus and re-runs the search
+        if key.code == KeyCode::Char('a') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.state.toggle_search_scope();
+            self.run_search().await;
+            return Ok(());
+        }
+
+        match self.state.search_focus {
+            SearchFocus::Query => match key.code {
+                KeyCode::Enter => {
+                    self.run_search().await;
+                    if !self.state.search_results.is_empty() {
+                        self.state.search_focus = SearchFocus::Results;
+                    }
+                }
+                KeyCode::Tab | KeyCode::Down if !self.state.search_results.is_empty() => {
+                    self.state.search_focus = SearchFocus::Results;
+                }
+                KeyCode::Backspace => {
+                    self.state.search_query.pop();
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.state.search_query.push(c);
+                }
+                KeyCode::Esc => self.go_back(),
+                _ => {}
+            },
+            SearchFocus::Results => match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.state.select_next_search_result(),
+                KeyCode::Char('k') | KeyCode::Up => self.state.select_prev_search_result(),
+                KeyCode::Enter => self.open_selected_search_result().await,
+                KeyCode::Char('a') => {
+                    self.state.toggle_search_scope();
+                    self.run_search().await;
+                }
+                KeyCode::Tab | KeyCode::Char('/') => {
+                    self.state.search_focus = SearchFocus::Query;
+                }
+                KeyCode::Esc | KeyCode::Backspace => {
+                    self.state.search_focus = SearchFocus::Query;
+                    self.go_back();
+                }
+                _ => {}
+            },
+        }
+        Ok(())
+    }
+
+    /// Handle mouse events in Search view
+    async fn handle_search_mouse(&mut self, mouse: MouseEvent) -> Result<()> {
+        // Query box (3 rows), scope/parsed header (2 rows), then the results border
+        const QUERY_END_Y: u16 = CONTEXT_BAR_HEIGHT + 3;
+        const RESULTS_START_Y: u16 = QUERY_END_Y + 2 + 1;
+
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.state.select_prev_search_result(),
+            MouseEventKind::ScrollDown => self.state.select_next_search_result(),
+            MouseEventKind::Down(MouseButton::Left) => {
+                if mouse.row < QUERY_END_Y {
+                    self.state.search_focus = SearchFocus::Query;
+                } else if mouse.row >= RESULTS_START_Y {
+                    let clicked_index = (mouse.row - RESULTS_START_Y) as usize;
+                    if clicked_index < self.state.search_results.len() {
+                        // Clicking the already selected result opens it
+                        let is_reclick = self.state.search_focus == SearchFocus::Results
+                            && self.state.selected_search_index == clicked_index;
+                        self.state.search_focus = SearchFocus::Results;
+                        self.state.selected_search_index = clicked_index;
+                        if is_reclick {
+                            self.open_selected_search_result().await;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Run the advanced search for the current query and scope
+    async fn run_search(&mut self) {
+        let query = self.state.search_query.trim().to_string();
+        if query.is_empty() {
+            return;
+        }
+
+        let multi_project = self.state.search_spans_all_projects();
+        let project_path = if multi_project {
+            None
+        } else {
+            self.state.selected_project_path.clone()
+        };
+
+        match self
+            .daemon
+            .advanced_search(&query, multi_project, project_path.as_deref())
+            .await
+        {
+            Ok(results) => self.state.set_search_results(
+                results.results,
+                results.total_count,
+                results.parsed_query,
+            ),
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Open the selected search result in its owning project
+    async fn open_selected_search_result(&mut self) {
+        let Some(result) = self.state.selected_search_result().cloned() else {
+            return;
+        };
+
+        self.open_jump_match(JumpMatch {
+            target: JumpTarget::Issue(result.issue),
+            project_path: result.project_path,
+            project_name: result.project_name,
+            display_path: result.display_path,
+        })
+        .await;
+    }
+
+    // =========== Jump Prompt ===========
+
+    /// Handle keys in the jump prompt
+    async fn handle_jump_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(action) = self.state.pending_jump_action.as_mut() else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                if action.searched {
+                    // Back from the match list to editing the input
+                    action.searched = false;
+                    action.matches.clear();
+                    action.errors.clear();
+                    action.selected_index = 0;
+                } else {
+                    self.state.pending_jump_action = None;
+                }
+            }
+            KeyCode::Up if action.searched => {
+                action.selected_index = action.selected_index.saturating_sub(1);
+            }
+            KeyCode::Down
+                if action.searched && action.selected_index + 1 < action.matches.len() =>
+            {
+                action.selected_index += 1;
+            }
+            KeyCode::Enter => {
+                if action.searched {
+                    if let Some(selected) = action.matches.get(action.selected_index).cloned() {
+                        self.state.pending_jump_action = None;
+                        self.open_jump_match(selected).await;
+                        self.refresh_current_actions().await;
+                    }
+                } else {
+                    self.resolve_jump().await;
+                }
+            }
+            KeyCode::Backspace => {
+                action.input.pop();
+                action.searched = false;
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                action.input.push(c);
+                action.searched = false;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Resolve the jump prompt input, opening a single match directly
+    async fn resolve_jump(&mut self) {
+        let Some(input) = self
+            .state
+            .pending_jump_action
+            .as_ref()
+            .map(|a| a.input.clone())
+        else {
+            return;
+        };
+        let Some(query) = JumpQuery::parse(&input) else {
+            return;
+        };
+
+        let mut matches = Vec::new();
+        let mut errors = Vec::new();
+        match query {
+            JumpQuery::Uuid(uuid) => {
+                match self.daemon.get_issues_by_uuid(&uuid).await {
+                    Ok((found, lookup_errors)) => {
+                        matches.extend(found);
+                        errors.extend(lookup_errors);
+                    }
+                    Err(e) => errors.push(e.to_string()),
+                }
+                match self.daemon.get_prs_by_uuid(&uuid).await {
+                    Ok((found, lookup_errors)) => {
+                        matches.extend(found);
+                        errors.extend(lookup_errors);
+                    }
+                    Err(e) => errors.push(e.to_string()),
+                }
+            }
+            JumpQuery::DisplayNumber(number) => {
+                if let Some(path) = self.state.selected_project_path.clone() {
+                    let project_name = self
+                        .state
+                        .projects
+                        .iter()
+                        .find(|p| p.path == path)
+                        .map(|p| p.display_name().to_string())
+                        .unwrap_or_else(|| path.clone());
+                    // A missing number is reported as an error by the daemon, so
+                    // lookup failures simply mean "no match" here
+                    if let Ok(issue) = self.daemon.get_issue_by_display_number(&path, number).await
+                    {
+                        matches.push(JumpMatch {
+                            target: JumpTarget::Issue(issue),
+                            project_path: path.clone(),
+                            project_name: project_name.clone(),
+                            display_path: path.clone(),
+                        });
+                    }
+                    if let Ok(pr) = self.daemon.get_pr_by_display_number(&path, number).await {
+                        matches.push(JumpMatch {
+                            target: JumpTarget::Pr(pr),
+                            project_path: path.clone(),
+                            project_name,
+                            display_path: path,
+                        });
+                    }
+                } else {
+                    errors.push("Open a project to jump by #number".to_string());
+                }
+            }
+            JumpQuery::Slug(slug) => match self.daemon.get_docs_by_slug(&slug).await {
+                Ok((found, lookup_errors)) => {
+                    matches.extend(found);
+                    errors.extend(lookup_errors);
+                }
+                Err(e) => errors.push(e.to_string()),
+            },
+        }
+
+        // A single match with nothing to report opens directly
+        if matches.len() == 1 && errors.is_empty() {
+            self.state.pending_jump_action = None;
+            self.open_jump_match(matches.remove(0)).await;
+            self.refresh_current_actions().await;
+            return;
+        }
+
+        if let Some(action) = self.state.pending_jump_action.as_mut() {
+            action.matches = matches;
+            action.errors = errors;
+            action.selected_index = 0;
+            action.searched = true;
+        }
+    }
+
+    /// Select the owning project of a match and navigate to its detail view
+    async fn open_jump_match(&mut self, found: JumpMatch) {
+        if self.state.selected_project_path.as_deref() != Some(found.project_path.as_str()) {
+            self.open_project(&found.project_path).await;
+        }
+
+        // Fall back to the looked-up copy if the project lists don't have it yet
+        let (view, params) = match found.target {
+            JumpTarget::Issue(issue) => {
+                let id = issue.id.clone();
+                if !self.state.issues.iter().any(|i| i.id == id) {
+                    self.state.issues.push(issue);
+                }
+                self.state.selected_issue_id = Some(id.clone());
+                (
+                    View::IssueDetail,
+                    ViewParams {
+                        issue_id: Some(id),
+                        ..Default::default()
+                    },
+                )
+            }
+            JumpTarget::Pr(pr) => {
+                let id = pr.id.clone();
+                if !self.state.prs.iter().any(|p| p.id == id) {
+                    self.state.prs.push(pr);
+                }
+                self.state.selected_pr_id = Some(id.clone());
+                (
+                    View::PrDetail,
+                    ViewParams {
+                        pr_id: Some(id),
+                        ..Default::default()
+                    },
+                )
+            }
+            JumpTarget::Doc(doc) => {
+                let slug = doc.slug.clone();
+                if !self.state.docs.iter().any(|d| d.slug == slug) {
+                    self.state.docs.push(doc);
+                }
+                self.state.selected_doc_slug = Some(slug.clone());
+                (
+                    View::DocDetail,
+                    ViewParams {
+                        doc_slug: Some(slug),
+                        ..Default::default()
+                    },
+                )
+            }
+        };
+
+        self.state.scroll_offset = 0;
+        self.state.selected_link_index = 0;
+        self.navigate(view, params);
+    }
+
+    /// Check if a key event matches an action's keyboard shortcut
+    fn key_matches_shortcut(key: &KeyEvent, shortcut: &str) -> bool {
+        if shortcut.is_empty() {
+            return false;
+        }
+
+        // Parse shortcut format: "n", "d", "Ctrl+D", "Shift+N", "Enter"
+        let parts: Vec<&str> = shortcut.split('+').collect();
+
+        let (expected_modifiers, key_str) = if parts.len() == 2 {
+            let mods = match parts[0].to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "shift" => KeyModifiers::SHIFT,
+                "alt" => KeyModifiers::ALT,
+                _ => KeyModifiers::NONE,
+            };
+            (mods, parts[1])
+        } else {
+            (KeyModifiers::NONE, parts[0])
+        };
+
+        // Check modifiers
+        if key.modifiers != expected_modifiers {
+            return false;
+        }
+
+        // Check key
+        match key_str.to_lowercase().as_str() {
+            "enter" => matches!(key.code, KeyCode::Enter),
+            "esc" | "escape" => matches!(key.code, KeyCode::Esc),
+            "tab" => matches!(key.code, KeyCode::Tab),
+            "backspace" => matches!(key.code, KeyCode::Backspace),
+            "delete" => matches!(key.code, KeyCode::Delete),
+            s if s.len() == 1 => {
+                if let Some(c) = s.chars().next() {
+                    matches!(key.code, KeyCode::Char(k) if k.to_ascii_lowercase() == c)
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Find action matching a key event
+    fn find_action_for_key(&self, key: &KeyEvent) -> Option<usize> {
+        self.state
+            .current_actions
+            .actions
+            .iter()
+            .position(|a| Self::key_matches_shortcut(key, &a.keyboard_shortcut))
+    }
+
+    /// Execute the currently selected dynamic action
+    pub async fn execute_selected_dynamic_action(&mut self) -> Result<()> {
+        let action = match self.state.selected_action() {
+            Some(a) => a.clone(),
+            None => return Ok(()),
+        };
+
+        if !action.enabled {
+            self.push_error(action.disabled_reason.clone());
+            return Ok(());
+        }
+
+        // Destructive actions wait for an explicit confirm key
+        if action.destructive {
+            match self.state.action_target() {
+                Some((entity, target)) => {
+                    self.state.pending_confirm_action = Some(PendingConfirmAction {
+                        title: format!("{} {}", action.label, entity),
+                        target,
+                        detail: "This cannot be undone.".to_string(),
+                        kind: ConfirmKind::EntityAction(action),
+                    });
+                }
+                None => self.push_error(format!("{}: nothing selected", action.label)),
+            }
+            return Ok(());
+        }
+
+        self.run_entity_action(action).await
+    }
+
+    /// Handle keys for the destructive confirmation dialog
+    async fn handle_confirm_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => self.run_pending_confirm_action().await?,
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.state.pending_confirm_action = None;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle clicks on the confirmation dialog buttons (everything else is ignored)
+    async fn handle_confirm_dialog_mouse(&mut self, mouse: MouseEvent) -> Result<()> {
+        if !matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) {
+            return Ok(());
+        }
+        let (height, width) = self.terminal_size.unwrap_or((24, 80));
+        let (confirm, cancel) =
+            crate::ui::components::confirm_dialog_buttons(Rect::new(0, 0, width, height));
+        let pos = Position::new(mouse.column, mouse.row);
+        if confirm.contains(pos) {
+            self.run_pending_confirm_action().await?;
+        } else if cancel.contains(pos) {
+            self.state.pending_confirm_action = None;
+        }
+        Ok(())
+    }
+
+    /// Run the confirmed destructive action
+    async fn run_pending_confirm_action(&mut self) -> Result<()> {
+        let Some(pending) = self.state.pending_confirm_action.take() else {
+            return Ok(());
+        };
+        match pending.kind {
+            ConfirmKind::EntityAction(action) => self.run_entity_action(action).await?,
+            ConfirmKind::UntrackProject { path } => self.untrack_project(&path).await,
+            ConfirmKind::DaemonControl(control) => self.run_daemon_control(control).await,
+            ConfirmKind::MigrateProject { path, version } => {
+                self.migrate_project_version(&path, &version).await
+            }
+            ConfirmKind::DeleteUser {
+                project_path,
+                user_id,
+            } => self.delete_user(&project_path, &user_id).await,
+        }
+        Ok(())
+    }
+
+    /// Remove a project from tracking, keeping the selection on a neighboring card
+    async fn untrack_project(&mut self, path: &str) {
+        let neighbor = self.state.neighbor_project_path(path);
+        if let Err(e) = self.daemon.untrack_project(path).await {
+            self.push_error(format!("Failed to untrack project: {}", e));
+            return;
+        }
+
+        if self.state.selected_project_path.as_deref() == Some(path) {
+            self.state.selected_project_path = None;
+        }
+        self.reload_projects(&[neighbor]).await;
+        self.copy_message = Some(format!("Untracked {}", path));
+    }
+
+    /// Archive the selected project, or unarchive it if it's already archived
+    async fn toggle_selected_archived(&mut self) {
+        let Some((path, archived)) = self
+            .state
+            .selectable_projects()
+            .get(self.state.selected_index)
+            .map(|p| (p.path.clone(), p.is_archived))
+        else {
+            return;
+        };
+
+        let neighbor = self.state.neighbor_project_path(&path);
+        if let Err(e) = self.daemon.set_project_archived(&path, !archived).await {
+            self.push_error(format!("Failed to update project: {}", e));
+            return;
+        }
+
+        // Stay on the card if it's still listed, else move to its neighbor
+        self.reload_projects(&[Some(path.clone()), neighbor]).await;
+        self.copy_message = Some(if archived {
+            format!("Unarchived {}", path)
+        } else if self.state.project_filter.include_archived {
+            format!("Archived {}", path)
+        } else {
+            format!("Archived {} (A shows archived projects)", path)
+        });
+    }
+
+    /// Flip one of the Projects grid toggles and reload, keeping the selected card
+    async fn toggle_project_filter(&mut self, flag: fn(&mut ProjectListFilter) -> &mut bool) {
+        let selected = self
+            .state
+            .selectable_projects()
+            .get(self.state.selected_index)
+            .map(|p| p.path.clone());
+        let value = flag(&mut self.state.project_filter);
+        *value = !*value;
+        self.reload_projects(&[selected]).await;
+    }
+
+    /// Reload the projects grid and select the first candidate that is still listed
+    async fn reload_projects(&mut self, candidates: &[Option<String>]) {
+        if let Ok(projects) = self.daemon.list_projects(self.state.project_filter).await {
+            self.state.projects = projects;
+        }
+        let found = candidates
+            .iter()
+            .flatten()
+            .any(|path| self.state.select_project_by_path(path));
+        if found {
+            let columns = self.calculate_project_grid_columns();
+            let visible_height = self.calculate_project_grid_visible_height();
+            self.state.ensure_selected_visible(columns, visible_height);
+        } else {
+            self.state.reset_selection();
+        }
+    }
+
+    /// Run an entity action (after any confirmation)
+    async fn run_entity_action(&mut self, action: EntityAction) -> Result<()> {
+        // Route to appropriate handler based on action ID
+        // Action IDs from daemon are generic (e.g., "create", "delete")
+        // We determine the entity type from the current view
+        match action.id.as_str() {
+            // Create action - contextual based on current view
+            "create" => match self.state.current_view {
+                View::Issues | View::IssueDetail => {
+                    self.navigate(View::IssueCreate, ViewParams::default());
+                }
+                View::Prs | View::PrDetail => {
+                    self.navigate(View::PrCreate, ViewParams::default());
+                }
+                View::Docs | View::DocDetail => {
+                    self.navigate(View::DocCreate, ViewParams::default());
+                }
+                _ => {}
+            },
+
+            // Delete action - contextual based on current view
+            "delete" => match self.state.current_view {
+                View::Issues | View::IssueDetail => {
+                    self.delete_selected_issue().await?;
+                }
+                View::Prs | View::PrDetail => {
+                    self.delete_selected_pr().await?;
+                }
+                View::Docs | View::DocDetail => {
+                    self.delete_selected_doc().await?;
+                }
+                _ => {}
+            },
+
+            // Duplicate action - contextual based on current view
+            "duplicate" => match self.state.current_view {
+                View::Issues | View::IssueDetail => {
+                    self.start_move_issue(MoveMode::Duplicate);
+                }
+                View::Docs | View::DocDetail => {
+                    self.start_move_doc(MoveMode::Duplicate);
+                }
+                _ => {}
+            },
+
+            // Move action - contextual based on current view
+            "move" => match self.state.current_view {
+                View::Issues | View::IssueDetail => {
+                    self.start_move_issue(MoveMode::Move);
+                }
+                View::Docs | View::DocDetail => {
+                    self.start_move_doc(MoveMode::Move);
+                }
+                _ => {}
+            },
+
+            // Mode actions (Issue-specific) - with "in progress" confirmation
+            "mode:plan" => {
+                if self.should_show_start_work_dialog() {
+                    self.state.pending_start_work_action = Some(PendingStartWorkAction {
+                        action_id: "mode:plan".to_string(),
+                        action_label: "Plan Mode".to_string(),
+                    });
+                } else {
+                    self.state.action_panel_llm_action = LlmAction::Plan;
+                }
+            }
+            "mode:implement" => {
+                if self.should_show_start_work_dialog() {
+                    self.state.pending_start_work_action = Some(PendingStartWorkAction {
+                        action_id: "mode:implement".to_string(),
+                        action_label: "Implement Mode".to_string(),
+                    });
+                } else {
+                    self.state.action_panel_llm_action = LlmAction::Implement;
+                }
+            }
+
+            // External actions - with "in progress" confirmation
+            "open_in_vscode" => {
+                if self.should_show_start_work_dialog() {
+                    self.state.pending_start_work_action = Some(PendingStartWorkAction {
+                        action_id: "open_in_vscode".to_string(),
+                        action_label: "Open in VS Code".to_string(),
+                    });
+                } else {
+                    self.execute_open_in_vscode().await?;
+                }
+            }
+            "open_in_terminal" => {
+                self.execute_open_in_terminal().await?;
+            }
+
+            // Status transitions (dynamic, e.g., "status:open", "status:closed")
+            id if id.starts_with("status:") => {
+                let new_status = id.strip_prefix("status:").unwrap_or("");
+                match self.state.current_view {
+                    View::Issues | View::IssueDetail => {
+                        self.update_issue_status(new_status.to_string()).await?;
+                    }
+                    View::Prs | View::PrDetail => {
+                        self.update_pr_status(new_status.to_string()).await?;
+                    }
+                    _ => {}
+                }
+            }
+
+            _ => {
+                self.push_error(format!("Unknown action: {}", action.id));
+            }
+        }
+
+        // Refresh actions after execution (state may have changed)
+        self.refresh_current_actions().await;
+
+        Ok(())
+    }
+
+    /// Handle keys in Issue Create view
+    async fn handle_issue_create_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Check if we're on the action panel (after the custom fields)
+        let on_action_panel =
+            self.state.active_form_field == self.state.issue_create_buttons_field();
+        let on_template = self.state.on_template_field();
+        let on_typed_field = self.state.on_typed_form_field();
+
+        match key.code {
+            KeyCode::Tab => self.state.next_form_field(),
+            KeyCode::BackTab => self.state.prev_form_field(),
+            // The template field is a selector
+            KeyCode::Left if on_template => self.state.cycle_form_template(false),
+            KeyCode::Right | KeyCode::Char(' ') if on_template => {
+                self.state.cycle_form_template(true)
+            }
+            // Pickers, toggles and multi-selects step with ←/→
+            KeyCode::Left if on_typed_field => self.state.cycle_form_field(false),
+            KeyCode::Right if on_typed_field => self.state.cycle_form_field(true),
+            // Up/Down for action panel navigation
+            KeyCode::Up | KeyCode::Char('k') if on_action_panel => {
+                if self.state.form_selected_button == 0 {
+                    self.state.form_selected_button = 3;
+                } else {
+                    self.state.form_selected_button -= 1;
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') if on_action_panel => {
+                self.state.form_selected_button = (self.state.form_selected_button + 1) % 4;
+            }
+            // Enter on action panel triggers selected button
+            // Button order: 0=Create, 1=Create&New, 2=Draft, 3=Cancel
+            KeyCode::Enter if on_action_panel => {
+                match self.state.form_selected_button {
+                    0 => {
+                        // Create
+                        self.create_issue_with_options(false, false).await;
+                    }
+                    1 => {
+                        // Create & New
+                        self.create_issue_with_options(false, true).await;
+                    }
+                    2 => {
+                        // Save as Draft
+                        self.create_issue_with_options(true, false).await;
+                    }
+                    3 => {
+                        // Cancel
+                        self.state.clear_form();
+                        self.go_back();
+                    }
+                    _ => {}
+                }
+            }
+            // Keyboard shortcuts (work from anywhere)
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.create_issue_with_options(false, false).await;
+            }
+            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.create_issue_with_options(false, false).await;
+            }
+            KeyCode::Char('d') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.create_issue_with_options(true, false).await;
+            }
+            KeyCode::Char('n') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.create_issue_with_options(false, true).await;
+            }
+            KeyCode::Esc => {
+                self.state.clear_form();
+                self.go_back();
+            }
+            // Form field input (only when not on action panel)
+            KeyCode::Char(c) if !on_action_panel => self
+                .state
+                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
+            KeyCode::Backspace if !on_action_panel => self.state.form_backspace(),
+            KeyCode::Enter if !on_action_panel && self.state.active_form_field == 1 => {
+                // Enter in description field adds newline
+                self.state.form_description.push('\n');
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Helper function to create an issue with different options
+    async fn create_issue_with_options(&mut self, draft: bool, create_new: bool) {
+        if let Err(e) = self.state.validate_custom_fields() {
+            self.push_error(e);
+            return;
+        }
+        if let Some(path) = self.state.selected_project_path.clone() {
+            let result = self
+                .daemon
+                .create_issue(
+                    &path,
+                    &self.state.form_title,
+                    &self.state.form_description,
+                    self.state.form_priority,
+                    draft,
+                    Some(self.state.form_template.as_str()).filter(|t| !t.is_empty()),
+                    self.state.custom_field_values(),
+                )
+                .await;
+            if let Ok(new_id) = result {
+                self.reload_issues(&path).await;
+
+                if create_new {
+                    // Clear form for next issue
+                    self.state.clear_form();
+                    self.state.prefill_custom_fields();
+                    self.copy_message = Some("Issue created! Ready for next issue.".to_string());
+                } else {
+                    self.state.selected_issue_id = Some(new_id.clone());
+                    let msg = if draft {
+                        "Draft saved!"
+                    } else {
+                        "Issue created!"
+                    };
+                    self.copy_message = Some(msg.to_string());
+                    self.navigate_to_created_item(
+                        View::IssueDetail,
+                        ViewParams {
+                            issue_id: Some(new_id),
+                            ..Default::default()
+                        },
+                    );
+                }
+            } else {
+                self.push_error("Failed to create issue");
+            }
+        } else {
+            self.push_error("No project selected");
+        }
+    }
+
+    /// Handle keys in Issue Edit view
+    async fn handle_issue_edit_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Tab => self.state.next_form_field(),
+            KeyCode::BackTab => self.state.prev_form_field(),
+            // Save (Ctrl+S or Cmd+W / Ctrl+W)
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_issue_edit().await;
+            }
+            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.save_issue_edit().await;
+            }
+            KeyCode::Esc => {
+                self.state.clear_form();
+                self.go_back();
+            }
+            // The draft field is a checkbox
+            KeyCode::Char(' ') | KeyCode::Enter if self.state.active_form_field == 4 => {
+                self.state.toggle_draft();
+            }
+            // Pickers, toggles and multi-selects step with ←/→
+            KeyCode::Left if self.state.on_typed_form_field() => self.state.cycle_form_field(false),
+            KeyCode::Right if self.state.on_typed_form_field() => self.state.cycle_form_field(true),
+            KeyCode::Char(c) => self
+                .state
+                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
+            KeyCode::Backspace => self.state.form_backspace(),
+            KeyCode::Enter if self.state.active_form_field == 1 => {
+                self.state.form_description.push('\n');
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Helper to save issue edit
+    async fn save_issue_edit(&mut self) {
+        if let Err(e) = self.state.validate_custom_fields() {
+            self.push_error(e);
+            return;
+        }
+        match (
+            &self.state.selected_project_path,
+            &self.state.selected_issue_id,
+        ) {
+            (Some(path), Some(issue_id)) => {
+                let cleared = self
+                    .state
+                    .issues
+                    .iter()
+                    .find(|issue| &issue.id == issue_id)
+                    .map(|issue| {
+                        self.state
+                            .cleared_custom_fields(&issue.metadata.custom_fields)
+                    })
+                    .unwrap_or_default();
+                if !cleared.is_empty() {
+                    self.push_error(format!(
+                        "{} can't be cleared (an empty value keeps the current one)",
+                        cleared.join(" and ")
+                    ));
+                    return;
+                }
+                let result = self
+                    .daemon
+                    .update_issue(
+                        path,
+                        issue_id,
+                        &self.state.form_title,
+                        &self.state.form_description,
+                        self.state.form_priority,
+                        &self.state.form_status,
+                        Some(self.state.form_is_draft),
+                        self.state.custom_field_values(),
+                    )
+                    .await;
+                if result.is_ok() {
+                    let path = path.clone();
+                    self.state.clear_form();
+                    self.go_back();
+                    self.reload_issues(&path).await;
+                } else {
+                    self.push_error("Failed to update issue");
+                }
+            }
+            (None, _) => {
+                self.push_error("No project selected");
+            }
+            (_, None) => {
+                self.push_error("No issue selected");
+            }
+        }
+    }
+
+    /// Handle keys in PRs view
+    async fn handle_prs_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Check for dynamic action shortcut first (when focused on list)
+        if matches!(self.state.prs_list_focus, PrsListFocus::List) {
+            if let Some(action_idx) = self.find_action_for_key(&key) {
+                self.state.action_panel_selected_index = action_idx;
+                self.execute_selected_dynamic_action().await?;
+                return Ok(());
+            }
+        }
+
+        match key.code {
+            // Tab: Switch focus between list and action panel
+            KeyCode::Tab => {
+                self.state.prs_list_focus.toggle();
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.prs_list_focus, PrsListFocus::List) {
+                    self.state
+                        .move_selection_down(self.state.sorted_prs().len());
+                } else {
+                    // Navigate down in action panel
+                    self.state.action_panel_down();
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.prs_list_focus, PrsListFocus::List) {
+                    self.state.move_selection_up();
+                } else {
+                    // Navigate up in action panel
+                    self.state.action_panel_up();
+                }
+            }
+            KeyCode::Enter => {
+                if matches!(self.state.prs_list_focus, PrsListFocus::ActionPanel) {
+                    self.execute_selected_dynamic_action().await?;
+                } else {
+                    // Open PR detail
+                    let pr_id = self
+                        .state
+                        .sorted_prs()
+                        .get(self.state.selected_index)
+                        .map(|pr| pr.id.clone());
+                    if let Some(id) = pr_id {
+                        self.state.selected_pr_id = Some(id.clone());
+                        self.navigate(
+                            View::PrDetail,
+                            ViewParams {
+                                pr_id: Some(id),
+                                ..Default::default()
+                            },
+                        );
+                    }
+                }
+            }
+            KeyCode::Char('s') => self.state.cycle_pr_sort_field(),
+            KeyCode::Char('S') => self.state.toggle_pr_sort_direction(),
+            KeyCode::Char('a') => {
+                self.state.show_merged_prs = !self.state.show_merged_prs;
+                self.state.reset_selection();
+            }
+            // Navigation to other views
+            KeyCode::Char('2') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Issues, ViewParams::default());
+            }
+            KeyCode::Char('4') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Docs, ViewParams::default());
+            }
+            KeyCode::Char('5') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::People, ViewParams::default());
+            }
+            KeyCode::Char('7') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Assets, ViewParams::default());
+            }
+            KeyCode::Char('8') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Features, ViewParams::default());
+            }
+            KeyCode::Char('/') => self.open_search(),
+            KeyCode::Esc | KeyCode::Backspace => {
+                // Reset focus state when leaving
+                self.state.prs_list_focus = PrsListFocus::List;
+                self.state.action_panel_selected_index = 0;
+                self.go_back();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle keys in PR Detail view
+    async fn handle_pr_detail_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Check for dynamic action shortcut first (when focused on content)
+        if matches!(self.state.pr_detail_focus, PrDetailFocus::Content) {
+            if let Some(action_idx) = self.find_action_for_key(&key) {
+                self.state.action_panel_selected_index = action_idx;
+                self.execute_selected_dynamic_action().await?;
+                return Ok(());
+            }
+        }
+
+        match key.code {
+            // Tab: Switch focus between content and action panel
+            KeyCode::Tab => {
+                self.state.pr_detail_focus.toggle();
+            }
+            // Edit PR
+            KeyCode::Char('e') => {
+                if let Some(pr_id) = self.state.selected_pr_id.clone() {
+                    // Load PR data into form before navigating
+                    if let Some(pr) = self.state.prs.iter().find(|p| p.id == pr_id).cloned() {
+                        self.state.load_pr_to_form(&pr);
+                    }
+                    self.navigate(
+                        View::PrEdit,
+                        ViewParams {
+                            pr_id: Some(pr_id),
+                            ..Default::default()
+                        },
+                    );
+                }
+            }
+            // Navigation (j/k/Up/Down)
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.pr_detail_focus, PrDetailFocus::Content) {
+                    self.state.scroll_down();
+                } else {
+                    // Navigate down in action panel
+                    self.state.action_panel_down();
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.pr_detail_focus, PrDetailFocus::Content) {
+                    self.state.scroll_up();
+                } else {
+                    // Navigate up in action panel
+                    self.state.action_panel_up();
+                }
+            }
+            // Execute action (Enter when action panel is focused), otherwise open selected link
+            KeyCode::Enter => {
+                if matches!(self.state.pr_detail_focus, PrDetailFocus::ActionPanel) {
+                    self.execute_selected_dynamic_action().await?;
+                } else {
+                    self.open_selected_link().await?;
+                }
+            }
+            // Links
+            KeyCode::Char(']') => self.state.select_next_link(),
+            KeyCode::Char('[') => self.state.select_prev_link(),
+            KeyCode::Char('L') => self.start_add_link().await,
+            KeyCode::Char('X') => self.start_remove_link(),
+            // Go back (also reset focus and action panel index)
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.state.pr_detail_focus = PrDetailFocus::Content;
+                self.state.action_panel_selected_index = 0;
+                self.go_back();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle keys in PR Create view
+    async fn handle_pr_create_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.state.clear_form();
+                self.go_back();
+            }
+            // Save (Ctrl+S or Cmd+W / Ctrl+W)
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_pr_create().await;
+            }
+            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.save_pr_create().await;
+            }
+            KeyCode::Tab => self.state.next_form_field(),
+            KeyCode::BackTab => self.state.prev_form_field(),
+            // The template field is a selector
+            KeyCode::Left if self.state.on_template_field() => {
+                self.state.cycle_form_template(false)
+            }
+            KeyCode::Right | KeyCode::Char(' ') if self.state.on_template_field() => {
+                self.state.cycle_form_template(true)
+            }
+            // Pickers, toggles and multi-selects step with ←/→
+            KeyCode::Left if self.state.on_typed_form_field() => self.state.cycle_form_field(false),
+            KeyCode::Right if self.state.on_typed_form_field() => self.state.cycle_form_field(true),
+            KeyCode::Char(c) => self
+                .state
+                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
+            KeyCode::Backspace => self.state.form_backspace(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Helper to save PR create
+    async fn save_pr_create(&mut self) {
+        if let Err(e) = self.state.validate_custom_fields() {
+            self.push_error(e);
+            return;
+        }
+        if let Some(path) = &self.state.selected_project_path {
+            let result = self
+                .daemon
+                .create_pr(
+                    path,
+                    &self.state.form_title,
+                    &self.state.form_description,
+                    &self.state.form_source_branch,
+                    &self.state.form_target_branch,
+                    self.state.form_reviewers.clone(),
+                    self.state.form_priority,
+                    Some(self.state.form_template.as_str()).filter(|t| !t.is_empty()),
+                    self.state.custom_field_values(),
+                )
+                .await;
+            if let Ok(new_id) = result {
+                if let Ok(prs) = self.daemon.list_prs(path).await {
+                    self.state.prs = prs;
+                }
+                self.state.selected_pr_id = Some(new_id.clone());
+                self.navigate_to_created_item(
+                    View::PrDetail,
+                    ViewParams {
+                        pr_id: Some(new_id),
+                        ..Default::default()
+                    },
+                );
+            } else {
+                self.push_error("Failed to create PR");
+            }
+        } else {
+            self.push_error("No project selected");
+        }
+    }
+
+    /// Handle keys in PR Edit view
+    async fn handle_pr_edit_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.state.clear_form();
+                self.go_back();
+            }
+            // Save (Ctrl+S or Cmd+W / Ctrl+W)
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_pr_edit().await;
+            }
+            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.save_pr_edit().await;
+            }
+            KeyCode::Tab => self.state.next_form_field(),
+            KeyCode::BackTab => self.state.prev_form_field(),
+            // Pickers, toggles and multi-selects step with ←/→
+            KeyCode::Left if self.state.on_typed_form_field() => self.state.cycle_form_field(false),
+            KeyCode::Right if self.state.on_typed_form_field() => self.state.cycle_form_field(true),
+            KeyCode::Char(c) => self
+                .state
+                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
+            KeyCode::Backspace => self.state.form_backspace(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Helper to save PR edit
+    async fn save_pr_edit(&mut self) {
+        if let Err(e) = self.state.validate_custom_fields() {
+            self.push_error(e);
+            return;
+        }
+        match (
+            &self.state.selected_project_path,
+            &self.state.selected_pr_id,
+        ) {
+            (Some(path), Some(pr_id)) => {
+                let cleared = self
+                    .state
+                    .prs
+                    .iter()
+                    .find(|pr| &pr.id == pr_id)
+                    .map(|pr| self.state.cleared_pr_fields(pr))
+                    .unwrap_or_default();
+                if !cleared.is_empty() {
+                    self.push_error(format!(
+                        "{} can't be cleared (an empty value keeps the current one)",
+                        cleared.join(" and ")
+                    ));
+                    return;
+                }
+                let result = self
+                    .daemon
+                    .update_pr(
+                        path,
+                        pr_id,
+                        &self.state.form_title,
+                        &self.state.form_description,
+                        &self.state.form_source_branch,
+                        &self.state.form_target_branch,
+                        &self.state.form_status,
+                        self.state.form_reviewers.clone(),
+                        self.state.form_priority,
+                        self.state.custom_field_values(),
+                    )
+                    .await;
+                if result.is_ok() {
+                    if let Ok(prs) = self.daemon.list_prs(path).await {
+                        self.state.prs = prs;
+                    }
+                    self.state.clear_form();
+                    self.go_back();
+                } else {
+                    self.push_error("Failed to update PR");
+                }
+            }
+            (None, _) => {
+                self.push_error("No project selected");
+            }
+            (_, None) => {
+                self.push_error("No PR selected");
+            }
+        }
+    }
+
+    /// Handle keys in Docs view
+    async fn handle_docs_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Check for dynamic action shortcut first (when focused on list)
+        if matches!(self.state.docs_list_focus, DocsListFocus::List) {
+            if let Some(action_idx) = self.find_action_for_key(&key) {
+                self.state.action_panel_selected_index = action_idx;
+                self.execute_selected_dynamic_action().await?;
+                return Ok(());
+            }
+        }
+
+        match key.code {
+            // Tab: Switch focus between list and action panel
+            KeyCode::Tab => {
+                self.state.docs_list_focus.toggle();
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.docs_list_focus, DocsListFocus::List) {
+                    self.state.move_selection_down(self.state.docs.len());
+                } else {
+                    // Navigate down in action panel
+                    self.state.action_panel_down();
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.docs_list_focus, DocsListFocus::List) {
+                    self.state.move_selection_up();
+                } else {
+                    // Navigate up in action panel
+                    self.state.action_panel_up();
+                }
+            }
+            KeyCode::Enter => {
+                if matches!(self.state.docs_list_focus, DocsListFocus::ActionPanel) {
+                    self.execute_selected_dynamic_action().await?;
+                } else {
+                    // Open doc detail
+                    if let Some(doc) = self.state.docs.get(self.state.selected_index) {
+                        self.state.selected_doc_slug = Some(doc.slug.clone());
+                        self.navigate(
+                            View::DocDetail,
+                            ViewParams {
+                                doc_slug: Some(doc.slug.clone()),
+                                ..Default::default()
+                            },
+                        );
+                    }
+                }
+            }
+            // Navigation to other views
+            KeyCode::Char('2') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Issues, ViewParams::default());
+            }
+            KeyCode::Char('3') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Prs, ViewParams::default());
+            }
+            KeyCode::Char('5') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::People, ViewParams::default());
+            }
+            KeyCode::Char('7') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Assets, ViewParams::default());
+            }
+            KeyCode::Char('8') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Features, ViewParams::default());
+            }
+            KeyCode::Char('/') => self.open_search(),
+            KeyCode::Esc | KeyCode::Backspace => {
+                // Reset focus state when leaving
+                self.state.docs_list_focus = DocsListFocus::List;
+                self.state.action_panel_selected_index = 0;
+                self.go_back();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle keys in Doc Detail view
+    async fn handle_doc_detail_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Check for dynamic action shortcut first (when focused on content)
+        if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
+            if let Some(action_idx) = self.find_action_for_key(&key) {
+                self.state.action_panel_selected_index = action_idx;
+                self.execute_selected_dynamic_action().await?;
+                return Ok(());
+            }
+        }
+
+        match key.code {
+            // Tab: Switch focus between content and action panel
+            KeyCode::Tab => {
+                self.state.doc_detail_focus.toggle();
+            }
+            // Navigation (j/k/Up/Down)
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
+                    self.state.scroll_down();
+                } else {
+                    // Navigate down in action panel
+                    self.state.action_panel_down();
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
+                    self.state.scroll_up();
+                } else {
+                    // Navigate up in action panel
+                    self.state.action_panel_up();
+                }
+            }
+            // Edit doc
+            KeyCode::Char('e') => {
+                if let Some(slug) = self.state.selected_doc_slug.clone() {
+                    // Load doc data into form before navigating
+                    if let Some(doc) = self.state.docs.iter().find(|d| d.slug == slug).cloned() {
+                        self.state.load_doc_to_form(&doc);
+                    }
+                    self.navigate(
+                        View::DocEdit,
+                        ViewParams {
+                            doc_slug: Some(slug),
+                            ..Default::default()
+                        },
+                    );
+                }
+            }
+            KeyCode::Char('d') | KeyCode::PageDown => {
+                if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
+                    self.state.scroll_down_page();
+                }
+            }
+            KeyCode::Char('u') | KeyCode::PageUp => {
+                if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
+                    self.state.scroll_up_page();
+                }
             }
-        };
-
-        self.copy_message = Some("Opening in terminal...".to_string());
-
-        match self
-            .daemon
-            .open_agent_in_terminal(&project_path, &issue_id, "", 0, 0)
-            .await
-        {
-            Ok(result) => {
-                if result.terminal_opened {
-                    self.copy_message = Some(format!(
-                        "Opened #{} in terminal with {}",
-                        result.display_number, result.agent_command
-                    ));
+            // Execute action (Enter when action panel is focused), otherwise open selected link
+            KeyCode::Enter => {
+                if matches!(self.state.doc_detail_focus, DocDetailFocus::ActionPanel) {
+                    self.execute_selected_dynamic_action().await?;
                 } else {
-                    self.copy_message =
-                        Some(format!("Agent ready at {}", result.working_directory));
+                    self.open_selected_link().await?;
                 }
             }
-            Err(e) => {
-                self.push_error(format!("Failed to open: {}", e));
+            // Links
+            KeyCode::Char(']') => self.state.select_next_link(),
+            KeyCode::Char('[') => self.state.select_prev_link(),
+            KeyCode::Char('L') => self.start_add_link().await,
+            KeyCode::Char('X') => self.start_remove_link(),
+            // Go back (also reset focus and action panel index)
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.state.doc_detail_focus = DocDetailFocus::Content;
+                self.state.action_panel_selected_index = 0;
+                self.go_back();
             }
+            _ => {}
         }
-
         Ok(())
     }
 
-    // =========== Dynamic Actions ===========
-
-    /// Fetch entity actions from daemon
-    pub async fn fetch_entity_actions(&mut self, entity_type: EntityType, entity_id: Option<&str>) {
-        let project_path = match &self.state.selected_project_path {
-            Some(path) => path.clone(),
-            None => return,
-        };
-
-        self.state.actions_loading = true;
-        self.state.actions_error = None;
+    /// Handle keys in Doc Create view
+    async fn handle_doc_create_key(&mut self, key: KeyEvent) -> Result<()> {
+        let field_count = get_doc_field_count(self);
+        let org_checkbox_field = if field_count == 4 { Some(3) } else { None };
 
-        match self
-            .daemon
-            .get_entity_actions(&project_path, entity_type, entity_id)
-            .await
-        {
-            Ok(response) => {
-                self.state.current_actions = response;
-                self.state.actions_loading = false;
-                self.state.action_panel_selected_index = 0;
-            }
-            Err(e) => {
-                self.state.actions_error = Some(e.to_string());
-                self.state.actions_loading = false;
-                self.state.current_actions = Default::default();
+        match key.code {
+            KeyCode::Esc => {
+                self.state.clear_form();
+                self.go_back();
             }
-        }
-    }
-
-    /// Refresh actions for current view
-    pub async fn refresh_current_actions(&mut self) {
-        match self.state.current_view {
-            View::Issues => {
-                let entity_id = self
-                    .state
-                    .sorted_issues()
-                    .get(self.state.selected_index)
-                    .map(|i| i.id.clone());
-                self.fetch_entity_actions(EntityType::Issue, entity_id.as_deref())
-                    .await;
+            // Save (Ctrl+S or Cmd+W / Ctrl+W)
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_doc_create().await;
             }
-            View::IssueDetail => {
-                let entity_id = self.state.selected_issue_id.clone();
-                self.fetch_entity_actions(EntityType::Issue, entity_id.as_deref())
-                    .await;
+            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.save_doc_create().await;
             }
-            View::Prs => {
-                let entity_id = self
-                    .state
-                    .sorted_prs()
-                    .get(self.state.selected_index)
-                    .map(|p| p.id.clone());
-                self.fetch_entity_actions(EntityType::Pr, entity_id.as_deref())
-                    .await;
+            KeyCode::Tab => {
+                // Wrap around based on actual field count
+                self.state.active_form_field = (self.state.active_form_field + 1) % field_count;
             }
-            View::PrDetail => {
-                let entity_id = self.state.selected_pr_id.clone();
-                self.fetch_entity_actions(EntityType::Pr, entity_id.as_deref())
-                    .await;
+            KeyCode::BackTab => {
+                // Wrap around based on actual field count
+                if self.state.active_form_field == 0 {
+                    self.state.active_form_field = field_count - 1;
+                } else {
+                    self.state.active_form_field -= 1;
+                }
             }
-            View::Docs => {
-                let entity_id = self
-                    .state
-                    .docs
-                    .get(self.state.selected_index)
-                    .map(|d| d.slug.clone());
-                self.fetch_entity_actions(EntityType::Doc, entity_id.as_deref())
-                    .await;
+            // Toggle checkbox with Space or Enter when on org checkbox field
+            KeyCode::Char(' ') | KeyCode::Enter
+                if org_checkbox_field == Some(self.state.active_form_field) =>
+            {
+                self.state.toggle_org_doc();
             }
-            View::DocDetail => {
-                let entity_id = self.state.selected_doc_slug.clone();
-                self.fetch_entity_actions(EntityType::Doc, entity_id.as_deref())
-                    .await;
+            KeyCode::Char(c) => self
+                .state
+                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
+            KeyCode::Backspace => self.state.form_backspace(),
+            KeyCode::Enter if self.state.active_form_field == 1 => {
+                self.state.form_description.push('\n');
             }
             _ => {}
         }
+        Ok(())
     }
 
-    /// Check if a key event matches an action's keyboard shortcut
-    fn key_matches_shortcut(key: &KeyEvent, shortcut: &str) -> bool {
-        if shortcut.is_empty() {
-            return false;
-        }
-
-        // Parse shortcut format: "n", "d", "Ctrl+D", "Shift+N", "Enter"
-        let parts: Vec<&str> = shortcut.split('+').collect();
+    /// Helper to save doc create
+    async fn save_doc_create(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
 
-        let (expected_modifiers, key_str) = if parts.len() == 2 {
-            let mods = match parts[0].to_lowercase().as_str() {
-                "ctrl" | "control" => KeyModifiers::CONTROL,
-                "shift" => KeyModifiers::SHIFT,
-                "alt" => KeyModifiers::ALT,
-                _ => KeyModifiers::NONE,
-            };
-            (mods, parts[1])
+        let slug = if self.state.form_slug.is_empty() {
+            None
         } else {
-            (KeyModifiers::NONE, parts[0])
+            Some(self.state.form_slug.clone())
         };
+        let result = self
+            .daemon
+            .create_doc(
+                &path,
+                &self.state.form_title,
+                &self.state.form_description,
+                slug.as_deref(),
+                self.state.form_is_org_doc,
+            )
+            .await;
+        match result {
+            Ok((new_slug, sync_results)) => {
+                // Show sync results if this was an org doc
+                if !sync_results.is_empty() {
+                    let success_count = sync_results.iter().filter(|r| r.success).count();
+                    let total = sync_results.len();
+                    if success_count < total {
+                        // Some failed - show warning
+                        let failed: Vec<_> = sync_results
+                            .iter()
+                            .filter(|r| !r.success)
+                            .map(|r| r.error.clone())
+                            .collect();
+                        self.push_error(format!(
+                            "Doc created, but sync failed for {} project(s): {}",
+                            total - success_count,
+                            failed.join(", ")
+                        ));
+                    }
+                }
 
-        // Check modifiers
-        if key.modifiers != expected_modifiers {
-            return false;
-        }
-
-        // Check key
-        match key_str.to_lowercase().as_str() {
-            "enter" => matches!(key.code, KeyCode::Enter),
-            "esc" | "escape" => matches!(key.code, KeyCode::Esc),
-            "tab" => matches!(key.code, KeyCode::Tab),
-            "backspace" => matches!(key.code, KeyCode::Backspace),
-            "delete" => matches!(key.code, KeyCode::Delete),
-            s if s.len() == 1 => {
-                if let Some(c) = s.chars().next() {
-                    matches!(key.code, KeyCode::Char(k) if k.to_ascii_lowercase() == c)
-                } else {
-                    false
+                if let Ok(docs) = self.daemon.list_docs(&path).await {
+                    self.state.docs = docs;
                 }
+                self.state.selected_doc_slug = Some(new_slug.clone());
+                self.navigate_to_created_item(
+                    View::DocDetail,
+                    ViewParams {
+                        doc_slug: Some(new_slug),
+                        ..Default::default()
+                    },
+                );
+            }
+            Err(e) => {
+                self.push_error(format!("Failed to create doc: {}", e));
             }
-            _ => false,
         }
     }
 
-    /// Find action matching a key event
-    fn find_action_for_key(&self, key: &KeyEvent) -> Option<usize> {
-        self.state
-            .current_actions
-            .actions
-            .iter()
-            .position(|a| Self::key_matches_shortcut(key, &a.keyboard_shortcut))
+    /// Handle keys in Doc Edit view
+    async fn handle_doc_edit_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.state.clear_form();
+                self.go_back();
+            }
+            // Save (Ctrl+S or Cmd+W / Ctrl+W)
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_doc_edit().await;
+            }
+            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.save_doc_edit().await;
+            }
+            KeyCode::Tab => self.state.next_form_field(),
+            KeyCode::BackTab => self.state.prev_form_field(),
+            KeyCode::Char(c) => self
+                .state
+                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
+            KeyCode::Backspace => self.state.form_backspace(),
+            KeyCode::Enter if self.state.active_form_field == 1 => {
+                // Enter in content field adds newline
+                self.state.form_description.push('\n');
+            }
+            _ => {}
+        }
+        Ok(())
     }
 
-    /// Execute the currently selected dynamic action
-    pub async fn execute_selected_dynamic_action(&mut self) -> Result<()> {
-        let action = match self.state.selected_action() {
-            Some(a) => a.clone(),
-            None => return Ok(()),
+    /// Helper to save doc edit
+    async fn save_doc_edit(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
+        let Some(slug) = self.state.selected_doc_slug.clone() else {
+            self.push_error("No doc selected");
+            return;
         };
 
-        if !action.enabled {
-            self.push_error(action.disabled_reason.clone());
-            return Ok(());
-        }
-
-        // Route to appropriate handler based on action ID
-        // Action IDs from daemon are generic (e.g., "create", "delete")
-        // We determine the entity type from the current view
-        match action.id.as_str() {
-            // Create action - contextual based on current view
-            "create" => match self.state.current_view {
-                View::Issues | View::IssueDetail => {
-                    self.navigate(View::IssueCreate, ViewParams::default());
-                }
-                View::Prs | View::PrDetail => {
-                    self.navigate(View::PrCreate, ViewParams::default());
-                }
-                View::Docs | View::DocDetail => {
-                    self.navigate(View::DocCreate, ViewParams::default());
-                }
-                _ => {}
-            },
+        // Check if slug changed
+        let new_slug = if !self.state.form_slug.is_empty() && self.state.form_slug != slug {
+            Some(self.state.form_slug.as_str())
+        } else {
+            None
+        };
 
-            // Delete action - contextual based on current view
-            "delete" => match self.state.current_view {
-                View::Issues | View::IssueDetail => {
-                    self.delete_selected_issue().await?;
-                }
-                View::Prs | View::PrDetail => {
-                    self.push_error("Delete PR: Not yet implemented");
-                }
-                View::Docs | View::DocDetail => {
-                    self.push_error("Delete doc: Not yet implemented");
-                }
-                _ => {}
-            },
+        let result = self
+            .daemon
+            .update_doc(
+                &path,
+                &slug,
+                &self.state.form_title,
+                &self.state.form_description,
+                new_slug,
+            )
+            .await;
 
-            // Duplicate action - contextual based on current view
-            "duplicate" => match self.state.current_view {
-                View::Issues | View::IssueDetail => {
-                    self.push_error("Duplicate issue: Not yet implemented");
+        match result {
+            Ok(()) => {
+                if let Ok(docs) = self.daemon.list_docs(&path).await {
+                    self.state.docs = docs;
                 }
-                View::Docs | View::DocDetail => {
-                    self.push_error("Duplicate doc: Not yet implemented");
+                // Update selected slug if it changed
+                if let Some(ns) = new_slug {
+                    self.state.selected_doc_slug = Some(ns.to_string());
                 }
-                _ => {}
-            },
+                self.state.clear_form();
+                self.go_back();
+            }
+            Err(e) => {
+                self.push_error(format!("Failed to update doc: {}", e));
+            }
+        }
+    }
 
-            // Move action - contextual based on current view
-            "move" => match self.state.current_view {
-                View::Issues | View::IssueDetail => {
-                    self.start_move_issue();
-                }
-                View::Docs | View::DocDetail => {
-                    self.start_move_doc();
-                }
-                _ => {}
-            },
+    /// Handle keys in People view
+    async fn handle_people_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Check for dynamic action shortcut first (when focused on list)
+        if matches!(self.state.people_list_focus, PeopleListFocus::List) {
+            if let Some(action_idx) = self.find_action_for_key(&key) {
+                self.state.action_panel_selected_index = action_idx;
+                self.execute_selected_dynamic_action().await?;
+                return Ok(());
+            }
+        }
 
-            // Mode actions (Issue-specific) - with "in progress" confirmation
-            "mode:plan" => {
-                if self.should_show_start_work_dialog() {
-                    self.state.pending_start_work_action = Some(PendingStartWorkAction {
-                        action_id: "mode:plan".to_string(),
-                        action_label: "Plan Mode".to_string(),
-                    });
+        match key.code {
+            // Tab: Switch focus between list and action panel
+            KeyCode::Tab => {
+                self.state.people_list_focus.toggle();
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.people_list_focus, PeopleListFocus::List) {
+                    self.state
+                        .move_selection_down(self.state.sorted_people().len());
                 } else {
-                    self.state.action_panel_llm_action = LlmAction::Plan;
+                    // Navigate down in action panel
+                    self.state.action_panel_down();
                 }
             }
-            "mode:implement" => {
-                if self.should_show_start_work_dialog() {
-                    self.state.pending_start_work_action = Some(PendingStartWorkAction {
-                        action_id: "mode:implement".to_string(),
-                        action_label: "Implement Mode".to_string(),
-                    });
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.people_list_focus, PeopleListFocus::List) {
+                    self.state.move_selection_up();
                 } else {
-                    self.state.action_panel_llm_action = LlmAction::Implement;
+                    // Navigate up in action panel
+                    self.state.action_panel_up();
                 }
             }
-
-            // External actions - with "in progress" confirmation
-            "open_in_vscode" => {
-                if self.should_show_start_work_dialog() {
-                    self.state.pending_start_work_action = Some(PendingStartWorkAction {
-                        action_id: "open_in_vscode".to_string(),
-                        action_label: "Open in VS Code".to_string(),
-                    });
+            KeyCode::Enter => {
+                if matches!(self.state.people_list_focus, PeopleListFocus::ActionPanel) {
+                    self.execute_selected_dynamic_action().await?;
                 } else {
-                    self.execute_open_in_vscode().await?;
+                    // Open person detail
+                    let sorted_people = self.state.sorted_people();
+                    if let Some(person) = sorted_people.get(self.state.selected_index) {
+                        self.state.selected_person_id = Some(person.id.clone());
+                        self.state.scroll_offset = 0;
+                        self.navigate(View::PersonDetail, ViewParams::default());
+                    }
                 }
             }
-            "open_in_terminal" => {
-                self.execute_open_in_terminal().await?;
+            // Sort controls
+            KeyCode::Char('s') => {
+                self.state.cycle_people_sort_field();
             }
-
-            // Status transitions (dynamic, e.g., "status:open", "status:closed")
-            id if id.starts_with("status:") => {
-                let new_status = id.strip_prefix("status:").unwrap_or("");
-                match self.state.current_view {
-                    View::Issues | View::IssueDetail => {
-                        self.update_issue_status(new_status.to_string()).await?;
-                    }
-                    View::Prs | View::PrDetail => {
-                        self.update_pr_status(new_status.to_string()).await?;
-                    }
-                    _ => {}
+            KeyCode::Char('S') => {
+                self.state.toggle_people_sort_direction();
+            }
+            // Team member management
+            KeyCode::Char('n') => {
+                self.state.clear_form();
+                self.navigate(View::PersonCreate, ViewParams::default());
+            }
+            KeyCode::Char('e') => {
+                if let Some(person) = self.state.selected_person().cloned() {
+                    self.open_person_edit(&person);
                 }
             }
-
-            _ => {
-                self.push_error(format!("Unknown action: {}", action.id));
+            KeyCode::Char('x') if self.state.selected_person().is_some() => {
+                self.state.selected_person_id = self.state.selected_person().map(|p| p.id.clone());
+                self.confirm_delete_user();
+            }
+            KeyCode::Char('g') => self.navigate(View::UserSync, ViewParams::default()),
+            // Navigation to other views
+            KeyCode::Char('2') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Issues, ViewParams::default());
+            }
+            KeyCode::Char('3') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Prs, ViewParams::default());
+            }
+            KeyCode::Char('4') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Docs, ViewParams::default());
+            }
+            KeyCode::Char('7') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Assets, ViewParams::default());
+            }
+            KeyCode::Char('8') if self.state.selected_project_path.is_some() => {
+                self.navigate(View::Features, ViewParams::default());
+            }
+            KeyCode::Esc | KeyCode::Backspace => {
+                // Reset focus state when leaving
+                self.state.people_list_focus = PeopleListFocus::List;
+                self.state.action_panel_selected_index = 0;
+                self.go_back();
             }
+            _ => {}
         }
-
-        // Refresh actions after execution (state may have changed)
-        self.refresh_current_actions().await;
-
         Ok(())
     }
 
-    /// Handle keys in Issue Create view
-    async fn handle_issue_create_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check if we're on the action panel (field 3)
-        let on_action_panel = self.state.active_form_field == 3;
+    /// Handle keys in Person Detail view
+    async fn handle_person_detail_key(&mut self, key: KeyEvent) -> Result<()> {
+        // Check for dynamic action shortcut first (when focused on content)
+        if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
+            if let Some(action_idx) = self.find_action_for_key(&key) {
+                self.state.action_panel_selected_index = action_idx;
+                self.execute_selected_dynamic_action().await?;
+                return Ok(());
+            }
+        }
 
         match key.code {
-            KeyCode::Tab => self.state.next_form_field(),
-            KeyCode::BackTab => self.state.prev_form_field(),
-            // Up/Down for action panel navigation
-            KeyCode::Up | KeyCode::Char('k') if on_action_panel => {
-                if self.state.form_selected_button == 0 {
-                    self.state.form_selected_button = 3;
-                } else {
-                    self.state.form_selected_button -= 1;
-                }
-            }
-            KeyCode::Down | KeyCode::Char('j') if on_action_panel => {
-                self.state.form_selected_button = (self.state.form_selected_button + 1) % 4;
+            // Tab: Switch focus between content and action panel
+            KeyCode::Tab => {
+                self.state.person_detail_focus.toggle();
             }
-            // Enter on action panel triggers selected button
-            // Button order: 0=Create, 1=Create&New, 2=Draft, 3=Cancel
-            KeyCode::Enter if on_action_panel => {
-                match self.state.form_selected_button {
-                    0 => {
-                        // Create
-                        self.create_issue_with_options(false, false).await;
-                    }
-                    1 => {
-                        // Create & New
-                        self.create_issue_with_options(false, true).await;
-                    }
-                    2 => {
-                        // Save as Draft
-                        self.create_issue_with_options(true, false).await;
-                    }
-                    3 => {
-                        // Cancel
-                        self.state.clear_form();
-                        self.go_back();
-                    }
-                    _ => {}
+            // Navigation (j/k/Up/Down)
+            KeyCode::Char('j') | KeyCode::Down => {
+                if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
+                    self.state.scroll_down();
+                } else {
+                    // Navigate down in action panel
+                    self.state.action_panel_down();
                 }
             }
-            // Keyboard shortcuts (work from anywhere)
-            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.create_issue_with_options(false, false).await;
+            KeyCode::Char('k') | KeyCode::Up => {
+                if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
+                    self.state.scroll_up();
+                } else {
+                    // Navigate up in action panel
+                    self.state.action_panel_up();
+                }
             }
-            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
-                self.create_issue_with_options(false, false).await;
+            KeyCode::Char('d') | KeyCode::PageDown => {
+                if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
+                    self.state.scroll_down_page();
+                }
             }
-            KeyCode::Char('d') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
-                self.create_issue_with_options(true, false).await;
+            KeyCode::Char('u') | KeyCode::PageUp => {
+                if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
+                    self.state.scroll_up_page();
+                }
             }
-            KeyCode::Char('n') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
-                self.create_issue_with_options(false, true).await;
+            KeyCode::Char('e') => {
+                let person = self.state.selected_person_id.as_ref().and_then(|id| {
+                    self.state
+                        .sorted_people()
+                        .into_iter()
+                        .find(|u| &u.id == id)
+                        .cloned()
+                });
+                if let Some(person) = person {
+                    self.open_person_edit(&person);
+                }
             }
-            KeyCode::Esc => {
-                self.state.clear_form();
-                self.go_back();
+            KeyCode::Char('x') if self.state.selected_person_id.is_some() => {
+                self.confirm_delete_user();
             }
-            // Form field input (only when not on action panel)
-            KeyCode::Char(c) if !on_action_panel => self
-                .state
-                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
-            KeyCode::Backspace if !on_action_panel => self.state.form_backspace(),
-            KeyCode::Enter if !on_action_panel => {
-                // Enter in description field adds newline
-                if self.state.active_form_field == 1 {
-                    self.state.form_description.push('\n');
+            // Execute action (Enter when action panel is focused)
+            KeyCode::Enter => {
+                if matches!(
+                    self.state.person_detail_focus,
+                    PersonDetailFocus::ActionPanel
+                ) {
+                    self.execute_selected_dynamic_action().await?;
                 }
             }
+            // Go back (also reset focus and action panel index)
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.state.person_detail_focus = PersonDetailFocus::Content;
+                self.state.action_panel_selected_index = 0;
+                self.go_back();
+            }
             _ => {}
         }
         Ok(())
     }
 
-    /// Helper function to create an issue with different options
-    async fn create_issue_with_options(&mut self, draft: bool, create_new: bool) {
-        if let Some(path) = self.state.selected_project_path.clone() {
-            let result = self
-                .daemon
-                .create_issue(
-                    &path,
-                    &self.state.form_title,
-                    &self.state.form_description,
-                    self.state.form_priority,
-                    draft,
-                )
-                .await;
-            if let Ok(new_id) = result {
-                if let Ok(issues) = self.daemon.list_issues(&path).await {
-                    self.state.issues = issues;
+    /// Reload the team members of the selected project
+    async fn refresh_users(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+        match self.daemon.list_users(&path).await {
+            Ok(users) => {
+                self.state.project_users.insert(path, users);
+                let count = self.state.sorted_people().len();
+                if self.state.selected_index >= count {
+                    self.state.selected_index = count.saturating_sub(1);
                 }
+            }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
 
-                if create_new {
-                    // Clear form for next issue
-                    self.state.clear_form();
-                    self.copy_message = Some("Issue created! Ready for next issue.".to_string());
-                } else {
-                    self.state.selected_issue_id = Some(new_id.clone());
-                    let msg = if draft {
-                        "Draft saved!"
-                    } else {
-                        "Issue created!"
-                    };
-                    self.copy_message = Some(msg.to_string());
-                    self.navigate_to_created_item(
-                        View::IssueDetail,
-                        ViewParams {
-                            issue_id: Some(new_id),
-                            ..Default::default()
-                        },
-                    );
+    /// Load the selected project's team members unless cached (PR reviewer picker and names)
+    async fn ensure_project_users(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+        if self.state.project_users.contains_key(&path) {
+            return;
+        }
+        if let Ok(users) = self.daemon.list_users(&path).await {
+            self.state.project_users.insert(path, users);
+        }
+    }
+
+    /// Open the edit form for a team member
+    fn open_person_edit(&mut self, person: &User) {
+        self.state.clear_form();
+        self.state.load_user_to_form(person);
+        // The ID is fixed once created, start on the name
+        self.state.active_form_field = 1;
+        self.navigate(
+            View::PersonEdit,
+            ViewParams {
+                person_id: Some(person.id.clone()),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Ask before deleting the selected team member
+    fn confirm_delete_user(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+        let confirm = self.state.selected_person_id.as_ref().and_then(|id| {
+            self.state
+                .sorted_people()
+                .into_iter()
+                .find(|u| &u.id == id)
+                .map(|user| PendingConfirmAction::delete_user(&path, user))
+        });
+        self.state.pending_confirm_action = confirm;
+    }
+
+    /// Delete a team member, leaving the detail view if open
+    async fn delete_user(&mut self, path: &str, user_id: &str) {
+        match self.daemon.delete_user(path, user_id).await {
+            Ok(()) => {
+                self.copy_message = Some(format!("Deleted {}", user_id));
+                self.state.selected_person_id = None;
+                if self.state.current_view == View::PersonDetail {
+                    self.go_back();
                 }
-            } else {
-                self.push_error("Failed to create issue");
+                self.refresh_users().await;
             }
-        } else {
-            self.push_error("No project selected");
+            Err(e) => self.push_error(e.to_string()),
         }
     }
 
-    /// Handle keys in Issue Edit view
-    async fn handle_issue_edit_key(&mut self, key: KeyEvent) -> Result<()> {
+    /// Handle keys in the person create/edit forms
+    async fn handle_person_form_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            KeyCode::Tab => self.state.next_form_field(),
-            KeyCode::BackTab => self.state.prev_form_field(),
+            KeyCode::Esc => {
+                self.state.clear_form();
+                self.go_back();
+            }
             // Save (Ctrl+S or Cmd+W / Ctrl+W)
             KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.save_issue_edit().await;
+                self.save_person_form().await;
             }
             KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
-                self.save_issue_edit().await;
-            }
-            KeyCode::Esc => {
-                self.state.clear_form();
-                self.go_back();
+                self.save_person_form().await;
             }
+            KeyCode::Tab => self.state.next_form_field(),
+            KeyCode::BackTab => self.state.prev_form_field(),
             KeyCode::Char(c) => self
                 .state
                 .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
             KeyCode::Backspace => self.state.form_backspace(),
-            KeyCode::Enter => {
-                if self.state.active_form_field == 1 {
-                    self.state.form_description.push('\n');
-                }
-            }
             _ => {}
         }
         Ok(())
     }
 
-    /// Helper to save issue edit
-    async fn save_issue_edit(&mut self) {
-        match (
-            &self.state.selected_project_path,
-            &self.state.selected_issue_id,
-        ) {
-            (Some(path), Some(issue_id)) => {
-                let result = self
-                    .daemon
-                    .update_issue(
-                        path,
-                        issue_id,
-                        &self.state.form_title,
-                        &self.state.form_description,
-                        self.state.form_priority,
-                        &self.state.form_status,
-                    )
-                    .await;
-                if result.is_ok() {
-                    if let Ok(issues) = self.daemon.list_issues(path).await {
-                        self.state.issues = issues;
-                    }
-                    self.state.clear_form();
-                    self.go_back();
-                } else {
-                    self.push_error("Failed to update issue");
+    /// Create or update the team member from the form
+    async fn save_person_form(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
+        let name = self.state.form_title.trim().to_string();
+        let email = self.state.form_email.trim().to_string();
+        let git_usernames = self.state.form_git_username_list();
+        if name.is_empty() {
+            self.push_error("Name is required");
+            return;
+        }
+
+        let result = match self.state.view_params.person_id.clone() {
+            Some(user_id) => {
+                let cleared = self
+                    .state
+                    .sorted_people()
+                    .into_iter()
+                    .find(|u| u.id == user_id)
+                    .map(|u| self.state.cleared_user_fields(u))
+                    .unwrap_or_default();
+                if !cleared.is_empty() {
+                    self.push_error(format!(
+                        "{} can't be cleared (an empty value keeps the current one)",
+                        cleared.join(" and ")
+                    ));
+                    return;
                 }
+                self.daemon
+                    .update_user(&path, &user_id, &name, &email, git_usernames)
+                    .await
             }
-            (None, _) => {
-                self.push_error("No project selected");
+            None => {
+                let id = self.state.form_slug.trim().to_string();
+                if id.is_empty() {
+                    self.push_error("ID is required");
+                    return;
+                }
+                self.daemon
+                    .create_user(&path, &id, &name, &email, git_usernames)
+                    .await
             }
-            (_, None) => {
-                self.push_error("No issue selected");
+        };
+
+        match result {
+            Ok(user) => {
+                self.state.clear_form();
+                self.go_back();
+                self.refresh_users().await;
+                if let Some(idx) = self
+                    .state
+                    .sorted_people()
+                    .iter()
+                    .position(|u| u.id == user.id)
+                {
+                    self.state.selected_index = idx;
+                }
+                self.state.selected_person_id = Some(user.id);
             }
+            Err(e) => self.push_error(e.to_string()),
         }
     }
 
-    /// Handle keys in PRs view
-    async fn handle_prs_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check for dynamic action shortcut first (when focused on list)
-        if matches!(self.state.prs_list_focus, PrsListFocus::List) {
-            if let Some(action_idx) = self.find_action_for_key(&key) {
-                self.state.action_panel_selected_index = action_idx;
-                self.execute_selected_dynamic_action().await?;
-                return Ok(());
+    /// Handle keys in the user sync screen
+    async fn handle_user_sync_key(&mut self, key: KeyEvent) -> Result<()> {
+        let dry_run = self.state.user_sync.as_ref().is_some_and(|s| s.dry_run);
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.state.scroll_down(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.scroll_up(),
+            KeyCode::Enter | KeyCode::Char('y') if dry_run => self.sync_users(false).await,
+            KeyCode::Char('r') => self.sync_users(true).await,
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.state.user_sync = None;
+                self.go_back();
             }
+            _ => {}
         }
+        Ok(())
+    }
 
-        match key.code {
-            // Tab: Switch focus between list and action panel
-            KeyCode::Tab => {
-                self.state.prs_list_focus.toggle();
-            }
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.prs_list_focus, PrsListFocus::List) {
-                    self.state
-                        .move_selection_down(self.state.sorted_prs().len());
-                } else {
-                    // Navigate down in action panel
-                    self.state.action_panel_down();
+    /// Sync users from git history; a dry run previews what would be created
+    async fn sync_users(&mut self, dry_run: bool) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+        self.state.scroll_offset = 0;
+        match self.daemon.sync_users(&path, dry_run).await {
+            Ok(sync) => {
+                if !dry_run {
+                    self.copy_message = Some(format!("Created {} user(s)", sync.created.len()));
+                }
+                self.state.user_sync = Some(sync);
+                if !dry_run {
+                    self.refresh_users().await;
                 }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.prs_list_focus, PrsListFocus::List) {
-                    self.state.move_selection_up();
-                } else {
-                    // Navigate up in action panel
-                    self.state.action_panel_up();
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Handle keys in Config view
+    async fn handle_config_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.state.scroll_down(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.scroll_up(),
+            KeyCode::Char('v') => self.navigate(View::Version, ViewParams::default()),
+            KeyCode::Char('a') => self.navigate(View::Agents, ViewParams::default()),
+            KeyCode::Char('e') => self.open_config_editor(),
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Start editing a copy of the loaded project config
+    fn open_config_editor(&mut self) {
+        if let Some(config) = self.state.config.clone() {
+            self.state.config_editor = Some(ConfigEditor::new(config));
+            self.navigate(View::ConfigEdit, ViewParams::default());
+        }
+    }
+
+    /// Handle keys in the config editor
+    async fn handle_config_editor_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(editor) = self.state.config_editor.as_mut() else {
+            self.go_back();
+            return Ok(());
+        };
+
+        // Typing a value
+        if editor.input.is_some() {
+            match key.code {
+                KeyCode::Esc => editor.input = None,
+                KeyCode::Enter => {
+                    if let Err(e) = editor.commit_input() {
+                        self.push_error(e);
+                    }
+                }
+                KeyCode::Backspace => {
+                    if let Some(input) = editor.input.as_mut() {
+                        input.value.pop();
+                    }
                 }
-            }
-            KeyCode::Enter => {
-                if matches!(self.state.prs_list_focus, PrsListFocus::ActionPanel) {
-                    self.execute_selected_dynamic_action().await?;
-                } else {
-                    // Open PR detail
-                    let pr_id = self
-                        .state
-                        .sorted_prs()
-                        .get(self.state.selected_index)
-                        .map(|pr| pr.id.clone());
-                    if let Some(id) = pr_id {
-                        self.state.selected_pr_id = Some(id.clone());
-                        self.navigate(
-                            View::PrDetail,
-                            ViewParams {
-                                pr_id: Some(id),
-                                ..Default::default()
-                            },
-                        );
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    if let Some(input) = editor.input.as_mut() {
+                        input.value.push(c);
                     }
                 }
+                _ => {}
             }
-            KeyCode::Char('s') => self.state.cycle_pr_sort_field(),
-            KeyCode::Char('S') => self.state.toggle_pr_sort_direction(),
-            KeyCode::Char('a') => {
-                self.state.show_merged_prs = !self.state.show_merged_prs;
-                self.state.reset_selection();
-            }
-            // Navigation to other views
-            KeyCode::Char('2') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Issues, ViewParams::default());
+            return Ok(());
+        }
+
+        // Discarding unsaved changes
+        if editor.confirm_discard {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    self.state.config_editor = None;
+                    self.copy_message = Some("Discarded unsaved config changes".to_string());
+                    self.go_back();
                 }
-            }
-            KeyCode::Char('4') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Docs, ViewParams::default());
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    editor.confirm_discard = false;
                 }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let has_item = editor.item_count() > 0;
+        let selected = editor.selected;
+        match (editor.section, key.code) {
+            // Save (Ctrl+S or Cmd+W / Ctrl+W)
+            (_, KeyCode::Char('s')) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_config().await;
+            }
+            (_, KeyCode::Char('w')) if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.save_config().await;
+            }
+            (_, KeyCode::Tab) => editor.cycle_section(true),
+            (_, KeyCode::BackTab) => editor.cycle_section(false),
+            (_, KeyCode::Char('j') | KeyCode::Down) => editor.select_next(),
+            (_, KeyCode::Char('k') | KeyCode::Up) => editor.select_prev(),
+            (_, KeyCode::Char('J')) => editor.move_selected(false),
+            (_, KeyCode::Char('K')) => editor.move_selected(true),
+            (_, KeyCode::Char('h') | KeyCode::Left) => editor.adjust(false),
+            (_, KeyCode::Char('l') | KeyCode::Right) => editor.adjust(true),
+            (_, KeyCode::Char(' ')) => editor.toggle(),
+            (ConfigSection::States, KeyCode::Char('a')) => {
+                editor.begin_input(ConfigInputTarget::NewState)
+            }
+            (ConfigSection::CustomFields, KeyCode::Char('a')) => {
+                editor.begin_input(ConfigInputTarget::NewField)
+            }
+            (ConfigSection::LinkTypes, KeyCode::Char('a')) => {
+                editor.begin_input(ConfigInputTarget::NewLinkType)
+            }
+            (ConfigSection::States, KeyCode::Char('e')) if has_item => {
+                editor.begin_input(ConfigInputTarget::StateName(selected))
+            }
+            (ConfigSection::CustomFields, KeyCode::Char('e')) if has_item => {
+                editor.begin_input(ConfigInputTarget::FieldDefault(selected))
+            }
+            (ConfigSection::CustomFields, KeyCode::Char('v')) if has_item => {
+                editor.begin_input(ConfigInputTarget::FieldEnumValues(selected))
             }
-            KeyCode::Char('5') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::People, ViewParams::default());
+            (ConfigSection::LinkTypes, KeyCode::Char('e')) if has_item => {
+                editor.begin_input(ConfigInputTarget::LinkInverse(selected))
+            }
+            (ConfigSection::LinkTypes, KeyCode::Char('d')) if has_item => {
+                editor.begin_input(ConfigInputTarget::LinkDescription(selected))
+            }
+            (_, KeyCode::Char('x')) => {
+                if let Err(e) = editor.remove_selected() {
+                    self.push_error(e);
                 }
             }
-            KeyCode::Esc | KeyCode::Backspace => {
-                // Reset focus state when leaving
-                self.state.prs_list_focus = PrsListFocus::List;
-                self.state.action_panel_selected_index = 0;
+            (_, KeyCode::Esc) if editor.dirty => editor.confirm_discard = true,
+            (_, KeyCode::Esc) => {
+                self.state.config_editor = None;
                 self.go_back();
             }
             _ => {}
@@ -1587,132 +4665,197 @@ impl App {
         Ok(())
     }
 
-    /// Handle keys in PR Detail view
-    async fn handle_pr_detail_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check for dynamic action shortcut first (when focused on content)
-        if matches!(self.state.pr_detail_focus, PrDetailFocus::Content) {
-            if let Some(action_idx) = self.find_action_for_key(&key) {
-                self.state.action_panel_selected_index = action_idx;
-                self.execute_selected_dynamic_action().await?;
-                return Ok(());
+    /// Save the config draft and show the daemon's normalized result
+    async fn save_config(&mut self) {
+        let (Some(path), Some(editor)) = (
+            self.state.selected_project_path.clone(),
+            self.state.config_editor.as_ref(),
+        ) else {
+            return;
+        };
+        match self.daemon.update_config(&path, &editor.draft).await {
+            Ok(saved) => {
+                if let Some(editor) = self.state.config_editor.as_mut() {
+                    editor.draft = saved.clone();
+                    editor.dirty = false;
+                    editor.selected = editor.selected.min(editor.item_count().saturating_sub(1));
+                }
+                self.state.config = Some(saved);
+                self.copy_message = Some("Config saved".to_string());
             }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Fetch daemon info and the selected project's version for the Version panel
+    async fn fetch_version_info(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+        match self.daemon.get_daemon_info().await {
+            Ok(info) => self.state.daemon_info = Some(info),
+            Err(e) => self.push_error(e.to_string()),
+        }
+        match self.daemon.get_project_version(&path).await {
+            Ok(version) => self.state.project_version = Some(version),
+            Err(e) => self.push_error(e.to_string()),
         }
 
+        // Preselect the daemon's version as the natural migration target
+        let daemon_version = self
+            .state
+            .project_version
+            .as_ref()
+            .map(|v| v.daemon_version.clone());
+        self.state.selected_version_index = self
+            .state
+            .available_versions()
+            .iter()
+            .position(|v| Some(v) == daemon_version.as_ref())
+            .unwrap_or(0);
+        self.state.migration_result = None;
+    }
+
+    /// Handle keys in the Version panel
+    async fn handle_version_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            // Tab: Switch focus between content and action panel
-            KeyCode::Tab => {
-                self.state.pr_detail_focus.toggle();
-            }
-            // Edit PR
-            KeyCode::Char('e') => {
-                if let Some(pr_id) = self.state.selected_pr_id.clone() {
-                    // Load PR data into form before navigating
-                    if let Some(pr) = self.state.prs.iter().find(|p| p.id == pr_id).cloned() {
-                        self.state.load_pr_to_form(&pr);
-                    }
-                    self.navigate(
-                        View::PrEdit,
-                        ViewParams {
-                            pr_id: Some(pr_id),
-                            ..Default::default()
-                        },
-                    );
+            KeyCode::Char('j') | KeyCode::Down => self.state.select_next_version(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.select_prev_version(),
+            KeyCode::Enter => self.confirm_migration(),
+            KeyCode::Char('6') => self.navigate(View::Config, ViewParams::default()),
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Ask before migrating the selected project to the selected version
+    fn confirm_migration(&mut self) {
+        let (Some(path), Some(target)) = (
+            self.state.selected_project_path.as_deref(),
+            self.state.selected_version(),
+        ) else {
+            return;
+        };
+        let current = self
+            .state
+            .project_version
+            .as_ref()
+            .map(|v| v.project_version.as_str());
+        self.state.pending_confirm_action =
+            Some(PendingConfirmAction::migrate_project(path, current, target));
+    }
+
+    /// Migrate a project to `target`
+    async fn migrate_project_version(&mut self, path: &str, target: &str) {
+        match self.daemon.update_version(path, target).await {
+            Ok(result) => {
+                if let Ok(version) = self.daemon.get_project_version(path).await {
+                    self.state.project_version = Some(version);
                 }
-            }
-            // Navigation (j/k/Up/Down)
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.pr_detail_focus, PrDetailFocus::Content) {
-                    self.state.scroll_down();
-                } else {
-                    // Navigate down in action panel
-                    self.state.action_panel_down();
+                if let Ok(config) = self.daemon.get_config(path).await {
+                    self.state.config = Some(config);
                 }
+                self.state.migration_result = Some(result);
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.pr_detail_focus, PrDetailFocus::Content) {
-                    self.state.scroll_up();
-                } else {
-                    // Navigate up in action panel
-                    self.state.action_panel_up();
-                }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Load feature status, uncompacted issues and both documents
+    async fn fetch_features(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+        match self.daemon.get_feature_status(&path).await {
+            Ok(status) => self.state.feature_status = Some(status),
+            Err(e) => {
+                self.push_error(e.to_string());
+                return;
             }
-            // Execute action (Enter when action panel is focused)
-            KeyCode::Enter => {
-                if matches!(self.state.pr_detail_focus, PrDetailFocus::ActionPanel) {
-                    self.execute_selected_dynamic_action().await?;
+        }
+        match self.daemon.list_uncompacted_issues(&path).await {
+            Ok(issues) => self.state.set_uncompacted_issues(issues),
+            Err(e) => self.push_error(e.to_string()),
+        }
+        self.state.feature_instruction =
+            self.daemon.get_instruction(&path).await.unwrap_or_default();
+        self.state.feature_compact = self.daemon.get_compact(&path).await.unwrap_or_default();
+        self.state.confirm_mark_compacted = false;
+    }
+
+    /// Handle keys in the Features view
+    async fn handle_features_key(&mut self, key: KeyEvent) -> Result<()> {
+        if self.state.confirm_mark_compacted {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    self.state.confirm_mark_compacted = false;
+                    self.mark_issues_compacted().await;
                 }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state.confirm_mark_compacted = false;
+                }
+                _ => {}
             }
-            // Go back (also reset focus and action panel index)
-            KeyCode::Esc | KeyCode::Backspace => {
-                self.state.pr_detail_focus = PrDetailFocus::Content;
-                self.state.action_panel_selected_index = 0;
-                self.go_back();
-            }
-            _ => {}
+            return Ok(());
         }
-        Ok(())
-    }
 
-    /// Handle keys in PR Create view
-    async fn handle_pr_create_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            KeyCode::Esc => {
+            KeyCode::Char('j') | KeyCode::Down => self.state.select_next_uncompacted(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.select_prev_uncompacted(),
+            KeyCode::Char(' ') => self.state.toggle_uncompacted_mark(),
+            KeyCode::Char('a') => self.state.toggle_all_uncompacted_marks(),
+            KeyCode::Tab => {
+                self.state.feature_document = self.state.feature_document.toggle();
+                self.state.scroll_offset = 0;
+            }
+            // Scroll the document pane
+            KeyCode::Char('d') | KeyCode::PageDown => self.state.scroll_down_page(),
+            KeyCode::Char('u') | KeyCode::PageUp => self.state.scroll_up_page(),
+            KeyCode::Char('e') => {
                 self.state.clear_form();
-                self.go_back();
+                self.state.form_description =
+                    self.state.feature_compact.clone().unwrap_or_default();
+                self.navigate(View::CompactEdit, ViewParams::default());
             }
-            // Save (Ctrl+S or Cmd+W / Ctrl+W)
-            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.save_pr_create().await;
+            KeyCode::Char('m') => {
+                self.state.clear_form();
+                self.state.form_description = self.state.migration_template();
+                self.navigate(View::MigrationCreate, ViewParams::default());
             }
-            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
-                self.save_pr_create().await;
+            KeyCode::Char('c') => {
+                if self.state.marked_uncompacted_ids.is_empty() {
+                    self.push_error("No issues selected. Press Space to select issues.");
+                } else {
+                    self.state.confirm_mark_compacted = true;
+                }
             }
-            KeyCode::Tab => self.state.next_form_field(),
-            KeyCode::BackTab => self.state.prev_form_field(),
-            KeyCode::Char(c) => self
-                .state
-                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
-            KeyCode::Backspace => self.state.form_backspace(),
+            KeyCode::Char('r') => self.fetch_features().await,
+            KeyCode::Char('2') => self.navigate(View::Issues, ViewParams::default()),
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
             _ => {}
         }
         Ok(())
     }
 
-    /// Helper to save PR create
-    async fn save_pr_create(&mut self) {
-        if let Some(path) = &self.state.selected_project_path {
-            let result = self
-                .daemon
-                .create_pr(
-                    path,
-                    &self.state.form_title,
-                    &self.state.form_description,
-                    &self.state.form_source_branch,
-                    &self.state.form_target_branch,
-                )
-                .await;
-            if let Ok(new_id) = result {
-                if let Ok(prs) = self.daemon.list_prs(path).await {
-                    self.state.prs = prs;
-                }
-                self.state.selected_pr_id = Some(new_id.clone());
-                self.navigate_to_created_item(
-                    View::PrDetail,
-                    ViewParams {
-                        pr_id: Some(new_id),
-                        ..Default::default()
-                    },
-                );
-            } else {
-                self.push_error("Failed to create PR");
+    /// Mark the selected uncompacted issues as compacted
+    async fn mark_issues_compacted(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            return;
+        };
+        let ids = self.state.marked_uncompacted_ids.clone();
+        match self.daemon.mark_issues_compacted(&path, &ids).await {
+            Ok(count) => {
+                self.state.marked_uncompacted_ids.clear();
+                self.copy_message = Some(format!("Marked {} issue(s) as compacted", count));
+                self.fetch_features().await;
             }
-        } else {
-            self.push_error("No project selected");
+            Err(e) => self.push_error(e.to_string()),
         }
     }
 
-    /// Handle keys in PR Edit view
-    async fn handle_pr_edit_key(&mut self, key: KeyEvent) -> Result<()> {
+    /// Handle keys in the compact.md and migration editors
+    async fn handle_feature_editor_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             KeyCode::Esc => {
                 self.state.clear_form();
@@ -1720,552 +4863,886 @@ impl App {
             }
             // Save (Ctrl+S or Cmd+W / Ctrl+W)
             KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.save_pr_edit().await;
+                self.save_feature_document().await;
             }
             KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
-                self.save_pr_edit().await;
+                self.save_feature_document().await;
             }
-            KeyCode::Tab => self.state.next_form_field(),
-            KeyCode::BackTab => self.state.prev_form_field(),
             KeyCode::Char(c) => self
                 .state
                 .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
             KeyCode::Backspace => self.state.form_backspace(),
+            KeyCode::Enter => self.state.form_description.push('\n'),
             _ => {}
         }
         Ok(())
     }
 
-    /// Helper to save PR edit
-    async fn save_pr_edit(&mut self) {
-        match (
-            &self.state.selected_project_path,
-            &self.state.selected_pr_id,
-        ) {
-            (Some(path), Some(pr_id)) => {
-                let result = self
-                    .daemon
-                    .update_pr(
-                        path,
-                        pr_id,
-                        &self.state.form_title,
-                        &self.state.form_description,
-                        &self.state.form_source_branch,
-                        &self.state.form_target_branch,
-                        &self.state.form_status,
-                    )
-                    .await;
-                if result.is_ok() {
-                    if let Ok(prs) = self.daemon.list_prs(path).await {
-                        self.state.prs = prs;
-                    }
-                    self.state.clear_form();
-                    self.go_back();
-                } else {
-                    self.push_error("Failed to update PR");
-                }
-            }
-            (None, _) => {
-                self.push_error("No project selected");
+    /// Save compact.md or the new migration file from the editor
+    async fn save_feature_document(&mut self) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
+        let content = self.state.form_description.clone();
+
+        let result = if self.state.current_view == View::CompactEdit {
+            self.daemon
+                .update_compact(&path, &content)
+                .await
+                .map(|_| "Saved compact.md".to_string())
+        } else {
+            if content.trim().is_empty() {
+                self.push_error("Migration content is required");
+                return;
             }
-            (_, None) => {
-                self.push_error("No PR selected");
+            self.daemon
+                .save_migration(&path, &content)
+                .await
+                .map(|filename| format!("Saved migration {}", filename))
+        };
+
+        match result {
+            Ok(message) => {
+                self.copy_message = Some(message);
+                self.state.clear_form();
+                self.go_back();
             }
+            Err(e) => self.push_error(e.to_string()),
         }
     }
 
-    /// Handle keys in Docs view
-    async fn handle_docs_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check for dynamic action shortcut first (when focused on list)
-        if matches!(self.state.docs_list_focus, DocsListFocus::List) {
-            if let Some(action_idx) = self.find_action_for_key(&key) {
-                self.state.action_panel_selected_index = action_idx;
-                self.execute_selected_dynamic_action().await?;
-                return Ok(());
+    /// Apply finished agent session lookups and start a new one every few seconds
+    pub fn poll_llm_work(&mut self) {
+        if let Some(rx) = self.llm_work_rx.as_mut() {
+            match rx.try_recv() {
+                Ok(sessions) => {
+                    self.llm_work_rx = None;
+                    self.state.set_llm_work(sessions);
+                }
+                Err(oneshot::error::TryRecvError::Empty) => return,
+                Err(oneshot::error::TryRecvError::Closed) => self.llm_work_rx = None,
             }
         }
 
-        match key.code {
-            // Tab: Switch focus between list and action panel
-            KeyCode::Tab => {
-                self.state.docs_list_focus.toggle();
-            }
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.docs_list_focus, DocsListFocus::List) {
-                    self.state.move_selection_down(self.state.docs.len());
-                } else {
-                    // Navigate down in action panel
-                    self.state.action_panel_down();
-                }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.docs_list_focus, DocsListFocus::List) {
-                    self.state.move_selection_up();
-                } else {
-                    // Navigate up in action panel
-                    self.state.action_panel_up();
+        if self.in_splash() || !self.state.daemon_connected {
+            return;
+        }
+        let due = self
+            .state
+            .last_llm_work_poll
+            .is_none_or(|last| last.elapsed() >= LLM_WORK_POLL_INTERVAL);
+        if due {
+            self.refresh_llm_work();
+        }
+    }
+
+    /// Fetch active agent sessions for the selected project, or for all projects while the
+    /// Agent Activity view shows them (background polls elsewhere stay on one project).
+    /// The lookups run in a spawned task so a slow daemon never blocks input; `poll_llm_work`
+    /// applies the result, and a newer refresh supersedes one still in flight.
+    fn refresh_llm_work(&mut self) {
+        self.state.last_llm_work_poll = Some(Instant::now());
+
+        let all_projects =
+            self.state.llm_work_all_projects && self.state.current_view == View::AgentActivity;
+        let paths: Vec<String> = if all_projects {
+            self.state
+                .projects
+                .iter()
+                .filter(|p| p.initialized)
+                .map(|p| p.path.clone())
+                .collect()
+        } else {
+            self.state.selected_project_path.iter().cloned().collect()
+        };
+
+        let mut daemon = self.daemon.clone();
+        let (tx, rx) = oneshot::channel();
+        self.llm_work_rx = Some(rx);
+        tokio::spawn(async move {
+            let mut sessions = Vec::new();
+            for path in paths {
+                let lookup = tokio::time::timeout(LLM_WORK_RPC_TIMEOUT, daemon.get_llm_work(&path));
+                if let Ok(Ok(Some(session))) = lookup.await {
+                    sessions.push(session);
                 }
             }
-            KeyCode::Enter => {
-                if matches!(self.state.docs_list_focus, DocsListFocus::ActionPanel) {
-                    self.execute_selected_dynamic_action().await?;
-                } else {
-                    // Open doc detail
-                    if let Some(doc) = self.state.docs.get(self.state.selected_index) {
-                        self.state.selected_doc_slug = Some(doc.slug.clone());
-                        self.navigate(
-                            View::DocDetail,
-                            ViewParams {
-                                doc_slug: Some(doc.slug.clone()),
-                                ..Default::default()
-                            },
-                        );
-                    }
+            // Liveness checks may spawn a process, keep them off the async workers
+            let sessions = tokio::task::spawn_blocking(move || {
+                for session in &mut sessions {
+                    session.pid_alive = crate::platform::pid_alive(session.pid);
                 }
+                sessions
+            })
+            .await
+            .unwrap_or_default();
+            // The receiver is gone when a newer refresh replaced this one
+            let _ = tx.send(sessions);
+        });
+    }
+
+    /// Open the spawn agent dialog, prefilled with an issue (UUID or display number)
+    async fn open_spawn_dialog(&mut self, issue: &str) {
+        let Some(path) = self.state.selected_project_path.clone() else {
+            self.push_error("No project selected");
+            return;
+        };
+        let agent_names = match self.daemon.get_local_llm_config(Some(&path)).await {
+            Ok(settings) => settings.config.agents.into_iter().map(|a| a.name).collect(),
+            Err(_) => Vec::new(),
+        };
+        self.state.pending_spawn_action = Some(PendingSpawnAction::new(&path, issue, agent_names));
+    }
+
+    /// Handle keys in the spawn agent dialog
+    async fn handle_spawn_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(action) = self.state.pending_spawn_action.as_mut() else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc => self.state.pending_spawn_action = None,
+            KeyCode::Tab | KeyCode::Down => {
+                action.field = (action.field + 1) % PendingSpawnAction::FIELD_COUNT;
             }
-            // Navigation to other views
-            KeyCode::Char('2') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Issues, ViewParams::default());
-                }
+            KeyCode::BackTab | KeyCode::Up => {
+                action.field = (action.field + PendingSpawnAction::FIELD_COUNT - 1)
+                    % PendingSpawnAction::FIELD_COUNT;
             }
-            KeyCode::Char('3') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Prs, ViewParams::default());
+            KeyCode::Left | KeyCode::Right if action.field == 1 => action.action.toggle(),
+            KeyCode::Left if action.field == 2 => action.cycle_agent(false),
+            KeyCode::Right if action.field == 2 => action.cycle_agent(true),
+            KeyCode::Char(c) => {
+                if let Some(input) = action.input_mut() {
+                    input.push(c);
                 }
             }
-            KeyCode::Char('5') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::People, ViewParams::default());
+            KeyCode::Backspace => {
+                if let Some(input) = action.input_mut() {
+                    input.pop();
                 }
             }
-            KeyCode::Esc | KeyCode::Backspace => {
-                // Reset focus state when leaving
-                self.state.docs_list_focus = DocsListFocus::List;
-                self.state.action_panel_selected_index = 0;
-                self.go_back();
-            }
+            KeyCode::Enter => self.spawn_agent().await,
             _ => {}
         }
         Ok(())
     }
 
-    /// Handle keys in Doc Detail view
-    async fn handle_doc_detail_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check for dynamic action shortcut first (when focused on content)
-        if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
-            if let Some(action_idx) = self.find_action_for_key(&key) {
-                self.state.action_panel_selected_index = action_idx;
-                self.execute_selected_dynamic_action().await?;
-                return Ok(());
-            }
+    /// Spawn the agent configured in the dialog
+    async fn spawn_agent(&mut self) {
+        let Some(action) = self.state.pending_spawn_action.clone() else {
+            return;
+        };
+        let issue = action.issue.trim().trim_start_matches('#').to_string();
+        if issue.is_empty() {
+            self.push_error("Enter an issue number or UUID");
+            return;
         }
+        self.state.pending_spawn_action = None;
 
-        match key.code {
-            // Tab: Switch focus between content and action panel
-            KeyCode::Tab => {
-                self.state.doc_detail_focus.toggle();
-            }
-            // Navigation (j/k/Up/Down)
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
-                    self.state.scroll_down();
-                } else {
-                    // Navigate down in action panel
-                    self.state.action_panel_down();
-                }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
-                    self.state.scroll_up();
-                } else {
-                    // Navigate up in action panel
-                    self.state.action_panel_up();
-                }
+        match self
+            .daemon
+            .spawn_agent(
+                &action.project_path,
+                &issue,
+                action.action,
+                action.agent_name(),
+                action.extra_args(),
+            )
+            .await
+        {
+            Ok(result) => {
+                self.copy_message = Some(format!(
+                    "Spawned {} on #{}",
+                    result.agent_name, result.display_number
+                ));
+                self.state.spawn_prompt_preview =
+                    (!result.prompt_preview.is_empty()).then_some(result.prompt_preview);
+                self.refresh_llm_work();
             }
-            // Edit doc
-            KeyCode::Char('e') => {
-                if let Some(slug) = self.state.selected_doc_slug.clone() {
-                    // Load doc data into form before navigating
-                    if let Some(doc) = self.state.docs.iter().find(|d| d.slug == slug).cloned() {
-                        self.state.load_doc_to_form(&doc);
-                    }
-                    self.navigate(
-                        View::DocEdit,
-                        ViewParams {
-                            doc_slug: Some(slug),
-                            ..Default::default()
-                        },
-                    );
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Handle keys in the Agent activity panel
+    async fn handle_agent_activity_key(&mut self, key: KeyEvent) -> Result<()> {
+        if self.state.confirm_clear_llm_work {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    self.state.confirm_clear_llm_work = false;
+                    self.clear_llm_work().await;
                 }
-            }
-            KeyCode::Char('d') | KeyCode::PageDown => {
-                if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
-                    self.state.scroll_down_page();
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state.confirm_clear_llm_work = false;
                 }
+                _ => {}
             }
-            KeyCode::Char('u') | KeyCode::PageUp => {
-                if matches!(self.state.doc_detail_focus, DocDetailFocus::Content) {
-                    self.state.scroll_up_page();
-                }
+            return Ok(());
+        }
+
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.state.select_next_llm_work(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.select_prev_llm_work(),
+            KeyCode::Char('a') => {
+                self.state.llm_work_all_projects = !self.state.llm_work_all_projects;
+                self.refresh_llm_work();
             }
-            // Execute action (Enter when action panel is focused)
-            KeyCode::Enter => {
-                if matches!(self.state.doc_detail_focus, DocDetailFocus::ActionPanel) {
-                    self.execute_selected_dynamic_action().await?;
-                }
+            KeyCode::Char('r') => self.refresh_llm_work(),
+            KeyCode::Char('s') => {
+                let issue = self
+                    .state
+                    .selected_llm_work()
+                    .filter(|s| Some(&s.project_path) == self.state.selected_project_path.as_ref())
+                    .map(|s| s.display_number.to_string())
+                    .unwrap_or_default();
+                self.open_spawn_dialog(&issue).await;
             }
-            // Go back (also reset focus and action panel index)
-            KeyCode::Esc | KeyCode::Backspace => {
-                self.state.doc_detail_focus = DocDetailFocus::Content;
-                self.state.action_panel_selected_index = 0;
-                self.go_back();
+            KeyCode::Char('c') if self.state.selected_llm_work().is_some() => {
+                self.state.confirm_clear_llm_work = true;
             }
+            KeyCode::Enter => self.open_llm_work_issue().await,
+            KeyCode::Char('W') => self.navigate(View::Workspaces, ViewParams::default()),
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
             _ => {}
         }
         Ok(())
     }
 
-    /// Handle keys in Doc Create view
-    async fn handle_doc_create_key(&mut self, key: KeyEvent) -> Result<()> {
-        let field_count = get_doc_field_count(self);
-        let org_checkbox_field = if field_count == 4 { Some(3) } else { None };
+    /// Clear the selected agent session
+    async fn clear_llm_work(&mut self) {
+        let Some(path) = self
+            .state
+            .selected_llm_work()
+            .map(|s| s.project_path.clone())
+        else {
+            return;
+        };
+        match self.daemon.clear_llm_work(&path).await {
+            Ok(()) => {
+                self.copy_message = Some("Cleared agent session".to_string());
+                self.refresh_llm_work();
+            }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
 
-        match key.code {
-            KeyCode::Esc => {
-                self.state.clear_form();
-                self.go_back();
+    /// Open the issue the selected agent session works on
+    async fn open_llm_work_issue(&mut self) {
+        let Some(session) = self.state.selected_llm_work().cloned() else {
+            return;
+        };
+        if self.state.selected_project_path.as_deref() != Some(session.project_path.as_str()) {
+            self.open_project(&session.project_path).await;
+        }
+        if !self.state.issues.iter().any(|i| i.id == session.issue_id) {
+            self.push_error(format!("Issue #{} not found", session.display_number));
+            return;
+        }
+        self.state.selected_issue_id = Some(session.issue_id.clone());
+        self.state.scroll_offset = 0;
+        self.state.selected_link_index = 0;
+        self.navigate(
+            View::IssueDetail,
+            ViewParams {
+                issue_id: Some(session.issue_id),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Reload temporary workspaces across all projects, expired ones included
+    async fn refresh_workspaces(&mut self) {
+        match self.daemon.list_temp_workspaces("", true).await {
+            Ok(workspaces) => self.state.set_workspaces(workspaces),
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Handle keys in the Workspaces view
+    async fn handle_workspaces_key(&mut self, key: KeyEvent) -> Result<()> {
+        if let Some(mut confirm) = self.state.workspace_confirm {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    self.state.workspace_confirm = None;
+                    match confirm {
+                        WorkspaceConfirm::Close { force } => self.close_workspace(force).await,
+                        WorkspaceConfirm::Extend { ttl_hours } => {
+                            self.extend_workspace(ttl_hours).await
+                        }
+                        WorkspaceConfirm::CleanupExpired => self.cleanup_expired_workspaces().await,
+                    }
+                }
+                KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char('-') => {
+                    confirm.adjust_ttl(key.code != KeyCode::Char('-'));
+                    self.state.workspace_confirm = Some(confirm);
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state.workspace_confirm = None;
+                }
+                _ => {}
             }
-            // Save (Ctrl+S or Cmd+W / Ctrl+W)
-            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.save_doc_create().await;
+            return Ok(());
+        }
+
+        let has_selection = self.state.selected_workspace().is_some();
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.state.select_next_workspace(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.select_prev_workspace(),
+            KeyCode::Char('o') | KeyCode::Enter => {
+                if let Some(path) = self
+                    .state
+                    .selected_workspace()
+                    .map(|w| w.workspace_path.clone())
+                {
+                    self.open_vscode_at_path(&path).await;
+                }
             }
-            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
-                self.save_doc_create().await;
+            KeyCode::Char('t') => {
+                if let Some(path) = self
+                    .state
+                    .selected_workspace()
+                    .map(|w| w.workspace_path.clone())
+                {
+                    match crate::platform::open_terminal_at(&path) {
+                        Ok(()) => self.copy_message = Some(format!("Opened terminal at {}", path)),
+                        Err(e) => self.push_error(format!("Failed to open terminal: {}", e)),
+                    }
+                }
             }
-            KeyCode::Tab => {
-                // Wrap around based on actual field count
-                self.state.active_form_field = (self.state.active_form_field + 1) % field_count;
+            KeyCode::Char('x') if has_selection => {
+                self.state.workspace_confirm = Some(WorkspaceConfirm::Close { force: false });
             }
-            KeyCode::BackTab => {
-                // Wrap around based on actual field count
-                if self.state.active_form_field == 0 {
-                    self.state.active_form_field = field_count - 1;
-                } else {
-                    self.state.active_form_field -= 1;
-                }
+            KeyCode::Char('X') if has_selection => {
+                self.state.workspace_confirm = Some(WorkspaceConfirm::Close { force: true });
             }
-            // Toggle checkbox with Space or Enter when on org checkbox field
-            KeyCode::Char(' ') | KeyCode::Enter
-                if org_checkbox_field == Some(self.state.active_form_field) =>
-            {
-                self.state.toggle_org_doc();
+            KeyCode::Char('e') if has_selection => {
+                self.state.workspace_confirm = Some(WorkspaceConfirm::Extend {
+                    ttl_hours: WorkspaceConfirm::DEFAULT_TTL_HOURS,
+                });
             }
-            KeyCode::Char(c) => self
-                .state
-                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
-            KeyCode::Backspace => self.state.form_backspace(),
-            KeyCode::Enter => {
-                if self.state.active_form_field == 1 {
-                    self.state.form_description.push('\n');
-                }
+            KeyCode::Char('C') => {
+                self.state.workspace_confirm = Some(WorkspaceConfirm::CleanupExpired);
             }
+            KeyCode::Char('r') => self.refresh_workspaces().await,
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
             _ => {}
         }
         Ok(())
     }
 
-    /// Helper to save doc create
-    async fn save_doc_create(&mut self) {
-        let Some(path) = self.state.selected_project_path.clone() else {
-            self.push_error("No project selected");
+    /// Close the selected workspace
+    async fn close_workspace(&mut self, force: bool) {
+        let Some(path) = self
+            .state
+            .selected_workspace()
+            .map(|w| w.workspace_path.clone())
+        else {
             return;
         };
-
-        let slug = if self.state.form_slug.is_empty() {
-            None
-        } else {
-            Some(self.state.form_slug.clone())
+        match self.daemon.close_temp_workspace(&path, force).await {
+            Ok(()) => {
+                self.copy_message = Some(format!("Closed workspace {}", path));
+                self.refresh_workspaces().await;
+            }
+            Err(e) if !force => self.push_error(format!(
+                "{}\nPress 'X' to force close (discards uncommitted changes).",
+                e
+            )),
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Give the selected workspace a fresh TTL.
+    ///
+    /// The daemon has no way to change an expiry in place, so the workspace is
+    /// closed (without force, so uncommitted changes block it) and a new one is
+    /// opened for the same issue, action and agent. Local commits that were not
+    /// pushed are discarded and the new workspace gets a different path; the
+    /// confirmation prompt says so.
+    async fn extend_workspace(&mut self, ttl_hours: u32) {
+        let Some(workspace) = self.state.selected_workspace().cloned() else {
+            return;
         };
-        let result = self
+        if let Err(e) = self
             .daemon
-            .create_doc(
-                &path,
-                &self.state.form_title,
-                &self.state.form_description,
-                slug.as_deref(),
-                self.state.form_is_org_doc,
+            .close_temp_workspace(&workspace.workspace_path, false)
+            .await
+        {
+            self.push_error(format!("Failed to extend workspace: {}", e));
+            return;
+        }
+        match self
+            .daemon
+            .open_in_temp_vscode(
+                &workspace.source_project_path,
+                &workspace.issue_id,
+                workspace.action,
+                &workspace.agent_name,
+                ttl_hours,
             )
-            .await;
-        match result {
-            Ok((new_slug, sync_results)) => {
-                // Show sync results if this was an org doc
-                if !sync_results.is_empty() {
-                    let success_count = sync_results.iter().filter(|r| r.success).count();
-                    let total = sync_results.len();
-                    if success_count < total {
-                        // Some failed - show warning
-                        let failed: Vec<_> = sync_results
-                            .iter()
-                            .filter(|r| !r.success)
-                            .map(|r| r.error.clone())
-                            .collect();
-                        self.push_error(format!(
-                            "Doc created, but sync failed for {} project(s): {}",
-                            total - success_count,
-                            failed.join(", ")
-                        ));
-                    }
-                }
+            .await
+        {
+            Ok(result) => {
+                self.copy_message = Some(format!(
+                    "Reopened #{} at {} for {}h (expires: {})",
+                    result.display_number,
+                    result.workspace_path,
+                    ttl_hours,
+                    result
+                        .expires_at
+                        .split('T')
+                        .next()
+                        .unwrap_or(&result.expires_at)
+                ));
+            }
+            Err(e) => self.push_error(format!("Failed to reopen workspace: {}", e)),
+        }
+        self.refresh_workspaces().await;
+    }
 
-                if let Ok(docs) = self.daemon.list_docs(&path).await {
-                    self.state.docs = docs;
+    /// Remove every expired workspace and keep the report for the view
+    async fn cleanup_expired_workspaces(&mut self) {
+        match self.daemon.cleanup_expired_workspaces().await {
+            Ok(cleanup) => {
+                self.copy_message = Some(format!(
+                    "Removed {} expired workspace(s), {} failed",
+                    cleanup.cleaned_paths.len(),
+                    cleanup.failed_paths.len()
+                ));
+                self.state.workspace_cleanup = Some(cleanup);
+                self.refresh_workspaces().await;
+            }
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    // =========== Daemon ===========
+
+    /// Load daemon info and the tracked project count for the Daemon panel
+    async fn fetch_daemon_status(&mut self) {
+        match self.daemon.get_daemon_info().await {
+            Ok(mut info) => {
+                // Count every tracked project, not just the ones the grid shows
+                let everything = ProjectListFilter {
+                    include_archived: true,
+                    include_stale: true,
+                    include_uninitialized: true,
+                    include_temp: true,
+                };
+                match self.daemon.list_projects(everything).await {
+                    Ok(projects) => info.project_count = projects.len() as u32,
+                    Err(e) => self.push_error(format!("Failed to count projects: {}", e)),
                 }
-                self.state.selected_doc_slug = Some(new_slug.clone());
-                self.navigate_to_created_item(
-                    View::DocDetail,
-                    ViewParams {
-                        doc_slug: Some(new_slug),
-                        ..Default::default()
-                    },
-                );
+                self.state.daemon_info = Some(info);
+                self.state.daemon_connected = true;
+                self.state
+                    .daemon_seen_since
+                    .get_or_insert_with(Instant::now);
             }
             Err(e) => {
-                self.push_error(format!("Failed to create doc: {}", e));
+                self.state.daemon_connected = false;
+                self.push_error(e.to_string());
             }
         }
     }
 
-    /// Handle keys in Doc Edit view
-    async fn handle_doc_edit_key(&mut self, key: KeyEvent) -> Result<()> {
+    /// Handle keys in the Daemon panel
+    async fn handle_daemon_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            KeyCode::Esc => {
-                self.state.clear_form();
-                self.go_back();
-            }
-            // Save (Ctrl+S or Cmd+W / Ctrl+W)
-            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.save_doc_edit().await;
-            }
-            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
-                self.save_doc_edit().await;
+            KeyCode::Char('r') => self.fetch_daemon_status().await,
+            KeyCode::Char('R') => self.confirm_daemon_control(DaemonControl::Restart),
+            KeyCode::Char('X') => self.confirm_daemon_control(DaemonControl::Shutdown),
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char('l') | KeyCode::Right => {
+                self.state.adjust_daemon_delay(true)
             }
-            KeyCode::Tab => self.state.next_form_field(),
-            KeyCode::BackTab => self.state.prev_form_field(),
-            KeyCode::Char(c) => self
-                .state
-                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
-            KeyCode::Backspace => self.state.form_backspace(),
-            KeyCode::Enter => {
-                // Enter in content field adds newline
-                if self.state.active_form_field == 1 {
-                    self.state.form_description.push('\n');
-                }
+            KeyCode::Char('-') | KeyCode::Char('h') | KeyCode::Left => {
+                self.state.adjust_daemon_delay(false)
             }
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
             _ => {}
         }
         Ok(())
     }
 
-    /// Helper to save doc edit
-    async fn save_doc_edit(&mut self) {
-        let Some(path) = self.state.selected_project_path.clone() else {
-            self.push_error("No project selected");
-            return;
+    /// Ask before restarting or shutting down the daemon
+    fn confirm_daemon_control(&mut self, control: DaemonControl) {
+        self.state.pending_confirm_action = Some(PendingConfirmAction::daemon_control(
+            control,
+            self.state.daemon_delay_seconds,
+        ));
+    }
+
+    /// Send a confirmed restart or shutdown with the chosen delay
+    async fn run_daemon_control(&mut self, control: DaemonControl) {
+        let delay = self.state.daemon_delay_seconds;
+        let result = match control {
+            DaemonControl::Restart => self.daemon.restart(delay).await,
+            DaemonControl::Shutdown => self.daemon.shutdown(delay).await,
         };
-        let Some(slug) = self.state.selected_doc_slug.clone() else {
-            self.push_error("No doc selected");
+        if let Err(e) = result {
+            self.push_error(e.to_string());
             return;
-        };
+        }
 
-        // Check if slug changed
-        let new_slug = if !self.state.form_slug.is_empty() && self.state.form_slug != slug {
-            Some(self.state.form_slug.as_str())
-        } else {
-            None
+        self.state.daemon_connected = false;
+        self.state.daemon_seen_since = None;
+        self.state.daemon_start_observed = false;
+        match control {
+            DaemonControl::Restart => {
+                self.state.daemon_reconnect = Some(DaemonReconnect::new(delay, Instant::now()));
+                self.copy_message = Some(format!("Daemon restarting in {}s...", delay));
+            }
+            DaemonControl::Shutdown => {
+                self.state.daemon_reconnect = None;
+                self.state.daemon_info = None;
+                self.copy_message = Some(format!("Daemon shutting down in {}s", delay));
+            }
+        }
+    }
+
+    /// Reconnect once a requested restart has completed
+    pub async fn poll_daemon_reconnect(&mut self) {
+        let now = Instant::now();
+        let Some(reconnect) = self.state.daemon_reconnect.as_mut() else {
+            return;
         };
+        if now < reconnect.retry_at {
+            return;
+        }
 
-        let result = self
-            .daemon
-            .update_doc(
-                &path,
-                &slug,
-                &self.state.form_title,
-                &self.state.form_description,
-                new_slug,
-            )
-            .await;
+        if !self.daemon.reconnect().await {
+            if !reconnect.retry_later(now) {
+                self.state.daemon_reconnect = None;
+                self.push_error("Daemon did not come back after the restart");
+            }
+            return;
+        }
 
-        match result {
-            Ok(()) => {
-                if let Ok(docs) = self.daemon.list_docs(&path).await {
-                    self.state.docs = docs;
-                }
-                // Update selected slug if it changed
-                if let Some(ns) = new_slug {
-                    self.state.selected_doc_slug = Some(ns.to_string());
-                }
-                self.state.clear_form();
-                self.go_back();
+        self.state.daemon_reconnect = None;
+        self.state.daemon_connected = true;
+        self.state.daemon_seen_since = Some(now);
+        self.state.daemon_start_observed = true;
+        if let Ok(projects) = self.daemon.list_projects(self.state.project_filter).await {
+            self.state.projects = projects;
+        }
+        if self.state.current_view == View::Daemon {
+            self.fetch_daemon_status().await;
+        }
+        self.copy_message = Some("Daemon restarted, reconnected".to_string());
+    }
+
+    /// Project path for the local LLM config being edited (None for the global config)
+    fn llm_config_path(&self) -> Option<String> {
+        match self.state.llm_config_scope {
+            LlmConfigScope::Project => self.state.selected_project_path.clone(),
+            LlmConfigScope::Global => None,
+        }
+    }
+
+    /// Load the local LLM config for the Agents view
+    async fn fetch_agents(&mut self) {
+        let path = self.llm_config_path();
+        match self.daemon.get_local_llm_config(path.as_deref()).await {
+            Ok(settings) => self.state.set_local_llm(settings),
+            Err(e) => self.push_error(e.to_string()),
+        }
+    }
+
+    /// Save the local LLM config and reload it. Returns true on success.
+    async fn save_local_llm_config(&mut self, config: LocalLlmConfig) -> bool {
+        let path = self.llm_config_path();
+        match self
+            .daemon
+            .update_local_llm_config(path.as_deref(), &config)
+            .await
+        {
+            Ok(_) => {
+                self.fetch_agents().await;
+                true
             }
             Err(e) => {
-                self.push_error(format!("Failed to update doc: {}", e));
+                self.push_error(e.to_string());
+                false
             }
         }
     }
 
-    /// Handle keys in People view
-    async fn handle_people_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check for dynamic action shortcut first (when focused on list)
-        if matches!(self.state.people_list_focus, PeopleListFocus::List) {
-            if let Some(action_idx) = self.find_action_for_key(&key) {
-                self.state.action_panel_selected_index = action_idx;
-                self.execute_selected_dynamic_action().await?;
-                return Ok(());
-            }
-        }
+    /// Copy of the loaded local LLM config, to modify and save
+    fn local_llm_config(&self) -> LocalLlmConfig {
+        self.state
+            .local_llm
+            .as_ref()
+            .map(|s| s.config.clone())
+            .unwrap_or_default()
+    }
 
+    /// Handle keys in the Agents view
+    async fn handle_agents_key(&mut self, key: KeyEvent) -> Result<()> {
+        let focus = self.state.agents_focus;
         match key.code {
-            // Tab: Switch focus between list and action panel
-            KeyCode::Tab => {
-                self.state.people_list_focus.toggle();
+            KeyCode::Char('j') | KeyCode::Down => self.state.select_next_agent_item(),
+            KeyCode::Char('k') | KeyCode::Up => self.state.select_prev_agent_item(),
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.state.agents_focus = match focus {
+                    AgentsFocus::Agents => AgentsFocus::EnvVars,
+                    AgentsFocus::EnvVars => AgentsFocus::Agents,
+                };
             }
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.people_list_focus, PeopleListFocus::List) {
-                    self.state
-                        .move_selection_down(self.state.sorted_people().len());
-                } else {
-                    // Navigate down in action panel
-                    self.state.action_panel_down();
-                }
+            KeyCode::Char('w') => self.navigate(View::AgentActivity, ViewParams::default()),
+            KeyCode::Char('g') => {
+                self.state.llm_config_scope.toggle();
+                self.fetch_agents().await;
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.people_list_focus, PeopleListFocus::List) {
-                    self.state.move_selection_up();
-                } else {
-                    // Navigate up in action panel
-                    self.state.action_panel_up();
+            KeyCode::Char('s') => self.state.reveal_env_values = !self.state.reveal_env_values,
+            KeyCode::Char('n') => match focus {
+                AgentsFocus::Agents => {
+                    self.state.clear_form();
+                    self.navigate(View::AgentCreate, ViewParams::default());
+                }
+                AgentsFocus::EnvVars => {
+                    self.state.pending_agent_action = Some(PendingAgentAction::EnvVar {
+                        original_key: None,
+                        key: String::new(),
+                        value: String::new(),
+                        editing_value: false,
+                        error: None,
+                    });
                 }
-            }
-            KeyCode::Enter => {
-                if matches!(self.state.people_list_focus, PeopleListFocus::ActionPanel) {
-                    self.execute_selected_dynamic_action().await?;
-                } else {
-                    // Open person detail
-                    let sorted_people = self.state.sorted_people();
-                    if let Some(person) = sorted_people.get(self.state.selected_index) {
-                        self.state.selected_person_id = Some(person.id.clone());
-                        self.state.scroll_offset = 0;
-                        self.navigate(View::PersonDetail, ViewParams::default());
+            },
+            KeyCode::Char('e') | KeyCode::Enter => match focus {
+                AgentsFocus::Agents => {
+                    if let Some(agent) = self.state.selected_agent().cloned() {
+                        self.state.clear_form();
+                        self.state.load_agent_to_form(&agent);
+                        self.navigate(
+                            View::AgentEdit,
+                            ViewParams {
+                                agent_name: Some(agent.name),
+                                ..Default::default()
+                            },
+                        );
                     }
                 }
-            }
-            // Sort controls
-            KeyCode::Char('s') => {
-                self.state.cycle_people_sort_field();
-            }
-            KeyCode::Char('S') => {
-                self.state.toggle_people_sort_direction();
-            }
-            // Navigation to other views
-            KeyCode::Char('2') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Issues, ViewParams::default());
+                AgentsFocus::EnvVars => {
+                    if let Some((key, value)) = self.state.selected_env_var() {
+                        self.state.pending_agent_action = Some(PendingAgentAction::EnvVar {
+                            original_key: Some(key.clone()),
+                            key,
+                            value,
+                            editing_value: true,
+                            error: None,
+                        });
+                    }
                 }
-            }
-            KeyCode::Char('3') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Prs, ViewParams::default());
+            },
+            KeyCode::Char('d') if focus == AgentsFocus::Agents => {
+                if let Some(name) = self.state.selected_agent().map(|a| a.name.clone()) {
+                    let mut config = self.local_llm_config();
+                    config.default_agent = name.clone();
+                    if self.save_local_llm_config(config).await {
+                        self.copy_message = Some(format!("Default agent set to {}", name));
+                    }
                 }
             }
-            KeyCode::Char('4') => {
-                if self.state.selected_project_path.is_some() {
-                    self.navigate(View::Docs, ViewParams::default());
+            KeyCode::Char('x') => match focus {
+                AgentsFocus::Agents => {
+                    if let Some(agent) = self.state.selected_agent() {
+                        self.state.pending_agent_action = Some(PendingAgentAction::RemoveAgent {
+                            name: agent.name.clone(),
+                        });
+                    }
                 }
-            }
-            KeyCode::Esc | KeyCode::Backspace => {
-                // Reset focus state when leaving
-                self.state.people_list_focus = PeopleListFocus::List;
-                self.state.action_panel_selected_index = 0;
+                AgentsFocus::EnvVars => {
+                    if let Some((key, _)) = self.state.selected_env_var() {
+                        self.state.pending_agent_action =
+                            Some(PendingAgentAction::RemoveEnvVar { key });
+                    }
+                }
+            },
+            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle keys in the agent create/edit form
+    async fn handle_agent_form_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.state.clear_form();
                 self.go_back();
             }
+            // Save (Ctrl+S or Cmd+W / Ctrl+W)
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_agent_form().await;
+            }
+            KeyCode::Char('w') if key.modifiers.contains(crate::platform::COPY_MODIFIER) => {
+                self.save_agent_form().await;
+            }
+            KeyCode::Tab => self.state.next_form_field(),
+            KeyCode::BackTab => self.state.prev_form_field(),
+            // The type field is a selector
+            KeyCode::Left if self.state.active_form_field == 1 => {
+                self.state.form_agent_type = self.state.form_agent_type.cycle(false);
+            }
+            KeyCode::Right | KeyCode::Char(' ') if self.state.active_form_field == 1 => {
+                self.state.form_agent_type = self.state.form_agent_type.cycle(true);
+            }
+            KeyCode::Char(c) => self
+                .state
+                .form_input_char(c, key.modifiers.contains(KeyModifiers::SHIFT)),
+            KeyCode::Backspace => self.state.form_backspace(),
             _ => {}
         }
-        Ok(())
-    }
+        Ok(())
+    }
+
+    /// Validate the agent form and save it into the local LLM config
+    async fn save_agent_form(&mut self) {
+        let agent = match self.state.agent_from_form() {
+            Ok(agent) => agent,
+            Err(e) => {
+                self.push_error(e);
+                return;
+            }
+        };
+        if agent.name.is_empty() {
+            self.push_error("Agent name is required");
+            return;
+        }
+        if agent.command.is_empty() {
+            self.push_error("Agent command is required");
+            return;
+        }
+        if !command_on_path(&agent.command) {
+            self.push_error(format!("Command '{}' was not found on PATH", agent.command));
+            return;
+        }
+
+        let mut config = self.local_llm_config();
+        let original = self.state.view_params.agent_name.clone();
+        let name = agent.name.clone();
+        if let Err(e) = config.upsert_agent(original.as_deref(), agent) {
+            self.push_error(e);
+            return;
+        }
 
-    /// Handle keys in Person Detail view
-    async fn handle_person_detail_key(&mut self, key: KeyEvent) -> Result<()> {
-        // Check for dynamic action shortcut first (when focused on content)
-        if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
-            if let Some(action_idx) = self.find_action_for_key(&key) {
-                self.state.action_panel_selected_index = action_idx;
-                self.execute_selected_dynamic_action().await?;
-                return Ok(());
+        if self.save_local_llm_config(config).await {
+            if let Some(idx) = self.state.llm_agents().iter().position(|a| a.name == name) {
+                self.state.selected_agent_index = idx;
             }
+            self.state.clear_form();
+            self.go_back();
         }
+    }
 
-        match key.code {
-            // Tab: Switch focus between content and action panel
-            KeyCode::Tab => {
-                self.state.person_detail_focus.toggle();
-            }
-            // Navigation (j/k/Up/Down)
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
-                    self.state.scroll_down();
-                } else {
-                    // Navigate down in action panel
-                    self.state.action_panel_down();
-                }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
-                    self.state.scroll_up();
-                } else {
-                    // Navigate up in action panel
-                    self.state.action_panel_up();
+    /// Handle keys in the agent/variable removal and environment variable dialogs
+    async fn handle_agent_dialog_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(action) = self.state.pending_agent_action.as_mut() else {
+            return Ok(());
+        };
+
+        match action {
+            PendingAgentAction::RemoveAgent { .. } | PendingAgentAction::RemoveEnvVar { .. } => {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                        let mut config = self.local_llm_config();
+                        match self.state.pending_agent_action.take() {
+                            Some(PendingAgentAction::RemoveAgent { name }) => {
+                                config.remove_agent(&name)
+                            }
+                            Some(PendingAgentAction::RemoveEnvVar { key }) => {
+                                config.env_vars.remove(&key);
+                            }
+                            _ => {}
+                        }
+                        self.save_local_llm_config(config).await;
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.state.pending_agent_action = None;
+                    }
+                    _ => {}
                 }
             }
-            KeyCode::Char('d') | KeyCode::PageDown => {
-                if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
-                    self.state.scroll_down_page();
+            PendingAgentAction::EnvVar {
+                key: name,
+                value,
+                editing_value,
+                error,
+                ..
+            } => match key.code {
+                KeyCode::Esc => self.state.pending_agent_action = None,
+                KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                    *editing_value = !*editing_value;
                 }
-            }
-            KeyCode::Char('u') | KeyCode::PageUp => {
-                if matches!(self.state.person_detail_focus, PersonDetailFocus::Content) {
-                    self.state.scroll_up_page();
+                KeyCode::Char(c) => {
+                    *error = None;
+                    if *editing_value {
+                        value.push(c);
+                    } else {
+                        name.push(c);
+                    }
                 }
-            }
-            // Execute action (Enter when action panel is focused)
-            KeyCode::Enter => {
-                if matches!(
-                    self.state.person_detail_focus,
-                    PersonDetailFocus::ActionPanel
-                ) {
-                    self.execute_selected_dynamic_action().await?;
+                KeyCode::Backspace => {
+                    if *editing_value {
+                        value.pop();
+                    } else {
+                        name.pop();
+                    }
                 }
-            }
-            // Go back (also reset focus and action panel index)
-            KeyCode::Esc | KeyCode::Backspace => {
-                self.state.person_detail_focus = PersonDetailFocus::Content;
-                self.state.action_panel_selected_index = 0;
-                self.go_back();
-            }
-            _ => {}
+                KeyCode::Enter => self.save_env_var().await,
+                _ => {}
+            },
         }
         Ok(())
     }
 
-    /// Handle keys in Config view
-    async fn handle_config_key(&mut self, key: KeyEvent) -> Result<()> {
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => self.state.scroll_down(),
-            KeyCode::Char('k') | KeyCode::Up => self.state.scroll_up(),
-            KeyCode::Esc | KeyCode::Backspace => self.go_back(),
-            _ => {}
+    /// Save the environment variable from the dialog
+    async fn save_env_var(&mut self) {
+        let Some(PendingAgentAction::EnvVar {
+            original_key,
+            key,
+            value,
+            error,
+            ..
+        }) = self.state.pending_agent_action.as_mut()
+        else {
+            return;
+        };
+
+        let name = key.trim().to_string();
+        let mut config = self
+            .state
+            .local_llm
+            .as_ref()
+            .map(|s| s.config.clone())
+            .unwrap_or_default();
+        if name.is_empty() || name.contains(char::is_whitespace) || name.contains('=') {
+            *error = Some("Name must be non-empty, without spaces or '='".to_string());
+            return;
+        }
+        if original_key.as_deref() != Some(name.as_str()) && config.env_vars.contains_key(&name) {
+            *error = Some(format!("{} is already set", name));
+            return;
+        }
+
+        if let Some(original) = original_key.as_ref() {
+            config.env_vars.remove(original);
+        }
+        config.env_vars.insert(name.clone(), value.clone());
+        self.state.pending_agent_action = None;
+
+        if self.save_local_llm_config(config).await {
+            if let Some(idx) = self
+                .state
+                .llm_env_vars()
+                .iter()
+                .position(|(k, _)| **k == name)
+            {
+                self.state.selected_env_var_index = idx;
+            }
         }
-        Ok(())
     }
 
     /// Handle keys for the worktree dialog
@@ -2377,6 +5854,27 @@ impl App {
                     // Execute the move
                     self.execute_move().await?;
                 }
+                // Duplicate: edit the new title/slug
+                KeyCode::Tab | KeyCode::BackTab => {
+                    if let Some(ref mut action) = self.state.pending_move_action {
+                        action.active_field =
+                            (action.active_field + 1) % action.duplicate_field_count();
+                    }
+                }
+                KeyCode::Backspace => {
+                    if let Some(ref mut action) = self.state.pending_move_action {
+                        if action.mode == MoveMode::Duplicate {
+                            action.active_input_mut().pop();
+                        }
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if let Some(ref mut action) = self.state.pending_move_action {
+                        if action.mode == MoveMode::Duplicate {
+                            action.active_input_mut().push(c);
+                        }
+                    }
+                }
                 _ => {}
             }
         } else {
@@ -2439,27 +5937,18 @@ impl App {
         Ok(())
     }
 
-    /// Get filtered list of valid move targets (excludes current project)
+    /// Get filtered list of valid move targets (excludes current project unless duplicating)
     fn get_filtered_move_targets(&self) -> Vec<&Project> {
-        let current_path = self
-            .state
-            .pending_move_action
-            .as_ref()
-            .map(|a| &a.source_project_path);
-
-        let search_filter = self
-            .state
-            .pending_move_action
-            .as_ref()
-            .map(|a| a.search_filter.to_lowercase())
-            .unwrap_or_default();
+        let Some(action) = self.state.pending_move_action.as_ref() else {
+            return Vec::new();
+        };
+        let search_filter = action.search_filter.to_lowercase();
 
         self.state
             .projects
             .iter()
             .filter(|p| {
-                p.initialized
-                    && Some(&p.path) != current_path
+                action.accepts_target(p)
                     && (search_filter.is_empty()
                         || p.display_name().to_lowercase().contains(&search_filter))
             })
@@ -2473,7 +5962,7 @@ impl App {
             None => return Ok(()),
         };
 
-        let target_path = match action.target_project_path {
+        let target_path = match action.target_project_path.clone() {
             Some(p) => p,
             None => {
                 self.push_error("No target project selected");
@@ -2481,6 +5970,11 @@ impl App {
             }
         };
 
+        if action.mode == MoveMode::Duplicate {
+            self.execute_duplicate(action, target_path).await;
+            return Ok(());
+        }
+
         match action.entity_type {
             MoveEntityType::Issue => {
                 match self
@@ -2548,6 +6042,76 @@ impl App {
         Ok(())
     }
 
+    /// Duplicate the entity into the target project and open the copy
+    async fn execute_duplicate(&mut self, action: PendingMoveAction, target_path: String) {
+        let title = action.new_title.trim();
+        let new_title = (!title.is_empty()).then_some(title);
+        let target_name = target_path
+            .rsplit('/')
+            .next()
+            .unwrap_or(&target_path)
+            .to_string();
+
+        let result = match action.entity_type {
+            MoveEntityType::Issue => self
+                .daemon
+                .duplicate_issue(
+                    &action.source_project_path,
+                    &action.entity_id,
+                    &target_path,
+                    new_title,
+                )
+                .await
+                .map(|issue| {
+                    let message =
+                        format!("Duplicated as #{} in {}", issue.display_number, target_name);
+                    (JumpTarget::Issue(issue), message)
+                }),
+            MoveEntityType::Doc => {
+                let slug = action.new_slug.trim();
+                self.daemon
+                    .duplicate_doc(
+                        &action.source_project_path,
+                        &action.entity_id,
+                        &target_path,
+                        (!slug.is_empty()).then_some(slug),
+                        new_title,
+                    )
+                    .await
+                    .map(|doc| {
+                        let message = format!("Duplicated as '{}' in {}", doc.slug, target_name);
+                        (JumpTarget::Doc(doc), message)
+                    })
+            }
+        };
+
+        let (target, message) = match result {
+            Ok(r) => r,
+            Err(e) => {
+                self.push_error(format!("Failed to duplicate: {}", e));
+                return;
+            }
+        };
+
+        // Same project: reload the lists so the copy shows up in them
+        if action.source_project_path == target_path {
+            self.reload_issues(&target_path).await;
+            if let Ok(docs) = self.daemon.list_docs(&target_path).await {
+                self.state.docs = docs;
+            }
+        }
+
+        self.open_jump_match(JumpMatch {
+            target,
+            project_path: target_path.clone(),
+            project_name: target_name,
+            display_path: target_path,
+        })
+        .await;
+        self.refresh_current_actions().await;
+        self.copy_message = Some(message);
+    }
+
     /// Check if the start work dialog should be shown
     /// Returns true if we're on an issue view and the issue is not already "in progress"
     fn should_show_start_work_dialog(&self) -> bool {
@@ -2610,8 +6174,8 @@ impl App {
         Ok(())
     }
 
-    /// Start the move flow for an issue
-    fn start_move_issue(&mut self) {
+    /// Open the project picker for the selected issue (move or duplicate)
+    fn start_move_issue(&mut self, mode: MoveMode) {
         let project_path = match &self.state.selected_project_path {
             Some(p) => p.clone(),
             None => {
@@ -2620,27 +6184,10 @@ impl App {
             }
         };
 
-        // Check if there are other projects to move to
-        let target_count = self
-            .state
-            .projects
-            .iter()
-            .filter(|p| p.initialized && p.path != project_path)
-            .count();
-
-        if target_count == 0 {
-            self.push_error("No other projects to move to");
-            return;
-        }
-
         // Get issue from current selection context
-        let (issue_id, display) = if let Some(id) = &self.state.selected_issue_id {
-            let issue = self.state.issues.iter().find(|i| &i.id == id);
-            match issue {
-                Some(i) => (
-                    i.id.clone(),
-                    format!("#{} {}", i.display_number, truncate_str(&i.title, 30)),
-                ),
+        let issue = if let Some(id) = &self.state.selected_issue_id {
+            match self.state.issues.iter().find(|i| &i.id == id) {
+                Some(i) => i.clone(),
                 None => {
                     self.push_error("Issue not found");
                     return;
@@ -2648,12 +6195,8 @@ impl App {
             }
         } else {
             // From list view
-            let sorted = self.state.sorted_issues();
-            match sorted.get(self.state.selected_index) {
-                Some(i) => (
-                    i.id.clone(),
-                    format!("#{} {}", i.display_number, truncate_str(&i.title, 30)),
-                ),
+            match self.state.sorted_issues().get(self.state.selected_index) {
+                Some(i) => (*i).clone(),
                 None => {
                     self.push_error("No issue selected");
                     return;
@@ -2661,20 +6204,23 @@ impl App {
             }
         };
 
-        self.state.pending_move_action = Some(PendingMoveAction {
-            entity_type: MoveEntityType::Issue,
-            source_project_path: project_path,
-            entity_id: issue_id,
-            entity_display: display,
-            selected_project_index: 0,
-            search_filter: String::new(),
-            show_confirmation: false,
-            target_project_path: None,
-        });
+        let mut action = PendingMoveAction::new(
+            mode,
+            MoveEntityType::Issue,
+            project_path,
+            issue.id.clone(),
+            format!(
+                "#{} {}",
+                issue.display_number,
+                truncate_str(&issue.title, 30)
+            ),
+        );
+        action.new_title = format!("Copy of {}", issue.title);
+        self.open_move_dialog(action);
     }
 
-    /// Start the move flow for a doc
-    fn start_move_doc(&mut self) {
+    /// Open the project picker for the selected doc (move or duplicate)
+    fn start_move_doc(&mut self, mode: MoveMode) {
         let project_path = match &self.state.selected_project_path {
             Some(p) => p.clone(),
             None => {
@@ -2683,26 +6229,9 @@ impl App {
             }
         };
 
-        // Check if there are other projects to move to
-        let target_count = self
-            .state
-            .projects
-            .iter()
-            .filter(|p| p.initialized && p.path != project_path)
-            .count();
-
-        if target_count == 0 {
-            self.push_error("No other projects to move to");
-            return;
-        }
-
-        let (slug, display) = if let Some(s) = &self.state.selected_doc_slug {
-            let doc = self.state.docs.iter().find(|d| &d.slug == s);
-            match doc {
-                Some(d) => (
-                    d.slug.clone(),
-                    format!("{} ({})", truncate_str(&d.title, 25), d.slug),
-                ),
+        let doc = if let Some(s) = &self.state.selected_doc_slug {
+            match self.state.docs.iter().find(|d| &d.slug == s) {
+                Some(d) => d.clone(),
                 None => {
                     self.push_error("Doc not found");
                     return;
@@ -2710,10 +6239,7 @@ impl App {
             }
         } else {
             match self.state.docs.get(self.state.selected_index) {
-                Some(d) => (
-                    d.slug.clone(),
-                    format!("{} ({})", truncate_str(&d.title, 25), d.slug),
-                ),
+                Some(d) => d.clone(),
                 None => {
                     self.push_error("No doc selected");
                     return;
@@ -2721,16 +6247,28 @@ impl App {
             }
         };
 
-        self.state.pending_move_action = Some(PendingMoveAction {
-            entity_type: MoveEntityType::Doc,
-            source_project_path: project_path,
-            entity_id: slug,
-            entity_display: display,
-            selected_project_index: 0,
-            search_filter: String::new(),
-            show_confirmation: false,
-            target_project_path: None,
-        });
+        let mut action = PendingMoveAction::new(
+            mode,
+            MoveEntityType::Doc,
+            project_path,
+            doc.slug.clone(),
+            format!("{} ({})", truncate_str(&doc.title, 25), doc.slug),
+        );
+        action.new_title = format!("Copy of {}", doc.title);
+        action.new_slug = format!("{}-copy", doc.slug);
+        self.open_move_dialog(action);
+    }
+
+    /// Show the project picker if there is at least one project to pick
+    fn open_move_dialog(&mut self, action: PendingMoveAction) {
+        if !self.state.projects.iter().any(|p| action.accepts_target(p)) {
+            self.push_error(match action.mode {
+                MoveMode::Move => "No other projects to move to",
+                MoveMode::Duplicate => "No projects to duplicate into",
+            });
+            return;
+        }
+        self.state.pending_move_action = Some(action);
     }
 
     /// Open VS Code at a specific path
@@ -2793,15 +6331,18 @@ impl App {
                 // Don't process normal UI events during drag - just update selection
                 return Ok(());
             }
-            MouseEventKind::Up(MouseButton::Left) => {
+            MouseEventKind::Up(MouseButton::Left) if self.state.selection.is_selecting => {
                 // Finish selection on mouse up
-                if self.state.selection.is_selecting {
-                    self.state.selection.finish();
-                }
+                self.state.selection.finish();
             }
             _ => {}
         }
 
+        // The confirmation dialog is modal
+        if self.state.pending_confirm_action.is_some() {
+            return self.handle_confirm_dialog_mouse(mouse).await;
+        }
+
         // Track view before handling mouse to detect navigation
         let view_before = self.state.current_view.clone();
 
@@ -2851,6 +6392,54 @@ impl App {
             }
             View::PersonDetail => self.handle_scroll_mouse(mouse).await?,
             View::Config => self.handle_scroll_mouse(mouse).await?,
+            View::Search => self.handle_search_mouse(mouse).await?,
+            View::Assets => self.handle_scroll_mouse(mouse).await?,
+            View::InitWizard => self.handle_init_wizard_mouse(mouse),
+            View::Version => match mouse.kind {
+                MouseEventKind::ScrollUp => self.state.select_prev_version(),
+                MouseEventKind::ScrollDown => self.state.select_next_version(),
+                _ => {}
+            },
+            View::Organizations => match mouse.kind {
+                MouseEventKind::ScrollUp => self.state.select_prev_organization(),
+                MouseEventKind::ScrollDown => self.state.select_next_organization(),
+                _ => {}
+            },
+            View::Features => match mouse.kind {
+                MouseEventKind::ScrollUp => self.state.select_prev_uncompacted(),
+                MouseEventKind::ScrollDown => self.state.select_next_uncompacted(),
+                _ => {}
+            },
+            View::AgentActivity => match mouse.kind {
+                MouseEventKind::ScrollUp => self.state.select_prev_llm_work(),
+                MouseEventKind::ScrollDown => self.state.select_next_llm_work(),
+                _ => {}
+            },
+            View::Workspaces => match mouse.kind {
+                MouseEventKind::ScrollUp => self.state.select_prev_workspace(),
+                MouseEventKind::ScrollDown => self.state.select_next_workspace(),
+                _ => {}
+            },
+            View::Daemon => {}
+            View::UserSync => match mouse.kind {
+                MouseEventKind::ScrollUp => self.state.scroll_up(),
+                MouseEventKind::ScrollDown => self.state.scroll_down(),
+                _ => {}
+            },
+            View::Agents => match mouse.kind {
+                MouseEventKind::ScrollUp => self.state.select_prev_agent_item(),
+                MouseEventKind::ScrollDown => self.state.select_next_agent_item(),
+                _ => {}
+            },
+            View::OrganizationCreate
+            | View::OrganizationEdit
+            | View::PersonCreate
+            | View::PersonEdit
+            | View::ConfigEdit
+            | View::CompactEdit
+            | View::MigrationCreate
+            | View::AgentCreate
+            | View::AgentEdit => {}
         }
 
         // Refresh actions if view changed to one that shows action panel
@@ -2871,7 +6460,7 @@ impl App {
             return Ok(false);
         }
 
-        let actions = get_local_actions(&self.state.current_view);
+        let actions = get_local_actions(&self.state);
         if actions.is_empty() {
             return Ok(false);
         }
@@ -2962,6 +6551,14 @@ impl App {
                     }
                 }
             }
+            "add_link" => {
+                self.start_add_link().await;
+            }
+            "spawn_agent" => {
+                let issue = self.state.selected_issue_id.clone().unwrap_or_default();
+                self.open_spawn_dialog(&issue).await;
+            }
+            "publish_draft" => self.publish_selected_draft().await,
             // Navigation actions between list pages
             "nav_issues" => {
                 self.navigate(View::Issues, ViewParams::default());
@@ -2975,6 +6572,25 @@ impl App {
             "nav_people" => {
                 self.navigate(View::People, ViewParams::default());
             }
+            "nav_assets" => {
+                self.navigate(View::Assets, ViewParams::default());
+            }
+            "nav_agent_activity" => {
+                self.navigate(View::AgentActivity, ViewParams::default());
+            }
+            "nav_agents" => {
+                self.navigate(View::Agents, ViewParams::default());
+            }
+            "nav_features" => {
+                self.navigate(View::Features, ViewParams::default());
+            }
+            "nav_version" => {
+                self.navigate(View::Version, ViewParams::default());
+            }
+            "nav_config" => {
+                self.navigate(View::Config, ViewParams::default());
+            }
+            "nav_config_edit" => self.open_config_editor(),
             "nav_projects" => {
                 self.navigate(View::Projects, ViewParams::default());
             }
@@ -3129,6 +6745,9 @@ impl App {
                 self.state.sidebar_index = 4;
                 self.navigate(View::Config, ViewParams::default());
             }
+            View::Organizations => {
+                self.navigate(View::Organizations, ViewParams::default());
+            }
             _ => {}
         }
     }
@@ -3315,91 +6934,82 @@ impl App {
                 self.state.move_selection_down_grouped_grid(columns);
                 self.state.ensure_selected_visible(columns, visible_height);
             }
-            MouseEventKind::Down(MouseButton::Left) => {
-                if mouse.column >= main_area_start_x && UiArea::GridContent.contains_row(mouse.row)
-                {
-                    let rel_x = mouse.column - main_area_start_x - 1; // -1 for border
-                                                                      // Account for scroll offset when calculating click position
-                    let scroll_offset = self.state.scroll_offset as u16;
-                    let rel_y = UiArea::GridContent.relative_row(mouse.row) + scroll_offset;
-
-                    // Calculate which card was clicked, accounting for section headers
-                    let sections = self.state.grouped_projects();
-                    let mut y_offset: u16 = 0;
-                    let mut global_project_index: usize = 0;
-                    let mut clicked_index: Option<usize> = None;
-
-                    for section in &sections {
-                        // Skip section header area
-                        y_offset += SECTION_HEADER_HEIGHT;
-
-                        let section_size = section.projects.len();
-                        let rows_in_section = section_size.div_ceil(columns);
-                        let section_height = (rows_in_section as u16) * CARD_HEIGHT;
-
-                        // Check if click is in this section's project area
-                        if rel_y >= y_offset && rel_y < y_offset + section_height {
-                            let rel_y_in_section = rel_y - y_offset;
-                            let row_in_section = (rel_y_in_section / CARD_HEIGHT) as usize;
-                            let col = (rel_x / (card_width + CARD_SPACING_H)) as usize;
-
-                            if col < columns {
-                                let idx_in_section = row_in_section * columns + col;
-                                if idx_in_section < section_size {
-                                    clicked_index = Some(global_project_index + idx_in_section);
-                                }
+            MouseEventKind::Down(MouseButton::Left)
+                if mouse.column >= main_area_start_x
+                    && UiArea::GridContent.contains_row(mouse.row) =>
+            {
+                let rel_x = mouse.column - main_area_start_x - 1; // -1 for border
+                                                                  // Account for scroll offset when calculating click position
+                let scroll_offset = self.state.scroll_offset as u16;
+                let rel_y = UiArea::GridContent.relative_row(mouse.row) + scroll_offset;
+
+                // Calculate which card was clicked, accounting for section headers
+                let sections = self.state.grouped_projects();
+                let mut y_offset: u16 = 0;
+                let mut global_project_index: usize = 0;
+                let mut clicked_index: Option<usize> = None;
+
+                for section in &sections {
+                    // Skip section header area
+                    y_offset += SECTION_HEADER_HEIGHT;
+
+                    let section_size = section.projects.len();
+                    let rows_in_section = section_size.div_ceil(columns);
+                    let section_height = (rows_in_section as u16) * CARD_HEIGHT;
+
+                    // Check if click is in this section's project area
+                    if rel_y >= y_offset && rel_y < y_offset + section_height {
+                        let rel_y_in_section = rel_y - y_offset;
+                        let row_in_section = (rel_y_in_section / CARD_HEIGHT) as usize;
+                        let col = (rel_x / (card_width + CARD_SPACING_H)) as usize;
+
+                        if col < columns {
+                            let idx_in_section = row_in_section * columns + col;
+                            if idx_in_section < section_size {
+                                clicked_index = Some(global_project_index + idx_in_section);
                             }
-                            break;
                         }
-
-                        y_offset += section_height;
-                        global_project_index += section_size;
+                        break;
                     }
 
-                    if let Some(clicked_idx) = clicked_index {
-                        // Check for double-click: same index clicked within 400ms
-                        let is_double_click = self
-                            .state
-                            .last_click_index
-                            .map(|last_idx| {
-                                last_idx == clicked_idx
-                                    && self
-                                        .state
-                                        .last_click_time
-                                        .map(|t| t.elapsed() < Duration::from_millis(400))
-                                        .unwrap_or(false)
-                            })
-                            .unwrap_or(false);
+                    y_offset += section_height;
+                    global_project_index += section_size;
+                }
 
-                        if is_double_click {
-                            // Double-click: open the project
-                            let project_path = self
-                                .state
-                                .selectable_projects()
-                                .get(clicked_idx)
-                                .map(|p| p.path.clone());
-                            if let Some(path) = project_path {
-                                self.state.selected_project_path = Some(path.clone());
-                                if let Ok(issues) = self.daemon.list_issues(&path).await {
-                                    self.state.issues = issues;
-                                }
-                                if let Ok(docs) = self.daemon.list_docs(&path).await {
-                                    self.state.docs = docs;
-                                }
-                                if let Ok(config) = self.daemon.get_config(&path).await {
-                                    self.state.config = Some(config);
-                                }
-                                self.navigate(View::Issues, ViewParams::default());
-                            }
-                            // Reset click tracking after opening
-                            self.state.last_click_time = None;
-                            self.state.last_click_index = None;
-                        } else {
-                            // Single click: select the card and update tracking
-                            self.state.selected_index = clicked_idx;
-                            self.state.last_click_time = Some(Instant::now());
-                            self.state.last_click_index = Some(clicked_idx);
+                if let Some(clicked_idx) = clicked_index {
+                    // Check for double-click: same index clicked within 400ms
+                    let is_double_click = self
+                        .state
+                        .last_click_index
+                        .map(|last_idx| {
+                            last_idx == clicked_idx
+                                && self
+                                    .state
+                                    .last_click_time
+                                    .map(|t| t.elapsed() < Duration::from_millis(400))
+                                    .unwrap_or(false)
+                        })
+                        .unwrap_or(false);
+
+                    if is_double_click {
+                        // Double-click: open the project
+                        let project_path = self
+                            .state
+                            .selectable_projects()
+                            .get(clicked_idx)
+                            .map(|p| p.path.clone());
+                        if let Some(path) = project_path {
+                            self.open_project(&path).await;
+                            self.navigate(View::Issues, ViewParams::default());
                         }
+                        // Reset click tracking after opening
+                        self.state.last_click_time = None;
+                        self.state.last_click_index = None;
+                    } else {
+                        // Single click: select the card and update tracking
+                        self.state.selected_index = clicked_idx;
+                        self.state.last_click_time = Some(Instant::now());
+                        self.state.last_click_index = Some(clicked_idx);
                     }
                 }
             }
@@ -3456,16 +7066,31 @@ impl App {
     }
 
     async fn handle_form_mouse(&mut self, mouse: MouseEvent) -> Result<()> {
-        let main_area_start_x = self.sidebar_width();
-        const FIELD_HEIGHT: u16 = 3;
         if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-            if mouse.column >= main_area_start_x && UiArea::FormContent.contains_row(mouse.row) {
-                let field_index =
-                    (UiArea::FormContent.relative_row(mouse.row) / FIELD_HEIGHT) as usize;
-                let max_fields = self.state.form_field_count();
-                if field_index < max_fields {
-                    self.state.active_form_field = field_index;
+            let (height, width) = self.terminal_size.unwrap_or((24, 80));
+            let area =
+                crate::ui::main_area(Rect::new(0, 0, width, height), &self.state.current_view);
+            let pos = Position::new(mouse.column, mouse.row);
+            let clicked = crate::ui::forms::field_areas(area, self)
+                .into_iter()
+                .enumerate()
+                .find(|(_, rect)| rect.contains(pos));
+            if let Some((field_index, rect)) = clicked {
+                // A click inside the border of the active typed field operates it
+                let inside = mouse.row > rect.y && mouse.row + 1 < rect.bottom();
+                if field_index == self.state.active_form_field && inside {
+                    let line = (mouse.row - rect.y - 1) as usize;
+                    self.state.update_typed_form_field(|field| {
+                        let scroll = match &field.value {
+                            FieldValue::MultiSelect { cursor, .. } => {
+                                crate::ui::forms::multi_select_scroll(rect, Some(*cursor))
+                            }
+                            _ => 0,
+                        };
+                        field.click(scroll + line);
+                    });
                 }
+                self.state.active_form_field = field_index;
             }
         }
         match mouse.kind {
@@ -3552,6 +7177,16 @@ impl App {
     }
 }
 
+/// Resolve where a saved asset goes; a directory keeps the asset's filename
+fn asset_save_target(asset: &Asset, path: &str) -> PathBuf {
+    let target = expand_tilde(path.trim());
+    if target.is_dir() {
+        target.join(&asset.filename)
+    } else {
+        target
+    }
+}
+
 /// Truncate a string to a maximum length with ellipsis
 fn truncate_str(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -4292,4 +7927,32 @@ mod tests {
             assert!(result.is_none());
         }
     }
+
+    mod asset_save_target_tests {
+        use super::*;
+        use chrono::Utc;
+
+        fn asset(filename: &str) -> Asset {
+            Asset {
+                filename: filename.to_string(),
+                size: 0,
+                mime_type: "text/plain".to_string(),
+                is_shared: false,
+                created_at: Utc::now(),
+            }
+        }
+
+        #[test]
+        fn test_directory_keeps_asset_filename() {
+            let dir = std::env::temp_dir();
+            let target = asset_save_target(&asset("notes.txt"), &dir.display().to_string());
+            assert_eq!(target, dir.join("notes.txt"));
+        }
+
+        #[test]
+        fn test_file_path_is_used_as_is() {
+            let target = asset_save_target(&asset("notes.txt"), " /nonexistent/out.txt ");
+            assert_eq!(target, PathBuf::from("/nonexistent/out.txt"));
+        }
+    }
 }
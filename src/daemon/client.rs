@@ -4,8 +4,12 @@
 //! via gRPC using the generated proto types.
 
 use crate::state::{
-    ActionCategory, Config, DaemonInfo, Doc, EntityAction, EntityActionsResponse, EntityType,
-    Issue, IssueMetadata, Organization, PrMetadata, Project, PullRequest, User,
+    ActionCategory, AgentConfig, AgentType, Asset, Config, CustomFieldDefinition, DaemonInfo, Doc,
+    EntityAction, EntityActionsResponse, EntityLink, EntityType, FeatureStatus, GitContributor,
+    InitResult, Issue, IssueMetadata, JumpMatch, JumpTarget, LinkTypeInfo, LlmAction, LlmConfig,
+    LlmWorkSession, LocalLlmConfig, LocalLlmSettings, OrgInference, Organization, PlanFile,
+    PrMetadata, Project, ProjectListFilter, ProjectVersion, PullRequest, ReconciliationPlan,
+    SearchResult, User, UserSync, VersionComparison, VersionMigration,
 };
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
@@ -22,6 +26,7 @@ use proto::centy_daemon_client::CentyDaemonClient;
 const DEFAULT_ADDRESS: &str = "http://127.0.0.1:50051";
 
 /// Client for communicating with the Centy daemon
+#[derive(Clone)]
 pub struct DaemonClient {
     /// The gRPC client
     client: Option<CentyDaemonClient<tonic::transport::Channel>>,
@@ -52,6 +57,15 @@ pub struct OpenInTerminalResult {
     pub expires_at: String,
 }
 
+/// Result from spawning an agent on an issue
+#[derive(Debug, Clone)]
+pub struct SpawnAgentResult {
+    pub agent_name: String,
+    pub display_number: u32,
+    /// First 500 characters of the generated prompt
+    pub prompt_preview: String,
+}
+
 /// A temporary workspace entry
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -67,6 +81,21 @@ pub struct TempWorkspace {
     pub expires_at: String,
 }
 
+/// Outcome of removing expired temporary workspaces
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceCleanup {
+    pub cleaned_paths: Vec<String>,
+    pub failed_paths: Vec<String>,
+}
+
+/// Results of an advanced search
+#[derive(Debug, Clone)]
+pub struct SearchResults {
+    pub results: Vec<SearchResult>,
+    pub total_count: i32,
+    pub parsed_query: Option<String>,
+}
+
 impl DaemonClient {
     /// Create a new daemon client
     pub async fn new() -> Result<Self> {
@@ -100,17 +129,28 @@ impl DaemonClient {
         self.client.is_some()
     }
 
-    /// List all tracked projects
-    pub async fn list_projects(&mut self) -> Result<Vec<Project>> {
+    /// Drop the current connection and connect again
+    pub async fn reconnect(&mut self) -> bool {
+        self.client = CentyDaemonClient::connect(self.address.clone()).await.ok();
+        self.client.is_some()
+    }
+
+    /// Address the client connects to
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// List tracked projects, including the extra kinds enabled in the filter
+    pub async fn list_projects(&mut self, filter: ProjectListFilter) -> Result<Vec<Project>> {
         let client = self.ensure_connected().await?;
 
         let request = tonic::Request::new(proto::ListProjectsRequest {
-            include_stale: false,
-            include_uninitialized: false,
-            include_archived: false,
+            include_stale: filter.include_stale,
+            include_uninitialized: filter.include_uninitialized,
+            include_archived: filter.include_archived,
             organization_slug: String::new(),
             ungrouped_only: false,
-            include_temp: false,
+            include_temp: filter.include_temp,
         });
 
         let response = client
@@ -122,36 +162,7 @@ impl DaemonClient {
             .into_inner()
             .projects
             .into_iter()
-            .map(|p| Project {
-                path: p.path,
-                name: p.name,
-                project_title: if p.project_title.is_empty() {
-                    None
-                } else {
-                    Some(p.project_title)
-                },
-                user_title: if p.user_title.is_empty() {
-                    None
-                } else {
-                    Some(p.user_title)
-                },
-                is_favorite: p.is_favorite,
-                is_archived: p.is_archived,
-                initialized: p.initialized,
-                issue_count: p.issue_count,
-                doc_count: p.doc_count,
-                pr_count: 0, // PR count not in proto, will need to be added or fetched separately
-                organization_slug: if p.organization_slug.is_empty() {
-                    None
-                } else {
-                    Some(p.organization_slug)
-                },
-                organization_name: if p.organization_name.is_empty() {
-                    None
-                } else {
-                    Some(p.organization_name)
-                },
-            })
+            .map(project_from_proto)
             .collect();
 
         Ok(projects)
@@ -177,27 +188,7 @@ impl DaemonClient {
             .into_inner()
             .issues
             .into_iter()
-            .map(|i| {
-                let metadata = i.metadata.unwrap_or_default();
-                Issue {
-                    id: i.id,
-                    display_number: i.display_number,
-                    title: i.title,
-                    description: i.description,
-                    metadata: IssueMetadata {
-                        status: metadata.status,
-                        priority: metadata.priority as u32,
-                        priority_label: if metadata.priority_label.is_empty() {
-                            None
-                        } else {
-                            Some(metadata.priority_label)
-                        },
-                        created_at: parse_timestamp(&metadata.created_at),
-                        updated_at: parse_timestamp(&metadata.updated_at),
-                        custom_fields: metadata.custom_fields,
-                    },
-                }
-            })
+            .map(issue_from_proto)
             .collect();
 
         Ok(issues)
@@ -224,41 +215,7 @@ impl DaemonClient {
             .into_inner()
             .prs
             .into_iter()
-            .map(|pr| {
-                let metadata = pr.metadata.unwrap_or_default();
-                PullRequest {
-                    id: pr.id,
-                    display_number: pr.display_number,
-                    title: pr.title,
-                    description: pr.description,
-                    metadata: PrMetadata {
-                        status: metadata.status,
-                        priority: metadata.priority as u32,
-                        priority_label: if metadata.priority_label.is_empty() {
-                            None
-                        } else {
-                            Some(metadata.priority_label)
-                        },
-                        source_branch: metadata.source_branch,
-                        target_branch: metadata.target_branch,
-                        linked_issues: Vec::new(), // Links are now in separate system
-                        reviewers: metadata.reviewers,
-                        created_at: parse_timestamp(&metadata.created_at),
-                        updated_at: parse_timestamp(&metadata.updated_at),
-                        merged_at: if metadata.merged_at.is_empty() {
-                            None
-                        } else {
-                            Some(parse_timestamp(&metadata.merged_at))
-                        },
-                        closed_at: if metadata.closed_at.is_empty() {
-                            None
-                        } else {
-                            Some(parse_timestamp(&metadata.closed_at))
-                        },
-                        custom_fields: metadata.custom_fields,
-                    },
-                }
-            })
+            .map(pr_from_proto)
             .collect();
 
         Ok(prs)
@@ -281,16 +238,7 @@ impl DaemonClient {
             .into_inner()
             .docs
             .into_iter()
-            .map(|d| {
-                let metadata = d.metadata.unwrap_or_default();
-                Doc {
-                    slug: d.slug,
-                    title: d.title,
-                    content: d.content,
-                    created_at: parse_timestamp(&metadata.created_at),
-                    updated_at: parse_timestamp(&metadata.updated_at),
-                }
-            })
+            .map(doc_from_proto)
             .collect();
 
         Ok(docs)
@@ -310,17 +258,35 @@ impl DaemonClient {
             .await
             .map_err(|e| anyhow!("Failed to get config: {}", e))?;
 
-        let config = response.into_inner();
-        Ok(Config {
-            priority_levels: config.priority_levels as u32,
-            allowed_states: config.allowed_states,
-            default_state: config.default_state,
-            version: config.version,
-        })
+        Ok(config_from_proto(response.into_inner()))
+    }
+
+    /// Save the project config, returning it as normalized by the daemon
+    pub async fn update_config(&mut self, project_path: &str, config: &Config) -> Result<Config> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::UpdateConfigRequest {
+            project_path: project_path.to_string(),
+            config: Some(config_to_proto(config)),
+        });
+
+        let response = client
+            .update_config(request)
+            .await
+            .map_err(|e| anyhow!("Failed to update config: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to update config: {}", inner.error));
+        }
+
+        Ok(inner
+            .config
+            .map(config_from_proto)
+            .unwrap_or_else(|| config.clone()))
     }
 
     /// Get daemon information
-    #[allow(dead_code)]
     pub async fn get_daemon_info(&mut self) -> Result<DaemonInfo> {
         let client = self.ensure_connected().await?;
 
@@ -334,8 +300,62 @@ impl DaemonClient {
         let info = response.into_inner();
         Ok(DaemonInfo {
             version: info.version,
-            uptime_seconds: 0, // Not provided by proto
-            project_count: 0,  // Need to fetch separately
+            binary_path: info.binary_path,
+            vscode_available: info.vscode_available,
+            project_count: 0, // Need to fetch separately
+            available_versions: info.available_versions,
+        })
+    }
+
+    /// Get the project's version compared to the daemon's
+    pub async fn get_project_version(&mut self, project_path: &str) -> Result<ProjectVersion> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetProjectVersionRequest {
+            project_path: project_path.to_string(),
+        });
+
+        let response = client
+            .get_project_version(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get project version: {}", e))?;
+
+        let info = response.into_inner();
+        Ok(ProjectVersion {
+            project_version: info.project_version,
+            daemon_version: info.daemon_version,
+            comparison: VersionComparison::parse(&info.comparison),
+            degraded_mode: info.degraded_mode,
+        })
+    }
+
+    /// Migrate a project to the target version
+    pub async fn update_version(
+        &mut self,
+        project_path: &str,
+        target_version: &str,
+    ) -> Result<VersionMigration> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::UpdateVersionRequest {
+            project_path: project_path.to_string(),
+            target_version: target_version.to_string(),
+        });
+
+        let response = client
+            .update_version(request)
+            .await
+            .map_err(|e| anyhow!("Failed to update version: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to update version: {}", inner.error));
+        }
+
+        Ok(VersionMigration {
+            from_version: inner.from_version,
+            to_version: inner.to_version,
+            migrations_applied: inner.migrations_applied,
         })
     }
 
@@ -381,8 +401,108 @@ impl DaemonClient {
         Ok(())
     }
 
+    /// Assign a project to an organization (`None` removes it from its organization)
+    pub async fn set_project_organization(
+        &mut self,
+        project_path: &str,
+        organization_slug: Option<&str>,
+    ) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::SetProjectOrganizationRequest {
+            project_path: project_path.to_string(),
+            organization_slug: organization_slug.unwrap_or_default().to_string(),
+        });
+
+        let response = client
+            .set_project_organization(request)
+            .await
+            .map_err(|e| anyhow!("Failed to set project organization: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!(
+                "Failed to set project organization: {}",
+                inner.error
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Set the personal project title (stored in ~/.centy, empty clears it)
+    pub async fn set_project_user_title(&mut self, project_path: &str, title: &str) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::SetProjectUserTitleRequest {
+            project_path: project_path.to_string(),
+            title: title.to_string(),
+        });
+
+        let response = client
+            .set_project_user_title(request)
+            .await
+            .map_err(|e| anyhow!("Failed to set project user title: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to set project user title: {}", inner.error));
+        }
+
+        Ok(())
+    }
+
+    /// Set the shared project title (stored in .centy/project.json, empty clears it)
+    pub async fn set_project_title(&mut self, project_path: &str, title: &str) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::SetProjectTitleRequest {
+            project_path: project_path.to_string(),
+            title: title.to_string(),
+        });
+
+        let response = client
+            .set_project_title(request)
+            .await
+            .map_err(|e| anyhow!("Failed to set project title: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to set project title: {}", inner.error));
+        }
+
+        Ok(())
+    }
+
+    /// Register a project directory with the daemon
+    pub async fn register_project(
+        &mut self,
+        project_path: &str,
+    ) -> Result<(Project, Option<OrgInference>)> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::RegisterProjectRequest {
+            project_path: project_path.to_string(),
+        });
+
+        let response = client
+            .register_project(request)
+            .await
+            .map_err(|e| anyhow!("Failed to register project: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to register project: {}", inner.error));
+        }
+
+        let project = inner
+            .project
+            .map(project_from_proto)
+            .ok_or_else(|| anyhow!("Failed to register project: no project returned"))?;
+        Ok((project, inner.org_inference.map(org_inference_from_proto)))
+    }
+
     /// Untrack a project
-    #[allow(dead_code)]
     pub async fn untrack_project(&mut self, project_path: &str) -> Result<()> {
         let client = self.ensure_connected().await?;
 
@@ -398,7 +518,8 @@ impl DaemonClient {
         Ok(())
     }
 
-    /// Create a new issue
+    /// Create a new issue, optionally from a template in .centy/templates/issues
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_issue(
         &mut self,
         project_path: &str,
@@ -406,6 +527,8 @@ impl DaemonClient {
         description: &str,
         priority: u32,
         draft: bool,
+        template: Option<&str>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<String> {
         let client = self.ensure_connected().await?;
 
@@ -415,8 +538,8 @@ impl DaemonClient {
             description: description.to_string(),
             priority: priority as i32,
             status: String::new(),
-            custom_fields: HashMap::new(),
-            template: String::new(),
+            custom_fields,
+            template: template.unwrap_or("").to_string(),
             draft,
         });
 
@@ -433,7 +556,8 @@ impl DaemonClient {
         Ok(inner.id)
     }
 
-    /// Update an existing issue
+    /// Update an existing issue (draft None leaves the draft flag unchanged)
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_issue(
         &mut self,
         project_path: &str,
@@ -442,6 +566,8 @@ impl DaemonClient {
         description: &str,
         priority: u32,
         status: &str,
+        draft: Option<bool>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<()> {
         let client = self.ensure_connected().await?;
 
@@ -452,8 +578,8 @@ impl DaemonClient {
             description: description.to_string(),
             status: status.to_string(),
             priority: priority as i32,
-            custom_fields: HashMap::new(),
-            draft: None,
+            custom_fields,
+            draft,
         });
 
         let response = client
@@ -516,26 +642,7 @@ impl DaemonClient {
             return Err(anyhow!("{}", inner.error));
         }
 
-        let proto_issue = inner.issue.unwrap_or_default();
-        let metadata = proto_issue.metadata.unwrap_or_default();
-        let issue = Issue {
-            id: proto_issue.id,
-            display_number: proto_issue.display_number,
-            title: proto_issue.title,
-            description: proto_issue.description,
-            metadata: IssueMetadata {
-                status: metadata.status,
-                priority: metadata.priority as u32,
-                priority_label: if metadata.priority_label.is_empty() {
-                    None
-                } else {
-                    Some(metadata.priority_label)
-                },
-                created_at: parse_timestamp(&metadata.created_at),
-                updated_at: parse_timestamp(&metadata.updated_at),
-                custom_fields: metadata.custom_fields,
-            },
-        };
+        let issue = issue_from_proto(inner.issue.unwrap_or_default());
 
         Ok((issue, inner.old_display_number))
     }
@@ -567,20 +674,75 @@ impl DaemonClient {
             return Err(anyhow!("{}", inner.error));
         }
 
-        let proto_doc = inner.doc.unwrap_or_default();
-        let metadata = proto_doc.metadata.unwrap_or_default();
-        let doc = Doc {
-            slug: proto_doc.slug,
-            title: proto_doc.title,
-            content: proto_doc.content,
-            created_at: parse_timestamp(&metadata.created_at),
-            updated_at: parse_timestamp(&metadata.updated_at),
-        };
+        let doc = doc_from_proto(inner.doc.unwrap_or_default());
 
         Ok((doc, inner.old_slug))
     }
 
-    /// Create a new PR
+    /// Duplicate an issue into a project (the source project is allowed)
+    pub async fn duplicate_issue(
+        &mut self,
+        source_project_path: &str,
+        issue_id: &str,
+        target_project_path: &str,
+        new_title: Option<&str>,
+    ) -> Result<Issue> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::DuplicateIssueRequest {
+            source_project_path: source_project_path.to_string(),
+            issue_id: issue_id.to_string(),
+            target_project_path: target_project_path.to_string(),
+            new_title: new_title.unwrap_or("").to_string(),
+        });
+
+        let response = client
+            .duplicate_issue(request)
+            .await
+            .map_err(|e| anyhow!("Failed to duplicate issue: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("{}", inner.error));
+        }
+
+        Ok(issue_from_proto(inner.issue.unwrap_or_default()))
+    }
+
+    /// Duplicate a doc into a project (the source project is allowed)
+    pub async fn duplicate_doc(
+        &mut self,
+        source_project_path: &str,
+        slug: &str,
+        target_project_path: &str,
+        new_slug: Option<&str>,
+        new_title: Option<&str>,
+    ) -> Result<Doc> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::DuplicateDocRequest {
+            source_project_path: source_project_path.to_string(),
+            slug: slug.to_string(),
+            target_project_path: target_project_path.to_string(),
+            new_slug: new_slug.unwrap_or("").to_string(),
+            new_title: new_title.unwrap_or("").to_string(),
+        });
+
+        let response = client
+            .duplicate_doc(request)
+            .await
+            .map_err(|e| anyhow!("Failed to duplicate doc: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("{}", inner.error));
+        }
+
+        Ok(doc_from_proto(inner.doc.unwrap_or_default()))
+    }
+
+    /// Create a new PR, optionally from a template in .centy/templates/prs
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_pr(
         &mut self,
         project_path: &str,
@@ -588,6 +750,10 @@ impl DaemonClient {
         description: &str,
         source_branch: &str,
         target_branch: &str,
+        reviewers: Vec<String>,
+        priority: u32,
+        template: Option<&str>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<String> {
         let client = self.ensure_connected().await?;
 
@@ -597,11 +763,11 @@ impl DaemonClient {
             description: description.to_string(),
             source_branch: source_branch.to_string(),
             target_branch: target_branch.to_string(),
-            reviewers: Vec::new(),
-            priority: 0,
+            reviewers,
+            priority: priority as i32,
             status: String::new(),
-            custom_fields: HashMap::new(),
-            template: String::new(),
+            custom_fields,
+            template: template.unwrap_or("").to_string(),
         });
 
         let response = client
@@ -617,6 +783,28 @@ impl DaemonClient {
         Ok(inner.id)
     }
 
+    /// Delete a PR
+    pub async fn delete_pr(&mut self, project_path: &str, pr_id: &str) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::DeletePrRequest {
+            project_path: project_path.to_string(),
+            pr_id: pr_id.to_string(),
+        });
+
+        let response = client
+            .delete_pr(request)
+            .await
+            .map_err(|e| anyhow!("Failed to delete PR: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to delete PR: {}", inner.error));
+        }
+
+        Ok(())
+    }
+
     /// Update an existing PR
     #[allow(clippy::too_many_arguments)]
     pub async fn update_pr(
@@ -628,6 +816,9 @@ impl DaemonClient {
         source_branch: &str,
         target_branch: &str,
         status: &str,
+        reviewers: Vec<String>,
+        priority: u32,
+        custom_fields: HashMap<String, String>,
     ) -> Result<()> {
         let client = self.ensure_connected().await?;
 
@@ -639,9 +830,9 @@ impl DaemonClient {
             status: status.to_string(),
             source_branch: source_branch.to_string(),
             target_branch: target_branch.to_string(),
-            reviewers: Vec::new(),
-            priority: 0,
-            custom_fields: HashMap::new(),
+            reviewers,
+            priority: priority as i32,
+            custom_fields,
         });
 
         let response = client
@@ -723,6 +914,28 @@ impl DaemonClient {
         Ok(())
     }
 
+    /// Delete a doc
+    pub async fn delete_doc(&mut self, project_path: &str, slug: &str) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::DeleteDocRequest {
+            project_path: project_path.to_string(),
+            slug: slug.to_string(),
+        });
+
+        let response = client
+            .delete_doc(request)
+            .await
+            .map_err(|e| anyhow!("Failed to delete doc: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to delete doc: {}", inner.error));
+        }
+
+        Ok(())
+    }
+
     /// Open a project in a temporary VS Code workspace
     pub async fn open_in_temp_vscode(
         &mut self,
@@ -801,11 +1014,15 @@ impl DaemonClient {
     }
 
     /// List temporary workspaces, optionally filtered by project path
-    pub async fn list_temp_workspaces(&mut self, project_path: &str) -> Result<Vec<TempWorkspace>> {
+    pub async fn list_temp_workspaces(
+        &mut self,
+        project_path: &str,
+        include_expired: bool,
+    ) -> Result<Vec<TempWorkspace>> {
         let client = self.ensure_connected().await?;
 
         let request = tonic::Request::new(proto::ListTempWorkspacesRequest {
-            include_expired: false,
+            include_expired,
             source_project_path: project_path.to_string(),
         });
 
@@ -856,211 +1073,1546 @@ impl DaemonClient {
         Ok(())
     }
 
-    /// Restart the daemon
-    #[allow(dead_code)]
-    pub async fn restart(&mut self) -> Result<()> {
+    /// Remove every expired temporary workspace
+    pub async fn cleanup_expired_workspaces(&mut self) -> Result<WorkspaceCleanup> {
         let client = self.ensure_connected().await?;
 
-        let request = tonic::Request::new(proto::RestartRequest { delay_seconds: 0 });
+        let request = tonic::Request::new(proto::CleanupExpiredWorkspacesRequest {});
 
-        client
+        let response = client
+            .cleanup_expired_workspaces(request)
+            .await
+            .map_err(|e| anyhow!("Failed to clean up expired workspaces: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!(
+                "Failed to clean up expired workspaces: {}",
+                inner.error
+            ));
+        }
+
+        Ok(WorkspaceCleanup {
+            cleaned_paths: inner.cleaned_paths,
+            failed_paths: inner.failed_paths,
+        })
+    }
+
+    /// Restart the daemon after delay_seconds
+    pub async fn restart(&mut self, delay_seconds: u32) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::RestartRequest { delay_seconds });
+
+        let response = client
             .restart(request)
             .await
             .map_err(|e| anyhow!("Failed to restart daemon: {}", e))?;
 
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to restart daemon: {}", inner.message));
+        }
+
         // Clear the client so we reconnect on next call
         self.client = None;
 
         Ok(())
     }
 
-    /// Shutdown the daemon
-    #[allow(dead_code)]
-    pub async fn shutdown(&mut self) -> Result<()> {
+    /// Shutdown the daemon after delay_seconds
+    pub async fn shutdown(&mut self, delay_seconds: u32) -> Result<()> {
         let client = self.ensure_connected().await?;
 
-        let request = tonic::Request::new(proto::ShutdownRequest { delay_seconds: 0 });
+        let request = tonic::Request::new(proto::ShutdownRequest { delay_seconds });
 
-        client
+        let response = client
             .shutdown(request)
             .await
             .map_err(|e| anyhow!("Failed to shutdown daemon: {}", e))?;
 
-        // Clear the client since daemon is shutting down
-        self.client = None;
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to shutdown daemon: {}", inner.message));
+        }
+
+        // Clear the client since daemon is shutting down
+        self.client = None;
+
+        Ok(())
+    }
+
+    /// Get available actions for an entity
+    pub async fn get_entity_actions(
+        &mut self,
+        project_path: &str,
+        entity_type: EntityType,
+        entity_id: Option<&str>,
+    ) -> Result<EntityActionsResponse> {
+        let client = self.ensure_connected().await?;
+
+        let proto_entity_type = match entity_type {
+            EntityType::Issue => proto::EntityType::Issue as i32,
+            EntityType::Pr => proto::EntityType::Pr as i32,
+            EntityType::Doc => proto::EntityType::Doc as i32,
+        };
+
+        let request = tonic::Request::new(proto::GetEntityActionsRequest {
+            project_path: project_path.to_string(),
+            entity_type: proto_entity_type,
+            entity_id: entity_id.unwrap_or("").to_string(),
+        });
+
+        let response = client
+            .get_entity_actions(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get entity actions: {}", e))?;
+
+        let inner = response.into_inner();
+
+        if !inner.success {
+            return Err(anyhow!("Failed to get entity actions: {}", inner.error));
+        }
+
+        let actions = inner
+            .actions
+            .into_iter()
+            .map(|a| EntityAction {
+                id: a.id,
+                label: a.label,
+                category: ActionCategory::from_proto(a.category),
+                enabled: a.enabled,
+                disabled_reason: a.disabled_reason,
+                destructive: a.destructive,
+                keyboard_shortcut: a.keyboard_shortcut,
+            })
+            .collect();
+
+        Ok(EntityActionsResponse { actions })
+    }
+
+    /// Get an organization by slug
+    pub async fn get_organization(&mut self, slug: &str) -> Result<Option<Organization>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetOrganizationRequest {
+            slug: slug.to_string(),
+        });
+
+        let response = client
+            .get_organization(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get organization: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.found {
+            return Ok(None);
+        }
+
+        Ok(inner.organization.map(organization_from_proto))
+    }
+
+    /// List all organizations
+    pub async fn list_organizations(&mut self) -> Result<Vec<Organization>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::ListOrganizationsRequest {});
+
+        let response = client
+            .list_organizations(request)
+            .await
+            .map_err(|e| anyhow!("Failed to list organizations: {}", e))?;
+
+        Ok(response
+            .into_inner()
+            .organizations
+            .into_iter()
+            .map(organization_from_proto)
+            .collect())
+    }
+
+    /// Create an organization (the daemon derives the slug from the name when empty)
+    pub async fn create_organization(
+        &mut self,
+        slug: Option<&str>,
+        name: &str,
+        description: &str,
+    ) -> Result<Organization> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::CreateOrganizationRequest {
+            slug: slug.unwrap_or_default().to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+        });
+
+        let response = client
+            .create_organization(request)
+            .await
+            .map_err(|e| anyhow!("Failed to create organization: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to create organization: {}", inner.error));
+        }
+
+        inner
+            .organization
+            .map(organization_from_proto)
+            .ok_or_else(|| anyhow!("Failed to create organization: no organization returned"))
+    }
+
+    /// Update an organization's name, description and optionally its slug
+    pub async fn update_organization(
+        &mut self,
+        slug: &str,
+        name: &str,
+        description: &str,
+        new_slug: Option<&str>,
+    ) -> Result<Organization> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::UpdateOrganizationRequest {
+            slug: slug.to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            new_slug: new_slug.unwrap_or_default().to_string(),
+        });
+
+        let response = client
+            .update_organization(request)
+            .await
+            .map_err(|e| anyhow!("Failed to update organization: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to update organization: {}", inner.error));
+        }
+
+        inner
+            .organization
+            .map(organization_from_proto)
+            .ok_or_else(|| anyhow!("Failed to update organization: no organization returned"))
+    }
+
+    /// Delete an organization. Returns the number of projects that became ungrouped.
+    pub async fn delete_organization(&mut self, slug: &str) -> Result<u32> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::DeleteOrganizationRequest {
+            slug: slug.to_string(),
+        });
+
+        let response = client
+            .delete_organization(request)
+            .await
+            .map_err(|e| anyhow!("Failed to delete organization: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to delete organization: {}", inner.error));
+        }
+
+        Ok(inner.unassigned_projects)
+    }
+
+    /// List users for a project
+    pub async fn list_users(&mut self, project_path: &str) -> Result<Vec<User>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::ListUsersRequest {
+            project_path: project_path.to_string(),
+            git_username: String::new(),
+        });
+
+        let response = client
+            .list_users(request)
+            .await
+            .map_err(|e| anyhow!("Failed to list users: {}", e))?;
+
+        let users = response
+            .into_inner()
+            .users
+            .into_iter()
+            .map(user_from_proto)
+            .collect();
+
+        Ok(users)
+    }
+
+    /// Create a team member
+    pub async fn create_user(
+        &mut self,
+        project_path: &str,
+        id: &str,
+        name: &str,
+        email: &str,
+        git_usernames: Vec<String>,
+    ) -> Result<User> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::CreateUserRequest {
+            project_path: project_path.to_string(),
+            id: id.to_string(),
+            name: name.to_string(),
+            email: email.to_string(),
+            git_usernames,
+        });
+
+        let response = client
+            .create_user(request)
+            .await
+            .map_err(|e| anyhow!("Failed to create user: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to create user: {}", inner.error));
+        }
+
+        inner
+            .user
+            .map(user_from_proto)
+            .ok_or_else(|| anyhow!("Failed to create user: no user returned"))
+    }
+
+    /// Update a team member (empty name/email or no git usernames leave them unchanged)
+    pub async fn update_user(
+        &mut self,
+        project_path: &str,
+        user_id: &str,
+        name: &str,
+        email: &str,
+        git_usernames: Vec<String>,
+    ) -> Result<User> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::UpdateUserRequest {
+            project_path: project_path.to_string(),
+            user_id: user_id.to_string(),
+            name: name.to_string(),
+            email: email.to_string(),
+            git_usernames,
+        });
+
+        let response = client
+            .update_user(request)
+            .await
+            .map_err(|e| anyhow!("Failed to update user: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to update user: {}", inner.error));
+        }
+
+        inner
+            .user
+            .map(user_from_proto)
+            .ok_or_else(|| anyhow!("Failed to update user: no user returned"))
+    }
+
+    /// Delete a team member
+    pub async fn delete_user(&mut self, project_path: &str, user_id: &str) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::DeleteUserRequest {
+            project_path: project_path.to_string(),
+            user_id: user_id.to_string(),
+        });
+
+        let response = client
+            .delete_user(request)
+            .await
+            .map_err(|e| anyhow!("Failed to delete user: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to delete user: {}", inner.error));
+        }
+
+        Ok(())
+    }
+
+    /// Create users from git history contributors (dry run only reports what would happen)
+    pub async fn sync_users(&mut self, project_path: &str, dry_run: bool) -> Result<UserSync> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::SyncUsersRequest {
+            project_path: project_path.to_string(),
+            dry_run,
+        });
+
+        let response = client
+            .sync_users(request)
+            .await
+            .map_err(|e| anyhow!("Failed to sync users: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to sync users: {}", inner.error));
+        }
+
+        let contributor = |c: proto::GitContributor| GitContributor {
+            name: c.name,
+            email: c.email,
+        };
+        Ok(UserSync {
+            dry_run,
+            created: inner.created,
+            skipped: inner.skipped,
+            errors: inner.errors,
+            would_create: inner.would_create.into_iter().map(contributor).collect(),
+            would_skip: inner.would_skip.into_iter().map(contributor).collect(),
+        })
+    }
+
+    /// List projects for a specific organization
+    pub async fn list_projects_by_organization(&mut self, org_slug: &str) -> Result<Vec<Project>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::ListProjectsRequest {
+            include_stale: false,
+            include_uninitialized: false,
+            include_archived: false,
+            organization_slug: org_slug.to_string(),
+            ungrouped_only: false,
+            include_temp: false,
+        });
+
+        let response = client
+            .list_projects(request)
+            .await
+            .map_err(|e| anyhow!("Failed to list projects: {}", e))?;
+
+        let projects = response
+            .into_inner()
+            .projects
+            .into_iter()
+            .map(project_from_proto)
+            .collect();
+
+        Ok(projects)
+    }
+
+    /// List all links for an entity
+    pub async fn list_links(
+        &mut self,
+        project_path: &str,
+        entity_id: &str,
+        entity_type: EntityType,
+    ) -> Result<Vec<EntityLink>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::ListLinksRequest {
+            project_path: project_path.to_string(),
+            entity_id: entity_id.to_string(),
+            entity_type: link_target_type(entity_type),
+        });
+
+        let response = client
+            .list_links(request)
+            .await
+            .map_err(|e| anyhow!("Failed to list links: {}", e))?;
+
+        let links = response
+            .into_inner()
+            .links
+            .into_iter()
+            .map(|l| EntityLink {
+                target_id: l.target_id,
+                target_type: entity_type_from_link_target(l.target_type),
+                link_type: l.link_type,
+                created_at: parse_timestamp(&l.created_at),
+            })
+            .collect();
+
+        Ok(links)
+    }
+
+    /// Create a link between two entities (the daemon also creates the inverse link)
+    pub async fn create_link(
+        &mut self,
+        project_path: &str,
+        source_id: &str,
+        source_type: EntityType,
+        target_id: &str,
+        target_type: EntityType,
+        link_type: &str,
+    ) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::CreateLinkRequest {
+            project_path: project_path.to_string(),
+            source_id: source_id.to_string(),
+            source_type: link_target_type(source_type),
+            target_id: target_id.to_string(),
+            target_type: link_target_type(target_type),
+            link_type: link_type.to_string(),
+        });
+
+        let response = client
+            .create_link(request)
+            .await
+            .map_err(|e| anyhow!("Failed to create link: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to create link: {}", inner.error));
+        }
+
+        Ok(())
+    }
+
+    /// Delete a link between two entities (the daemon also deletes the inverse link)
+    pub async fn delete_link(
+        &mut self,
+        project_path: &str,
+        source_id: &str,
+        source_type: EntityType,
+        target_id: &str,
+        target_type: EntityType,
+        link_type: &str,
+    ) -> Result<u32> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::DeleteLinkRequest {
+            project_path: project_path.to_string(),
+            source_id: source_id.to_string(),
+            source_type: link_target_type(source_type),
+            target_id: target_id.to_string(),
+            target_type: link_target_type(target_type),
+            link_type: link_type.to_string(),
+        });
+
+        let response = client
+            .delete_link(request)
+            .await
+            .map_err(|e| anyhow!("Failed to delete link: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to delete link: {}", inner.error));
+        }
+
+        Ok(inner.deleted_count)
+    }
+
+    /// Get all available link types (built-in and custom)
+    pub async fn get_available_link_types(
+        &mut self,
+        project_path: &str,
+    ) -> Result<Vec<LinkTypeInfo>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetAvailableLinkTypesRequest {
+            project_path: project_path.to_string(),
+        });
+
+        let response = client
+            .get_available_link_types(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get link types: {}", e))?;
+
+        let link_types = response
+            .into_inner()
+            .link_types
+            .into_iter()
+            .map(|t| LinkTypeInfo {
+                name: t.name,
+                inverse: t.inverse,
+                description: t.description,
+                is_builtin: t.is_builtin,
+            })
+            .collect();
+
+        Ok(link_types)
+    }
+
+    /// Find issues with the given UUID across all projects (matches, non-fatal errors)
+    pub async fn get_issues_by_uuid(
+        &mut self,
+        uuid: &str,
+    ) -> Result<(Vec<JumpMatch>, Vec<String>)> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetIssuesByUuidRequest {
+            uuid: uuid.to_string(),
+        });
+
+        let response = client
+            .get_issues_by_uuid(request)
+            .await
+            .map_err(|e| anyhow!("Failed to look up issue: {}", e))?;
+
+        let inner = response.into_inner();
+        let matches = inner
+            .issues
+            .into_iter()
+            .map(|i| JumpMatch {
+                target: JumpTarget::Issue(issue_from_proto(i.issue.unwrap_or_default())),
+                project_path: i.project_path,
+                project_name: i.project_name,
+                display_path: i.display_path,
+            })
+            .collect();
+
+        Ok((matches, inner.errors))
+    }
+
+    /// Find PRs with the given UUID across all projects (matches, non-fatal errors)
+    pub async fn get_prs_by_uuid(&mut self, uuid: &str) -> Result<(Vec<JumpMatch>, Vec<String>)> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetPrsByUuidRequest {
+            uuid: uuid.to_string(),
+        });
+
+        let response = client
+            .get_prs_by_uuid(request)
+            .await
+            .map_err(|e| anyhow!("Failed to look up PR: {}", e))?;
+
+        let inner = response.into_inner();
+        let matches = inner
+            .prs
+            .into_iter()
+            .map(|p| JumpMatch {
+                target: JumpTarget::Pr(pr_from_proto(p.pr.unwrap_or_default())),
+                project_path: p.project_path,
+                project_name: p.project_name,
+                display_path: p.display_path,
+            })
+            .collect();
+
+        Ok((matches, inner.errors))
+    }
+
+    /// Find docs with the given slug across all projects (matches, non-fatal errors)
+    pub async fn get_docs_by_slug(&mut self, slug: &str) -> Result<(Vec<JumpMatch>, Vec<String>)> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetDocsBySlugRequest {
+            slug: slug.to_string(),
+        });
+
+        let response = client
+            .get_docs_by_slug(request)
+            .await
+            .map_err(|e| anyhow!("Failed to look up doc: {}", e))?;
+
+        let inner = response.into_inner();
+        let matches = inner
+            .docs
+            .into_iter()
+            .map(|d| JumpMatch {
+                target: JumpTarget::Doc(doc_from_proto(d.doc.unwrap_or_default())),
+                project_path: d.project_path,
+                project_name: d.project_name,
+                display_path: d.display_path,
+            })
+            .collect();
+
+        Ok((matches, inner.errors))
+    }
+
+    /// Get an issue by its display number
+    pub async fn get_issue_by_display_number(
+        &mut self,
+        project_path: &str,
+        display_number: u32,
+    ) -> Result<Issue> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetIssueByDisplayNumberRequest {
+            project_path: project_path.to_string(),
+            display_number,
+        });
+
+        let response = client
+            .get_issue_by_display_number(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get issue #{}: {}", display_number, e))?;
+
+        Ok(issue_from_proto(response.into_inner()))
+    }
+
+    /// Get a PR by its display number
+    pub async fn get_pr_by_display_number(
+        &mut self,
+        project_path: &str,
+        display_number: u32,
+    ) -> Result<PullRequest> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetPrByDisplayNumberRequest {
+            project_path: project_path.to_string(),
+            display_number,
+        });
+
+        let response = client
+            .get_pr_by_display_number(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get PR #{}: {}", display_number, e))?;
+
+        Ok(pr_from_proto(response.into_inner()))
+    }
+
+    /// Attach a file to an issue, or add it to the shared library when `issue_id` is None
+    pub async fn add_asset(
+        &mut self,
+        project_path: &str,
+        issue_id: Option<&str>,
+        filename: &str,
+        data: Vec<u8>,
+    ) -> Result<Asset> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::AddAssetRequest {
+            project_path: project_path.to_string(),
+            issue_id: issue_id.unwrap_or("").to_string(),
+            filename: filename.to_string(),
+            data,
+            is_shared: issue_id.is_none(),
+        });
+
+        let response = client
+            .add_asset(request)
+            .await
+            .map_err(|e| anyhow!("Failed to add asset: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to add asset: {}", inner.error));
+        }
+
+        Ok(asset_from_proto(inner.asset.unwrap_or_default()))
+    }
+
+    /// List the assets attached to an issue, shared assets included
+    pub async fn list_assets(&mut self, project_path: &str, issue_id: &str) -> Result<Vec<Asset>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::ListAssetsRequest {
+            project_path: project_path.to_string(),
+            issue_id: issue_id.to_string(),
+            include_shared: true,
+        });
+
+        let response = client
+            .list_assets(request)
+            .await
+            .map_err(|e| anyhow!("Failed to list assets: {}", e))?;
+
+        let assets = response
+            .into_inner()
+            .assets
+            .into_iter()
+            .map(asset_from_proto)
+            .collect();
+
+        Ok(assets)
+    }
+
+    /// List the project's shared assets
+    pub async fn list_shared_assets(&mut self, project_path: &str) -> Result<Vec<Asset>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::ListSharedAssetsRequest {
+            project_path: project_path.to_string(),
+        });
+
+        let response = client
+            .list_shared_assets(request)
+            .await
+            .map_err(|e| anyhow!("Failed to list shared assets: {}", e))?;
+
+        let assets = response
+            .into_inner()
+            .assets
+            .into_iter()
+            .map(asset_from_proto)
+            .collect();
+
+        Ok(assets)
+    }
+
+    /// Download an asset's contents (issue asset, or shared when `issue_id` is None)
+    pub async fn get_asset(
+        &mut self,
+        project_path: &str,
+        issue_id: Option<&str>,
+        filename: &str,
+    ) -> Result<Vec<u8>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetAssetRequest {
+            project_path: project_path.to_string(),
+            issue_id: issue_id.unwrap_or("").to_string(),
+            filename: filename.to_string(),
+            is_shared: issue_id.is_none(),
+        });
+
+        let response = client
+            .get_asset(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get asset: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to get asset: {}", inner.error));
+        }
+
+        Ok(inner.data)
+    }
+
+    /// Delete an asset (issue asset, or shared when `issue_id` is None)
+    pub async fn delete_asset(
+        &mut self,
+        project_path: &str,
+        issue_id: Option<&str>,
+        filename: &str,
+    ) -> Result<()> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::DeleteAssetRequest {
+            project_path: project_path.to_string(),
+            issue_id: issue_id.unwrap_or("").to_string(),
+            filename: filename.to_string(),
+            is_shared: issue_id.is_none(),
+        });
+
+        let response = client
+            .delete_asset(request)
+            .await
+            .map_err(|e| anyhow!("Failed to delete asset: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to delete asset: {}", inner.error));
+        }
+
+        Ok(())
+    }
+
+    /// Get the reconciliation plan for initializing a project
+    pub async fn get_reconciliation_plan(
+        &mut self,
+        project_path: &str,
+    ) -> Result<ReconciliationPlan> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetReconciliationPlanRequest {
+            project_path: project_path.to_string(),
+        });
+
+        let response = client
+            .get_reconciliation_plan(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get reconciliation plan: {}", e))?;
+
+        let plan = response.into_inner();
+        let files = |files: Vec<proto::FileInfo>| -> Vec<PlanFile> {
+            files
+                .into_iter()
+                .map(|f| PlanFile {
+                    is_directory: f.file_type == proto::FileType::Directory as i32,
+                    path: f.path,
+                    content_preview: f.content_preview,
+                })
+                .collect()
+        };
+
+        Ok(ReconciliationPlan {
+            to_create: files(plan.to_create),
+            to_restore: files(plan.to_restore),
+            to_reset: files(plan.to_reset),
+            up_to_date: files(plan.up_to_date),
+            user_files: files(plan.user_files),
+            needs_decisions: plan.needs_decisions,
+        })
+    }
+
+    /// Initialize a project, restoring and resetting the chosen files
+    pub async fn execute_reconciliation(
+        &mut self,
+        project_path: &str,
+        restore: Vec<String>,
+        reset: Vec<String>,
+    ) -> Result<InitResult> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::ExecuteReconciliationRequest {
+            project_path: project_path.to_string(),
+            decisions: Some(proto::ReconciliationDecisions { restore, reset }),
+        });
+
+        let response = client
+            .execute_reconciliation(request)
+            .await
+            .map_err(|e| anyhow!("Failed to initialize project: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to initialize project: {}", inner.error));
+        }
+
+        Ok(InitResult {
+            created: inner.created,
+            restored: inner.restored,
+            reset: inner.reset,
+            skipped: inner.skipped,
+            org_inference: inner.org_inference.map(org_inference_from_proto),
+        })
+    }
+
+    /// Search issues with the advanced query language, in one project or across all projects
+    pub async fn advanced_search(
+        &mut self,
+        query: &str,
+        multi_project: bool,
+        project_path: Option<&str>,
+    ) -> Result<SearchResults> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::AdvancedSearchRequest {
+            query: query.to_string(),
+            sort_by: String::new(),
+            sort_descending: false,
+            multi_project,
+            project_path: project_path.unwrap_or("").to_string(),
+        });
+
+        let response = client
+            .advanced_search(request)
+            .await
+            .map_err(|e| anyhow!("Failed to search issues: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to search issues: {}", inner.error));
+        }
+
+        let results = inner
+            .results
+            .into_iter()
+            .map(|r| SearchResult {
+                issue: issue_from_proto(r.issue.unwrap_or_default()),
+                project_path: r.project_path,
+                project_name: r.project_name,
+                display_path: r.display_path,
+            })
+            .collect();
+
+        Ok(SearchResults {
+            results,
+            total_count: inner.total_count,
+            parsed_query: if inner.parsed_query.is_empty() {
+                None
+            } else {
+                Some(inner.parsed_query)
+            },
+        })
+    }
+
+    /// Get the features subsystem status counters
+    pub async fn get_feature_status(&mut self, project_path: &str) -> Result<FeatureStatus> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetFeatureStatusRequest {
+            project_path: project_path.to_string(),
+        });
+
+        let response = client
+            .get_feature_status(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get feature status: {}", e))?;
+
+        let inner = response.into_inner();
+        Ok(FeatureStatus {
+            initialized: inner.initialized,
+            has_compact: inner.has_compact,
+            has_instruction: inner.has_instruction,
+            migration_count: inner.migration_count,
+            uncompacted_count: inner.uncompacted_count,
+        })
+    }
+
+    /// List issues that haven't been compacted yet
+    pub async fn list_uncompacted_issues(&mut self, project_path: &str) -> Result<Vec<Issue>> {
+        let client = self.ensure_connected().await?;
 
-        Ok(())
+        let request = tonic::Request::new(proto::ListUncompactedIssuesRequest {
+            project_path: project_path.to_string(),
+        });
+
+        let response = client
+            .list_uncompacted_issues(request)
+            .await
+            .map_err(|e| anyhow!("Failed to list uncompacted issues: {}", e))?;
+
+        Ok(response
+            .into_inner()
+            .issues
+            .into_iter()
+            .map(issue_from_proto)
+            .collect())
     }
 
-    /// Get available actions for an entity
-    pub async fn get_entity_actions(
-        &mut self,
-        project_path: &str,
-        entity_type: EntityType,
-        entity_id: Option<&str>,
-    ) -> Result<EntityActionsResponse> {
+    /// Get the instruction.md content
+    pub async fn get_instruction(&mut self, project_path: &str) -> Result<String> {
         let client = self.ensure_connected().await?;
 
-        let proto_entity_type = match entity_type {
-            EntityType::Issue => proto::EntityType::Issue as i32,
-            EntityType::Pr => proto::EntityType::Pr as i32,
-            EntityType::Doc => proto::EntityType::Doc as i32,
-        };
+        let request = tonic::Request::new(proto::GetInstructionRequest {
+            project_path: project_path.to_string(),
+        });
 
-        let request = tonic::Request::new(proto::GetEntityActionsRequest {
+        let response = client
+            .get_instruction(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get instruction: {}", e))?;
+
+        Ok(response.into_inner().content)
+    }
+
+    /// Get the compact.md content, None if the file doesn't exist
+    pub async fn get_compact(&mut self, project_path: &str) -> Result<Option<String>> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetCompactRequest {
             project_path: project_path.to_string(),
-            entity_type: proto_entity_type,
-            entity_id: entity_id.unwrap_or("").to_string(),
         });
 
         let response = client
-            .get_entity_actions(request)
+            .get_compact(request)
             .await
-            .map_err(|e| anyhow!("Failed to get entity actions: {}", e))?;
+            .map_err(|e| anyhow!("Failed to get compact: {}", e))?;
 
         let inner = response.into_inner();
+        Ok(inner.exists.then_some(inner.content))
+    }
+
+    /// Replace the compact.md content
+    pub async fn update_compact(&mut self, project_path: &str, content: &str) -> Result<()> {
+        let client = self.ensure_connected().await?;
 
+        let request = tonic::Request::new(proto::UpdateCompactRequest {
+            project_path: project_path.to_string(),
+            content: content.to_string(),
+        });
+
+        let response = client
+            .update_compact(request)
+            .await
+            .map_err(|e| anyhow!("Failed to update compact: {}", e))?;
+
+        let inner = response.into_inner();
         if !inner.success {
-            return Err(anyhow!("Failed to get entity actions: {}", inner.error));
+            return Err(anyhow!("Failed to update compact: {}", inner.error));
         }
 
-        let actions = inner
-            .actions
-            .into_iter()
-            .map(|a| EntityAction {
-                id: a.id,
-                label: a.label,
-                category: ActionCategory::from_proto(a.category),
-                enabled: a.enabled,
-                disabled_reason: a.disabled_reason,
-                destructive: a.destructive,
-                keyboard_shortcut: a.keyboard_shortcut,
-            })
-            .collect();
+        Ok(())
+    }
 
-        Ok(EntityActionsResponse { actions })
+    /// Save a new migration file. Returns the created filename.
+    pub async fn save_migration(&mut self, project_path: &str, content: &str) -> Result<String> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::SaveMigrationRequest {
+            project_path: project_path.to_string(),
+            content: content.to_string(),
+        });
+
+        let response = client
+            .save_migration(request)
+            .await
+            .map_err(|e| anyhow!("Failed to save migration: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to save migration: {}", inner.error));
+        }
+
+        Ok(inner.filename)
     }
 
-    /// Get an organization by slug
-    pub async fn get_organization(&mut self, slug: &str) -> Result<Option<Organization>> {
+    /// Mark issues as compacted. Returns the number of issues marked.
+    pub async fn mark_issues_compacted(
+        &mut self,
+        project_path: &str,
+        issue_ids: &[String],
+    ) -> Result<u32> {
         let client = self.ensure_connected().await?;
 
-        let request = tonic::Request::new(proto::GetOrganizationRequest {
-            slug: slug.to_string(),
+        let request = tonic::Request::new(proto::MarkIssuesCompactedRequest {
+            project_path: project_path.to_string(),
+            issue_ids: issue_ids.to_vec(),
         });
 
         let response = client
-            .get_organization(request)
+            .mark_issues_compacted(request)
             .await
-            .map_err(|e| anyhow!("Failed to get organization: {}", e))?;
+            .map_err(|e| anyhow!("Failed to mark issues compacted: {}", e))?;
 
         let inner = response.into_inner();
-        if !inner.found {
-            return Ok(None);
+        if !inner.success {
+            return Err(anyhow!("Failed to mark issues compacted: {}", inner.error));
         }
 
-        let org = inner.organization.map(|o| Organization {
-            slug: o.slug,
-            name: o.name,
-            description: o.description,
-            created_at: parse_timestamp(&o.created_at),
-            updated_at: parse_timestamp(&o.updated_at),
-            project_count: o.project_count,
+        Ok(inner.marked_count)
+    }
+
+    /// Spawn an agent to work on an issue
+    pub async fn spawn_agent(
+        &mut self,
+        project_path: &str,
+        issue_id: &str,
+        action: LlmAction,
+        agent_name: &str,
+        extra_args: Vec<String>,
+    ) -> Result<SpawnAgentResult> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::SpawnAgentRequest {
+            project_path: project_path.to_string(),
+            issue_id: issue_id.to_string(),
+            action: action.as_proto_value(),
+            agent_name: agent_name.to_string(),
+            extra_args,
         });
 
-        Ok(org)
+        let response = client
+            .spawn_agent(request)
+            .await
+            .map_err(|e| anyhow!("Failed to spawn agent: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to spawn agent: {}", inner.error));
+        }
+
+        Ok(SpawnAgentResult {
+            agent_name: inner.agent_name,
+            display_number: inner.display_number,
+            prompt_preview: inner.prompt_preview,
+        })
     }
 
-    /// List users for a project
-    pub async fn list_users(&mut self, project_path: &str) -> Result<Vec<User>> {
+    /// Get the active agent session for a project, if any
+    pub async fn get_llm_work(&mut self, project_path: &str) -> Result<Option<LlmWorkSession>> {
         let client = self.ensure_connected().await?;
 
-        let request = tonic::Request::new(proto::ListUsersRequest {
+        let request = tonic::Request::new(proto::GetLlmWorkRequest {
             project_path: project_path.to_string(),
-            git_username: String::new(),
         });
 
         let response = client
-            .list_users(request)
+            .get_llm_work(request)
             .await
-            .map_err(|e| anyhow!("Failed to list users: {}", e))?;
+            .map_err(|e| anyhow!("Failed to get agent work: {}", e))?;
 
-        let users = response
-            .into_inner()
-            .users
-            .into_iter()
-            .map(|u| User {
-                id: u.id,
-                name: u.name,
-                email: u.email,
-                git_usernames: u.git_usernames,
-                // Contribution stats - default values, can be computed client-side
-                role: None,
-                commit_count: 0,
-                issues_assigned: 0,
-                issues_created: 0,
-                prs_created: 0,
-            })
-            .collect();
+        let inner = response.into_inner();
+        if !inner.has_active_work {
+            return Ok(None);
+        }
 
-        Ok(users)
+        Ok(inner.session.map(|s| LlmWorkSession {
+            project_path: project_path.to_string(),
+            issue_id: s.issue_id,
+            display_number: s.display_number,
+            issue_title: s.issue_title,
+            agent_name: s.agent_name,
+            action: LlmAction::from_proto_value(s.action),
+            started_at: parse_timestamp(&s.started_at),
+            pid: s.pid,
+            pid_alive: false,
+        }))
     }
 
-    /// List projects for a specific organization
-    pub async fn list_projects_by_organization(&mut self, org_slug: &str) -> Result<Vec<Project>> {
+    /// Clear the active agent session tracking for a project
+    pub async fn clear_llm_work(&mut self, project_path: &str) -> Result<()> {
         let client = self.ensure_connected().await?;
 
-        let request = tonic::Request::new(proto::ListProjectsRequest {
-            include_stale: false,
-            include_uninitialized: false,
-            include_archived: false,
-            organization_slug: org_slug.to_string(),
-            ungrouped_only: false,
-            include_temp: false,
+        let request = tonic::Request::new(proto::ClearLlmWorkRequest {
+            project_path: project_path.to_string(),
         });
 
         let response = client
-            .list_projects(request)
+            .clear_llm_work(request)
             .await
-            .map_err(|e| anyhow!("Failed to list projects: {}", e))?;
+            .map_err(|e| anyhow!("Failed to clear agent work: {}", e))?;
 
-        let projects = response
-            .into_inner()
-            .projects
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!("Failed to clear agent work: {}", inner.error));
+        }
+
+        Ok(())
+    }
+
+    /// Get the local LLM config. `None` reads the global config only.
+    pub async fn get_local_llm_config(
+        &mut self,
+        project_path: Option<&str>,
+    ) -> Result<LocalLlmSettings> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::GetLocalLlmConfigRequest {
+            project_path: project_path.unwrap_or("").to_string(),
+        });
+
+        let response = client
+            .get_local_llm_config(request)
+            .await
+            .map_err(|e| anyhow!("Failed to get local LLM config: {}", e))?;
+
+        let inner = response.into_inner();
+        Ok(LocalLlmSettings {
+            config: local_llm_config_from_proto(inner.config.unwrap_or_default()),
+            has_project_config: inner.has_project_config,
+            has_global_config: inner.has_global_config,
+        })
+    }
+
+    /// Save the local LLM config. `None` updates the global config.
+    pub async fn update_local_llm_config(
+        &mut self,
+        project_path: Option<&str>,
+        config: &LocalLlmConfig,
+    ) -> Result<LocalLlmConfig> {
+        let client = self.ensure_connected().await?;
+
+        let request = tonic::Request::new(proto::UpdateLocalLlmConfigRequest {
+            project_path: project_path.unwrap_or("").to_string(),
+            config: Some(local_llm_config_to_proto(config)),
+        });
+
+        let response = client
+            .update_local_llm_config(request)
+            .await
+            .map_err(|e| anyhow!("Failed to update local LLM config: {}", e))?;
+
+        let inner = response.into_inner();
+        if !inner.success {
+            return Err(anyhow!(
+                "Failed to update local LLM config: {}",
+                inner.error
+            ));
+        }
+
+        Ok(local_llm_config_from_proto(
+            inner.config.unwrap_or_default(),
+        ))
+    }
+}
+
+/// Convert an entity type to the proto link target type
+fn link_target_type(entity_type: EntityType) -> i32 {
+    match entity_type {
+        EntityType::Issue => proto::LinkTargetType::Issue as i32,
+        EntityType::Pr => proto::LinkTargetType::Pr as i32,
+        EntityType::Doc => proto::LinkTargetType::Doc as i32,
+    }
+}
+
+/// Convert a proto link target type to an entity type (unknown values map to Issue)
+fn entity_type_from_link_target(value: i32) -> EntityType {
+    match proto::LinkTargetType::try_from(value) {
+        Ok(proto::LinkTargetType::Pr) => EntityType::Pr,
+        Ok(proto::LinkTargetType::Doc) => EntityType::Doc,
+        _ => EntityType::Issue,
+    }
+}
+
+/// Convert a proto project to the TUI project model
+fn project_from_proto(p: proto::ProjectInfo) -> Project {
+    Project {
+        path: p.path,
+        name: p.name,
+        project_title: if p.project_title.is_empty() {
+            None
+        } else {
+            Some(p.project_title)
+        },
+        user_title: if p.user_title.is_empty() {
+            None
+        } else {
+            Some(p.user_title)
+        },
+        is_favorite: p.is_favorite,
+        is_archived: p.is_archived,
+        initialized: p.initialized,
+        issue_count: p.issue_count,
+        doc_count: p.doc_count,
+        pr_count: 0, // PR count not in proto, will need to be added or fetched separately
+        organization_slug: if p.organization_slug.is_empty() {
+            None
+        } else {
+            Some(p.organization_slug)
+        },
+        organization_name: if p.organization_name.is_empty() {
+            None
+        } else {
+            Some(p.organization_name)
+        },
+    }
+}
+
+fn organization_from_proto(o: proto::Organization) -> Organization {
+    Organization {
+        slug: o.slug,
+        name: o.name,
+        description: o.description,
+        created_at: parse_timestamp(&o.created_at),
+        updated_at: parse_timestamp(&o.updated_at),
+        project_count: o.project_count,
+    }
+}
+
+/// Convert a proto AgentType value, treating unknown values as custom
+fn agent_type_from_proto(value: i32) -> AgentType {
+    match proto::AgentType::try_from(value) {
+        Ok(proto::AgentType::Claude) => AgentType::Claude,
+        Ok(proto::AgentType::Gemini) => AgentType::Gemini,
+        Ok(proto::AgentType::Codex) => AgentType::Codex,
+        Ok(proto::AgentType::Opencode) => AgentType::OpenCode,
+        _ => AgentType::Custom,
+    }
+}
+
+fn agent_type_to_proto(agent_type: AgentType) -> i32 {
+    match agent_type {
+        AgentType::Claude => proto::AgentType::Claude as i32,
+        AgentType::Gemini => proto::AgentType::Gemini as i32,
+        AgentType::Codex => proto::AgentType::Codex as i32,
+        AgentType::OpenCode => proto::AgentType::Opencode as i32,
+        AgentType::Custom => proto::AgentType::Custom as i32,
+    }
+}
+
+fn local_llm_config_from_proto(c: proto::LocalLlmConfig) -> LocalLlmConfig {
+    LocalLlmConfig {
+        default_agent: c.default_agent,
+        agents: c
+            .agents
             .into_iter()
-            .map(|p| Project {
-                path: p.path,
-                name: p.name,
-                project_title: if p.project_title.is_empty() {
-                    None
-                } else {
-                    Some(p.project_title)
-                },
-                user_title: if p.user_title.is_empty() {
-                    None
-                } else {
-                    Some(p.user_title)
-                },
-                is_favorite: p.is_favorite,
-                is_archived: p.is_archived,
-                initialized: p.initialized,
-                issue_count: p.issue_count,
-                doc_count: p.doc_count,
-                pr_count: 0,
-                organization_slug: if p.organization_slug.is_empty() {
-                    None
-                } else {
-                    Some(p.organization_slug)
-                },
-                organization_name: if p.organization_name.is_empty() {
-                    None
-                } else {
-                    Some(p.organization_name)
-                },
+            .map(|a| AgentConfig {
+                agent_type: agent_type_from_proto(a.agent_type),
+                name: a.name,
+                command: a.command,
+                default_args: a.default_args,
+                plan_template: a.plan_template,
+                implement_template: a.implement_template,
             })
-            .collect();
+            .collect(),
+        env_vars: c.env_vars.into_iter().collect(),
+    }
+}
 
-        Ok(projects)
+fn local_llm_config_to_proto(c: &LocalLlmConfig) -> proto::LocalLlmConfig {
+    proto::LocalLlmConfig {
+        default_agent: c.default_agent.clone(),
+        agents: c
+            .agents
+            .iter()
+            .map(|a| proto::AgentConfig {
+                agent_type: agent_type_to_proto(a.agent_type),
+                name: a.name.clone(),
+                command: a.command.clone(),
+                default_args: a.default_args.clone(),
+                plan_template: a.plan_template.clone(),
+                implement_template: a.implement_template.clone(),
+            })
+            .collect(),
+        env_vars: c.env_vars.clone().into_iter().collect(),
+    }
+}
+
+/// Convert a proto issue to the TUI issue model
+fn issue_from_proto(i: proto::Issue) -> Issue {
+    let metadata = i.metadata.unwrap_or_default();
+    Issue {
+        id: i.id,
+        display_number: i.display_number,
+        title: i.title,
+        description: i.description,
+        metadata: IssueMetadata {
+            status: metadata.status,
+            priority: metadata.priority as u32,
+            priority_label: if metadata.priority_label.is_empty() {
+                None
+            } else {
+                Some(metadata.priority_label)
+            },
+            created_at: parse_timestamp(&metadata.created_at),
+            updated_at: parse_timestamp(&metadata.updated_at),
+            custom_fields: metadata.custom_fields,
+            draft: metadata.draft,
+        },
+    }
+}
+
+/// Convert a proto PR to the TUI PR model
+fn pr_from_proto(pr: proto::PullRequest) -> PullRequest {
+    let metadata = pr.metadata.unwrap_or_default();
+    PullRequest {
+        id: pr.id,
+        display_number: pr.display_number,
+        title: pr.title,
+        description: pr.description,
+        metadata: PrMetadata {
+            status: metadata.status,
+            priority: metadata.priority as u32,
+            priority_label: if metadata.priority_label.is_empty() {
+                None
+            } else {
+                Some(metadata.priority_label)
+            },
+            source_branch: metadata.source_branch,
+            target_branch: metadata.target_branch,
+            linked_issues: Vec::new(), // Links are now in separate system
+            reviewers: metadata.reviewers,
+            created_at: parse_timestamp(&metadata.created_at),
+            updated_at: parse_timestamp(&metadata.updated_at),
+            merged_at: if metadata.merged_at.is_empty() {
+                None
+            } else {
+                Some(parse_timestamp(&metadata.merged_at))
+            },
+            closed_at: if metadata.closed_at.is_empty() {
+                None
+            } else {
+                Some(parse_timestamp(&metadata.closed_at))
+            },
+            custom_fields: metadata.custom_fields,
+        },
+    }
+}
+
+/// Convert a proto doc to the TUI doc model
+fn doc_from_proto(d: proto::Doc) -> Doc {
+    let metadata = d.metadata.unwrap_or_default();
+    Doc {
+        slug: d.slug,
+        title: d.title,
+        content: d.content,
+        created_at: parse_timestamp(&metadata.created_at),
+        updated_at: parse_timestamp(&metadata.updated_at),
+    }
+}
+
+/// Convert a proto asset to the TUI asset model
+fn asset_from_proto(a: proto::Asset) -> Asset {
+    Asset {
+        filename: a.filename,
+        size: a.size,
+        mime_type: a.mime_type,
+        is_shared: a.is_shared,
+        created_at: parse_timestamp(&a.created_at),
+    }
+}
+
+/// Convert a proto org inference result to the TUI model
+fn org_inference_from_proto(o: proto::OrgInferenceResult) -> OrgInference {
+    OrgInference {
+        inferred_org_slug: o.inferred_org_slug,
+        inferred_org_name: o.inferred_org_name,
+        org_created: o.org_created,
+        existing_org_slug: o.existing_org_slug,
+        has_mismatch: o.has_mismatch,
+        message: o.message,
+    }
+}
+
+/// Convert a proto config to the TUI config model
+fn config_from_proto(c: proto::Config) -> Config {
+    let llm = c.llm.unwrap_or_default();
+    Config {
+        priority_levels: c.priority_levels.max(0) as u32,
+        allowed_states: c.allowed_states,
+        default_state: c.default_state,
+        version: c.version,
+        custom_link_types: c
+            .custom_link_types
+            .into_iter()
+            .map(|t| LinkTypeInfo {
+                name: t.name,
+                inverse: t.inverse,
+                description: t.description,
+                is_builtin: false,
+            })
+            .collect(),
+        custom_fields: c
+            .custom_fields
+            .into_iter()
+            .map(|f| CustomFieldDefinition {
+                name: f.name,
+                field_type: f.field_type,
+                required: f.required,
+                default_value: f.default_value,
+                enum_values: f.enum_values,
+            })
+            .collect(),
+        defaults: c.defaults.into_iter().collect(),
+        state_colors: c.state_colors.into_iter().collect(),
+        priority_colors: c.priority_colors.into_iter().collect(),
+        llm: LlmConfig {
+            auto_close_on_complete: llm.auto_close_on_complete,
+            update_status_on_start: llm.update_status_on_start,
+            allow_direct_edits: llm.allow_direct_edits,
+        },
+    }
+}
+
+/// Convert the TUI config model back to a proto config
+fn config_to_proto(c: &Config) -> proto::Config {
+    proto::Config {
+        custom_fields: c
+            .custom_fields
+            .iter()
+            .map(|f| proto::CustomFieldDefinition {
+                name: f.name.clone(),
+                field_type: f.field_type.clone(),
+                required: f.required,
+                default_value: f.default_value.clone(),
+                enum_values: f.enum_values.clone(),
+            })
+            .collect(),
+        defaults: c.defaults.clone().into_iter().collect(),
+        priority_levels: c.priority_levels as i32,
+        allowed_states: c.allowed_states.clone(),
+        default_state: c.default_state.clone(),
+        version: c.version.clone(),
+        state_colors: c.state_colors.clone().into_iter().collect(),
+        priority_colors: c.priority_colors.clone().into_iter().collect(),
+        llm: Some(proto::LlmConfig {
+            auto_close_on_complete: c.llm.auto_close_on_complete,
+            update_status_on_start: c.llm.update_status_on_start,
+            allow_direct_edits: c.llm.allow_direct_edits,
+        }),
+        custom_link_types: c
+            .custom_link_types
+            .iter()
+            .map(|t| proto::LinkTypeDefinition {
+                name: t.name.clone(),
+                inverse: t.inverse.clone(),
+                description: t.description.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Convert a proto user to the TUI user model
+fn user_from_proto(u: proto::User) -> User {
+    User {
+        id: u.id,
+        name: u.name,
+        email: u.email,
+        git_usernames: u.git_usernames,
+        // Contribution stats - default values, can be computed client-side
+        role: None,
+        commit_count: 0,
+        issues_assigned: 0,
+        issues_created: 0,
+        prs_created: 0,
     }
 }
 
@@ -1084,8 +2636,8 @@ impl DaemonClientTrait for DaemonClient {
         DaemonClient::check_connection(self).await
     }
 
-    async fn list_projects(&mut self) -> Result<Vec<Project>> {
-        DaemonClient::list_projects(self).await
+    async fn list_projects(&mut self, filter: ProjectListFilter) -> Result<Vec<Project>> {
+        DaemonClient::list_projects(self, filter).await
     }
 
     async fn list_issues(&mut self, project_path: &str) -> Result<Vec<Issue>> {
@@ -1127,8 +2679,20 @@ impl DaemonClientTrait for DaemonClient {
         description: &str,
         priority: u32,
         draft: bool,
+        template: Option<String>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<String> {
-        DaemonClient::create_issue(self, project_path, title, description, priority, draft).await
+        DaemonClient::create_issue(
+            self,
+            project_path,
+            title,
+            description,
+            priority,
+            draft,
+            template.as_deref(),
+            custom_fields,
+        )
+        .await
     }
 
     async fn update_issue(
@@ -1139,6 +2703,8 @@ impl DaemonClientTrait for DaemonClient {
         description: &str,
         priority: u32,
         status: &str,
+        draft: Option<bool>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<()> {
         DaemonClient::update_issue(
             self,
@@ -1148,6 +2714,8 @@ impl DaemonClientTrait for DaemonClient {
             description,
             priority,
             status,
+            draft,
+            custom_fields,
         )
         .await
     }
@@ -1156,6 +2724,14 @@ impl DaemonClientTrait for DaemonClient {
         DaemonClient::delete_issue(self, project_path, issue_id).await
     }
 
+    async fn delete_pr(&mut self, project_path: &str, pr_id: &str) -> Result<()> {
+        DaemonClient::delete_pr(self, project_path, pr_id).await
+    }
+
+    async fn delete_doc(&mut self, project_path: &str, slug: &str) -> Result<()> {
+        DaemonClient::delete_doc(self, project_path, slug).await
+    }
+
     async fn move_issue(
         &mut self,
         source_project_path: &str,
@@ -1189,6 +2765,10 @@ impl DaemonClientTrait for DaemonClient {
         description: &str,
         source_branch: &str,
         target_branch: &str,
+        reviewers: Vec<String>,
+        priority: u32,
+        template: Option<String>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<String> {
         DaemonClient::create_pr(
             self,
@@ -1197,6 +2777,10 @@ impl DaemonClientTrait for DaemonClient {
             description,
             source_branch,
             target_branch,
+            reviewers,
+            priority,
+            template.as_deref(),
+            custom_fields,
         )
         .await
     }
@@ -1210,6 +2794,9 @@ impl DaemonClientTrait for DaemonClient {
         source_branch: &str,
         target_branch: &str,
         status: &str,
+        reviewers: Vec<String>,
+        priority: u32,
+        custom_fields: HashMap<String, String>,
     ) -> Result<()> {
         DaemonClient::update_pr(
             self,
@@ -1220,6 +2807,9 @@ impl DaemonClientTrait for DaemonClient {
             source_branch,
             target_branch,
             status,
+            reviewers,
+            priority,
+            custom_fields,
         )
         .await
     }
@@ -1281,20 +2871,24 @@ impl DaemonClientTrait for DaemonClient {
         .await
     }
 
-    async fn list_temp_workspaces(&mut self, project_path: &str) -> Result<Vec<TempWorkspace>> {
-        DaemonClient::list_temp_workspaces(self, project_path).await
+    async fn list_temp_workspaces(
+        &mut self,
+        project_path: &str,
+        include_expired: bool,
+    ) -> Result<Vec<TempWorkspace>> {
+        DaemonClient::list_temp_workspaces(self, project_path, include_expired).await
     }
 
     async fn close_temp_workspace(&mut self, workspace_path: &str, force: bool) -> Result<()> {
         DaemonClient::close_temp_workspace(self, workspace_path, force).await
     }
 
-    async fn restart(&mut self) -> Result<()> {
-        DaemonClient::restart(self).await
+    async fn restart(&mut self, delay_seconds: u32) -> Result<()> {
+        DaemonClient::restart(self, delay_seconds).await
     }
 
-    async fn shutdown(&mut self) -> Result<()> {
-        DaemonClient::shutdown(self).await
+    async fn shutdown(&mut self, delay_seconds: u32) -> Result<()> {
+        DaemonClient::shutdown(self, delay_seconds).await
     }
 
     async fn get_entity_actions(
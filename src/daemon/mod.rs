@@ -3,7 +3,7 @@
 mod client;
 mod traits;
 
-pub use client::{DaemonClient, TempWorkspace};
+pub use client::{DaemonClient, TempWorkspace, WorkspaceCleanup};
 
 #[cfg(test)]
 #[allow(unused_imports)]
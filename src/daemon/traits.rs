@@ -1,10 +1,12 @@
 //! Trait abstraction for daemon client to enable mocking in tests
 
 use crate::state::{
-    Config, DaemonInfo, Doc, EntityActionsResponse, EntityType, Issue, Project, PullRequest,
+    Config, DaemonInfo, Doc, EntityActionsResponse, EntityType, Issue, Project, ProjectListFilter,
+    PullRequest,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use std::collections::HashMap;
 
 use super::client::{proto, OpenInTerminalResult, OpenInVscodeResult, TempWorkspace};
 
@@ -16,8 +18,8 @@ pub trait DaemonClientTrait: Send + Sync {
     /// Check if the daemon is reachable
     async fn check_connection(&self) -> bool;
 
-    /// List all tracked projects
-    async fn list_projects(&mut self) -> Result<Vec<Project>>;
+    /// List tracked projects, including the extra kinds enabled in the filter
+    async fn list_projects(&mut self, filter: ProjectListFilter) -> Result<Vec<Project>>;
 
     /// List issues for a project
     async fn list_issues(&mut self, project_path: &str) -> Result<Vec<Issue>>;
@@ -43,7 +45,8 @@ pub trait DaemonClientTrait: Send + Sync {
     /// Untrack a project
     async fn untrack_project(&mut self, project_path: &str) -> Result<()>;
 
-    /// Create a new issue
+    /// Create a new issue, optionally from a template
+    #[allow(clippy::too_many_arguments)]
     async fn create_issue(
         &mut self,
         project_path: &str,
@@ -51,9 +54,12 @@ pub trait DaemonClientTrait: Send + Sync {
         description: &str,
         priority: u32,
         draft: bool,
+        template: Option<String>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<String>;
 
     /// Update an existing issue
+    #[allow(clippy::too_many_arguments)]
     async fn update_issue(
         &mut self,
         project_path: &str,
@@ -62,11 +68,19 @@ pub trait DaemonClientTrait: Send + Sync {
         description: &str,
         priority: u32,
         status: &str,
+        draft: Option<bool>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<()>;
 
     /// Delete an issue
     async fn delete_issue(&mut self, project_path: &str, issue_id: &str) -> Result<()>;
 
+    /// Delete a PR
+    async fn delete_pr(&mut self, project_path: &str, pr_id: &str) -> Result<()>;
+
+    /// Delete a doc
+    async fn delete_doc(&mut self, project_path: &str, slug: &str) -> Result<()>;
+
     /// Move an issue to a different project
     async fn move_issue(
         &mut self,
@@ -84,7 +98,8 @@ pub trait DaemonClientTrait: Send + Sync {
         new_slug: Option<String>,
     ) -> Result<(Doc, String)>;
 
-    /// Create a new PR
+    /// Create a new PR, optionally from a template
+    #[allow(clippy::too_many_arguments)]
     async fn create_pr(
         &mut self,
         project_path: &str,
@@ -92,6 +107,10 @@ pub trait DaemonClientTrait: Send + Sync {
         description: &str,
         source_branch: &str,
         target_branch: &str,
+        reviewers: Vec<String>,
+        priority: u32,
+        template: Option<String>,
+        custom_fields: HashMap<String, String>,
     ) -> Result<String>;
 
     /// Update an existing PR
@@ -105,6 +124,9 @@ pub trait DaemonClientTrait: Send + Sync {
         source_branch: &str,
         target_branch: &str,
         status: &str,
+        reviewers: Vec<String>,
+        priority: u32,
+        custom_fields: HashMap<String, String>,
     ) -> Result<()>;
 
     /// Create a new doc
@@ -139,16 +161,20 @@ pub trait DaemonClientTrait: Send + Sync {
     ) -> Result<OpenInTerminalResult>;
 
     /// List temporary workspaces, optionally filtered by project path
-    async fn list_temp_workspaces(&mut self, project_path: &str) -> Result<Vec<TempWorkspace>>;
+    async fn list_temp_workspaces(
+        &mut self,
+        project_path: &str,
+        include_expired: bool,
+    ) -> Result<Vec<TempWorkspace>>;
 
     /// Close a temporary workspace
     async fn close_temp_workspace(&mut self, workspace_path: &str, force: bool) -> Result<()>;
 
-    /// Restart the daemon
-    async fn restart(&mut self) -> Result<()>;
+    /// Restart the daemon after delay_seconds
+    async fn restart(&mut self, delay_seconds: u32) -> Result<()>;
 
-    /// Shutdown the daemon
-    async fn shutdown(&mut self) -> Result<()>;
+    /// Shutdown the daemon after delay_seconds
+    async fn shutdown(&mut self, delay_seconds: u32) -> Result<()>;
 
     /// Get available actions for an entity
     async fn get_entity_actions(
@@ -83,6 +83,12 @@ async fn run_app<B: ratatui::backend::Backend>(
         // Update button press animation state
         app.update_button_press();
 
+        // Keep active agent sessions current
+        app.poll_llm_work();
+
+        // Reconnect after a requested daemon restart
+        app.poll_daemon_reconnect().await;
+
         // Draw the UI
         terminal.draw(|frame| ui::draw(frame, app))?;
 
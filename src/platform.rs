@@ -14,3 +14,71 @@ pub const COPY_MODIFIER: KeyModifiers = KeyModifiers::CONTROL;
 /// Save shortcut display for form help text
 /// Ctrl+S works on all platforms (Cmd+W/Ctrl+W also work as fallback)
 pub const SAVE_SHORTCUT: &str = "Ctrl+S";
+
+/// Whether a local process with this PID is still running (0 means unknown/exited)
+pub fn pid_alive(pid: u32) -> bool {
+    if pid == 0 {
+        return false;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::path::Path::new("/proc").join(pid.to_string()).exists()
+    }
+
+    #[cfg(all(unix, not(target_os = "linux")))]
+    {
+        std::process::Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status()
+            .map(|status| status.success())
+            .unwrap_or(false)
+    }
+
+    #[cfg(windows)]
+    {
+        std::process::Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+            .output()
+            .map(|out| String::from_utf8_lossy(&out.stdout).contains(&pid.to_string()))
+            .unwrap_or(false)
+    }
+}
+
+/// Open a new terminal window in a directory
+pub fn open_terminal_at(path: &str) -> std::io::Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = std::process::Command::new("open");
+        command.args(["-a", "Terminal", path]);
+        command
+    };
+
+    #[cfg(windows)]
+    let mut command = {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", "cmd"]);
+        command
+    };
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    let mut command = std::process::Command::new(
+        std::env::var("TERMINAL").unwrap_or_else(|_| "x-terminal-emulator".to_string()),
+    );
+
+    command.current_dir(path).spawn().map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pid_alive() {
+        assert!(!pid_alive(0));
+        #[cfg(unix)]
+        assert!(pid_alive(std::process::id()));
+    }
+}
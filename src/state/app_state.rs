@@ -1,12 +1,14 @@
 //! Application state definitions
 
 use super::forms::{
-    DocCreateForm, FormState, IssueCreateForm, IssueEditForm, PrCreateForm, PrEditForm,
+    priority_label, DocCreateForm, FieldValue, FormField, FormState, IssueCreateForm,
+    IssueEditForm, PrCreateForm, PrEditForm, DEFAULT_PRIORITY_LEVELS,
 };
-use super::SelectionState;
-use crate::daemon::TempWorkspace;
+use super::{ConfigEditor, SelectionState};
+use crate::daemon::{TempWorkspace, WorkspaceCleanup};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::time::{Duration, Instant};
@@ -33,7 +35,41 @@ pub enum View {
     DocEdit,
     People,
     PersonDetail,
+    PersonCreate,
+    PersonEdit,
+    /// Import team members from git history (dry run, then sync)
+    UserSync,
     Config,
+    /// Editor for the project config (states, priorities, custom fields, link types, LLM flags)
+    ConfigEdit,
+    /// Advanced issue search across the selected project or all projects
+    Search,
+    /// Project-wide shared assets library
+    Assets,
+    /// Project initialization wizard (reconciliation plan review)
+    InitWizard,
+    /// Project version vs daemon version, with migration
+    Version,
+    /// Feature compaction workflow (uncompacted issues, instruction.md, compact.md)
+    Features,
+    /// Editor for compact.md
+    CompactEdit,
+    /// Editor for a new migration file
+    MigrationCreate,
+    /// Active agent sessions, with spawn and clear controls
+    AgentActivity,
+    /// Temporary workspaces across all projects
+    Workspaces,
+    /// Daemon status with restart and shutdown controls
+    Daemon,
+    /// Local LLM agents and environment variables
+    Agents,
+    AgentCreate,
+    AgentEdit,
+    /// All organizations (create, edit, delete)
+    Organizations,
+    OrganizationCreate,
+    OrganizationEdit,
 }
 
 impl View {
@@ -47,6 +83,15 @@ impl View {
                 | View::PrEdit
                 | View::DocCreate
                 | View::DocEdit
+                | View::OrganizationCreate
+                | View::OrganizationEdit
+                | View::PersonCreate
+                | View::PersonEdit
+                | View::ConfigEdit
+                | View::CompactEdit
+                | View::MigrationCreate
+                | View::AgentCreate
+                | View::AgentEdit
         )
     }
 }
@@ -59,6 +104,10 @@ pub struct ViewParams {
     pub pr_id: Option<String>,
     pub doc_slug: Option<String>,
     pub organization_slug: Option<String>,
+    /// Agent being edited in AgentEdit
+    pub agent_name: Option<String>,
+    /// User being edited in PersonEdit
+    pub person_id: Option<String>,
 }
 
 /// Sort field for issues
@@ -94,6 +143,45 @@ impl IssueSortField {
     }
 }
 
+/// Draft filter for the issues list
+///
+/// Applied client-side in `sorted_issues` rather than through
+/// `ListIssuesRequest.draft`, so links, agent sessions and detail views
+/// still see every issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IssueDraftFilter {
+    #[default]
+    All,
+    DraftsOnly,
+    HideDrafts,
+}
+
+impl IssueDraftFilter {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::All => Self::DraftsOnly,
+            Self::DraftsOnly => Self::HideDrafts,
+            Self::HideDrafts => Self::All,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::DraftsOnly => "drafts only",
+            Self::HideDrafts => "no drafts",
+        }
+    }
+
+    pub fn matches(&self, issue: &Issue) -> bool {
+        match self {
+            Self::All => true,
+            Self::DraftsOnly => issue.metadata.draft,
+            Self::HideDrafts => !issue.metadata.draft,
+        }
+    }
+}
+
 /// Sort field for PRs
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PrSortField {
@@ -361,6 +449,250 @@ impl LlmAction {
             Self::Implement => 2,
         }
     }
+
+    pub fn from_proto_value(value: i32) -> Self {
+        match value {
+            2 => Self::Implement,
+            _ => Self::Plan,
+        }
+    }
+}
+
+/// Active agent work session for a project (from GetLlmWork)
+#[derive(Debug, Clone)]
+pub struct LlmWorkSession {
+    pub project_path: String,
+    pub issue_id: String,
+    pub display_number: u32,
+    pub issue_title: String,
+    pub agent_name: String,
+    pub action: LlmAction,
+    pub started_at: DateTime<Utc>,
+    /// Agent process ID, 0 if unknown or exited
+    pub pid: u32,
+    /// Whether the PID is still running on this machine
+    pub pid_alive: bool,
+}
+
+/// Confirmation pending in the Workspaces view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceConfirm {
+    /// Close the selected workspace; force removes it even with uncommitted changes
+    Close { force: bool },
+    /// Close the selected workspace and open a new one (new path) with a fresh TTL
+    Extend { ttl_hours: u32 },
+    /// Remove every expired workspace
+    CleanupExpired,
+}
+
+impl WorkspaceConfirm {
+    /// TTL offered when extending (the daemon default)
+    pub const DEFAULT_TTL_HOURS: u32 = 12;
+    pub const MAX_TTL_HOURS: u32 = 168;
+
+    /// Adjust the extend TTL by one hour, within 1..=MAX_TTL_HOURS
+    pub fn adjust_ttl(&mut self, increase: bool) {
+        if let WorkspaceConfirm::Extend { ttl_hours } = self {
+            *ttl_hours = if increase {
+                (*ttl_hours + 1).min(Self::MAX_TTL_HOURS)
+            } else {
+                ttl_hours.saturating_sub(1).max(1)
+            };
+        }
+    }
+}
+
+/// Time left until a workspace expires, e.g. "3h 05m left" or "expired"
+pub fn expiry_countdown(expires_at: &str, now: DateTime<Utc>) -> String {
+    let Ok(expires) = DateTime::parse_from_rfc3339(expires_at) else {
+        return "no expiry".to_string();
+    };
+    let minutes = expires
+        .with_timezone(&Utc)
+        .signed_duration_since(now)
+        .num_minutes();
+    if minutes <= 0 {
+        "expired".to_string()
+    } else if minutes < 60 {
+        format!("{}m left", minutes)
+    } else {
+        format!("{}h {:02}m left", minutes / 60, minutes % 60)
+    }
+}
+
+/// Whether a workspace's expiry time has passed
+pub fn workspace_expired(workspace: &TempWorkspace, now: DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc3339(&workspace.expires_at)
+        .map(|expires| expires.with_timezone(&Utc) <= now)
+        .unwrap_or(false)
+}
+
+/// Longest restart/shutdown delay offered in the Daemon panel
+pub const MAX_DAEMON_DELAY_SECONDS: u32 = 60;
+
+/// How long to keep trying to reconnect after the daemon was asked to restart
+const DAEMON_RECONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Restart or shutdown requested from the Daemon panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaemonControl {
+    Restart,
+    Shutdown,
+}
+
+impl DaemonControl {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DaemonControl::Restart => "Restart",
+            DaemonControl::Shutdown => "Shut down",
+        }
+    }
+}
+
+/// Reconnection attempts after a restart was requested
+#[derive(Debug, Clone)]
+pub struct DaemonReconnect {
+    /// Next attempt (the first waits out the requested delay)
+    pub retry_at: Instant,
+    /// Give up after this
+    pub deadline: Instant,
+    pub attempts: u32,
+}
+
+impl DaemonReconnect {
+    pub fn new(delay_seconds: u32, now: Instant) -> Self {
+        let retry_at = now + Duration::from_secs(u64::from(delay_seconds) + 1);
+        Self {
+            retry_at,
+            deadline: retry_at + DAEMON_RECONNECT_TIMEOUT,
+            attempts: 0,
+        }
+    }
+
+    /// Record a failed attempt; false once the deadline has passed
+    pub fn retry_later(&mut self, now: Instant) -> bool {
+        self.attempts += 1;
+        self.retry_at = now + Duration::from_secs(1);
+        now < self.deadline
+    }
+}
+
+/// Compact uptime, e.g. "2d 03h", "1h 05m", "4m 10s" or "12s"
+pub fn format_uptime(seconds: u64) -> String {
+    let (days, hours) = (seconds / 86_400, seconds / 3_600 % 24);
+    let (minutes, secs) = (seconds / 60 % 60, seconds % 60);
+    if days > 0 {
+        format!("{}d {:02}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// A template the daemon can fill in when creating an issue or PR
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormTemplate {
+    /// File name without the .md extension, as sent in the create request
+    pub name: String,
+    pub body: String,
+}
+
+/// Templates in `.centy/templates/<kind>/` (kind is "issues" or "prs"), sorted by name
+pub fn discover_templates(project_path: &str, kind: &str) -> Vec<FormTemplate> {
+    let dir = std::path::Path::new(project_path)
+        .join(".centy")
+        .join("templates")
+        .join(kind);
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut templates: Vec<FormTemplate> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("md") {
+                return None;
+            }
+            let name = path.file_stem()?.to_str()?.to_string();
+            let body = std::fs::read_to_string(&path).ok()?;
+            Some(FormTemplate { name, body })
+        })
+        .collect();
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+/// Pending spawn-agent dialog
+#[derive(Debug, Clone)]
+pub struct PendingSpawnAction {
+    pub project_path: String,
+    /// Issue UUID or display number
+    pub issue: String,
+    pub action: LlmAction,
+    /// Configured agent names; index 0 of the picker is the default agent
+    pub agent_names: Vec<String>,
+    pub agent_index: usize,
+    /// Extra CLI arguments, whitespace separated
+    pub extra_args: String,
+    /// 0 = issue, 1 = action, 2 = agent, 3 = extra args
+    pub field: usize,
+}
+
+impl PendingSpawnAction {
+    pub const FIELD_COUNT: usize = 4;
+
+    pub fn new(project_path: &str, issue: &str, agent_names: Vec<String>) -> Self {
+        Self {
+            project_path: project_path.to_string(),
+            issue: issue.to_string(),
+            action: LlmAction::default(),
+            agent_names,
+            agent_index: 0,
+            extra_args: String::new(),
+            field: if issue.is_empty() { 0 } else { 3 },
+        }
+    }
+
+    /// Agent to spawn, empty for the default agent
+    pub fn agent_name(&self) -> &str {
+        match self.agent_index {
+            0 => "",
+            i => self
+                .agent_names
+                .get(i - 1)
+                .map(String::as_str)
+                .unwrap_or(""),
+        }
+    }
+
+    pub fn cycle_agent(&mut self, forward: bool) {
+        let len = self.agent_names.len() + 1;
+        self.agent_index = if forward {
+            (self.agent_index + 1) % len
+        } else {
+            (self.agent_index + len - 1) % len
+        };
+    }
+
+    /// Text input for the focused field, if it is a text field
+    pub fn input_mut(&mut self) -> Option<&mut String> {
+        match self.field {
+            0 => Some(&mut self.issue),
+            3 => Some(&mut self.extra_args),
+            _ => None,
+        }
+    }
+
+    pub fn extra_args(&self) -> Vec<String> {
+        self.extra_args
+            .split_whitespace()
+            .map(str::to_string)
+            .collect()
+    }
 }
 
 /// Project information
@@ -420,14 +752,67 @@ pub struct User {
     pub prs_created: u32,
 }
 
+/// Contributor found in git history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitContributor {
+    pub name: String,
+    pub email: String,
+}
+
+/// Result of SyncUsers. A dry run only fills `would_create` and `would_skip`.
+#[derive(Debug, Clone, Default)]
+pub struct UserSync {
+    pub dry_run: bool,
+    /// User IDs that were created
+    pub created: Vec<String>,
+    /// Emails that were skipped because the user already exists
+    pub skipped: Vec<String>,
+    pub errors: Vec<String>,
+    pub would_create: Vec<GitContributor>,
+    pub would_skip: Vec<GitContributor>,
+}
+
 /// A section in the grouped projects view
 #[derive(Debug, Clone)]
 pub struct ProjectSection<'a> {
     pub header: String,
     pub is_favorites: bool,
+    pub is_archived: bool,
     pub projects: Vec<&'a Project>,
 }
 
+/// Which extra projects the Projects grid asks the daemon for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProjectListFilter {
+    pub include_archived: bool,
+    /// Projects whose path no longer exists
+    pub include_stale: bool,
+    /// Projects without a .centy manifest
+    pub include_uninitialized: bool,
+    /// Projects in the system temp directory
+    pub include_temp: bool,
+}
+
+impl ProjectListFilter {
+    /// Each toggle as (key, label, enabled), in grid order
+    pub fn toggles(&self) -> [(char, &'static str, bool); 4] {
+        [
+            ('A', "archived", self.include_archived),
+            ('S', "stale", self.include_stale),
+            ('U', "uninitialized", self.include_uninitialized),
+            ('T', "temp", self.include_temp),
+        ]
+    }
+
+    /// Labels of the enabled toggles, for the grid title
+    pub fn active_labels(&self) -> Vec<&'static str> {
+        self.toggles()
+            .into_iter()
+            .filter_map(|(_, label, on)| on.then_some(label))
+            .collect()
+    }
+}
+
 /// Issue metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueMetadata {
@@ -437,6 +822,8 @@ pub struct IssueMetadata {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub custom_fields: HashMap<String, String>,
+    #[serde(default)]
+    pub draft: bool,
 }
 
 /// Issue information
@@ -459,18 +846,18 @@ impl Issue {
         }
     }
 
-    pub fn priority_label(&self) -> &str {
+    /// The daemon's priority label, or the shared fallback on the default scale
+    pub fn priority_label(&self) -> String {
         self.metadata
             .priority_label
-            .as_deref()
-            .unwrap_or(match self.metadata.priority {
-                1 => "high",
-                2 => "med",
-                _ => "low",
-            })
+            .clone()
+            .unwrap_or_else(|| priority_label(self.metadata.priority, DEFAULT_PRIORITY_LEVELS))
     }
 }
 
+/// Statuses a PR can be moved between
+pub const PR_STATUSES: [&str; 4] = ["draft", "open", "merged", "closed"];
+
 /// PR metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PrMetadata {
@@ -509,6 +896,14 @@ impl PullRequest {
             _ => "gray",
         }
     }
+
+    /// The daemon's priority label, or the shared fallback on the default scale
+    pub fn priority_label(&self) -> String {
+        self.metadata
+            .priority_label
+            .clone()
+            .unwrap_or_else(|| priority_label(self.metadata.priority, DEFAULT_PRIORITY_LEVELS))
+    }
 }
 
 /// Doc information
@@ -522,20 +917,190 @@ pub struct Doc {
 }
 
 /// Project configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
     pub priority_levels: u32,
     pub allowed_states: Vec<String>,
     pub default_state: String,
     pub version: String,
+    #[serde(default)]
+    pub custom_link_types: Vec<LinkTypeInfo>,
+    #[serde(default)]
+    pub custom_fields: Vec<CustomFieldDefinition>,
+    /// Default values for new items, by field name
+    #[serde(default)]
+    pub defaults: BTreeMap<String, String>,
+    /// State name → hex color (e.g. "open" → "#10b981")
+    #[serde(default)]
+    pub state_colors: BTreeMap<String, String>,
+    /// Priority level → hex color (e.g. "1" → "#ef4444")
+    #[serde(default)]
+    pub priority_colors: BTreeMap<String, String>,
+    #[serde(default)]
+    pub llm: LlmConfig,
+}
+
+/// Custom field defined in the project config
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomFieldDefinition {
+    pub name: String,
+    /// "string", "number", "boolean" or "enum"
+    pub field_type: String,
+    pub required: bool,
+    pub default_value: String,
+    /// Allowed values for enum fields
+    pub enum_values: Vec<String>,
+}
+
+/// How a custom field is edited in the issue and PR forms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomFieldKind {
+    Text,
+    Number,
+    Boolean,
+    Enum,
+}
+
+impl CustomFieldDefinition {
+    /// Unknown types are edited as plain text
+    pub fn kind(&self) -> CustomFieldKind {
+        match self.field_type.as_str() {
+            "number" => CustomFieldKind::Number,
+            "boolean" => CustomFieldKind::Boolean,
+            "enum" => CustomFieldKind::Enum,
+            _ => CustomFieldKind::Text,
+        }
+    }
+
+    /// Value a new issue or PR starts with
+    pub fn initial_value(&self) -> String {
+        match self.kind() {
+            CustomFieldKind::Enum if self.default_value.is_empty() && self.required => {
+                self.enum_values.first().cloned().unwrap_or_default()
+            }
+            CustomFieldKind::Boolean if self.default_value.is_empty() => "false".to_string(),
+            _ => self.default_value.clone(),
+        }
+    }
+
+    /// Form input for this field holding `value`; required fields are marked with '*'
+    pub fn form_field(&self, value: &str) -> FormField {
+        let label = if self.required {
+            format!("{} *", self.name)
+        } else {
+            self.name.clone()
+        };
+        match self.kind() {
+            CustomFieldKind::Text => {
+                FormField::text_with_value(&self.name, &label, value.to_string(), false)
+            }
+            CustomFieldKind::Number => FormField::number(
+                &self.name,
+                &format!("{} (number)", label),
+                value.to_string(),
+            ),
+            CustomFieldKind::Boolean => FormField::boolean(&self.name, &label, value == "true"),
+            // Optional enums can be unset
+            CustomFieldKind::Enum => {
+                let unset = (!self.required).then(String::new);
+                let options = unset.into_iter().chain(self.enum_values.iter().cloned());
+                FormField::select(&self.name, &label, options.collect(), value)
+            }
+        }
+    }
+}
+
+/// LLM-related project settings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LlmConfig {
+    /// Close issues when an agent marks them complete
+    pub auto_close_on_complete: bool,
+    /// Move issues to in-progress when an agent starts work
+    pub update_status_on_start: bool,
+    /// Let agents edit issue files directly
+    pub allow_direct_edits: bool,
 }
 
 /// Daemon information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DaemonInfo {
     pub version: String,
-    pub uptime_seconds: u64,
+    /// Absolute path of the running daemon binary
+    #[serde(default)]
+    pub binary_path: String,
+    #[serde(default)]
+    pub vscode_available: bool,
+    /// Tracked projects (filled in by a separate ListProjects call)
     pub project_count: u32,
+    /// Versions projects can be migrated to
+    #[serde(default)]
+    pub available_versions: Vec<String>,
+}
+
+/// How a project's version compares to the daemon's
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VersionComparison {
+    #[default]
+    Equal,
+    ProjectBehind,
+    ProjectAhead,
+}
+
+impl VersionComparison {
+    /// Parse the daemon's comparison string ("equal", "project_behind", "project_ahead")
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "project_behind" => VersionComparison::ProjectBehind,
+            "project_ahead" => VersionComparison::ProjectAhead,
+            _ => VersionComparison::Equal,
+        }
+    }
+}
+
+/// Project version compared to the daemon (from GetProjectVersion)
+#[derive(Debug, Clone, Default)]
+pub struct ProjectVersion {
+    pub project_version: String,
+    pub daemon_version: String,
+    pub comparison: VersionComparison,
+    /// The project is newer than the daemon, so some operations are disabled
+    pub degraded_mode: bool,
+}
+
+/// Result of migrating a project to another version (from UpdateVersion)
+#[derive(Debug, Clone)]
+pub struct VersionMigration {
+    pub from_version: String,
+    pub to_version: String,
+    pub migrations_applied: Vec<String>,
+}
+
+/// Status counters of the features subsystem (from GetFeatureStatus)
+#[derive(Debug, Clone, Default)]
+pub struct FeatureStatus {
+    /// features/ folder exists
+    pub initialized: bool,
+    pub has_compact: bool,
+    pub has_instruction: bool,
+    pub migration_count: u32,
+    pub uncompacted_count: u32,
+}
+
+/// Document shown in the Features view's document pane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FeatureDocument {
+    #[default]
+    Instruction,
+    Compact,
+}
+
+impl FeatureDocument {
+    pub fn toggle(self) -> Self {
+        match self {
+            FeatureDocument::Instruction => FeatureDocument::Compact,
+            FeatureDocument::Compact => FeatureDocument::Instruction,
+        }
+    }
 }
 
 /// Entity type for action requests
@@ -630,6 +1195,42 @@ impl EntityActionsResponse {
     }
 }
 
+/// A link from the current entity to another issue, PR, or doc
+#[derive(Debug, Clone)]
+pub struct EntityLink {
+    /// Target entity ID (UUID for issues/PRs, slug for docs)
+    pub target_id: String,
+    pub target_type: EntityType,
+    pub link_type: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A link type with its inverse (built-in or custom)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkTypeInfo {
+    pub name: String,
+    pub inverse: String,
+    pub description: String,
+    pub is_builtin: bool,
+}
+
+/// Links sharing the same link type, for display in detail views
+#[derive(Debug, Clone)]
+pub struct LinkGroup<'a> {
+    pub link_type: String,
+    /// True when the links were created from the other side (e.g. "blocked-by")
+    pub is_inverse: bool,
+    pub links: Vec<&'a EntityLink>,
+}
+
+/// A candidate target entity in the add link dialog
+#[derive(Debug, Clone)]
+pub struct LinkTarget {
+    pub id: String,
+    pub entity_type: EntityType,
+    pub display: String,
+}
+
 /// Identifies which button is currently pressed for animation
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PressedButton {
@@ -706,9 +1307,19 @@ pub enum MoveEntityType {
     Doc,
 }
 
+/// What happens to the entity once a target project is picked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MoveMode {
+    #[default]
+    Move,
+    /// Copy into the target project (the source project is allowed)
+    Duplicate,
+}
+
 /// Pending move action state (project picker + confirmation)
 #[derive(Debug, Clone)]
 pub struct PendingMoveAction {
+    pub mode: MoveMode,
     pub entity_type: MoveEntityType,
     pub source_project_path: String,
     pub entity_id: String,
@@ -717,6 +1328,146 @@ pub struct PendingMoveAction {
     pub search_filter: String,
     pub show_confirmation: bool,
     pub target_project_path: Option<String>,
+    /// Title of the duplicate
+    pub new_title: String,
+    /// Slug of the duplicate (docs only)
+    pub new_slug: String,
+    /// Duplicate input being edited: 0 = title, 1 = slug
+    pub active_field: usize,
+}
+
+impl PendingMoveAction {
+    pub fn new(
+        mode: MoveMode,
+        entity_type: MoveEntityType,
+        source_project_path: String,
+        entity_id: String,
+        entity_display: String,
+    ) -> Self {
+        Self {
+            mode,
+            entity_type,
+            source_project_path,
+            entity_id,
+            entity_display,
+            selected_project_index: 0,
+            search_filter: String::new(),
+            show_confirmation: false,
+            target_project_path: None,
+            new_title: String::new(),
+            new_slug: String::new(),
+            active_field: 0,
+        }
+    }
+
+    /// Whether the project can be picked: initialized, not archived, and not the source
+    /// unless duplicating
+    pub fn accepts_target(&self, project: &Project) -> bool {
+        project.initialized
+            && !project.is_archived
+            && (self.mode == MoveMode::Duplicate || project.path != self.source_project_path)
+    }
+
+    /// Number of inputs on the duplicate confirmation
+    pub fn duplicate_field_count(&self) -> usize {
+        match self.entity_type {
+            MoveEntityType::Issue => 1,
+            MoveEntityType::Doc => 2,
+        }
+    }
+
+    /// Input being edited on the duplicate confirmation
+    pub fn active_input_mut(&mut self) -> &mut String {
+        if self.active_field == 1 {
+            &mut self.new_slug
+        } else {
+            &mut self.new_title
+        }
+    }
+}
+
+/// What runs once a destructive confirmation is accepted
+#[derive(Debug, Clone)]
+pub enum ConfirmKind {
+    /// A daemon entity action flagged destructive
+    EntityAction(EntityAction),
+    /// Remove a project from the tracked list
+    UntrackProject { path: String },
+    /// Restart or shut down the daemon with the panel's delay
+    DaemonControl(DaemonControl),
+    /// Migrate a project's .centy folder to another version
+    MigrateProject { path: String, version: String },
+    /// Remove a team member from a project
+    DeleteUser {
+        project_path: String,
+        user_id: String,
+    },
+}
+
+/// Destructive action waiting for an explicit confirm key
+#[derive(Debug, Clone)]
+pub struct PendingConfirmAction {
+    pub kind: ConfirmKind,
+    /// Dialog title, e.g. "Delete Issue"
+    pub title: String,
+    /// What will be affected, e.g. "#12 Fix login"
+    pub target: String,
+    /// Consequence shown under the target
+    pub detail: String,
+}
+
+impl PendingConfirmAction {
+    pub fn untrack_project(project: &Project) -> Self {
+        Self {
+            kind: ConfirmKind::UntrackProject {
+                path: project.path.clone(),
+            },
+            title: format!("Untrack {}", project.display_name()),
+            target: project.path.clone(),
+            detail: "Only removes it from the list, files stay on disk.".to_string(),
+        }
+    }
+
+    pub fn migrate_project(path: &str, current: Option<&str>, version: &str) -> Self {
+        Self {
+            kind: ConfirmKind::MigrateProject {
+                path: path.to_string(),
+                version: version.to_string(),
+            },
+            title: "Migrate Project".to_string(),
+            target: path.to_string(),
+            detail: format!(
+                "Migrates .centy from {} to {}.",
+                current.unwrap_or("?"),
+                version
+            ),
+        }
+    }
+
+    pub fn delete_user(project_path: &str, user: &User) -> Self {
+        Self {
+            kind: ConfirmKind::DeleteUser {
+                project_path: project_path.to_string(),
+                user_id: user.id.clone(),
+            },
+            title: "Delete Team Member".to_string(),
+            target: format!("{} ({})", user.name, user.id),
+            detail: "Removes them from this project's team.".to_string(),
+        }
+    }
+
+    pub fn daemon_control(control: DaemonControl, delay_seconds: u32) -> Self {
+        let detail = match control {
+            DaemonControl::Restart => "The TUI reconnects once it is back.",
+            DaemonControl::Shutdown => "Every client loses the daemon until it is started again.",
+        };
+        Self {
+            kind: ConfirmKind::DaemonControl(control),
+            title: format!("{} the daemon", control.label()),
+            target: format!("in {}s", delay_seconds),
+            detail: detail.to_string(),
+        }
+    }
 }
 
 /// Pending start work action state (status change confirmation)
@@ -726,15 +1477,658 @@ pub struct PendingStartWorkAction {
     pub action_label: String,
 }
 
-/// Main application state
-#[derive(Default)]
-pub struct AppState {
-    // Navigation
-    pub current_view: View,
-    pub view_params: ViewParams,
-    pub view_history: Vec<(View, ViewParams)>,
+/// Step of the add link dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkDialogStep {
+    #[default]
+    SelectType,
+    SelectTarget,
+}
 
-    // Project
+/// Pending add link action state (link type picker + target search)
+#[derive(Debug, Clone)]
+pub struct PendingLinkAction {
+    pub project_path: String,
+    pub source_id: String,
+    pub source_type: EntityType,
+    pub source_display: String,
+    pub step: LinkDialogStep,
+    pub selected_type_index: usize,
+    pub search_filter: String,
+    pub selected_target_index: usize,
+}
+
+/// Pending link removal awaiting confirmation
+#[derive(Debug, Clone)]
+pub struct PendingUnlinkAction {
+    pub project_path: String,
+    pub source_id: String,
+    pub source_type: EntityType,
+    pub link: EntityLink,
+    pub target_display: String,
+}
+
+/// An issue matched by an advanced search, with its owning project
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub issue: Issue,
+    pub project_path: String,
+    pub project_name: String,
+    pub display_path: String,
+}
+
+/// Focus state for the search view (query input vs results list)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchFocus {
+    #[default]
+    Query,
+    Results,
+}
+
+/// What the jump prompt input refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JumpQuery {
+    /// Issue or PR UUID, looked up across all projects
+    Uuid(String),
+    /// Issue or PR display number ("#123"), looked up in the selected project
+    DisplayNumber(u32),
+    /// Doc slug, looked up across all projects
+    Slug(String),
+}
+
+impl JumpQuery {
+    /// Parse the jump prompt input; returns None for empty or malformed input
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+        if let Some(number) = input.strip_prefix('#') {
+            return number.parse().ok().map(JumpQuery::DisplayNumber);
+        }
+        if is_uuid(input) {
+            return Some(JumpQuery::Uuid(input.to_lowercase()));
+        }
+        Some(JumpQuery::Slug(input.to_string()))
+    }
+}
+
+/// Check for the canonical 8-4-4-4-12 hex UUID format
+fn is_uuid(s: &str) -> bool {
+    let parts: Vec<&str> = s.split('-').collect();
+    parts.len() == 5
+        && parts
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(part, len)| part.len() == len && part.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// The entity a jump match points to
+#[derive(Debug, Clone)]
+pub enum JumpTarget {
+    Issue(Issue),
+    Pr(PullRequest),
+    Doc(Doc),
+}
+
+/// An entity found by the jump prompt, with its owning project
+#[derive(Debug, Clone)]
+pub struct JumpMatch {
+    pub target: JumpTarget,
+    pub project_path: String,
+    pub project_name: String,
+    pub display_path: String,
+}
+
+impl JumpMatch {
+    /// Human-readable label for the disambiguation list
+    pub fn label(&self) -> String {
+        match &self.target {
+            JumpTarget::Issue(issue) => format!("Issue #{} {}", issue.display_number, issue.title),
+            JumpTarget::Pr(pr) => format!("PR #{} {}", pr.display_number, pr.title),
+            JumpTarget::Doc(doc) => format!("Doc {} ({})", doc.title, doc.slug),
+        }
+    }
+}
+
+/// Pending jump prompt state (input, then disambiguation list)
+#[derive(Debug, Clone, Default)]
+pub struct PendingJumpAction {
+    pub input: String,
+    pub matches: Vec<JumpMatch>,
+    /// Non-fatal lookup errors (e.g. projects that couldn't be accessed)
+    pub errors: Vec<String>,
+    pub selected_index: usize,
+    /// Whether a lookup has run for the current input
+    pub searched: bool,
+}
+
+/// File attached to an issue, or shared across a project
+#[derive(Debug, Clone)]
+pub struct Asset {
+    pub filename: String,
+    pub size: u64,
+    pub mime_type: String,
+    pub is_shared: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Asset {
+    /// Whether the asset can be previewed inline as text
+    pub fn is_text(&self) -> bool {
+        self.mime_type.starts_with("text/")
+            || matches!(
+                self.mime_type.as_str(),
+                "application/json"
+                    | "application/xml"
+                    | "application/yaml"
+                    | "application/toml"
+                    | "application/javascript"
+                    | "application/x-sh"
+            )
+    }
+
+    /// Human-readable file size (e.g. "1.5 KB")
+    pub fn size_label(&self) -> String {
+        const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+        let mut size = self.size as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            format!("{} B", self.size)
+        } else {
+            format!("{:.1} {}", size, UNITS[unit])
+        }
+    }
+}
+
+/// Inline text preview of an asset
+#[derive(Debug, Clone)]
+pub struct AssetPreview {
+    pub filename: String,
+    pub content: String,
+}
+
+/// Tab shown in the issue detail content area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IssueDetailTab {
+    #[default]
+    Details,
+    Assets,
+}
+
+impl IssueDetailTab {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            Self::Details => Self::Assets,
+            Self::Assets => Self::Details,
+        };
+    }
+}
+
+/// Pending asset dialog (attach, save or delete)
+#[derive(Debug, Clone)]
+pub enum PendingAssetAction {
+    /// Attach a local file to `issue_id`, or to the project-wide library when `shared`
+    /// (always shared outside issue detail, where there is no issue)
+    Attach {
+        path: String,
+        issue_id: Option<String>,
+        shared: bool,
+    },
+    /// Save an asset to a local path
+    Save { asset: Asset, path: String },
+    /// Confirm replacing an existing file when saving an asset
+    Overwrite { asset: Asset, target: String },
+    /// Confirm deleting an asset
+    Delete { asset: Asset },
+}
+
+/// Pending organization dialog (delete confirmation or project assignment)
+#[derive(Debug, Clone)]
+pub enum PendingOrgAction {
+    /// Confirm deleting an organization
+    Delete { org: Organization },
+    /// Pick the organization for a project card. Index 0 is "no organization",
+    /// the rest map onto `AppState::organizations`.
+    Assign {
+        project_path: String,
+        project_name: String,
+        selected_index: usize,
+    },
+}
+
+/// Which project title a rename applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TitleScope {
+    /// Personal title, stored in ~/.centy
+    #[default]
+    User,
+    /// Shared title, committed in .centy/project.json
+    Project,
+}
+
+impl TitleScope {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            TitleScope::User => TitleScope::Project,
+            TitleScope::Project => TitleScope::User,
+        };
+    }
+}
+
+/// Pending rename dialog for a project card
+#[derive(Debug, Clone)]
+pub struct PendingRenameAction {
+    pub project_path: String,
+    /// Directory name, shown when both titles are empty
+    pub name: String,
+    pub scope: TitleScope,
+    pub user_title: String,
+    pub project_title: String,
+    /// Titles as last saved, to tell which ones were edited
+    pub saved_user_title: String,
+    pub saved_project_title: String,
+}
+
+impl PendingRenameAction {
+    pub fn new(project: &Project) -> Self {
+        let user_title = project.user_title.clone().unwrap_or_default();
+        let project_title = project.project_title.clone().unwrap_or_default();
+        Self {
+            project_path: project.path.clone(),
+            name: project.name.clone(),
+            scope: TitleScope::default(),
+            saved_user_title: user_title.clone(),
+            saved_project_title: project_title.clone(),
+            user_title,
+            project_title,
+        }
+    }
+
+    /// Trimmed titles that differ from the saved ones, in either scope
+    pub fn changed_titles(&self) -> Vec<(TitleScope, String)> {
+        [
+            (TitleScope::User, &self.user_title, &self.saved_user_title),
+            (
+                TitleScope::Project,
+                &self.project_title,
+                &self.saved_project_title,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, title, saved)| title.trim() != saved.trim())
+        .map(|(scope, title, _)| (scope, title.trim().to_string()))
+        .collect()
+    }
+
+    /// Record a title as saved, so a retry after a failure doesn't resend it
+    pub fn mark_saved(&mut self, scope: TitleScope) {
+        match scope {
+            TitleScope::User => self.saved_user_title = self.user_title.clone(),
+            TitleScope::Project => self.saved_project_title = self.project_title.clone(),
+        }
+    }
+
+    /// Input for the selected scope
+    pub fn input_mut(&mut self) -> &mut String {
+        match self.scope {
+            TitleScope::User => &mut self.user_title,
+            TitleScope::Project => &mut self.project_title,
+        }
+    }
+
+    /// Name the project would display with the titles as currently typed
+    pub fn effective_name(&self) -> &str {
+        [self.user_title.trim(), self.project_title.trim()]
+            .into_iter()
+            .find(|t| !t.is_empty())
+            .unwrap_or(&self.name)
+    }
+}
+
+/// Pending register-project dialog
+#[derive(Debug, Clone)]
+pub enum PendingRegisterAction {
+    /// Typing the directory to register; `error` is the last validation failure
+    Path {
+        input: String,
+        error: Option<String>,
+    },
+    /// The registered directory has no `.centy` folder yet: offer to initialize it
+    ConfirmInit { project_path: String },
+}
+
+/// A file in a reconciliation plan
+#[derive(Debug, Clone)]
+pub struct PlanFile {
+    pub path: String,
+    pub is_directory: bool,
+    pub content_preview: String,
+}
+
+/// Reconciliation plan for initializing a project (from GetReconciliationPlan)
+#[derive(Debug, Clone, Default)]
+pub struct ReconciliationPlan {
+    pub to_create: Vec<PlanFile>,
+    pub to_restore: Vec<PlanFile>,
+    pub to_reset: Vec<PlanFile>,
+    pub up_to_date: Vec<PlanFile>,
+    pub user_files: Vec<PlanFile>,
+    pub needs_decisions: bool,
+}
+
+/// Bucket of a file in the reconciliation plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanBucket {
+    Create,
+    Restore,
+    Reset,
+    UserFile,
+}
+
+impl PlanBucket {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlanBucket::Create => "To create",
+            PlanBucket::Restore => "To restore",
+            PlanBucket::Reset => "To reset",
+            PlanBucket::UserFile => "User files",
+        }
+    }
+}
+
+/// Organization inference from the git remote during init
+#[derive(Debug, Clone, Default)]
+pub struct OrgInference {
+    pub inferred_org_slug: String,
+    pub inferred_org_name: String,
+    pub org_created: bool,
+    pub existing_org_slug: String,
+    pub has_mismatch: bool,
+    pub message: String,
+}
+
+/// Summary of an init run (from ExecuteReconciliation)
+#[derive(Debug, Clone, Default)]
+pub struct InitResult {
+    pub created: Vec<String>,
+    pub restored: Vec<String>,
+    pub reset: Vec<String>,
+    pub skipped: Vec<String>,
+    pub org_inference: Option<OrgInference>,
+}
+
+/// Init wizard state: plan review with per-file decisions, then the result
+#[derive(Debug, Clone, Default)]
+pub struct InitWizard {
+    pub project_path: String,
+    pub plan: ReconciliationPlan,
+    /// Files from `to_restore` chosen to be restored (all by default)
+    pub restore: Vec<String>,
+    /// Files from `to_reset` chosen to be reset (none by default)
+    pub reset: Vec<String>,
+    pub selected_index: usize,
+    pub result: Option<InitResult>,
+}
+
+impl InitWizard {
+    /// Start a wizard with the daemon's defaults: restore everything, reset nothing
+    pub fn new(project_path: String, plan: ReconciliationPlan) -> Self {
+        let restore = plan.to_restore.iter().map(|f| f.path.clone()).collect();
+        Self {
+            project_path,
+            plan,
+            restore,
+            ..Default::default()
+        }
+    }
+
+    /// Plan files in display order (up-to-date files are only counted)
+    pub fn rows(&self) -> Vec<(PlanBucket, &PlanFile)> {
+        let buckets = [
+            (PlanBucket::Create, &self.plan.to_create),
+            (PlanBucket::Restore, &self.plan.to_restore),
+            (PlanBucket::Reset, &self.plan.to_reset),
+            (PlanBucket::UserFile, &self.plan.user_files),
+        ];
+        buckets
+            .into_iter()
+            .flat_map(|(bucket, files)| files.iter().map(move |f| (bucket, f)))
+            .collect()
+    }
+
+    /// Currently selected plan file
+    pub fn selected_row(&self) -> Option<(PlanBucket, &PlanFile)> {
+        self.rows().get(self.selected_index).copied()
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected_index + 1 < self.rows().len() {
+            self.selected_index += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    /// Whether the decision for a restore/reset file is "apply"
+    pub fn is_chosen(&self, bucket: PlanBucket, path: &str) -> bool {
+        match bucket {
+            PlanBucket::Restore => self.restore.iter().any(|p| p == path),
+            PlanBucket::Reset => self.reset.iter().any(|p| p == path),
+            PlanBucket::Create => true,
+            PlanBucket::UserFile => false,
+        }
+    }
+
+    /// Flip the decision for the selected file (restore/reset only, when decisions are needed)
+    pub fn toggle_selected(&mut self) {
+        if !self.plan.needs_decisions {
+            return;
+        }
+        let Some((bucket, file)) = self.selected_row() else {
+            return;
+        };
+        let path = file.path.clone();
+        let chosen = match bucket {
+            PlanBucket::Restore => &mut self.restore,
+            PlanBucket::Reset => &mut self.reset,
+            PlanBucket::Create | PlanBucket::UserFile => return,
+        };
+        if let Some(pos) = chosen.iter().position(|p| *p == path) {
+            chosen.remove(pos);
+        } else {
+            chosen.push(path);
+        }
+    }
+}
+
+/// Agent CLI type (mirrors the daemon's AgentType)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AgentType {
+    Claude,
+    Gemini,
+    Codex,
+    OpenCode,
+    #[default]
+    Custom,
+}
+
+impl AgentType {
+    pub const ALL: [AgentType; 5] = [
+        AgentType::Claude,
+        AgentType::Gemini,
+        AgentType::Codex,
+        AgentType::OpenCode,
+        AgentType::Custom,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AgentType::Claude => "claude",
+            AgentType::Gemini => "gemini",
+            AgentType::Codex => "codex",
+            AgentType::OpenCode => "opencode",
+            AgentType::Custom => "custom",
+        }
+    }
+
+    /// Cycle forward (or backward) through the agent types
+    pub fn cycle(self, forward: bool) -> Self {
+        let idx = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        let len = Self::ALL.len();
+        let next = if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+/// A configured LLM agent
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgentConfig {
+    pub agent_type: AgentType,
+    pub name: String,
+    pub command: String,
+    pub default_args: Vec<String>,
+    pub plan_template: String,
+    pub implement_template: String,
+}
+
+/// Local LLM configuration (config.local.json, not version controlled)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocalLlmConfig {
+    pub default_agent: String,
+    pub agents: Vec<AgentConfig>,
+    /// Environment variables passed to agents, sorted by name
+    pub env_vars: BTreeMap<String, String>,
+}
+
+impl LocalLlmConfig {
+    /// Add an agent, or replace the one named `original`. The first agent becomes the default,
+    /// and renaming the default agent keeps it the default.
+    pub fn upsert_agent(
+        &mut self,
+        original: Option<&str>,
+        agent: AgentConfig,
+    ) -> Result<(), String> {
+        let clash = self
+            .agents
+            .iter()
+            .any(|a| a.name == agent.name && Some(a.name.as_str()) != original);
+        if clash {
+            return Err(format!("An agent named '{}' already exists", agent.name));
+        }
+
+        match original.and_then(|name| self.agents.iter().position(|a| a.name == name)) {
+            Some(idx) => {
+                if self.default_agent == self.agents[idx].name {
+                    self.default_agent = agent.name.clone();
+                }
+                self.agents[idx] = agent;
+            }
+            None => {
+                if self.default_agent.is_empty() {
+                    self.default_agent = agent.name.clone();
+                }
+                self.agents.push(agent);
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove an agent. If it was the default, the first remaining agent takes over.
+    pub fn remove_agent(&mut self, name: &str) {
+        self.agents.retain(|a| a.name != name);
+        if self.default_agent == name {
+            self.default_agent = self
+                .agents
+                .first()
+                .map(|a| a.name.clone())
+                .unwrap_or_default();
+        }
+    }
+}
+
+/// Local LLM config as loaded from the daemon, with which config files exist
+#[derive(Debug, Clone, Default)]
+pub struct LocalLlmSettings {
+    pub config: LocalLlmConfig,
+    pub has_project_config: bool,
+    pub has_global_config: bool,
+}
+
+/// Which local LLM config the Agents view edits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LlmConfigScope {
+    /// The selected project's config.local.json
+    #[default]
+    Project,
+    /// The global config, shared by all projects
+    Global,
+}
+
+impl LlmConfigScope {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            LlmConfigScope::Project => LlmConfigScope::Global,
+            LlmConfigScope::Global => LlmConfigScope::Project,
+        };
+    }
+}
+
+/// Focused list in the Agents view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AgentsFocus {
+    #[default]
+    Agents,
+    EnvVars,
+}
+
+/// Pending dialog in the Agents view
+#[derive(Debug, Clone)]
+pub enum PendingAgentAction {
+    /// Confirm removing an agent
+    RemoveAgent { name: String },
+    /// Add or edit an environment variable
+    EnvVar {
+        /// Name of the variable being edited, None when adding
+        original_key: Option<String>,
+        key: String,
+        value: String,
+        /// Typing into the value rather than the name
+        editing_value: bool,
+        error: Option<String>,
+    },
+    /// Confirm removing an environment variable
+    RemoveEnvVar { key: String },
+}
+
+/// Mask a secret value for display
+pub fn mask_secret(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        "•".repeat(8)
+    }
+}
+
+/// Main application state
+#[derive(Default)]
+pub struct AppState {
+    // Navigation
+    pub current_view: View,
+    pub view_params: ViewParams,
+    pub view_history: Vec<(View, ViewParams)>,
+
+    // Project
     pub projects: Vec<Project>,
     pub selected_project_path: Option<String>,
 
@@ -743,8 +2137,68 @@ pub struct AppState {
     pub prs: Vec<PullRequest>,
     pub docs: Vec<Doc>,
     pub config: Option<Config>,
-    #[allow(dead_code)]
+    /// Draft being edited in ConfigEdit
+    pub config_editor: Option<ConfigEditor>,
     pub daemon_info: Option<DaemonInfo>,
+    /// Version of the selected project compared to the daemon
+    pub project_version: Option<ProjectVersion>,
+
+    // Version panel state
+    pub selected_version_index: usize,
+    pub migration_result: Option<VersionMigration>,
+
+    // Features view state
+    pub feature_status: Option<FeatureStatus>,
+    pub uncompacted_issues: Vec<Issue>,
+    pub selected_uncompacted_index: usize,
+    /// IDs of uncompacted issues marked for compaction (multi-select)
+    pub marked_uncompacted_ids: Vec<String>,
+    pub feature_instruction: String,
+    /// compact.md content, None when the file doesn't exist yet
+    pub feature_compact: Option<String>,
+    pub feature_document: FeatureDocument,
+    /// Waiting for y/n before marking the selected issues as compacted
+    pub confirm_mark_compacted: bool,
+
+    // Agents view state
+    pub local_llm: Option<LocalLlmSettings>,
+    pub llm_config_scope: LlmConfigScope,
+    pub agents_focus: AgentsFocus,
+    pub selected_agent_index: usize,
+    pub selected_env_var_index: usize,
+    /// Show environment variable values instead of masking them
+    pub reveal_env_values: bool,
+
+    // Agent activity state
+    /// Active agent sessions (selected project, or all projects)
+    pub llm_work: Vec<LlmWorkSession>,
+    pub llm_work_all_projects: bool,
+    pub selected_llm_work_index: usize,
+    pub last_llm_work_poll: Option<Instant>,
+    /// Waiting for y/n before clearing the selected session
+    pub confirm_clear_llm_work: bool,
+    /// Prompt preview of the last spawned agent
+    pub spawn_prompt_preview: Option<String>,
+
+    // Workspaces view state
+    /// Temporary workspaces across all projects, expired ones included
+    pub workspaces: Vec<TempWorkspace>,
+    pub selected_workspace_index: usize,
+    /// Waiting for y/n before closing, extending or cleaning up
+    pub workspace_confirm: Option<WorkspaceConfirm>,
+    /// Result of the last expired-workspace cleanup
+    pub workspace_cleanup: Option<WorkspaceCleanup>,
+
+    // Daemon panel
+    /// Address the client connects to
+    pub daemon_address: String,
+    /// Since when the daemon is known to be up: the restart we waited for, else our first connection
+    pub daemon_seen_since: Option<Instant>,
+    /// daemon_seen_since is the daemon's actual start
+    pub daemon_start_observed: bool,
+    /// delay_seconds sent with restart and shutdown
+    pub daemon_delay_seconds: u32,
+    pub daemon_reconnect: Option<DaemonReconnect>,
 
     // Selection
     pub selected_index: usize,
@@ -761,6 +2215,7 @@ pub struct AppState {
 
     // Filters
     pub show_closed_issues: bool,
+    pub issue_draft_filter: IssueDraftFilter,
     pub show_merged_prs: bool,
 
     // UI state
@@ -768,13 +2223,34 @@ pub struct AppState {
     /// Scroll offset for sidebars (nav sidebar and action panel share this)
     pub sidebar_scroll_offset: usize,
     pub daemon_connected: bool,
-    pub confirm_action: Option<String>,
+    /// Extra projects requested for the Projects grid
+    pub project_filter: ProjectListFilter,
     /// Pending worktree action when a workspace already exists
     pub pending_worktree_action: Option<PendingWorktreeAction>,
     /// Pending move action when moving an issue or doc
     pub pending_move_action: Option<PendingMoveAction>,
     /// Pending start work action when confirming status change to "in progress"
     pub pending_start_work_action: Option<PendingStartWorkAction>,
+    /// Destructive action awaiting confirmation
+    pub pending_confirm_action: Option<PendingConfirmAction>,
+    /// Pending add link action (link type + target picker)
+    pub pending_link_action: Option<PendingLinkAction>,
+    /// Pending link removal awaiting confirmation
+    pub pending_unlink_action: Option<PendingUnlinkAction>,
+    /// Pending jump prompt (go to issue, PR or doc by UUID, number or slug)
+    pub pending_jump_action: Option<PendingJumpAction>,
+    /// Pending attach/save/delete asset dialog
+    pub pending_asset_action: Option<PendingAssetAction>,
+    /// Pending organization delete confirmation or project assignment
+    pub pending_org_action: Option<PendingOrgAction>,
+    /// Pending spawn-agent dialog
+    pub pending_spawn_action: Option<PendingSpawnAction>,
+    /// Pending agent removal or environment variable dialog
+    pub pending_agent_action: Option<PendingAgentAction>,
+    /// Pending project rename dialog
+    pub pending_rename_action: Option<PendingRenameAction>,
+    /// Pending register-project dialog
+    pub pending_register_action: Option<PendingRegisterAction>,
     /// Queue of error messages to display one at a time
     pub error_queue: VecDeque<String>,
 
@@ -803,6 +2279,8 @@ pub struct AppState {
     pub people_sort_field: PeopleSortField,
     pub people_sort_direction: SortDirection,
     pub selected_person_id: Option<String>,
+    /// Last SyncUsers result shown in the sync screen
+    pub user_sync: Option<UserSync>,
 
     // Organization view state
     pub organization_focus: OrganizationFocus,
@@ -811,6 +2289,35 @@ pub struct AppState {
     pub project_users: HashMap<String, Vec<User>>,
     pub selected_project_in_org: usize,
 
+    // Organizations list state (from ListOrganizations)
+    pub organizations: Vec<Organization>,
+    pub selected_organization_index: usize,
+
+    // Entity links state (from ListLinks / GetAvailableLinkTypes)
+    pub current_links: Vec<EntityLink>,
+    pub available_link_types: Vec<LinkTypeInfo>,
+    pub selected_link_index: usize,
+
+    // Assets state (from ListAssets / ListSharedAssets)
+    pub issue_detail_tab: IssueDetailTab,
+    pub assets: Vec<Asset>,
+    pub selected_asset_index: usize,
+    pub asset_preview: Option<AssetPreview>,
+
+    // Init wizard state (from GetReconciliationPlan / ExecuteReconciliation)
+    pub init_wizard: Option<InitWizard>,
+
+    // Search view state (from AdvancedSearch)
+    pub search_query: String,
+    /// Search across all projects instead of only the selected one
+    pub search_all_projects: bool,
+    pub search_results: Vec<SearchResult>,
+    pub search_total_count: i32,
+    /// The query as understood by the daemon, echoed back to the user
+    pub search_parsed_query: Option<String>,
+    pub search_focus: SearchFocus,
+    pub selected_search_index: usize,
+
     // Dynamic actions state (from GetEntityActions)
     pub current_actions: EntityActionsResponse,
     pub actions_loading: bool,
@@ -830,13 +2337,35 @@ pub struct AppState {
     pub form_title: String,
     pub form_description: String,
     pub form_priority: u32,
+    /// Priority of the issue or PR being edited and the daemon's label for it
+    pub form_priority_name: Option<(u32, String)>,
     pub form_status: String,
     pub form_slug: String,
     pub form_is_org_doc: bool,
+    pub form_is_draft: bool,
     pub form_source_branch: String,
     pub form_target_branch: String,
+    pub form_agent_type: AgentType,
+    pub form_agent_command: String,
+    /// Agent default args, shell-quoted
+    pub form_agent_args: String,
+    pub form_plan_template: String,
+    pub form_implement_template: String,
+    pub form_email: String,
+    /// Git usernames, comma or whitespace separated
+    pub form_git_usernames: String,
     /// Selected button index for create forms (0=Cancel, 1=Draft, 2=Create&New, 3=Create)
     pub form_selected_button: usize,
+    /// Templates offered by the issue or PR create form
+    pub form_templates: Vec<FormTemplate>,
+    /// Selected template name (empty for none)
+    pub form_template: String,
+    /// Custom field values in the issue and PR forms, by field name
+    pub form_custom_fields: BTreeMap<String, String>,
+    /// Reviewer user IDs in the PR forms
+    pub form_reviewers: Vec<String>,
+    /// Highlighted option of the reviewer picker
+    pub form_reviewer_cursor: usize,
 
     // Text selection state
     pub selection: SelectionState,
@@ -1108,6 +2637,12 @@ impl AppState {
         self.reset_selection();
     }
 
+    /// Cycle the issues list draft filter
+    pub fn cycle_issue_draft_filter(&mut self) {
+        self.issue_draft_filter = self.issue_draft_filter.next();
+        self.reset_selection();
+    }
+
     /// Toggle issue sort direction
     pub fn toggle_issue_sort_direction(&mut self) {
         self.issue_sort_direction = self.issue_sort_direction.toggle();
@@ -1142,7 +2677,7 @@ impl AppState {
     #[allow(dead_code)]
     pub fn sorted_projects(&self) -> Vec<&Project> {
         let mut projects: Vec<_> = self.projects.iter().collect();
-        projects.sort_by(|a, b| b.is_favorite.cmp(&a.is_favorite));
+        projects.sort_by_key(|p| std::cmp::Reverse(p.is_favorite));
         projects
     }
 
@@ -1151,18 +2686,24 @@ impl AppState {
     /// 1. Favorites (all favorited projects, regardless of org)
     /// 2. Organization groups (sorted alphabetically by org name)
     /// 3. Ungrouped (projects without organization)
+    /// 4. Archived (only when include_archived is on)
     pub fn grouped_projects(&self) -> Vec<ProjectSection<'_>> {
         use std::collections::HashMap;
 
         let mut sections = Vec::new();
 
+        // Archived projects (only listed when requested) get their own section at the end
+        let (archived, active): (Vec<&Project>, Vec<&Project>) =
+            self.projects.iter().partition(|p| p.is_archived);
+
         // 1. Collect all favorites first
-        let favorites: Vec<&Project> = self.projects.iter().filter(|p| p.is_favorite).collect();
+        let favorites: Vec<&Project> = active.iter().copied().filter(|p| p.is_favorite).collect();
 
         if !favorites.is_empty() {
             sections.push(ProjectSection {
                 header: "Favorites".to_string(),
                 is_favorites: true,
+                is_archived: false,
                 projects: favorites,
             });
         }
@@ -1171,7 +2712,7 @@ impl AppState {
         let mut org_groups: HashMap<String, Vec<&Project>> = HashMap::new();
         let mut ungrouped: Vec<&Project> = Vec::new();
 
-        for project in self.projects.iter().filter(|p| !p.is_favorite) {
+        for project in active.into_iter().filter(|p| !p.is_favorite) {
             if let Some(org_slug) = &project.organization_slug {
                 org_groups
                     .entry(org_slug.clone())
@@ -1197,6 +2738,7 @@ impl AppState {
                 sections.push(ProjectSection {
                     header: org_name,
                     is_favorites: false,
+                    is_archived: false,
                     projects: projects.clone(),
                 });
             }
@@ -1207,10 +2749,21 @@ impl AppState {
             sections.push(ProjectSection {
                 header: "Ungrouped".to_string(),
                 is_favorites: false,
+                is_archived: false,
                 projects: ungrouped,
             });
         }
 
+        // 4. Archived section last
+        if !archived.is_empty() {
+            sections.push(ProjectSection {
+                header: "Archived".to_string(),
+                is_favorites: false,
+                is_archived: true,
+                projects: archived,
+            });
+        }
+
         sections
     }
 
@@ -1223,16 +2776,159 @@ impl AppState {
             .collect()
     }
 
-    /// Get sorted issues
-    pub fn sorted_issues(&self) -> Vec<&Issue> {
-        let mut issues: Vec<_> = self
-            .issues
-            .iter()
-            .filter(|i| self.show_closed_issues || i.metadata.status != "closed")
-            .collect();
+    /// Banner text when the selected project runs in degraded mode
+    pub fn degraded_mode_message(&self) -> Option<String> {
+        self.selected_project_path.as_ref()?;
+        let version = self.project_version.as_ref().filter(|v| v.degraded_mode)?;
+        Some(format!(
+            "Degraded mode: project version {} is newer than daemon {}",
+            version.project_version, version.daemon_version
+        ))
+    }
 
-        issues.sort_by(|a, b| {
-            let cmp = match self.issue_sort_field {
+    /// Versions offered as migration targets (from the daemon info)
+    pub fn available_versions(&self) -> &[String] {
+        self.daemon_info
+            .as_ref()
+            .map(|info| info.available_versions.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Migration target currently selected in the Version panel
+    pub fn selected_version(&self) -> Option<&String> {
+        self.available_versions().get(self.selected_version_index)
+    }
+
+    /// Change the restart/shutdown delay in 5 second steps
+    pub fn adjust_daemon_delay(&mut self, increase: bool) {
+        self.daemon_delay_seconds = if increase {
+            (self.daemon_delay_seconds + 5).min(MAX_DAEMON_DELAY_SECONDS)
+        } else {
+            self.daemon_delay_seconds.saturating_sub(5)
+        };
+    }
+
+    pub fn select_next_version(&mut self) {
+        if self.selected_version_index + 1 < self.available_versions().len() {
+            self.selected_version_index += 1;
+        }
+    }
+
+    pub fn select_prev_version(&mut self) {
+        self.selected_version_index = self.selected_version_index.saturating_sub(1);
+    }
+
+    /// Replace the uncompacted issues, dropping marks for issues no longer listed
+    pub fn set_uncompacted_issues(&mut self, issues: Vec<Issue>) {
+        self.marked_uncompacted_ids
+            .retain(|id| issues.iter().any(|i| &i.id == id));
+        self.uncompacted_issues = issues;
+        if self.selected_uncompacted_index >= self.uncompacted_issues.len() {
+            self.selected_uncompacted_index = self.uncompacted_issues.len().saturating_sub(1);
+        }
+    }
+
+    pub fn select_next_uncompacted(&mut self) {
+        if self.selected_uncompacted_index + 1 < self.uncompacted_issues.len() {
+            self.selected_uncompacted_index += 1;
+        }
+    }
+
+    pub fn select_prev_uncompacted(&mut self) {
+        self.selected_uncompacted_index = self.selected_uncompacted_index.saturating_sub(1);
+    }
+
+    pub fn is_uncompacted_marked(&self, issue_id: &str) -> bool {
+        self.marked_uncompacted_ids.iter().any(|id| id == issue_id)
+    }
+
+    /// Toggle the mark on the uncompacted issue under the cursor
+    pub fn toggle_uncompacted_mark(&mut self) {
+        let Some(issue) = self.uncompacted_issues.get(self.selected_uncompacted_index) else {
+            return;
+        };
+        if let Some(pos) = self
+            .marked_uncompacted_ids
+            .iter()
+            .position(|id| id == &issue.id)
+        {
+            self.marked_uncompacted_ids.remove(pos);
+        } else {
+            self.marked_uncompacted_ids.push(issue.id.clone());
+        }
+    }
+
+    /// Mark every uncompacted issue, or clear the marks if all are already marked
+    pub fn toggle_all_uncompacted_marks(&mut self) {
+        if self.marked_uncompacted_ids.len() == self.uncompacted_issues.len() {
+            self.marked_uncompacted_ids.clear();
+        } else {
+            self.marked_uncompacted_ids = self
+                .uncompacted_issues
+                .iter()
+                .map(|i| i.id.clone())
+                .collect();
+        }
+    }
+
+    /// Uncompacted issues marked for compaction, in list order
+    pub fn marked_uncompacted_issues(&self) -> Vec<&Issue> {
+        self.uncompacted_issues
+            .iter()
+            .filter(|i| self.is_uncompacted_marked(&i.id))
+            .collect()
+    }
+
+    /// Template for a new migration file covering the marked issues
+    pub fn migration_template(&self) -> String {
+        let issues = self.marked_uncompacted_issues();
+        let mut content = String::from("---\nissues:\n");
+        for issue in &issues {
+            content.push_str(&format!("  - {}\n", issue.id));
+        }
+        content.push_str("---\n\n");
+        for issue in &issues {
+            content.push_str(&format!("- #{} {}\n", issue.display_number, issue.title));
+        }
+        content
+    }
+
+    /// Project to select once the given one leaves the grid: the next card, else the previous
+    pub fn neighbor_project_path(&self, path: &str) -> Option<String> {
+        let projects = self.selectable_projects();
+        let idx = projects.iter().position(|p| p.path == path)?;
+        projects
+            .get(idx + 1)
+            .or_else(|| idx.checked_sub(1).and_then(|prev| projects.get(prev)))
+            .map(|p| p.path.clone())
+    }
+
+    /// Select the project card with the given path. Returns false if it isn't listed.
+    pub fn select_project_by_path(&mut self, path: &str) -> bool {
+        match self
+            .selectable_projects()
+            .iter()
+            .position(|p| p.path == path)
+        {
+            Some(idx) => {
+                self.selected_index = idx;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get sorted issues
+    pub fn sorted_issues(&self) -> Vec<&Issue> {
+        let mut issues: Vec<_> = self
+            .issues
+            .iter()
+            .filter(|i| self.show_closed_issues || i.metadata.status != "closed")
+            .filter(|i| self.issue_draft_filter.matches(i))
+            .collect();
+
+        issues.sort_by(|a, b| {
+            let cmp = match self.issue_sort_field {
                 IssueSortField::Priority => a.metadata.priority.cmp(&b.metadata.priority),
                 IssueSortField::DisplayNumber => a.display_number.cmp(&b.display_number),
                 IssueSortField::CreatedAt => a.metadata.created_at.cmp(&b.metadata.created_at),
@@ -1249,6 +2945,55 @@ impl AppState {
         issues
     }
 
+    /// Issue the action panel applies to (selected row, or the open issue)
+    pub fn action_issue(&self) -> Option<&Issue> {
+        match self.current_view {
+            View::IssueDetail => {
+                let id = self.selected_issue_id.as_ref()?;
+                self.issues.iter().find(|i| &i.id == id)
+            }
+            _ => self.sorted_issues().get(self.selected_index).copied(),
+        }
+    }
+
+    /// PR the action panel applies to (selected row, or the open PR)
+    pub fn action_pr(&self) -> Option<&PullRequest> {
+        match self.current_view {
+            View::PrDetail => {
+                let id = self.selected_pr_id.as_ref()?;
+                self.prs.iter().find(|p| &p.id == id)
+            }
+            _ => self.sorted_prs().get(self.selected_index).copied(),
+        }
+    }
+
+    /// Doc the action panel applies to (selected row, or the open doc)
+    pub fn action_doc(&self) -> Option<&Doc> {
+        match self.current_view {
+            View::DocDetail => {
+                let slug = self.selected_doc_slug.as_ref()?;
+                self.docs.iter().find(|d| &d.slug == slug)
+            }
+            _ => self.docs.get(self.selected_index),
+        }
+    }
+
+    /// Entity name and label of what the action panel applies to
+    pub fn action_target(&self) -> Option<(&'static str, String)> {
+        match self.current_view {
+            View::Issues | View::IssueDetail => self
+                .action_issue()
+                .map(|i| ("Issue", format!("#{} {}", i.display_number, i.title))),
+            View::Prs | View::PrDetail => self
+                .action_pr()
+                .map(|p| ("PR", format!("#{} {}", p.display_number, p.title))),
+            View::Docs | View::DocDetail => self
+                .action_doc()
+                .map(|d| ("Doc", format!("{} ({})", d.title, d.slug))),
+            _ => None,
+        }
+    }
+
     /// Get sorted PRs
     pub fn sorted_prs(&self) -> Vec<&PullRequest> {
         let mut prs: Vec<_> = self
@@ -1319,13 +3064,20 @@ impl AppState {
 
     /// Get number of form fields for current view
     pub fn form_field_count(&self) -> usize {
+        let custom = self.custom_field_definitions().len();
         match self.current_view {
-            View::IssueCreate => 4, // title, description, priority, buttons
-            View::IssueEdit => 4,   // title, description, priority, status
-            View::PrCreate => 5,    // title, description, source, target, priority
-            View::PrEdit => 6,      // title, description, source, target, priority, status
-            View::DocCreate => 3,   // title, content, slug
-            View::DocEdit => 3,     // title, content, slug
+            // Custom fields follow the fixed fields (before the buttons on issue create)
+            View::IssueCreate => 5 + custom, // title, description, priority, template, buttons
+            View::IssueEdit => 5 + custom,   // title, description, priority, status, draft
+            // title, description, source, target, priority, template, reviewers
+            View::PrCreate => 7 + custom,
+            // title, description, source, target, priority, status, reviewers
+            View::PrEdit => 7 + custom,
+            View::DocCreate => 3, // title, content, slug
+            View::DocEdit => 3,   // title, content, slug
+            View::OrganizationCreate | View::OrganizationEdit => 3, // slug, name, description
+            View::AgentCreate | View::AgentEdit => 6, // name, type, command, args, plan, implement
+            View::PersonCreate | View::PersonEdit => 4, // id, name, email, git usernames
             _ => 1,
         }
     }
@@ -1334,16 +3086,15 @@ impl AppState {
     pub fn form_input_char(&mut self, c: char, shift: bool) {
         let ch = if shift { c.to_ascii_uppercase() } else { c };
 
+        if self.update_typed_form_field(|field| field.push_char(ch)) {
+            return;
+        }
+
         match self.current_view {
             View::IssueCreate | View::IssueEdit => match self.active_form_field {
                 0 => self.form_title.push(ch),
                 1 => self.form_description.push(ch),
-                2 => {
-                    if let Some(d) = c.to_digit(10) {
-                        self.form_priority = d;
-                    }
-                }
-                3 => self.form_status.push(ch),
+                3 if self.current_view == View::IssueEdit => self.form_status.push(ch),
                 _ => {}
             },
             View::PrCreate | View::PrEdit => match self.active_form_field {
@@ -1351,12 +3102,6 @@ impl AppState {
                 1 => self.form_description.push(ch),
                 2 => self.form_source_branch.push(ch),
                 3 => self.form_target_branch.push(ch),
-                4 => {
-                    if let Some(d) = c.to_digit(10) {
-                        self.form_priority = d;
-                    }
-                }
-                5 => self.form_status.push(ch),
                 _ => {}
             },
             View::DocCreate | View::DocEdit => match self.active_form_field {
@@ -1365,12 +3110,38 @@ impl AppState {
                 2 => self.form_slug.push(ch),
                 _ => {}
             },
+            View::OrganizationCreate | View::OrganizationEdit => match self.active_form_field {
+                0 => self.form_slug.push(ch),
+                1 => self.form_title.push(ch),
+                2 => self.form_description.push(ch),
+                _ => {}
+            },
+            View::CompactEdit | View::MigrationCreate => self.form_description.push(ch),
+            View::AgentCreate | View::AgentEdit => match self.active_form_field {
+                0 => self.form_title.push(ch),
+                2 => self.form_agent_command.push(ch),
+                3 => self.form_agent_args.push(ch),
+                4 => self.form_plan_template.push(ch),
+                5 => self.form_implement_template.push(ch),
+                _ => {}
+            },
+            // The user ID can't be changed once created
+            View::PersonCreate | View::PersonEdit => match self.active_form_field {
+                0 if self.current_view == View::PersonCreate => self.form_slug.push(ch),
+                1 => self.form_title.push(ch),
+                2 => self.form_email.push(ch),
+                3 => self.form_git_usernames.push(ch),
+                _ => {}
+            },
             _ => {}
         }
     }
 
     /// Handle backspace in form
     pub fn form_backspace(&mut self) {
+        if self.update_typed_form_field(FormField::pop_char) {
+            return;
+        }
         match self.current_view {
             View::IssueCreate | View::IssueEdit => match self.active_form_field {
                 0 => {
@@ -1379,7 +3150,7 @@ impl AppState {
                 1 => {
                     self.form_description.pop();
                 }
-                3 => {
+                3 if self.current_view == View::IssueEdit => {
                     self.form_status.pop();
                 }
                 _ => {}
@@ -1397,9 +3168,6 @@ impl AppState {
                 3 => {
                     self.form_target_branch.pop();
                 }
-                5 => {
-                    self.form_status.pop();
-                }
                 _ => {}
             },
             View::DocCreate | View::DocEdit => match self.active_form_field {
@@ -1414,6 +3182,54 @@ impl AppState {
                 }
                 _ => {}
             },
+            View::OrganizationCreate | View::OrganizationEdit => match self.active_form_field {
+                0 => {
+                    self.form_slug.pop();
+                }
+                1 => {
+                    self.form_title.pop();
+                }
+                2 => {
+                    self.form_description.pop();
+                }
+                _ => {}
+            },
+            View::CompactEdit | View::MigrationCreate => {
+                self.form_description.pop();
+            }
+            View::AgentCreate | View::AgentEdit => match self.active_form_field {
+                0 => {
+                    self.form_title.pop();
+                }
+                2 => {
+                    self.form_agent_command.pop();
+                }
+                3 => {
+                    self.form_agent_args.pop();
+                }
+                4 => {
+                    self.form_plan_template.pop();
+                }
+                5 => {
+                    self.form_implement_template.pop();
+                }
+                _ => {}
+            },
+            View::PersonCreate | View::PersonEdit => match self.active_form_field {
+                0 if self.current_view == View::PersonCreate => {
+                    self.form_slug.pop();
+                }
+                1 => {
+                    self.form_title.pop();
+                }
+                2 => {
+                    self.form_email.pop();
+                }
+                3 => {
+                    self.form_git_usernames.pop();
+                }
+                _ => {}
+            },
             _ => {}
         }
     }
@@ -1424,740 +3240,3192 @@ impl AppState {
         self.form_title.clear();
         self.form_description.clear();
         self.form_priority = 0;
+        self.form_priority_name = None;
         self.form_status.clear();
         self.form_slug.clear();
         self.form_is_org_doc = false;
+        self.form_is_draft = false;
         self.form_source_branch.clear();
         self.form_target_branch.clear();
+        self.form_agent_type = AgentType::default();
+        self.form_agent_command.clear();
+        self.form_agent_args.clear();
+        self.form_plan_template.clear();
+        self.form_implement_template.clear();
+        self.form_email.clear();
+        self.form_git_usernames.clear();
         self.form_selected_button = 0; // Default to "Create" button (first in sidebar)
+        self.form_template.clear();
+        self.form_custom_fields.clear();
+        self.form_reviewers.clear();
+        self.form_reviewer_cursor = 0;
     }
 
-    /// Load issue data into form for editing
-    pub fn load_issue_to_form(&mut self, issue: &Issue) {
-        self.form_title = issue.title.clone();
-        self.form_description = issue.description.clone();
-        self.form_priority = issue.metadata.priority;
-        self.form_status = issue.metadata.status.clone();
+    /// Custom fields defined in the selected project's config
+    pub fn custom_field_definitions(&self) -> &[CustomFieldDefinition] {
+        self.config
+            .as_ref()
+            .map_or(&[], |config| config.custom_fields.as_slice())
     }
 
-    /// Load PR data into form for editing
-    pub fn load_pr_to_form(&mut self, pr: &PullRequest) {
-        self.form_title = pr.title.clone();
-        self.form_description = pr.description.clone();
-        self.form_priority = pr.metadata.priority;
-        self.form_status = pr.metadata.status.clone();
-        self.form_source_branch = pr.metadata.source_branch.clone();
-        self.form_target_branch = pr.metadata.target_branch.clone();
+    /// Form index of the first custom field in the current form
+    pub fn custom_field_offset(&self) -> Option<usize> {
+        match self.current_view {
+            View::IssueCreate => Some(4),
+            View::IssueEdit => Some(5),
+            View::PrCreate | View::PrEdit => Some(7),
+            _ => None,
+        }
     }
 
-    /// Load doc data into form for editing
-    pub fn load_doc_to_form(&mut self, doc: &Doc) {
-        self.form_title = doc.title.clone();
-        self.form_description = doc.content.clone();
-        self.form_slug = doc.slug.clone();
+    /// Definition of the custom field at form index `index`, if it is one
+    fn custom_field_at(&self, index: usize) -> Option<&CustomFieldDefinition> {
+        let offset = self.custom_field_offset()?;
+        self.custom_field_definitions()
+            .get(index.checked_sub(offset)?)
     }
 
-    /// Toggle the org-wide doc checkbox
-    pub fn toggle_org_doc(&mut self) {
-        self.form_is_org_doc = !self.form_is_org_doc;
+    /// Priority levels of the selected project
+    pub fn priority_levels(&self) -> u32 {
+        self.config
+            .as_ref()
+            .map(|config| config.priority_levels)
+            .filter(|levels| *levels > 0)
+            .unwrap_or(DEFAULT_PRIORITY_LEVELS)
     }
 
-    // =========== Form State Transitions (DDD) ===========
-    // Scaffolding for future form refactoring - not yet integrated
-
-    #[allow(dead_code)]
-    pub fn start_issue_create(&mut self) {
-        self.form = FormState::IssueCreate(IssueCreateForm::new());
+    /// Form field `index` of the current form as a typed field, for the fields that
+    /// aren't plain text: priority, status (when the states are known), draft and custom fields
+    pub fn typed_form_field(&self, index: usize) -> Option<FormField> {
+        if let Some(field) = self.custom_field_at(index) {
+            let value = self.form_custom_fields.get(&field.name).map_or("", |v| v);
+            return Some(field.form_field(value));
+        }
+        match (&self.current_view, index) {
+            (View::IssueCreate | View::IssueEdit, 2) | (View::PrCreate | View::PrEdit, 4) => Some(
+                FormField::priority_with_value("priority", "Priority", self.form_priority)
+                    .with_priority_levels(self.priority_levels())
+                    .with_priority_name(self.form_priority_name.clone()),
+            ),
+            (View::IssueEdit, 3) => {
+                let states = self
+                    .config
+                    .as_ref()
+                    .map(|config| config.allowed_states.clone())
+                    .unwrap_or_default();
+                (!states.is_empty())
+                    .then(|| FormField::select("status", "Status", states, &self.form_status))
+            }
+            (View::IssueEdit, 4) => Some(FormField::boolean("draft", "Draft", self.form_is_draft)),
+            // Without candidates there is nothing to toggle
+            (View::PrCreate | View::PrEdit, 6) => {
+                let candidates = self.reviewer_candidates();
+                (!candidates.is_empty()).then(|| {
+                    FormField::multi_select(
+                        "reviewers",
+                        "Reviewers",
+                        candidates,
+                        &self.form_reviewers,
+                    )
+                    .with_cursor(self.form_reviewer_cursor)
+                })
+            }
+            (View::PrEdit, 5) => Some(FormField::select(
+                "status",
+                "Status",
+                PR_STATUSES.iter().map(|s| s.to_string()).collect(),
+                &self.form_status,
+            )),
+            _ => None,
+        }
     }
 
-    #[allow(dead_code)]
-    pub fn start_issue_edit(&mut self, issue: &Issue) {
-        self.form = FormState::IssueEdit(IssueEditForm::from_issue(issue));
+    /// Write a typed field back to the form state it was built from
+    fn store_typed_form_field(&mut self, index: usize, field: &FormField) {
+        if self.custom_field_at(index).is_some() {
+            self.form_custom_fields
+                .insert(field.name.clone(), field.as_text().to_string());
+            return;
+        }
+        match (field.name.as_str(), &field.value) {
+            ("priority", FieldValue::Priority(p)) => self.form_priority = *p,
+            ("draft", FieldValue::Boolean(b)) => self.form_is_draft = *b,
+            ("reviewers", FieldValue::MultiSelect { cursor, .. }) => {
+                self.form_reviewers = field.selected_values();
+                self.form_reviewer_cursor = *cursor;
+            }
+            ("status", _) => self.form_status = field.as_text().to_string(),
+            _ => {}
+        }
     }
 
-    #[allow(dead_code)]
-    pub fn start_pr_create(&mut self) {
-        self.form = FormState::PrCreate(PrCreateForm::new());
+    /// Users that can review PRs in the selected project, plus reviewers of the edited
+    /// PR that aren't team members (so they stay listed after being unchecked)
+    pub fn reviewer_candidates(&self) -> Vec<String> {
+        let mut candidates: Vec<String> = self
+            .selected_project_path
+            .as_ref()
+            .and_then(|path| self.project_users.get(path))
+            .map(|users| users.iter().map(|u| u.id.clone()).collect())
+            .unwrap_or_default();
+        let saved_reviewers = self
+            .selected_pr_id
+            .as_ref()
+            .filter(|_| self.current_view == View::PrEdit)
+            .and_then(|id| self.prs.iter().find(|pr| &pr.id == id))
+            .map(|pr| pr.metadata.reviewers.as_slice())
+            .unwrap_or_default();
+        for reviewer in saved_reviewers {
+            if !candidates.contains(reviewer) {
+                candidates.push(reviewer.clone());
+            }
+        }
+        candidates
     }
 
-    #[allow(dead_code)]
-    pub fn start_pr_edit(&mut self, pr: &PullRequest) {
-        self.form = FormState::PrEdit(PrEditForm::from_pr(pr));
+    /// Apply `update` to the active field when it is typed; false for plain text fields
+    pub fn update_typed_form_field(&mut self, update: impl FnOnce(&mut FormField)) -> bool {
+        let index = self.active_form_field;
+        let Some(mut field) = self.typed_form_field(index) else {
+            return false;
+        };
+        update(&mut field);
+        self.store_typed_form_field(index, &field);
+        true
     }
 
-    #[allow(dead_code)]
-    pub fn start_doc_create(&mut self) {
-        self.form = FormState::DocCreate(DocCreateForm::new());
+    /// Whether the active field is a typed field (←/→ cycle pickers)
+    pub fn on_typed_form_field(&self) -> bool {
+        self.typed_form_field(self.active_form_field).is_some()
     }
 
-    #[allow(dead_code)]
-    pub fn reset_form(&mut self) {
-        self.form = FormState::None;
+    /// ←/→ on the active field: steps pickers, toggles booleans, moves multi-select highlight
+    pub fn cycle_form_field(&mut self, forward: bool) {
+        self.update_typed_form_field(|field| field.cycle(forward));
     }
 
-    // =========== Action Panel Navigation ===========
-
-    /// Get currently selected action
-    pub fn selected_action(&self) -> Option<&EntityAction> {
-        self.current_actions
-            .actions
-            .get(self.action_panel_selected_index)
+    /// Form index of the issue create buttons row (after the custom fields)
+    pub fn issue_create_buttons_field(&self) -> usize {
+        4 + self.custom_field_definitions().len()
     }
 
-    /// Navigate action panel up
-    pub fn action_panel_up(&mut self) {
-        if self.action_panel_selected_index > 0 {
-            self.action_panel_selected_index -= 1;
+    /// Fill in default values for custom fields that have no value yet
+    pub fn prefill_custom_fields(&mut self) {
+        let defaults: Vec<(String, String)> = self
+            .custom_field_definitions()
+            .iter()
+            .map(|f| (f.name.clone(), f.initial_value()))
+            .collect();
+        for (name, value) in defaults {
+            self.form_custom_fields.entry(name).or_insert(value);
         }
     }
 
-    /// Navigate action panel down
-    pub fn action_panel_down(&mut self) {
-        let total = self.current_actions.actions.len();
-        if total > 0 && self.action_panel_selected_index < total - 1 {
-            self.action_panel_selected_index += 1;
-        }
+    /// Custom field values to show in a detail view: configured fields first
+    /// (in config order, unset ones skipped), then any other keys sorted by name
+    pub fn custom_field_rows(&self, values: &HashMap<String, String>) -> Vec<(String, String)> {
+        let defined = self.custom_field_definitions();
+        let mut rows: Vec<(String, String)> = defined
+            .iter()
+            .filter_map(|f| {
+                let value = values.get(&f.name).filter(|v| !v.is_empty())?;
+                Some((f.name.clone(), value.clone()))
+            })
+            .collect();
+        let mut extra: Vec<(String, String)> = values
+            .iter()
+            .filter(|(k, v)| !v.is_empty() && !defined.iter().any(|f| &f.name == *k))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        extra.sort();
+        rows.extend(extra);
+        rows
     }
 
-    /// Check if action panel is focused for current view
-    pub fn is_action_panel_focused(&self) -> bool {
-        match self.current_view {
-            View::Issues => matches!(self.issues_list_focus, IssuesListFocus::ActionPanel),
-            View::IssueDetail => matches!(self.issue_detail_focus, IssueDetailFocus::ActionPanel),
-            View::Prs => matches!(self.prs_list_focus, PrsListFocus::ActionPanel),
-            View::PrDetail => matches!(self.pr_detail_focus, PrDetailFocus::ActionPanel),
-            View::Docs => matches!(self.docs_list_focus, DocsListFocus::ActionPanel),
-            View::DocDetail => matches!(self.doc_detail_focus, DocDetailFocus::ActionPanel),
-            View::Organization => matches!(self.organization_focus, OrganizationFocus::ActionPanel),
-            _ => false,
-        }
+    /// Load an issue's or PR's custom field values into the form
+    pub fn load_custom_fields(&mut self, values: &HashMap<String, String>) {
+        self.form_custom_fields = self
+            .custom_field_definitions()
+            .iter()
+            .map(|f| {
+                let value = values.get(&f.name).cloned().unwrap_or_default();
+                (f.name.clone(), value)
+            })
+            .collect();
     }
 
-    // =========== Error Queue Management ===========
-
-    /// Add an error to the queue
-    pub fn push_error(&mut self, message: String) {
-        self.error_queue.push_back(message);
+    /// Check required and number fields; on failure the offending field becomes active
+    pub fn validate_custom_fields(&mut self) -> Result<(), String> {
+        let Some(offset) = self.custom_field_offset() else {
+            return Ok(());
+        };
+        let error = self
+            .custom_field_definitions()
+            .iter()
+            .enumerate()
+            .find_map(|(idx, field)| {
+                let value = self
+                    .form_custom_fields
+                    .get(&field.name)
+                    .map_or("", |v| v.trim());
+                if value.is_empty() {
+                    return field
+                        .required
+                        .then(|| (idx, format!("{} is required", field.name)));
+                }
+                (field.kind() == CustomFieldKind::Number && value.parse::<f64>().is_err())
+                    .then(|| (idx, format!("{} must be a number", field.name)))
+            });
+        match error {
+            Some((idx, message)) => {
+                self.active_form_field = offset + idx;
+                Err(message)
+            }
+            None => Ok(()),
+        }
     }
 
-    /// Get the current error (front of queue) without removing it
-    pub fn current_error(&self) -> Option<&String> {
-        self.error_queue.front()
+    /// Custom field values to send to the daemon; empty ones are left out
+    pub fn custom_field_values(&self) -> HashMap<String, String> {
+        self.custom_field_definitions()
+            .iter()
+            .filter_map(|field| {
+                let value = self
+                    .form_custom_fields
+                    .get(&field.name)
+                    .map_or(String::new(), |v| v.trim().to_string());
+                (!value.is_empty()).then(|| (field.name.clone(), value))
+            })
+            .collect()
     }
 
-    /// Dismiss the current error (removes from front of queue)
-    pub fn dismiss_error(&mut self) {
-        self.error_queue.pop_front();
+    /// Custom fields with a value in `saved` emptied in the form; the daemon reads an
+    /// empty value as "unchanged", so these can't be cleared
+    pub fn cleared_custom_fields(&self, saved: &HashMap<String, String>) -> Vec<String> {
+        self.custom_field_definitions()
+            .iter()
+            .filter(|field| saved.get(&field.name).is_some_and(|v| !v.trim().is_empty()))
+            .filter(|field| {
+                self.form_custom_fields
+                    .get(&field.name)
+                    .is_none_or(|v| v.trim().is_empty())
+            })
+            .map(|field| field.name.clone())
+            .collect()
+    }
+
+    /// Whether the active field is the template selector of a create form
+    pub fn on_template_field(&self) -> bool {
+        matches!(
+            (&self.current_view, self.active_form_field),
+            (View::IssueCreate, 3) | (View::PrCreate, 5)
+        )
+    }
+
+    /// Load the templates for the issue or PR create form from the selected project
+    pub fn load_form_templates(&mut self) {
+        let kind = match self.current_view {
+            View::IssueCreate => "issues",
+            View::PrCreate => "prs",
+            _ => return,
+        };
+        self.form_templates = self
+            .selected_project_path
+            .as_deref()
+            .map(|path| discover_templates(path, kind))
+            .unwrap_or_default();
+        if self.selected_form_template().is_none() {
+            self.form_template.clear();
+        }
+    }
+
+    /// Cycle through the templates, with "none" before the first one
+    pub fn cycle_form_template(&mut self, forward: bool) {
+        let count = self.form_templates.len() + 1;
+        let current = self
+            .form_templates
+            .iter()
+            .position(|t| t.name == self.form_template)
+            .map_or(0, |idx| idx + 1);
+        let next = if forward {
+            (current + 1) % count
+        } else {
+            (current + count - 1) % count
+        };
+        self.form_template = match next {
+            0 => String::new(),
+            idx => self.form_templates[idx - 1].name.clone(),
+        };
+    }
+
+    /// The selected template, if any
+    pub fn selected_form_template(&self) -> Option<&FormTemplate> {
+        self.form_templates
+            .iter()
+            .find(|t| t.name == self.form_template)
+    }
+
+    /// Load an agent into the form for editing
+    pub fn load_agent_to_form(&mut self, agent: &AgentConfig) {
+        self.form_title = agent.name.clone();
+        self.form_agent_type = agent.agent_type;
+        self.form_agent_command = agent.command.clone();
+        // Only NUL bytes can't be quoted, and those can't come from the form anyway
+        self.form_agent_args = shlex::try_join(agent.default_args.iter().map(String::as_str))
+            .unwrap_or_else(|_| agent.default_args.join(" "));
+        self.form_plan_template = agent.plan_template.clone();
+        self.form_implement_template = agent.implement_template.clone();
+    }
+
+    /// Build an agent from the form (default args are split like a shell would)
+    pub fn agent_from_form(&self) -> Result<AgentConfig, String> {
+        let default_args = shlex::split(&self.form_agent_args)
+            .ok_or_else(|| "Default args have an unterminated quote or escape".to_string())?;
+        Ok(AgentConfig {
+            agent_type: self.form_agent_type,
+            name: self.form_title.trim().to_string(),
+            command: self.form_agent_command.trim().to_string(),
+            default_args,
+            plan_template: self.form_plan_template.trim().to_string(),
+            implement_template: self.form_implement_template.trim().to_string(),
+        })
+    }
+
+    /// Agents of the loaded local LLM config
+    pub fn llm_agents(&self) -> &[AgentConfig] {
+        self.local_llm
+            .as_ref()
+            .map(|s| s.config.agents.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Environment variables of the loaded local LLM config, sorted by name
+    pub fn llm_env_vars(&self) -> Vec<(&String, &String)> {
+        self.local_llm
+            .as_ref()
+            .map(|s| s.config.env_vars.iter().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn selected_agent(&self) -> Option<&AgentConfig> {
+        self.llm_agents().get(self.selected_agent_index)
+    }
+
+    pub fn selected_env_var(&self) -> Option<(String, String)> {
+        self.llm_env_vars()
+            .get(self.selected_env_var_index)
+            .map(|(k, v)| ((*k).clone(), (*v).clone()))
+    }
+
+    /// Active agent session working on an issue, if any
+    pub fn llm_session_for_issue(&self, issue_id: &str) -> Option<&LlmWorkSession> {
+        self.llm_work.iter().find(|s| s.issue_id == issue_id)
+    }
+
+    pub fn selected_llm_work(&self) -> Option<&LlmWorkSession> {
+        self.llm_work.get(self.selected_llm_work_index)
+    }
+
+    /// Replace the agent sessions, keeping the selection in range
+    pub fn set_llm_work(&mut self, sessions: Vec<LlmWorkSession>) {
+        self.llm_work = sessions;
+        if self.selected_llm_work_index >= self.llm_work.len() {
+            self.selected_llm_work_index = self.llm_work.len().saturating_sub(1);
+        }
+    }
+
+    pub fn select_next_llm_work(&mut self) {
+        if self.selected_llm_work_index + 1 < self.llm_work.len() {
+            self.selected_llm_work_index += 1;
+        }
+    }
+
+    pub fn select_prev_llm_work(&mut self) {
+        self.selected_llm_work_index = self.selected_llm_work_index.saturating_sub(1);
+    }
+
+    pub fn selected_workspace(&self) -> Option<&TempWorkspace> {
+        self.workspaces.get(self.selected_workspace_index)
+    }
+
+    /// Replace the workspace list, keeping the selection in range
+    pub fn set_workspaces(&mut self, workspaces: Vec<TempWorkspace>) {
+        self.workspaces = workspaces;
+        if self.selected_workspace_index >= self.workspaces.len() {
+            self.selected_workspace_index = self.workspaces.len().saturating_sub(1);
+        }
+    }
+
+    pub fn select_next_workspace(&mut self) {
+        if self.selected_workspace_index + 1 < self.workspaces.len() {
+            self.selected_workspace_index += 1;
+        }
+    }
+
+    pub fn select_prev_workspace(&mut self) {
+        self.selected_workspace_index = self.selected_workspace_index.saturating_sub(1);
+    }
+
+    /// Replace the local LLM config, keeping both selections in range
+    pub fn set_local_llm(&mut self, settings: LocalLlmSettings) {
+        let agents = settings.config.agents.len();
+        let vars = settings.config.env_vars.len();
+        self.local_llm = Some(settings);
+        self.selected_agent_index = self.selected_agent_index.min(agents.saturating_sub(1));
+        self.selected_env_var_index = self.selected_env_var_index.min(vars.saturating_sub(1));
+    }
+
+    /// Move the selection down in the focused Agents view list
+    pub fn select_next_agent_item(&mut self) {
+        let agents = self.llm_agents().len();
+        let vars = self.llm_env_vars().len();
+        let (index, len) = match self.agents_focus {
+            AgentsFocus::Agents => (&mut self.selected_agent_index, agents),
+            AgentsFocus::EnvVars => (&mut self.selected_env_var_index, vars),
+        };
+        if *index + 1 < len {
+            *index += 1;
+        }
+    }
+
+    /// Move the selection up in the focused Agents view list
+    pub fn select_prev_agent_item(&mut self) {
+        let index = match self.agents_focus {
+            AgentsFocus::Agents => &mut self.selected_agent_index,
+            AgentsFocus::EnvVars => &mut self.selected_env_var_index,
+        };
+        *index = index.saturating_sub(1);
+    }
+
+    /// Load a user into the form for editing
+    pub fn load_user_to_form(&mut self, user: &User) {
+        self.form_slug = user.id.clone();
+        self.form_title = user.name.clone();
+        self.form_email = user.email.clone();
+        self.form_git_usernames = user.git_usernames.join(", ");
+    }
+
+    /// Fields of `user` emptied in the form; the daemon reads an empty value as "unchanged",
+    /// so these can't be cleared
+    pub fn cleared_user_fields(&self, user: &User) -> Vec<&'static str> {
+        let mut cleared = Vec::new();
+        if !user.email.is_empty() && self.form_email.trim().is_empty() {
+            cleared.push("Email");
+        }
+        if !user.git_usernames.is_empty() && self.form_git_username_list().is_empty() {
+            cleared.push("Git usernames");
+        }
+        cleared
+    }
+
+    /// Git usernames entered in the form
+    pub fn form_git_username_list(&self) -> Vec<String> {
+        self.form_git_usernames
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Person selected in the People list
+    pub fn selected_person(&self) -> Option<&User> {
+        self.sorted_people().get(self.selected_index).copied()
+    }
+
+    /// Load organization data into form for editing
+    pub fn load_organization_to_form(&mut self, org: &Organization) {
+        self.form_slug = org.slug.clone();
+        self.form_title = org.name.clone();
+        self.form_description = org.description.clone();
+    }
+
+    /// Currently selected organization in the Organizations list
+    pub fn selected_organization(&self) -> Option<&Organization> {
+        self.organizations.get(self.selected_organization_index)
+    }
+
+    /// Replace the organizations list, keeping the selection in range
+    pub fn set_organizations(&mut self, organizations: Vec<Organization>) {
+        self.organizations = organizations;
+        self.selected_organization_index = self
+            .selected_organization_index
+            .min(self.organizations.len().saturating_sub(1));
+    }
+
+    pub fn select_next_organization(&mut self) {
+        if self.selected_organization_index + 1 < self.organizations.len() {
+            self.selected_organization_index += 1;
+        }
+    }
+
+    pub fn select_prev_organization(&mut self) {
+        self.selected_organization_index = self.selected_organization_index.saturating_sub(1);
+    }
+
+    /// Load issue data into form for editing
+    pub fn load_issue_to_form(&mut self, issue: &Issue) {
+        self.form_title = issue.title.clone();
+        self.form_description = issue.description.clone();
+        self.form_priority = issue.metadata.priority;
+        self.form_priority_name = issue
+            .metadata
+            .priority_label
+            .clone()
+            .map(|label| (issue.metadata.priority, label));
+        self.form_status = issue.metadata.status.clone();
+        self.form_is_draft = issue.metadata.draft;
+        self.load_custom_fields(&issue.metadata.custom_fields);
+    }
+
+    /// Load PR data into form for editing
+    pub fn load_pr_to_form(&mut self, pr: &PullRequest) {
+        self.form_title = pr.title.clone();
+        self.form_description = pr.description.clone();
+        self.form_priority = pr.metadata.priority;
+        self.form_priority_name = pr
+            .metadata
+            .priority_label
+            .clone()
+            .map(|label| (pr.metadata.priority, label));
+        self.form_status = pr.metadata.status.clone();
+        self.form_source_branch = pr.metadata.source_branch.clone();
+        self.form_target_branch = pr.metadata.target_branch.clone();
+        self.form_reviewers = pr.metadata.reviewers.clone();
+        self.load_custom_fields(&pr.metadata.custom_fields);
+    }
+
+    /// Fields of `pr` emptied in the form; the daemon reads an empty reviewer list,
+    /// priority 0 or an empty custom field as "unchanged", so these can't be cleared
+    pub fn cleared_pr_fields(&self, pr: &PullRequest) -> Vec<String> {
+        let mut cleared = Vec::new();
+        if !pr.metadata.reviewers.is_empty() && self.form_reviewers.is_empty() {
+            cleared.push("Reviewers".to_string());
+        }
+        if pr.metadata.priority != 0 && self.form_priority == 0 {
+            cleared.push("Priority".to_string());
+        }
+        cleared.extend(self.cleared_custom_fields(&pr.metadata.custom_fields));
+        cleared
+    }
+
+    /// Load doc data into form for editing
+    pub fn load_doc_to_form(&mut self, doc: &Doc) {
+        self.form_title = doc.title.clone();
+        self.form_description = doc.content.clone();
+        self.form_slug = doc.slug.clone();
+    }
+
+    /// Toggle the org-wide doc checkbox
+    pub fn toggle_org_doc(&mut self) {
+        self.form_is_org_doc = !self.form_is_org_doc;
+    }
+
+    /// Toggle the draft checkbox in the issue edit form
+    pub fn toggle_draft(&mut self) {
+        self.form_is_draft = !self.form_is_draft;
+    }
+
+    // =========== Form State Transitions (DDD) ===========
+    // Scaffolding for future form refactoring - not yet integrated
+
+    #[allow(dead_code)]
+    pub fn start_issue_create(&mut self) {
+        self.form = FormState::IssueCreate(IssueCreateForm::new());
+    }
+
+    #[allow(dead_code)]
+    pub fn start_issue_edit(&mut self, issue: &Issue) {
+        self.form = FormState::IssueEdit(IssueEditForm::from_issue(issue));
+    }
+
+    #[allow(dead_code)]
+    pub fn start_pr_create(&mut self) {
+        self.form = FormState::PrCreate(PrCreateForm::new());
+    }
+
+    #[allow(dead_code)]
+    pub fn start_pr_edit(&mut self, pr: &PullRequest) {
+        self.form = FormState::PrEdit(PrEditForm::from_pr(pr));
+    }
+
+    #[allow(dead_code)]
+    pub fn start_doc_create(&mut self) {
+        self.form = FormState::DocCreate(DocCreateForm::new());
+    }
+
+    #[allow(dead_code)]
+    pub fn reset_form(&mut self) {
+        self.form = FormState::None;
+    }
+
+    // =========== Action Panel Navigation ===========
+
+    /// Get currently selected action
+    pub fn selected_action(&self) -> Option<&EntityAction> {
+        self.current_actions
+            .actions
+            .get(self.action_panel_selected_index)
+    }
+
+    /// Navigate action panel up
+    pub fn action_panel_up(&mut self) {
+        if self.action_panel_selected_index > 0 {
+            self.action_panel_selected_index -= 1;
+        }
+    }
+
+    /// Navigate action panel down
+    pub fn action_panel_down(&mut self) {
+        let total = self.current_actions.actions.len();
+        if total > 0 && self.action_panel_selected_index < total - 1 {
+            self.action_panel_selected_index += 1;
+        }
+    }
+
+    /// Check if action panel is focused for current view
+    pub fn is_action_panel_focused(&self) -> bool {
+        match self.current_view {
+            View::Issues => matches!(self.issues_list_focus, IssuesListFocus::ActionPanel),
+            View::IssueDetail => matches!(self.issue_detail_focus, IssueDetailFocus::ActionPanel),
+            View::Prs => matches!(self.prs_list_focus, PrsListFocus::ActionPanel),
+            View::PrDetail => matches!(self.pr_detail_focus, PrDetailFocus::ActionPanel),
+            View::Docs => matches!(self.docs_list_focus, DocsListFocus::ActionPanel),
+            View::DocDetail => matches!(self.doc_detail_focus, DocDetailFocus::ActionPanel),
+            View::Organization => matches!(self.organization_focus, OrganizationFocus::ActionPanel),
+            _ => false,
+        }
+    }
+
+    // =========== Entity Links ===========
+
+    /// Get all known link types: daemon-provided types plus custom types from config
+    pub fn link_types(&self) -> Vec<LinkTypeInfo> {
+        let mut types = self.available_link_types.clone();
+        if let Some(config) = &self.config {
+            for custom in &config.custom_link_types {
+                if !types.iter().any(|t| t.name == custom.name) {
+                    types.push(custom.clone());
+                }
+            }
+        }
+        types
+    }
+
+    /// Get links for the current entity grouped by type
+    /// Outgoing groups come first, then inverse groups, then unknown types
+    pub fn grouped_links(&self) -> Vec<LinkGroup<'_>> {
+        let types = self.link_types();
+        let mut groups: Vec<LinkGroup<'_>> = Vec::new();
+
+        let mut push_group = |link_type: &str, is_inverse: bool| {
+            if groups.iter().any(|g| g.link_type == link_type) {
+                return;
+            }
+            let links: Vec<_> = self
+                .current_links
+                .iter()
+                .filter(|l| l.link_type == link_type)
+                .collect();
+            if !links.is_empty() {
+                groups.push(LinkGroup {
+                    link_type: link_type.to_string(),
+                    is_inverse,
+                    links,
+                });
+            }
+        };
+
+        for link_type in &types {
+            push_group(&link_type.name, false);
+        }
+        for link_type in &types {
+            push_group(&link_type.inverse, true);
+        }
+        for link in &self.current_links {
+            push_group(&link.link_type, false);
+        }
+
+        groups
+    }
+
+    /// Get links in display order (flattened groups)
+    pub fn ordered_links(&self) -> Vec<&EntityLink> {
+        self.grouped_links()
+            .into_iter()
+            .flat_map(|g| g.links)
+            .collect()
+    }
+
+    /// Get the currently selected link
+    pub fn selected_link(&self) -> Option<&EntityLink> {
+        self.ordered_links().get(self.selected_link_index).copied()
+    }
+
+    /// Select the next link (wraps around)
+    pub fn select_next_link(&mut self) {
+        let total = self.current_links.len();
+        if total > 0 {
+            self.selected_link_index = (self.selected_link_index + 1) % total;
+        }
+    }
+
+    /// Select the previous link (wraps around)
+    pub fn select_prev_link(&mut self) {
+        let total = self.current_links.len();
+        if total > 0 {
+            self.selected_link_index = (self.selected_link_index + total - 1) % total;
+        }
+    }
+
+    /// Get a human-readable label for a link target
+    pub fn link_target_display(&self, target_id: &str, target_type: EntityType) -> String {
+        let found = match target_type {
+            EntityType::Issue => self
+                .issues
+                .iter()
+                .find(|i| i.id == target_id)
+                .map(|i| format!("#{} {}", i.display_number, i.title)),
+            EntityType::Pr => self
+                .prs
+                .iter()
+                .find(|p| p.id == target_id)
+                .map(|p| format!("PR #{} {}", p.display_number, p.title)),
+            EntityType::Doc => self
+                .docs
+                .iter()
+                .find(|d| d.slug == target_id)
+                .map(|d| format!("{} ({})", d.title, d.slug)),
+        };
+        found.unwrap_or_else(|| target_id.to_string())
+    }
+
+    /// Get entities that can be linked from the given source, filtered by search text
+    pub fn link_target_candidates(&self, source_id: &str, filter: &str) -> Vec<LinkTarget> {
+        let filter = filter.to_lowercase();
+        let issues = self.issues.iter().map(|i| LinkTarget {
+            id: i.id.clone(),
+            entity_type: EntityType::Issue,
+            display: format!("#{} {}", i.display_number, i.title),
+        });
+        let prs = self.prs.iter().map(|p| LinkTarget {
+            id: p.id.clone(),
+            entity_type: EntityType::Pr,
+            display: format!("PR #{} {}", p.display_number, p.title),
+        });
+        let docs = self.docs.iter().map(|d| LinkTarget {
+            id: d.slug.clone(),
+            entity_type: EntityType::Doc,
+            display: format!("{} ({})", d.title, d.slug),
+        });
+
+        issues
+            .chain(prs)
+            .chain(docs)
+            .filter(|t| {
+                t.id != source_id
+                    && (filter.is_empty() || t.display.to_lowercase().contains(&filter))
+            })
+            .collect()
+    }
+
+    // =========== Assets ===========
+
+    /// Get the currently selected asset
+    pub fn selected_asset(&self) -> Option<&Asset> {
+        self.assets.get(self.selected_asset_index)
+    }
+
+    /// Replace the asset list, keeping the selection in range
+    pub fn set_assets(&mut self, assets: Vec<Asset>) {
+        self.assets = assets;
+        self.selected_asset_index = self
+            .selected_asset_index
+            .min(self.assets.len().saturating_sub(1));
+        self.asset_preview = None;
+    }
+
+    /// Move the asset selection down (clears the preview)
+    pub fn select_next_asset(&mut self) {
+        if self.selected_asset_index + 1 < self.assets.len() {
+            self.selected_asset_index += 1;
+            self.asset_preview = None;
+        }
+    }
+
+    /// Move the asset selection up (clears the preview)
+    pub fn select_prev_asset(&mut self) {
+        if self.selected_asset_index > 0 {
+            self.selected_asset_index -= 1;
+            self.asset_preview = None;
+        }
+    }
+
+    // =========== Search ===========
+
+    /// Whether the search spans all projects (always true without a selected project)
+    pub fn search_spans_all_projects(&self) -> bool {
+        self.search_all_projects || self.selected_project_path.is_none()
+    }
+
+    /// Toggle between searching the selected project and all projects
+    pub fn toggle_search_scope(&mut self) {
+        if self.selected_project_path.is_some() {
+            self.search_all_projects = !self.search_all_projects;
+        }
+    }
+
+    /// Replace the search results, keeping the selection in range
+    pub fn set_search_results(
+        &mut self,
+        results: Vec<SearchResult>,
+        total_count: i32,
+        parsed_query: Option<String>,
+    ) {
+        self.search_results = results;
+        self.search_total_count = total_count;
+        self.search_parsed_query = parsed_query;
+        self.selected_search_index = 0;
+    }
+
+    /// Get the currently selected search result
+    pub fn selected_search_result(&self) -> Option<&SearchResult> {
+        self.search_results.get(self.selected_search_index)
+    }
+
+    /// Move the search result selection down
+    pub fn select_next_search_result(&mut self) {
+        if self.selected_search_index + 1 < self.search_results.len() {
+            self.selected_search_index += 1;
+        }
+    }
+
+    /// Move the search result selection up
+    pub fn select_prev_search_result(&mut self) {
+        self.selected_search_index = self.selected_search_index.saturating_sub(1);
+    }
+
+    // =========== Error Queue Management ===========
+
+    /// Add an error to the queue
+    pub fn push_error(&mut self, message: String) {
+        self.error_queue.push_back(message);
+    }
+
+    /// Get the current error (front of queue) without removing it
+    pub fn current_error(&self) -> Option<&String> {
+        self.error_queue.front()
+    }
+
+    /// Dismiss the current error (removes from front of queue)
+    pub fn dismiss_error(&mut self) {
+        self.error_queue.pop_front();
     }
 
     /// Check if there are any errors to display
     pub fn has_errors(&self) -> bool {
         !self.error_queue.is_empty()
     }
-}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Open issue with the default priority
+    fn issue(id: &str, display_number: u32, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            display_number,
+            title: title.to_string(),
+            description: String::new(),
+            metadata: IssueMetadata {
+                status: "open".to_string(),
+                priority: 2,
+                priority_label: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                custom_fields: HashMap::new(),
+                draft: false,
+            },
+        }
+    }
+
+    /// Open PR from `login` into `main` with the default priority and no reviewers
+    fn pr(id: &str, display_number: u32, title: &str) -> PullRequest {
+        PullRequest {
+            id: id.to_string(),
+            display_number,
+            title: title.to_string(),
+            description: String::new(),
+            metadata: PrMetadata {
+                status: "open".to_string(),
+                priority: 2,
+                priority_label: None,
+                source_branch: "login".to_string(),
+                target_branch: "main".to_string(),
+                linked_issues: vec![],
+                reviewers: vec![],
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                merged_at: None,
+                closed_at: None,
+                custom_fields: HashMap::new(),
+            },
+        }
+    }
+
+    /// Initialized project named after its path
+    fn project(path: &str) -> Project {
+        Project {
+            path: path.to_string(),
+            name: path.trim_start_matches('/').to_string(),
+            project_title: None,
+            user_title: None,
+            is_favorite: false,
+            is_archived: false,
+            initialized: true,
+            issue_count: 0,
+            doc_count: 0,
+            pr_count: 0,
+            organization_slug: None,
+            organization_name: None,
+        }
+    }
+
+    mod view_tests {
+        use super::*;
+
+        #[test]
+        fn test_default_is_projects() {
+            let view = View::default();
+            assert_eq!(view, View::Projects);
+        }
+
+        #[test]
+        fn test_is_form_view_true_for_create_edit() {
+            assert!(View::IssueCreate.is_form_view());
+            assert!(View::IssueEdit.is_form_view());
+            assert!(View::PrCreate.is_form_view());
+            assert!(View::PrEdit.is_form_view());
+            assert!(View::DocCreate.is_form_view());
+            assert!(View::DocEdit.is_form_view());
+            assert!(View::OrganizationCreate.is_form_view());
+            assert!(View::OrganizationEdit.is_form_view());
+        }
+
+        #[test]
+        fn test_is_form_view_false_for_other_views() {
+            assert!(!View::Projects.is_form_view());
+            assert!(!View::Issues.is_form_view());
+            assert!(!View::IssueDetail.is_form_view());
+            assert!(!View::Prs.is_form_view());
+            assert!(!View::PrDetail.is_form_view());
+            assert!(!View::Docs.is_form_view());
+            assert!(!View::DocDetail.is_form_view());
+            assert!(!View::Config.is_form_view());
+            assert!(!View::Search.is_form_view());
+            assert!(!View::Organizations.is_form_view());
+            assert!(!View::Splash.is_form_view());
+        }
+    }
+
+    mod issue_sort_field_tests {
+        use super::*;
+
+        #[test]
+        fn test_default_is_priority() {
+            assert_eq!(IssueSortField::default(), IssueSortField::Priority);
+        }
+
+        #[test]
+        fn test_next_cycles_through_all() {
+            let mut field = IssueSortField::Priority;
+            field = field.next();
+            assert_eq!(field, IssueSortField::DisplayNumber);
+            field = field.next();
+            assert_eq!(field, IssueSortField::CreatedAt);
+            field = field.next();
+            assert_eq!(field, IssueSortField::UpdatedAt);
+            field = field.next();
+            assert_eq!(field, IssueSortField::Status);
+            field = field.next();
+            assert_eq!(field, IssueSortField::Priority);
+        }
+
+        #[test]
+        fn test_label_returns_correct_strings() {
+            assert_eq!(IssueSortField::Priority.label(), "Priority");
+            assert_eq!(IssueSortField::DisplayNumber.label(), "Number");
+            assert_eq!(IssueSortField::CreatedAt.label(), "Created");
+            assert_eq!(IssueSortField::UpdatedAt.label(), "Updated");
+            assert_eq!(IssueSortField::Status.label(), "Status");
+        }
+    }
+
+    mod pr_sort_field_tests {
+        use super::*;
+
+        #[test]
+        fn test_default_is_priority() {
+            assert_eq!(PrSortField::default(), PrSortField::Priority);
+        }
+
+        #[test]
+        fn test_next_cycles_through_all() {
+            let mut field = PrSortField::Priority;
+            field = field.next();
+            assert_eq!(field, PrSortField::DisplayNumber);
+            field = field.next();
+            assert_eq!(field, PrSortField::CreatedAt);
+            field = field.next();
+            assert_eq!(field, PrSortField::UpdatedAt);
+            field = field.next();
+            assert_eq!(field, PrSortField::Status);
+            field = field.next();
+            assert_eq!(field, PrSortField::Priority);
+        }
+
+        #[test]
+        fn test_label_returns_correct_strings() {
+            assert_eq!(PrSortField::Priority.label(), "Priority");
+            assert_eq!(PrSortField::DisplayNumber.label(), "Number");
+        }
+    }
+
+    mod sort_direction_tests {
+        use super::*;
+
+        #[test]
+        fn test_default_is_asc() {
+            assert_eq!(SortDirection::default(), SortDirection::Asc);
+        }
+
+        #[test]
+        fn test_toggle_asc_to_desc() {
+            let dir = SortDirection::Asc;
+            assert_eq!(dir.toggle(), SortDirection::Desc);
+        }
+
+        #[test]
+        fn test_toggle_desc_to_asc() {
+            let dir = SortDirection::Desc;
+            assert_eq!(dir.toggle(), SortDirection::Asc);
+        }
+
+        #[test]
+        fn test_symbol_returns_arrows() {
+            assert_eq!(SortDirection::Asc.symbol(), "↑");
+            assert_eq!(SortDirection::Desc.symbol(), "↓");
+        }
+    }
+
+    mod focus_toggle_tests {
+        use super::*;
+
+        #[test]
+        fn test_issue_detail_focus_toggle() {
+            let mut focus = IssueDetailFocus::Content;
+            focus.toggle();
+            assert_eq!(focus, IssueDetailFocus::ActionPanel);
+            focus.toggle();
+            assert_eq!(focus, IssueDetailFocus::Content);
+        }
+
+        #[test]
+        fn test_issues_list_focus_toggle() {
+            let mut focus = IssuesListFocus::List;
+            focus.toggle();
+            assert_eq!(focus, IssuesListFocus::ActionPanel);
+            focus.toggle();
+            assert_eq!(focus, IssuesListFocus::List);
+        }
+
+        #[test]
+        fn test_prs_list_focus_toggle() {
+            let mut focus = PrsListFocus::List;
+            focus.toggle();
+            assert_eq!(focus, PrsListFocus::ActionPanel);
+        }
+
+        #[test]
+        fn test_pr_detail_focus_toggle() {
+            let mut focus = PrDetailFocus::Content;
+            focus.toggle();
+            assert_eq!(focus, PrDetailFocus::ActionPanel);
+        }
+
+        #[test]
+        fn test_docs_list_focus_toggle() {
+            let mut focus = DocsListFocus::List;
+            focus.toggle();
+            assert_eq!(focus, DocsListFocus::ActionPanel);
+        }
+
+        #[test]
+        fn test_doc_detail_focus_toggle() {
+            let mut focus = DocDetailFocus::Content;
+            focus.toggle();
+            assert_eq!(focus, DocDetailFocus::ActionPanel);
+        }
+    }
+
+    mod llm_action_tests {
+        use super::*;
+
+        #[test]
+        fn test_default_is_plan() {
+            assert_eq!(LlmAction::default(), LlmAction::Plan);
+        }
+
+        #[test]
+        fn test_toggle() {
+            let mut action = LlmAction::Plan;
+            action.toggle();
+            assert_eq!(action, LlmAction::Implement);
+            action.toggle();
+            assert_eq!(action, LlmAction::Plan);
+        }
+
+        #[test]
+        fn test_label() {
+            assert_eq!(LlmAction::Plan.label(), "Plan");
+            assert_eq!(LlmAction::Implement.label(), "Implement");
+        }
+
+        #[test]
+        fn test_as_proto_value() {
+            assert_eq!(LlmAction::Plan.as_proto_value(), 1);
+            assert_eq!(LlmAction::Implement.as_proto_value(), 2);
+        }
+    }
+
+    mod action_category_tests {
+        use super::*;
+
+        #[test]
+        fn test_from_proto() {
+            assert_eq!(ActionCategory::from_proto(1), ActionCategory::Crud);
+            assert_eq!(ActionCategory::from_proto(2), ActionCategory::Mode);
+            assert_eq!(ActionCategory::from_proto(3), ActionCategory::Status);
+            assert_eq!(ActionCategory::from_proto(4), ActionCategory::External);
+            assert_eq!(ActionCategory::from_proto(0), ActionCategory::Unspecified);
+            assert_eq!(ActionCategory::from_proto(99), ActionCategory::Unspecified);
+        }
+
+        #[test]
+        fn test_label() {
+            assert_eq!(ActionCategory::Unspecified.label(), "Other");
+            assert_eq!(ActionCategory::Crud.label(), "Actions");
+            assert_eq!(ActionCategory::Mode.label(), "Mode");
+            assert_eq!(ActionCategory::Status.label(), "Status");
+            assert_eq!(ActionCategory::External.label(), "External");
+        }
+    }
+
+    mod project_tests {
+        use super::*;
+
+        #[test]
+        fn test_display_name_prefers_user_title() {
+            let project = Project {
+                path: "/test".to_string(),
+                name: "project".to_string(),
+                project_title: Some("Project Title".to_string()),
+                user_title: Some("My Custom Name".to_string()),
+                is_favorite: false,
+                is_archived: false,
+                initialized: true,
+                issue_count: 0,
+                doc_count: 0,
+                pr_count: 0,
+                organization_slug: None,
+                organization_name: None,
+            };
+            assert_eq!(project.display_name(), "My Custom Name");
+        }
+
+        #[test]
+        fn test_display_name_falls_back_to_project_title() {
+            let project = Project {
+                path: "/test".to_string(),
+                name: "project".to_string(),
+                project_title: Some("Project Title".to_string()),
+                user_title: None,
+                is_favorite: false,
+                is_archived: false,
+                initialized: true,
+                issue_count: 0,
+                doc_count: 0,
+                pr_count: 0,
+                organization_slug: None,
+                organization_name: None,
+            };
+            assert_eq!(project.display_name(), "Project Title");
+        }
+
+        #[test]
+        fn test_display_name_falls_back_to_name() {
+            let project = Project {
+                path: "/test".to_string(),
+                name: "project-name".to_string(),
+                project_title: None,
+                user_title: None,
+                is_favorite: false,
+                is_archived: false,
+                initialized: true,
+                issue_count: 0,
+                doc_count: 0,
+                pr_count: 0,
+                organization_slug: None,
+                organization_name: None,
+            };
+            assert_eq!(project.display_name(), "project-name");
+        }
+
+        #[test]
+        fn test_archived_projects_grouped_last() {
+            let mut archived_favorite = project("/old");
+            archived_favorite.is_archived = true;
+            archived_favorite.is_favorite = true;
+            let mut favorite = project("/fav");
+            favorite.is_favorite = true;
+            let state = AppState {
+                projects: vec![archived_favorite, project("/a"), favorite],
+                ..Default::default()
+            };
+
+            let sections = state.grouped_projects();
+            let headers: Vec<_> = sections.iter().map(|s| s.header.as_str()).collect();
+            assert_eq!(headers, vec!["Favorites", "Ungrouped", "Archived"]);
+            assert_eq!(sections[0].projects.len(), 1);
+            assert!(sections[2].is_archived);
+            assert_eq!(sections[2].projects[0].path, "/old");
+        }
+
+        #[test]
+        fn test_project_filter_labels() {
+            assert!(ProjectListFilter::default().active_labels().is_empty());
+            let filter = ProjectListFilter {
+                include_archived: true,
+                include_temp: true,
+                ..Default::default()
+            };
+            assert_eq!(filter.active_labels(), vec!["archived", "temp"]);
+        }
+
+        #[test]
+        fn test_neighbor_project_prefers_next_card() {
+            let state = AppState {
+                projects: vec![project("/a"), project("/b"), project("/c")],
+                ..Default::default()
+            };
+            let order: Vec<String> = state
+                .selectable_projects()
+                .iter()
+                .map(|p| p.path.clone())
+                .collect();
+
+            assert_eq!(
+                state.neighbor_project_path(&order[0]),
+                Some(order[1].clone())
+            );
+            assert_eq!(
+                state.neighbor_project_path(&order[2]),
+                Some(order[1].clone())
+            );
+            assert_eq!(state.neighbor_project_path("/missing"), None);
+
+            let single = AppState {
+                projects: vec![project("/a")],
+                ..Default::default()
+            };
+            assert_eq!(single.neighbor_project_path("/a"), None);
+        }
+
+        #[test]
+        fn test_untrack_confirmation_names_path() {
+            let mut p = project("/work/api");
+            p.user_title = Some("API".to_string());
+            let confirm = PendingConfirmAction::untrack_project(&p);
+            assert_eq!(confirm.title, "Untrack API");
+            assert_eq!(confirm.target, "/work/api");
+            assert!(matches!(
+                confirm.kind,
+                ConfirmKind::UntrackProject { ref path } if path == "/work/api"
+            ));
+        }
+
+        #[test]
+        fn test_rename_starts_from_current_titles() {
+            let mut p = project("/a");
+            p.project_title = Some("Shared".to_string());
+            let mut rename = PendingRenameAction::new(&p);
+            assert_eq!(rename.scope, TitleScope::User);
+            assert_eq!(rename.user_title, "");
+            assert_eq!(rename.project_title, "Shared");
+            assert_eq!(rename.effective_name(), "Shared");
+
+            rename.input_mut().push_str("Mine");
+            assert_eq!(rename.effective_name(), "Mine");
+        }
+
+        #[test]
+        fn test_rename_scope_toggle_and_clear() {
+            let mut p = project("/a");
+            p.user_title = Some("Mine".to_string());
+            p.project_title = Some("Shared".to_string());
+            let mut rename = PendingRenameAction::new(&p);
+            rename.input_mut().clear();
+            assert_eq!(rename.effective_name(), "Shared");
+
+            rename.scope.toggle();
+            assert_eq!(rename.scope, TitleScope::Project);
+            rename.input_mut().clear();
+            assert_eq!(rename.effective_name(), "a");
+        }
+
+        #[test]
+        fn test_rename_changed_titles_covers_both_scopes() {
+            let mut p = project("/a");
+            p.project_title = Some("Shared".to_string());
+            let mut rename = PendingRenameAction::new(&p);
+            assert!(rename.changed_titles().is_empty());
+
+            rename.input_mut().push_str("Mine");
+            rename.scope.toggle();
+            rename.input_mut().push(' ');
+            assert_eq!(
+                rename.changed_titles(),
+                vec![(TitleScope::User, "Mine".to_string())]
+            );
+
+            rename.input_mut().clear();
+            assert_eq!(
+                rename.changed_titles(),
+                vec![
+                    (TitleScope::User, "Mine".to_string()),
+                    (TitleScope::Project, String::new()),
+                ]
+            );
+
+            rename.mark_saved(TitleScope::User);
+            assert_eq!(
+                rename.changed_titles(),
+                vec![(TitleScope::Project, String::new())]
+            );
+        }
+
+        #[test]
+        fn test_select_project_by_path() {
+            let mut state = AppState {
+                projects: vec![project("/a"), project("/b"), project("/c")],
+                ..Default::default()
+            };
+            assert!(state.select_project_by_path("/c"));
+            assert_eq!(state.selectable_projects()[state.selected_index].path, "/c");
+        }
+
+        #[test]
+        fn test_select_project_by_path_missing_keeps_selection() {
+            let mut state = AppState {
+                projects: vec![project("/a"), project("/b")],
+                selected_index: 1,
+                ..Default::default()
+            };
+            assert!(!state.select_project_by_path("/missing"));
+            assert_eq!(state.selected_index, 1);
+        }
+    }
+
+    mod issue_tests {
+        use super::*;
+
+        fn create_test_issue(priority: u32, status: &str) -> Issue {
+            Issue {
+                id: "test-id".to_string(),
+                display_number: 1,
+                title: "Test".to_string(),
+                description: "Desc".to_string(),
+                metadata: IssueMetadata {
+                    status: status.to_string(),
+                    priority,
+                    priority_label: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                    custom_fields: HashMap::new(),
+                    draft: false,
+                },
+            }
+        }
+
+        #[test]
+        fn test_priority_color() {
+            assert_eq!(create_test_issue(1, "open").priority_color(), "red");
+            assert_eq!(create_test_issue(2, "open").priority_color(), "yellow");
+            assert_eq!(create_test_issue(3, "open").priority_color(), "green");
+        }
+
+        #[test]
+        fn test_priority_label_default() {
+            assert_eq!(create_test_issue(1, "open").priority_label(), "high");
+            assert_eq!(create_test_issue(2, "open").priority_label(), "medium");
+            assert_eq!(create_test_issue(3, "open").priority_label(), "low");
+        }
+
+        #[test]
+        fn test_draft_filter_cycles_and_filters_list() {
+            let mut draft = create_test_issue(1, "open");
+            draft.id = "draft".to_string();
+            draft.metadata.draft = true;
+            let mut state = AppState {
+                issues: vec![draft, create_test_issue(2, "open")],
+                ..Default::default()
+            };
+            let ids = |state: &AppState| -> Vec<String> {
+                state.sorted_issues().iter().map(|i| i.id.clone()).collect()
+            };
+
+            assert_eq!(ids(&state), vec!["draft", "test-id"]);
+            state.cycle_issue_draft_filter();
+            assert_eq!(state.issue_draft_filter, IssueDraftFilter::DraftsOnly);
+            assert_eq!(ids(&state), vec!["draft"]);
+            state.cycle_issue_draft_filter();
+            assert_eq!(ids(&state), vec!["test-id"]);
+            state.cycle_issue_draft_filter();
+            assert_eq!(state.issue_draft_filter, IssueDraftFilter::All);
+        }
+
+        #[test]
+        fn test_load_issue_to_form_keeps_draft_flag() {
+            let mut issue = create_test_issue(1, "open");
+            issue.metadata.draft = true;
+            let mut state = AppState::default();
+            state.load_issue_to_form(&issue);
+            assert!(state.form_is_draft);
+            state.toggle_draft();
+            assert!(!state.form_is_draft);
+            state.clear_form();
+            assert!(!state.form_is_draft);
+        }
+    }
+
+    mod app_state_tests {
+        use super::*;
+
+        fn create_default_state() -> AppState {
+            AppState::default()
+        }
+
+        #[test]
+        fn test_default_view_is_projects() {
+            let state = create_default_state();
+            // View::default() is Projects (see View enum with #[default])
+            assert_eq!(state.current_view, View::Projects);
+        }
+
+        #[test]
+        fn test_move_selection_down() {
+            let mut state = create_default_state();
+            state.selected_index = 0;
+            state.move_selection_down(5);
+            assert_eq!(state.selected_index, 1);
+        }
+
+        #[test]
+        fn test_move_selection_down_at_max() {
+            let mut state = create_default_state();
+            state.selected_index = 4;
+            state.move_selection_down(5);
+            assert_eq!(state.selected_index, 4); // Stays at max
+        }
+
+        #[test]
+        fn test_move_selection_up() {
+            let mut state = create_default_state();
+            state.selected_index = 3;
+            state.move_selection_up();
+            assert_eq!(state.selected_index, 2);
+        }
+
+        #[test]
+        fn test_move_selection_up_at_zero() {
+            let mut state = create_default_state();
+            state.selected_index = 0;
+            state.move_selection_up();
+            assert_eq!(state.selected_index, 0);
+        }
+
+        #[test]
+        fn test_scroll_down() {
+            let mut state = create_default_state();
+            state.scroll_offset = 0;
+            state.scroll_down();
+            assert_eq!(state.scroll_offset, 1);
+        }
+
+        #[test]
+        fn test_scroll_up() {
+            let mut state = create_default_state();
+            state.scroll_offset = 5;
+            state.scroll_up();
+            assert_eq!(state.scroll_offset, 4);
+        }
+
+        #[test]
+        fn test_scroll_up_at_zero() {
+            let mut state = create_default_state();
+            state.scroll_offset = 0;
+            state.scroll_up();
+            assert_eq!(state.scroll_offset, 0);
+        }
+
+        #[test]
+        fn test_scroll_down_page() {
+            let mut state = create_default_state();
+            state.scroll_offset = 0;
+            state.scroll_down_page();
+            assert_eq!(state.scroll_offset, 10);
+        }
+
+        #[test]
+        fn test_scroll_up_page() {
+            let mut state = create_default_state();
+            state.scroll_offset = 15;
+            state.scroll_up_page();
+            assert_eq!(state.scroll_offset, 5);
+        }
+
+        #[test]
+        fn test_scroll_up_page_clamps_at_zero() {
+            let mut state = create_default_state();
+            state.scroll_offset = 3;
+            state.scroll_up_page();
+            assert_eq!(state.scroll_offset, 0);
+        }
+
+        #[test]
+        fn test_form_field_count_for_views() {
+            let mut state = create_default_state();
+
+            state.current_view = View::IssueCreate;
+            assert_eq!(state.form_field_count(), 5);
+
+            state.current_view = View::IssueEdit;
+            assert_eq!(state.form_field_count(), 5);
+
+            state.current_view = View::PrCreate;
+            assert_eq!(state.form_field_count(), 7);
+
+            state.current_view = View::PrEdit;
+            assert_eq!(state.form_field_count(), 7);
+
+            state.current_view = View::DocCreate;
+            assert_eq!(state.form_field_count(), 3);
+
+            state.current_view = View::Projects;
+            assert_eq!(state.form_field_count(), 1);
+        }
+
+        #[test]
+        fn test_next_form_field_cycles() {
+            let mut state = create_default_state();
+            state.current_view = View::IssueCreate;
+            state.active_form_field = 4;
+            state.next_form_field();
+            assert_eq!(state.active_form_field, 0);
+        }
+
+        #[test]
+        fn test_prev_form_field_cycles() {
+            let mut state = create_default_state();
+            state.current_view = View::IssueCreate;
+            state.active_form_field = 0;
+            state.prev_form_field();
+            assert_eq!(state.active_form_field, 4);
+        }
+
+        #[test]
+        fn test_form_input_char_appends_to_title() {
+            let mut state = create_default_state();
+            state.current_view = View::IssueCreate;
+            state.active_form_field = 0;
+            state.form_input_char('a', false);
+            state.form_input_char('b', false);
+            assert_eq!(state.form_title, "ab");
+        }
+
+        #[test]
+        fn test_form_input_char_with_shift() {
+            let mut state = create_default_state();
+            state.current_view = View::IssueCreate;
+            state.active_form_field = 0;
+            state.form_input_char('a', true);
+            assert_eq!(state.form_title, "A");
+        }
+
+        #[test]
+        fn test_form_backspace() {
+            let mut state = create_default_state();
+            state.current_view = View::IssueCreate;
+            state.active_form_field = 0;
+            state.form_title = "abc".to_string();
+            state.form_backspace();
+            assert_eq!(state.form_title, "ab");
+        }
+
+        #[test]
+        fn test_clear_form() {
+            let mut state = create_default_state();
+            state.form_title = "Title".to_string();
+            state.form_description = "Desc".to_string();
+            state.form_priority = 2;
+            state.active_form_field = 2;
+
+            state.clear_form();
+
+            assert_eq!(state.form_title, "");
+            assert_eq!(state.form_description, "");
+            assert_eq!(state.form_priority, 0);
+            assert_eq!(state.active_form_field, 0);
+        }
+
+        fn template(name: &str) -> FormTemplate {
+            FormTemplate {
+                name: name.to_string(),
+                body: format!("# {}", name),
+            }
+        }
+
+        #[test]
+        fn test_cycle_form_template_wraps_through_none() {
+            let mut state = create_default_state();
+            state.form_templates = vec![template("bug"), template("feature")];
+
+            state.cycle_form_template(true);
+            assert_eq!(state.form_template, "bug");
+            state.cycle_form_template(true);
+            assert_eq!(state.form_template, "feature");
+            state.cycle_form_template(true);
+            assert_eq!(state.form_template, "");
+            state.cycle_form_template(false);
+            assert_eq!(state.form_template, "feature");
+            assert_eq!(
+                state.selected_form_template().map(|t| t.body.as_str()),
+                Some("# feature")
+            );
+        }
+
+        #[test]
+        fn test_template_field_takes_no_text() {
+            let mut state = create_default_state();
+            state.current_view = View::IssueCreate;
+            state.active_form_field = 3;
+            assert!(state.on_template_field());
+            state.form_input_char('x', false);
+            assert_eq!(state.form_status, "");
+
+            state.current_view = View::IssueEdit;
+            assert!(!state.on_template_field());
+            state.form_input_char('x', false);
+            assert_eq!(state.form_status, "x");
+        }
+
+        #[test]
+        fn test_discover_templates_reads_markdown_files() {
+            let root =
+                std::env::temp_dir().join(format!("centy-tui-templates-{}", std::process::id()));
+            let dir = root.join(".centy/templates/issues");
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("feature.md"), "## Goal").unwrap();
+            std::fs::write(dir.join("bug.md"), "## Steps").unwrap();
+            std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+            let project = root.to_str().unwrap();
+            let names: Vec<String> = discover_templates(project, "issues")
+                .into_iter()
+                .map(|t| t.name)
+                .collect();
+            assert_eq!(names, vec!["bug", "feature"]);
+            assert!(discover_templates(project, "prs").is_empty());
+            let _ = std::fs::remove_dir_all(&root);
+        }
+
+        #[test]
+        fn test_action_panel_up() {
+            let mut state = create_default_state();
+            state.action_panel_selected_index = 3;
+            state.action_panel_up();
+            assert_eq!(state.action_panel_selected_index, 2);
+        }
+
+        #[test]
+        fn test_action_panel_up_at_zero() {
+            let mut state = create_default_state();
+            state.action_panel_selected_index = 0;
+            state.action_panel_up();
+            assert_eq!(state.action_panel_selected_index, 0);
+        }
+
+        #[test]
+        fn test_action_panel_down() {
+            let mut state = create_default_state();
+            state.current_actions.actions = vec![
+                EntityAction {
+                    id: "1".to_string(),
+                    label: "One".to_string(),
+                    category: ActionCategory::Crud,
+                    enabled: true,
+                    disabled_reason: String::new(),
+                    destructive: false,
+                    keyboard_shortcut: String::new(),
+                },
+                EntityAction {
+                    id: "2".to_string(),
+                    label: "Two".to_string(),
+                    category: ActionCategory::Crud,
+                    enabled: true,
+                    disabled_reason: String::new(),
+                    destructive: false,
+                    keyboard_shortcut: String::new(),
+                },
+            ];
+            state.action_panel_selected_index = 0;
+            state.action_panel_down();
+            assert_eq!(state.action_panel_selected_index, 1);
+        }
+
+        #[test]
+        fn test_action_panel_down_at_max() {
+            let mut state = create_default_state();
+            state.current_actions.actions = vec![EntityAction {
+                id: "1".to_string(),
+                label: "One".to_string(),
+                category: ActionCategory::Crud,
+                enabled: true,
+                disabled_reason: String::new(),
+                destructive: false,
+                keyboard_shortcut: String::new(),
+            }];
+            state.action_panel_selected_index = 0;
+            state.action_panel_down();
+            assert_eq!(state.action_panel_selected_index, 0);
+        }
+
+        #[test]
+        fn test_error_queue_push() {
+            let mut state = create_default_state();
+            assert!(!state.has_errors());
+            state.push_error("Error 1".to_string());
+            assert!(state.has_errors());
+        }
+
+        #[test]
+        fn test_error_queue_current() {
+            let mut state = create_default_state();
+            state.push_error("Error 1".to_string());
+            state.push_error("Error 2".to_string());
+            assert_eq!(state.current_error(), Some(&"Error 1".to_string()));
+        }
+
+        #[test]
+        fn test_error_queue_dismiss() {
+            let mut state = create_default_state();
+            state.push_error("Error 1".to_string());
+            state.push_error("Error 2".to_string());
+            state.dismiss_error();
+            assert_eq!(state.current_error(), Some(&"Error 2".to_string()));
+        }
+
+        #[test]
+        fn test_is_action_panel_focused() {
+            let mut state = create_default_state();
+
+            state.current_view = View::Issues;
+            state.issues_list_focus = IssuesListFocus::List;
+            assert!(!state.is_action_panel_focused());
+
+            state.issues_list_focus = IssuesListFocus::ActionPanel;
+            assert!(state.is_action_panel_focused());
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    mod custom_field_tests {
+        use super::*;
 
-    mod view_tests {
+        fn field(name: &str, field_type: &str, required: bool) -> CustomFieldDefinition {
+            CustomFieldDefinition {
+                name: name.to_string(),
+                field_type: field_type.to_string(),
+                required,
+                default_value: String::new(),
+                enum_values: if field_type == "enum" {
+                    vec!["web".to_string(), "api".to_string()]
+                } else {
+                    Vec::new()
+                },
+            }
+        }
+
+        fn state_with_fields(view: View, fields: Vec<CustomFieldDefinition>) -> AppState {
+            AppState {
+                current_view: view,
+                config: Some(Config {
+                    custom_fields: fields,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn test_initial_values() {
+            let mut estimate = field("estimate", "number", false);
+            estimate.default_value = "3".to_string();
+            assert_eq!(estimate.initial_value(), "3");
+            assert_eq!(field("team", "enum", true).initial_value(), "web");
+            assert_eq!(field("team", "enum", false).initial_value(), "");
+            assert_eq!(field("blocked", "boolean", false).initial_value(), "false");
+            assert_eq!(
+                field("notes", "unknown", false).kind(),
+                CustomFieldKind::Text
+            );
+        }
+
+        #[test]
+        fn test_custom_fields_extend_form() {
+            let fields = vec![
+                field("team", "enum", false),
+                field("estimate", "number", false),
+            ];
+            let mut state = state_with_fields(View::IssueCreate, fields);
+            assert_eq!(state.form_field_count(), 7);
+            assert_eq!(state.issue_create_buttons_field(), 6);
+
+            state.active_form_field = 5;
+            state.form_input_char('1', false);
+            state.form_input_char('x', false);
+            state.form_input_char('.', false);
+            state.form_input_char('5', false);
+            assert_eq!(state.form_custom_fields["estimate"], "1.5");
+            state.form_backspace();
+            assert_eq!(state.form_custom_fields["estimate"], "1.");
+        }
+
+        #[test]
+        fn test_cycle_enum_and_boolean() {
+            let fields = vec![
+                field("team", "enum", false),
+                field("blocked", "boolean", true),
+            ];
+            let mut state = state_with_fields(View::PrEdit, fields);
+            state.active_form_field = 7;
+            state.cycle_form_field(true);
+            assert_eq!(state.form_custom_fields["team"], "web");
+            state.cycle_form_field(false);
+            state.cycle_form_field(false);
+            assert_eq!(state.form_custom_fields["team"], "api");
+            state.form_backspace();
+            assert_eq!(state.form_custom_fields["team"], "");
+
+            state.active_form_field = 8;
+            state.prefill_custom_fields();
+            state.form_input_char(' ', false);
+            assert_eq!(state.form_custom_fields["blocked"], "true");
+        }
+
+        #[test]
+        fn test_priority_and_status_pickers_follow_config() {
+            let mut state = state_with_fields(View::IssueEdit, Vec::new());
+            state.config.as_mut().unwrap().priority_levels = 4;
+            state.active_form_field = 2;
+            state.form_input_char('4', false);
+            assert_eq!(state.form_priority, 4);
+            state.form_input_char('5', false);
+            assert_eq!(state.form_priority, 4);
+            state.cycle_form_field(true);
+            assert_eq!(state.form_priority, 0);
+
+            // Without states the status stays free text
+            state.active_form_field = 3;
+            state.form_input_char('x', false);
+            assert_eq!(state.form_status, "x");
+            state.config.as_mut().unwrap().allowed_states =
+                vec!["open".to_string(), "closed".to_string()];
+            state.form_status = "open".to_string();
+            state.form_input_char('x', false);
+            assert_eq!(state.form_status, "open");
+            state.cycle_form_field(false);
+            assert_eq!(state.form_status, "closed");
+
+            state.active_form_field = 4;
+            state.cycle_form_field(true);
+            assert!(state.form_is_draft);
+        }
+
+        #[test]
+        fn test_pr_status_cycles_pr_statuses() {
+            let mut state = state_with_fields(View::PrEdit, Vec::new());
+            state.form_status = "open".to_string();
+            state.active_form_field = 5;
+            assert!(state.on_typed_form_field());
+            state.cycle_form_field(true);
+            assert_eq!(state.form_status, "merged");
+            state.form_backspace();
+            assert_eq!(state.form_status, "merged");
+        }
+
+        #[test]
+        fn test_validate_focuses_invalid_field() {
+            let fields = vec![
+                field("owner", "string", true),
+                field("estimate", "number", false),
+            ];
+            let mut state = state_with_fields(View::IssueEdit, fields);
+            assert!(state.validate_custom_fields().is_err());
+            assert_eq!(state.active_form_field, 5);
+
+            state
+                .form_custom_fields
+                .insert("owner".to_string(), "ana".to_string());
+            state
+                .form_custom_fields
+                .insert("estimate".to_string(), "1-".to_string());
+            assert!(state.validate_custom_fields().is_err());
+            assert_eq!(state.active_form_field, 6);
+
+            state
+                .form_custom_fields
+                .insert("estimate".to_string(), String::new());
+            assert!(state.validate_custom_fields().is_ok());
+            assert_eq!(state.custom_field_values().len(), 1);
+        }
+
+        #[test]
+        fn test_cleared_custom_fields() {
+            let fields = vec![
+                field("owner", "string", false),
+                field("estimate", "number", false),
+            ];
+            let mut state = state_with_fields(View::IssueEdit, fields);
+            let saved: HashMap<String, String> = [("owner".to_string(), "ana".to_string())]
+                .into_iter()
+                .collect();
+            state.load_custom_fields(&saved);
+            assert!(state.cleared_custom_fields(&saved).is_empty());
+
+            state.active_form_field = 5;
+            state.form_backspace();
+            state.form_backspace();
+            state.form_backspace();
+            assert_eq!(state.cleared_custom_fields(&saved), vec!["owner"]);
+        }
+
+        #[test]
+        fn test_detail_rows_put_configured_fields_first() {
+            let state = state_with_fields(
+                View::IssueDetail,
+                vec![
+                    field("team", "enum", false),
+                    field("owner", "string", false),
+                ],
+            );
+            let values = HashMap::from([
+                ("zeta".to_string(), "1".to_string()),
+                ("owner".to_string(), "ana".to_string()),
+                ("alpha".to_string(), "2".to_string()),
+                ("team".to_string(), String::new()),
+            ]);
+            let names: Vec<String> = state
+                .custom_field_rows(&values)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect();
+            assert_eq!(names, vec!["owner", "alpha", "zeta"]);
+        }
+    }
+
+    mod link_tests {
         use super::*;
 
+        fn link_type(name: &str, inverse: &str, is_builtin: bool) -> LinkTypeInfo {
+            LinkTypeInfo {
+                name: name.to_string(),
+                inverse: inverse.to_string(),
+                description: String::new(),
+                is_builtin,
+            }
+        }
+
+        fn link(target_id: &str, target_type: EntityType, link_type: &str) -> EntityLink {
+            EntityLink {
+                target_id: target_id.to_string(),
+                target_type,
+                link_type: link_type.to_string(),
+                created_at: Utc::now(),
+            }
+        }
+
+        fn create_state_with_links() -> AppState {
+            AppState {
+                available_link_types: vec![
+                    link_type("blocks", "blocked-by", true),
+                    link_type("parent-of", "child-of", true),
+                ],
+                current_links: vec![
+                    link("a", EntityType::Issue, "blocked-by"),
+                    link("b", EntityType::Issue, "blocks"),
+                    link("c", EntityType::Doc, "mystery"),
+                    link("d", EntityType::Pr, "blocks"),
+                ],
+                ..Default::default()
+            }
+        }
+
         #[test]
-        fn test_default_is_projects() {
-            let view = View::default();
-            assert_eq!(view, View::Projects);
+        fn test_link_types_include_custom_from_config() {
+            let mut state = create_state_with_links();
+            state.config = Some(Config {
+                priority_levels: 3,
+                allowed_states: vec![],
+                default_state: "open".to_string(),
+                version: String::new(),
+                custom_link_types: vec![
+                    link_type("blocks", "blocked-by", false),
+                    link_type("depends-on", "dependency-of", false),
+                ],
+                ..Default::default()
+            });
+            let names: Vec<_> = state.link_types().into_iter().map(|t| t.name).collect();
+            assert_eq!(names, vec!["blocks", "parent-of", "depends-on"]);
         }
 
         #[test]
-        fn test_is_form_view_true_for_create_edit() {
-            assert!(View::IssueCreate.is_form_view());
-            assert!(View::IssueEdit.is_form_view());
-            assert!(View::PrCreate.is_form_view());
-            assert!(View::PrEdit.is_form_view());
-            assert!(View::DocCreate.is_form_view());
-            assert!(View::DocEdit.is_form_view());
+        fn test_grouped_links_outgoing_then_inverse_then_unknown() {
+            let state = create_state_with_links();
+            let groups = state.grouped_links();
+            let summary: Vec<_> = groups
+                .iter()
+                .map(|g| (g.link_type.as_str(), g.is_inverse, g.links.len()))
+                .collect();
+            assert_eq!(
+                summary,
+                vec![
+                    ("blocks", false, 2),
+                    ("blocked-by", true, 1),
+                    ("mystery", false, 1)
+                ]
+            );
         }
 
         #[test]
-        fn test_is_form_view_false_for_other_views() {
-            assert!(!View::Projects.is_form_view());
-            assert!(!View::Issues.is_form_view());
-            assert!(!View::IssueDetail.is_form_view());
-            assert!(!View::Prs.is_form_view());
-            assert!(!View::PrDetail.is_form_view());
-            assert!(!View::Docs.is_form_view());
-            assert!(!View::DocDetail.is_form_view());
-            assert!(!View::Config.is_form_view());
-            assert!(!View::Splash.is_form_view());
+        fn test_ordered_links_follow_groups() {
+            let state = create_state_with_links();
+            let ids: Vec<_> = state
+                .ordered_links()
+                .iter()
+                .map(|l| l.target_id.as_str())
+                .collect();
+            assert_eq!(ids, vec!["b", "d", "a", "c"]);
+        }
+
+        #[test]
+        fn test_select_link_wraps() {
+            let mut state = create_state_with_links();
+            state.select_prev_link();
+            assert_eq!(state.selected_link_index, 3);
+            assert_eq!(state.selected_link().unwrap().target_id, "c");
+            state.select_next_link();
+            assert_eq!(state.selected_link_index, 0);
+            assert_eq!(state.selected_link().unwrap().target_id, "b");
+        }
+
+        #[test]
+        fn test_select_link_without_links_is_noop() {
+            let mut state = AppState::default();
+            state.select_next_link();
+            state.select_prev_link();
+            assert_eq!(state.selected_link_index, 0);
+            assert!(state.selected_link().is_none());
+        }
+
+        #[test]
+        fn test_link_target_display_falls_back_to_id() {
+            let state = AppState {
+                issues: vec![issue("i1", 7, "Fix login")],
+                ..Default::default()
+            };
+            assert_eq!(
+                state.link_target_display("i1", EntityType::Issue),
+                "#7 Fix login"
+            );
+            assert_eq!(
+                state.link_target_display("missing", EntityType::Pr),
+                "missing"
+            );
+        }
+
+        #[test]
+        fn test_link_target_candidates_exclude_source_and_filter() {
+            let state = AppState {
+                issues: vec![
+                    issue("i1", 1, "Login page"),
+                    issue("i2", 2, "Logout button"),
+                    issue("i3", 3, "Settings"),
+                ],
+                ..Default::default()
+            };
+            let all = state.link_target_candidates("i1", "");
+            assert_eq!(all.len(), 2);
+            let filtered = state.link_target_candidates("i1", "LOG");
+            assert_eq!(filtered.len(), 1);
+            assert_eq!(filtered[0].id, "i2");
+            assert_eq!(filtered[0].entity_type, EntityType::Issue);
         }
     }
 
-    mod issue_sort_field_tests {
+    mod search_tests {
         use super::*;
 
+        fn result(id: &str, project_path: &str) -> SearchResult {
+            SearchResult {
+                issue: issue(id, 1, "Result"),
+                project_path: project_path.to_string(),
+                project_name: "project".to_string(),
+                display_path: project_path.to_string(),
+            }
+        }
+
         #[test]
-        fn test_default_is_priority() {
-            assert_eq!(IssueSortField::default(), IssueSortField::Priority);
+        fn test_search_spans_all_projects_without_selected_project() {
+            let mut state = AppState::default();
+            assert!(state.search_spans_all_projects());
+
+            // Toggling has no effect without a project to scope to
+            state.toggle_search_scope();
+            assert!(!state.search_all_projects);
+            assert!(state.search_spans_all_projects());
         }
 
         #[test]
-        fn test_next_cycles_through_all() {
-            let mut field = IssueSortField::Priority;
-            field = field.next();
-            assert_eq!(field, IssueSortField::DisplayNumber);
-            field = field.next();
-            assert_eq!(field, IssueSortField::CreatedAt);
-            field = field.next();
-            assert_eq!(field, IssueSortField::UpdatedAt);
-            field = field.next();
-            assert_eq!(field, IssueSortField::Status);
-            field = field.next();
-            assert_eq!(field, IssueSortField::Priority);
+        fn test_toggle_search_scope_with_selected_project() {
+            let mut state = AppState {
+                selected_project_path: Some("/a".to_string()),
+                ..Default::default()
+            };
+            assert!(!state.search_spans_all_projects());
+
+            state.toggle_search_scope();
+            assert!(state.search_spans_all_projects());
+
+            state.toggle_search_scope();
+            assert!(!state.search_spans_all_projects());
         }
 
         #[test]
-        fn test_label_returns_correct_strings() {
-            assert_eq!(IssueSortField::Priority.label(), "Priority");
-            assert_eq!(IssueSortField::DisplayNumber.label(), "Number");
-            assert_eq!(IssueSortField::CreatedAt.label(), "Created");
-            assert_eq!(IssueSortField::UpdatedAt.label(), "Updated");
-            assert_eq!(IssueSortField::Status.label(), "Status");
+        fn test_set_search_results_resets_selection() {
+            let mut state = AppState {
+                selected_search_index: 4,
+                ..Default::default()
+            };
+            state.set_search_results(
+                vec![result("i1", "/a"), result("i2", "/b")],
+                2,
+                Some("status:open".to_string()),
+            );
+
+            assert_eq!(state.selected_search_index, 0);
+            assert_eq!(state.search_total_count, 2);
+            assert_eq!(state.search_parsed_query.as_deref(), Some("status:open"));
+            assert_eq!(state.selected_search_result().unwrap().issue.id, "i1");
+        }
+
+        #[test]
+        fn test_search_selection_stays_in_bounds() {
+            let mut state = AppState::default();
+            state.set_search_results(vec![result("i1", "/a"), result("i2", "/b")], 2, None);
+
+            state.select_prev_search_result();
+            assert_eq!(state.selected_search_index, 0);
+
+            state.select_next_search_result();
+            state.select_next_search_result();
+            assert_eq!(state.selected_search_index, 1);
+            assert_eq!(state.selected_search_result().unwrap().project_path, "/b");
         }
     }
 
-    mod pr_sort_field_tests {
+    mod jump_tests {
         use super::*;
 
         #[test]
-        fn test_default_is_priority() {
-            assert_eq!(PrSortField::default(), PrSortField::Priority);
+        fn test_parse_display_number() {
+            assert_eq!(
+                JumpQuery::parse("#123"),
+                Some(JumpQuery::DisplayNumber(123))
+            );
+            assert_eq!(JumpQuery::parse(" #7 "), Some(JumpQuery::DisplayNumber(7)));
+            assert_eq!(JumpQuery::parse("#abc"), None);
         }
 
         #[test]
-        fn test_next_cycles_through_all() {
-            let mut field = PrSortField::Priority;
-            field = field.next();
-            assert_eq!(field, PrSortField::DisplayNumber);
-            field = field.next();
-            assert_eq!(field, PrSortField::CreatedAt);
-            field = field.next();
-            assert_eq!(field, PrSortField::UpdatedAt);
-            field = field.next();
-            assert_eq!(field, PrSortField::Status);
-            field = field.next();
-            assert_eq!(field, PrSortField::Priority);
+        fn test_parse_uuid() {
+            assert_eq!(
+                JumpQuery::parse("4A1B2C3D-0000-4000-8000-1234567890AB"),
+                Some(JumpQuery::Uuid(
+                    "4a1b2c3d-0000-4000-8000-1234567890ab".to_string()
+                ))
+            );
         }
 
         #[test]
-        fn test_label_returns_correct_strings() {
-            assert_eq!(PrSortField::Priority.label(), "Priority");
-            assert_eq!(PrSortField::DisplayNumber.label(), "Number");
+        fn test_parse_slug() {
+            assert_eq!(
+                JumpQuery::parse("getting-started"),
+                Some(JumpQuery::Slug("getting-started".to_string()))
+            );
+            // Almost a UUID, but the last group is too short
+            assert_eq!(
+                JumpQuery::parse("4a1b2c3d-0000-4000-8000-1234"),
+                Some(JumpQuery::Slug("4a1b2c3d-0000-4000-8000-1234".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_parse_empty() {
+            assert_eq!(JumpQuery::parse(""), None);
+            assert_eq!(JumpQuery::parse("   "), None);
+        }
+
+        #[test]
+        fn test_jump_match_label() {
+            let doc = JumpMatch {
+                target: JumpTarget::Doc(Doc {
+                    slug: "intro".to_string(),
+                    title: "Introduction".to_string(),
+                    content: String::new(),
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                }),
+                project_path: "/a".to_string(),
+                project_name: "a".to_string(),
+                display_path: "~/a".to_string(),
+            };
+            assert_eq!(doc.label(), "Doc Introduction (intro)");
         }
     }
 
-    mod sort_direction_tests {
+    mod asset_tests {
         use super::*;
 
+        fn asset(filename: &str, size: u64, mime_type: &str) -> Asset {
+            Asset {
+                filename: filename.to_string(),
+                size,
+                mime_type: mime_type.to_string(),
+                is_shared: false,
+                created_at: Utc::now(),
+            }
+        }
+
         #[test]
-        fn test_default_is_asc() {
-            assert_eq!(SortDirection::default(), SortDirection::Asc);
+        fn test_size_label() {
+            assert_eq!(asset("a", 512, "").size_label(), "512 B");
+            assert_eq!(asset("a", 1536, "").size_label(), "1.5 KB");
+            assert_eq!(asset("a", 3 * 1024 * 1024, "").size_label(), "3.0 MB");
         }
 
         #[test]
-        fn test_toggle_asc_to_desc() {
-            let dir = SortDirection::Asc;
-            assert_eq!(dir.toggle(), SortDirection::Desc);
+        fn test_is_text() {
+            assert!(asset("a.md", 1, "text/markdown").is_text());
+            assert!(asset("a.json", 1, "application/json").is_text());
+            assert!(!asset("a.png", 1, "image/png").is_text());
         }
 
         #[test]
-        fn test_toggle_desc_to_asc() {
-            let dir = SortDirection::Desc;
-            assert_eq!(dir.toggle(), SortDirection::Asc);
+        fn test_set_assets_clamps_selection() {
+            let mut state = AppState {
+                selected_asset_index: 5,
+                asset_preview: Some(AssetPreview {
+                    filename: "a".to_string(),
+                    content: String::new(),
+                }),
+                ..Default::default()
+            };
+            state.set_assets(vec![asset("a", 1, ""), asset("b", 1, "")]);
+            assert_eq!(state.selected_asset_index, 1);
+            assert!(state.asset_preview.is_none());
+
+            state.set_assets(Vec::new());
+            assert_eq!(state.selected_asset_index, 0);
+            assert!(state.selected_asset().is_none());
         }
 
         #[test]
-        fn test_symbol_returns_arrows() {
-            assert_eq!(SortDirection::Asc.symbol(), "↑");
-            assert_eq!(SortDirection::Desc.symbol(), "↓");
+        fn test_asset_selection_bounds() {
+            let mut state = AppState::default();
+            state.set_assets(vec![asset("a", 1, ""), asset("b", 1, "")]);
+
+            state.select_prev_asset();
+            assert_eq!(state.selected_asset_index, 0);
+            state.select_next_asset();
+            state.select_next_asset();
+            assert_eq!(state.selected_asset().unwrap().filename, "b");
+        }
+
+        #[test]
+        fn test_issue_detail_tab_toggle() {
+            let mut tab = IssueDetailTab::default();
+            tab.toggle();
+            assert_eq!(tab, IssueDetailTab::Assets);
+            tab.toggle();
+            assert_eq!(tab, IssueDetailTab::Details);
         }
     }
 
-    mod focus_toggle_tests {
+    mod init_wizard_tests {
         use super::*;
 
-        #[test]
-        fn test_issue_detail_focus_toggle() {
-            let mut focus = IssueDetailFocus::Content;
-            focus.toggle();
-            assert_eq!(focus, IssueDetailFocus::ActionPanel);
-            focus.toggle();
-            assert_eq!(focus, IssueDetailFocus::Content);
+        fn file(path: &str) -> PlanFile {
+            PlanFile {
+                path: path.to_string(),
+                is_directory: false,
+                content_preview: String::new(),
+            }
+        }
+
+        fn wizard(needs_decisions: bool) -> InitWizard {
+            InitWizard::new(
+                "/p".to_string(),
+                ReconciliationPlan {
+                    to_create: vec![file("README.md")],
+                    to_restore: vec![file("config.json")],
+                    to_reset: vec![file("templates/issue.md")],
+                    up_to_date: vec![file("issues/")],
+                    user_files: vec![file("notes.md")],
+                    needs_decisions,
+                },
+            )
         }
 
         #[test]
-        fn test_issues_list_focus_toggle() {
-            let mut focus = IssuesListFocus::List;
-            focus.toggle();
-            assert_eq!(focus, IssuesListFocus::ActionPanel);
-            focus.toggle();
-            assert_eq!(focus, IssuesListFocus::List);
+        fn test_new_restores_all_and_resets_none() {
+            let wizard = wizard(true);
+            assert_eq!(wizard.restore, vec!["config.json".to_string()]);
+            assert!(wizard.reset.is_empty());
         }
 
         #[test]
-        fn test_prs_list_focus_toggle() {
-            let mut focus = PrsListFocus::List;
-            focus.toggle();
-            assert_eq!(focus, PrsListFocus::ActionPanel);
+        fn test_rows_order_skips_up_to_date() {
+            let wizard = wizard(true);
+            let rows: Vec<_> = wizard
+                .rows()
+                .into_iter()
+                .map(|(bucket, f)| (bucket, f.path.as_str()))
+                .collect();
+            assert_eq!(
+                rows,
+                vec![
+                    (PlanBucket::Create, "README.md"),
+                    (PlanBucket::Restore, "config.json"),
+                    (PlanBucket::Reset, "templates/issue.md"),
+                    (PlanBucket::UserFile, "notes.md"),
+                ]
+            );
         }
 
         #[test]
-        fn test_pr_detail_focus_toggle() {
-            let mut focus = PrDetailFocus::Content;
-            focus.toggle();
-            assert_eq!(focus, PrDetailFocus::ActionPanel);
+        fn test_toggle_selected_decisions() {
+            let mut wizard = wizard(true);
+
+            // Create rows have no decision
+            wizard.toggle_selected();
+            assert!(wizard.is_chosen(PlanBucket::Create, "README.md"));
+
+            wizard.select_next();
+            wizard.toggle_selected();
+            assert!(!wizard.is_chosen(PlanBucket::Restore, "config.json"));
+
+            wizard.select_next();
+            wizard.toggle_selected();
+            assert!(wizard.is_chosen(PlanBucket::Reset, "templates/issue.md"));
         }
 
         #[test]
-        fn test_docs_list_focus_toggle() {
-            let mut focus = DocsListFocus::List;
-            focus.toggle();
-            assert_eq!(focus, DocsListFocus::ActionPanel);
+        fn test_toggle_ignored_without_needs_decisions() {
+            let mut wizard = wizard(false);
+            wizard.select_next();
+            wizard.toggle_selected();
+            assert!(wizard.is_chosen(PlanBucket::Restore, "config.json"));
         }
 
         #[test]
-        fn test_doc_detail_focus_toggle() {
-            let mut focus = DocDetailFocus::Content;
-            focus.toggle();
-            assert_eq!(focus, DocDetailFocus::ActionPanel);
+        fn test_selection_bounds() {
+            let mut wizard = wizard(true);
+            wizard.select_prev();
+            assert_eq!(wizard.selected_index, 0);
+            for _ in 0..10 {
+                wizard.select_next();
+            }
+            assert_eq!(wizard.selected_index, 3);
+            assert_eq!(wizard.selected_row().unwrap().1.path, "notes.md");
         }
     }
-
-    mod llm_action_tests {
+    mod organization_tests {
         use super::*;
 
-        #[test]
-        fn test_default_is_plan() {
-            assert_eq!(LlmAction::default(), LlmAction::Plan);
+        fn org(slug: &str) -> Organization {
+            Organization {
+                slug: slug.to_string(),
+                name: slug.to_uppercase(),
+                description: format!("{} description", slug),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                project_count: 2,
+            }
         }
 
         #[test]
-        fn test_toggle() {
-            let mut action = LlmAction::Plan;
-            action.toggle();
-            assert_eq!(action, LlmAction::Implement);
-            action.toggle();
-            assert_eq!(action, LlmAction::Plan);
+        fn test_set_organizations_clamps_selection() {
+            let mut state = AppState {
+                selected_organization_index: 4,
+                ..Default::default()
+            };
+            state.set_organizations(vec![org("a"), org("b")]);
+            assert_eq!(state.selected_organization_index, 1);
+            assert_eq!(state.selected_organization().unwrap().slug, "b");
+
+            state.set_organizations(Vec::new());
+            assert_eq!(state.selected_organization_index, 0);
+            assert!(state.selected_organization().is_none());
         }
 
         #[test]
-        fn test_label() {
-            assert_eq!(LlmAction::Plan.label(), "Plan");
-            assert_eq!(LlmAction::Implement.label(), "Implement");
+        fn test_organization_selection_bounds() {
+            let mut state = AppState::default();
+            state.set_organizations(vec![org("a"), org("b")]);
+            state.select_prev_organization();
+            assert_eq!(state.selected_organization_index, 0);
+            state.select_next_organization();
+            state.select_next_organization();
+            assert_eq!(state.selected_organization_index, 1);
         }
 
         #[test]
-        fn test_as_proto_value() {
-            assert_eq!(LlmAction::Plan.as_proto_value(), 1);
-            assert_eq!(LlmAction::Implement.as_proto_value(), 2);
+        fn test_organization_form_fields() {
+            let mut state = AppState {
+                current_view: View::OrganizationEdit,
+                ..Default::default()
+            };
+            state.load_organization_to_form(&org("acme"));
+            assert_eq!(state.form_slug, "acme");
+            assert_eq!(state.form_title, "ACME");
+            assert_eq!(state.form_field_count(), 3);
+
+            state.form_input_char('x', false);
+            assert_eq!(state.form_slug, "acmex");
+            state.active_form_field = 1;
+            state.form_backspace();
+            assert_eq!(state.form_title, "ACM");
+            state.active_form_field = 2;
+            state.form_input_char('!', false);
+            assert_eq!(state.form_description, "acme description!");
         }
     }
-
-    mod action_category_tests {
+    mod user_tests {
         use super::*;
 
+        fn user(id: &str) -> User {
+            User {
+                id: id.to_string(),
+                name: "Jane Doe".to_string(),
+                email: "jane@example.com".to_string(),
+                git_usernames: vec!["jane".to_string(), "jdoe".to_string()],
+                role: None,
+                commit_count: 0,
+                issues_assigned: 0,
+                issues_created: 0,
+                prs_created: 0,
+            }
+        }
+
         #[test]
-        fn test_from_proto() {
-            assert_eq!(ActionCategory::from_proto(1), ActionCategory::Crud);
-            assert_eq!(ActionCategory::from_proto(2), ActionCategory::Mode);
-            assert_eq!(ActionCategory::from_proto(3), ActionCategory::Status);
-            assert_eq!(ActionCategory::from_proto(4), ActionCategory::External);
-            assert_eq!(ActionCategory::from_proto(0), ActionCategory::Unspecified);
-            assert_eq!(ActionCategory::from_proto(99), ActionCategory::Unspecified);
+        fn test_user_form_round_trip() {
+            let mut state = AppState {
+                current_view: View::PersonEdit,
+                ..Default::default()
+            };
+            state.load_user_to_form(&user("jane-doe"));
+            assert_eq!(state.form_git_usernames, "jane, jdoe");
+            assert_eq!(state.form_field_count(), 4);
+
+            state.form_git_usernames.push_str(" janed,,");
+            assert_eq!(
+                state.form_git_username_list(),
+                vec!["jane", "jdoe", "janed"]
+            );
         }
 
         #[test]
-        fn test_label() {
-            assert_eq!(ActionCategory::Unspecified.label(), "Other");
-            assert_eq!(ActionCategory::Crud.label(), "Actions");
-            assert_eq!(ActionCategory::Mode.label(), "Mode");
-            assert_eq!(ActionCategory::Status.label(), "Status");
-            assert_eq!(ActionCategory::External.label(), "External");
+        fn test_cleared_user_fields() {
+            let mut state = AppState::default();
+            let jane = user("jane-doe");
+            state.load_user_to_form(&jane);
+            assert!(state.cleared_user_fields(&jane).is_empty());
+
+            state.form_email = "  ".to_string();
+            state.form_git_usernames = ", ".to_string();
+            assert_eq!(
+                state.cleared_user_fields(&jane),
+                vec!["Email", "Git usernames"]
+            );
         }
-    }
 
-    mod project_tests {
-        use super::*;
+        #[test]
+        fn test_delete_user_confirmation() {
+            let jane = user("jane-doe");
+            let confirm = PendingConfirmAction::delete_user("/p", &jane);
+            assert_eq!(confirm.target, format!("{} (jane-doe)", jane.name));
+            assert!(matches!(
+                confirm.kind,
+                ConfirmKind::DeleteUser { ref project_path, ref user_id }
+                    if project_path == "/p" && user_id == "jane-doe"
+            ));
+        }
 
         #[test]
-        fn test_display_name_prefers_user_title() {
-            let project = Project {
-                path: "/test".to_string(),
-                name: "project".to_string(),
-                project_title: Some("Project Title".to_string()),
-                user_title: Some("My Custom Name".to_string()),
-                is_favorite: false,
-                is_archived: false,
-                initialized: true,
-                issue_count: 0,
-                doc_count: 0,
-                pr_count: 0,
-                organization_slug: None,
-                organization_name: None,
+        fn test_user_id_only_editable_on_create() {
+            let mut state = AppState {
+                current_view: View::PersonEdit,
+                ..Default::default()
             };
-            assert_eq!(project.display_name(), "My Custom Name");
+            state.load_user_to_form(&user("jane-doe"));
+            state.form_input_char('x', false);
+            state.form_backspace();
+            assert_eq!(state.form_slug, "jane-doe");
+
+            state.current_view = View::PersonCreate;
+            state.clear_form();
+            state.form_input_char('j', false);
+            state.active_form_field = 2;
+            state.form_input_char('@', false);
+            assert_eq!(state.form_slug, "j");
+            assert_eq!(state.form_email, "@");
         }
 
         #[test]
-        fn test_display_name_falls_back_to_project_title() {
-            let project = Project {
-                path: "/test".to_string(),
-                name: "project".to_string(),
-                project_title: Some("Project Title".to_string()),
-                user_title: None,
-                is_favorite: false,
-                is_archived: false,
-                initialized: true,
-                issue_count: 0,
-                doc_count: 0,
-                pr_count: 0,
-                organization_slug: None,
-                organization_name: None,
+        fn test_selected_person_follows_sort() {
+            let mut state = AppState {
+                selected_project_path: Some("/p".to_string()),
+                ..Default::default()
             };
-            assert_eq!(project.display_name(), "Project Title");
+            let mut zed = user("zed");
+            zed.name = "Zed".to_string();
+            state
+                .project_users
+                .insert("/p".to_string(), vec![zed, user("jane-doe")]);
+            let first = state.sorted_people()[0].id.clone();
+            assert_eq!(state.selected_person().unwrap().id, first);
         }
 
         #[test]
-        fn test_display_name_falls_back_to_name() {
-            let project = Project {
-                path: "/test".to_string(),
-                name: "project-name".to_string(),
-                project_title: None,
-                user_title: None,
-                is_favorite: false,
-                is_archived: false,
-                initialized: true,
-                issue_count: 0,
-                doc_count: 0,
-                pr_count: 0,
-                organization_slug: None,
-                organization_name: None,
+        fn test_reviewer_picker_lists_team_members() {
+            let mut reviewed = pr("pr-1", 1, "Add login");
+            reviewed.metadata.reviewers = vec!["bo".to_string(), "ex-member".to_string()];
+            let mut state = AppState {
+                current_view: View::PrEdit,
+                selected_project_path: Some("/p".to_string()),
+                selected_pr_id: Some("pr-1".to_string()),
+                prs: vec![reviewed.clone()],
+                ..Default::default()
             };
-            assert_eq!(project.display_name(), "project-name");
+            state
+                .project_users
+                .insert("/p".to_string(), vec![user("ana"), user("bo")]);
+            state.load_pr_to_form(&reviewed);
+            assert_eq!(state.reviewer_candidates(), vec!["ana", "bo", "ex-member"]);
+
+            state.active_form_field = 6;
+            state.form_input_char(' ', false);
+            assert_eq!(state.form_reviewers, vec!["ana", "bo", "ex-member"]);
+            state.cycle_form_field(true);
+            state.form_input_char(' ', false);
+            assert_eq!(state.form_reviewer_cursor, 1);
+            assert_eq!(state.form_reviewers, vec!["ana", "ex-member"]);
+
+            // Unchecking a former member keeps them listed to re-check
+            state.cycle_form_field(true);
+            state.form_input_char(' ', false);
+            assert_eq!(state.form_reviewers, vec!["ana"]);
+            assert_eq!(state.reviewer_candidates(), vec!["ana", "bo", "ex-member"]);
+            assert_eq!(state.form_reviewer_cursor, 2);
+
+            state.clear_form();
+            assert!(state.form_reviewers.is_empty());
+            assert_eq!(state.form_reviewer_cursor, 0);
         }
-    }
 
-    mod issue_tests {
+        #[test]
+        fn test_reviewer_picker_without_team_members() {
+            let mut state = AppState {
+                current_view: View::PrCreate,
+                selected_project_path: Some("/p".to_string()),
+                active_form_field: 6,
+                ..Default::default()
+            };
+            assert!(!state.on_typed_form_field());
+            state.form_input_char(' ', false);
+            assert!(state.form_reviewers.is_empty());
+        }
+
+        #[test]
+        fn test_cleared_pr_fields() {
+            let mut pr = pr("pr-1", 1, "Add login");
+            pr.metadata.reviewers = vec!["ana".to_string()];
+            let mut state = AppState::default();
+            state.load_pr_to_form(&pr);
+            assert!(state.cleared_pr_fields(&pr).is_empty());
+
+            state.form_reviewers.clear();
+            state.form_priority = 0;
+            assert_eq!(state.cleared_pr_fields(&pr), vec!["Reviewers", "Priority"]);
+        }
+    }
+    mod daemon_tests {
         use super::*;
 
-        fn create_test_issue(priority: u32, status: &str) -> Issue {
-            Issue {
-                id: "test-id".to_string(),
-                display_number: 1,
-                title: "Test".to_string(),
-                description: "Desc".to_string(),
-                metadata: IssueMetadata {
-                    status: status.to_string(),
-                    priority,
-                    priority_label: None,
-                    created_at: Utc::now(),
-                    updated_at: Utc::now(),
-                    custom_fields: HashMap::new(),
-                },
-            }
+        #[test]
+        fn test_daemon_control_confirmation() {
+            let confirm = PendingConfirmAction::daemon_control(DaemonControl::Shutdown, 5);
+            assert_eq!(confirm.title, "Shut down the daemon");
+            assert_eq!(confirm.target, "in 5s");
+            assert!(matches!(
+                confirm.kind,
+                ConfirmKind::DaemonControl(DaemonControl::Shutdown)
+            ));
         }
 
         #[test]
-        fn test_priority_color() {
-            assert_eq!(create_test_issue(1, "open").priority_color(), "red");
-            assert_eq!(create_test_issue(2, "open").priority_color(), "yellow");
-            assert_eq!(create_test_issue(3, "open").priority_color(), "green");
+        fn test_format_uptime() {
+            assert_eq!(format_uptime(12), "12s");
+            assert_eq!(format_uptime(250), "4m 10s");
+            assert_eq!(format_uptime(3_900), "1h 05m");
+            assert_eq!(format_uptime(2 * 86_400 + 3 * 3_600 + 59), "2d 03h");
         }
 
         #[test]
-        fn test_priority_label_default() {
-            assert_eq!(create_test_issue(1, "open").priority_label(), "high");
-            assert_eq!(create_test_issue(2, "open").priority_label(), "med");
-            assert_eq!(create_test_issue(3, "open").priority_label(), "low");
+        fn test_delay_is_clamped() {
+            let mut state = AppState::default();
+            state.adjust_daemon_delay(false);
+            assert_eq!(state.daemon_delay_seconds, 0);
+            for _ in 0..20 {
+                state.adjust_daemon_delay(true);
+            }
+            assert_eq!(state.daemon_delay_seconds, MAX_DAEMON_DELAY_SECONDS);
+        }
+
+        #[test]
+        fn test_reconnect_waits_out_delay_then_gives_up() {
+            let now = Instant::now();
+            let mut reconnect = DaemonReconnect::new(5, now);
+            assert_eq!(reconnect.retry_at, now + Duration::from_secs(6));
+
+            assert!(reconnect.retry_later(reconnect.retry_at));
+            assert_eq!(reconnect.attempts, 1);
+            let deadline = reconnect.deadline;
+            assert!(!reconnect.retry_later(deadline));
         }
     }
 
-    mod app_state_tests {
+    mod version_tests {
         use super::*;
 
-        fn create_default_state() -> AppState {
-            AppState::default()
+        fn daemon_info(versions: &[&str]) -> DaemonInfo {
+            DaemonInfo {
+                version: "0.3.0".to_string(),
+                binary_path: String::new(),
+                vscode_available: false,
+                project_count: 0,
+                available_versions: versions.iter().map(|v| v.to_string()).collect(),
+            }
         }
 
         #[test]
-        fn test_default_view_is_projects() {
-            let state = create_default_state();
-            // View::default() is Projects (see View enum with #[default])
-            assert_eq!(state.current_view, View::Projects);
+        fn test_comparison_parse() {
+            assert_eq!(
+                VersionComparison::parse("project_behind"),
+                VersionComparison::ProjectBehind
+            );
+            assert_eq!(
+                VersionComparison::parse("project_ahead"),
+                VersionComparison::ProjectAhead
+            );
+            assert_eq!(VersionComparison::parse("equal"), VersionComparison::Equal);
+            assert_eq!(VersionComparison::parse(""), VersionComparison::Equal);
         }
 
         #[test]
-        fn test_move_selection_down() {
-            let mut state = create_default_state();
-            state.selected_index = 0;
-            state.move_selection_down(5);
-            assert_eq!(state.selected_index, 1);
+        fn test_degraded_mode_message_requires_project() {
+            let mut state = AppState {
+                project_version: Some(ProjectVersion {
+                    project_version: "0.4.0".to_string(),
+                    daemon_version: "0.3.0".to_string(),
+                    comparison: VersionComparison::ProjectAhead,
+                    degraded_mode: true,
+                }),
+                ..Default::default()
+            };
+            assert!(state.degraded_mode_message().is_none());
+
+            state.selected_project_path = Some("/p".to_string());
+            let message = state.degraded_mode_message().unwrap();
+            assert!(message.contains("0.4.0"));
+            assert!(message.contains("0.3.0"));
+
+            state.project_version.as_mut().unwrap().degraded_mode = false;
+            assert!(state.degraded_mode_message().is_none());
         }
 
         #[test]
-        fn test_move_selection_down_at_max() {
-            let mut state = create_default_state();
-            state.selected_index = 4;
-            state.move_selection_down(5);
-            assert_eq!(state.selected_index, 4); // Stays at max
+        fn test_version_selection_bounds() {
+            let mut state = AppState {
+                daemon_info: Some(daemon_info(&["0.1.0", "0.2.0", "0.3.0"])),
+                ..Default::default()
+            };
+            state.select_prev_version();
+            assert_eq!(state.selected_version().unwrap(), "0.1.0");
+            for _ in 0..5 {
+                state.select_next_version();
+            }
+            assert_eq!(state.selected_version().unwrap(), "0.3.0");
         }
 
         #[test]
-        fn test_move_selection_up() {
-            let mut state = create_default_state();
-            state.selected_index = 3;
-            state.move_selection_up();
-            assert_eq!(state.selected_index, 2);
+        fn test_migrate_project_confirmation() {
+            let confirm = PendingConfirmAction::migrate_project("/p", Some("0.1.0"), "0.2.0");
+            assert_eq!(confirm.target, "/p");
+            assert_eq!(confirm.detail, "Migrates .centy from 0.1.0 to 0.2.0.");
+            assert!(matches!(
+                confirm.kind,
+                ConfirmKind::MigrateProject { ref path, ref version }
+                    if path == "/p" && version == "0.2.0"
+            ));
         }
 
         #[test]
-        fn test_move_selection_up_at_zero() {
-            let mut state = create_default_state();
-            state.selected_index = 0;
-            state.move_selection_up();
-            assert_eq!(state.selected_index, 0);
+        fn test_no_versions_without_daemon_info() {
+            let mut state = AppState::default();
+            state.select_next_version();
+            assert!(state.available_versions().is_empty());
+            assert!(state.selected_version().is_none());
         }
+    }
 
-        #[test]
-        fn test_scroll_down() {
-            let mut state = create_default_state();
-            state.scroll_offset = 0;
-            state.scroll_down();
-            assert_eq!(state.scroll_offset, 1);
+    mod feature_tests {
+        use super::*;
+
+        fn state_with_issues() -> AppState {
+            AppState {
+                uncompacted_issues: vec![
+                    issue("a", 1, "First"),
+                    issue("b", 2, "Second"),
+                    issue("c", 3, "Third"),
+                ],
+                ..Default::default()
+            }
         }
 
         #[test]
-        fn test_scroll_up() {
-            let mut state = create_default_state();
-            state.scroll_offset = 5;
-            state.scroll_up();
-            assert_eq!(state.scroll_offset, 4);
+        fn test_toggle_mark_under_cursor() {
+            let mut state = state_with_issues();
+            state.select_next_uncompacted();
+            state.toggle_uncompacted_mark();
+            assert!(state.is_uncompacted_marked("b"));
+            state.toggle_uncompacted_mark();
+            assert!(!state.is_uncompacted_marked("b"));
         }
 
         #[test]
-        fn test_scroll_up_at_zero() {
-            let mut state = create_default_state();
-            state.scroll_offset = 0;
-            state.scroll_up();
-            assert_eq!(state.scroll_offset, 0);
+        fn test_toggle_all_marks() {
+            let mut state = state_with_issues();
+            state.toggle_uncompacted_mark();
+            state.toggle_all_uncompacted_marks();
+            assert_eq!(state.marked_uncompacted_issues().len(), 3);
+            state.toggle_all_uncompacted_marks();
+            assert!(state.marked_uncompacted_issues().is_empty());
         }
 
         #[test]
-        fn test_scroll_down_page() {
-            let mut state = create_default_state();
-            state.scroll_offset = 0;
-            state.scroll_down_page();
-            assert_eq!(state.scroll_offset, 10);
+        fn test_set_issues_drops_stale_marks_and_clamps() {
+            let mut state = state_with_issues();
+            state.toggle_all_uncompacted_marks();
+            state.selected_uncompacted_index = 2;
+            state.set_uncompacted_issues(vec![issue("c", 3, "Third")]);
+            assert_eq!(state.marked_uncompacted_ids, vec!["c".to_string()]);
+            assert_eq!(state.selected_uncompacted_index, 0);
         }
 
         #[test]
-        fn test_scroll_up_page() {
-            let mut state = create_default_state();
-            state.scroll_offset = 15;
-            state.scroll_up_page();
-            assert_eq!(state.scroll_offset, 5);
+        fn test_migration_template_lists_marked_issues() {
+            let mut state = state_with_issues();
+            state.select_next_uncompacted();
+            state.select_next_uncompacted();
+            state.toggle_uncompacted_mark();
+            let template = state.migration_template();
+            assert!(template.starts_with("---\nissues:\n  - c\n---\n"));
+            assert!(template.contains("- #3 Third"));
+            assert!(!template.contains("First"));
         }
+    }
 
-        #[test]
-        fn test_scroll_up_page_clamps_at_zero() {
-            let mut state = create_default_state();
-            state.scroll_offset = 3;
-            state.scroll_up_page();
-            assert_eq!(state.scroll_offset, 0);
+    mod agent_tests {
+        use super::*;
+
+        fn agent(name: &str) -> AgentConfig {
+            AgentConfig {
+                name: name.to_string(),
+                command: name.to_string(),
+                ..Default::default()
+            }
         }
 
         #[test]
-        fn test_form_field_count_for_views() {
-            let mut state = create_default_state();
+        fn test_first_agent_becomes_default() {
+            let mut config = LocalLlmConfig::default();
+            config.upsert_agent(None, agent("claude")).unwrap();
+            config.upsert_agent(None, agent("gemini")).unwrap();
+            assert_eq!(config.default_agent, "claude");
+            assert_eq!(config.agents.len(), 2);
+        }
 
-            state.current_view = View::IssueCreate;
-            assert_eq!(state.form_field_count(), 4);
+        #[test]
+        fn test_upsert_rejects_duplicate_names() {
+            let mut config = LocalLlmConfig::default();
+            config.upsert_agent(None, agent("claude")).unwrap();
+            config.upsert_agent(None, agent("gemini")).unwrap();
+            assert!(config.upsert_agent(None, agent("claude")).is_err());
+            assert!(config
+                .upsert_agent(Some("gemini"), agent("claude"))
+                .is_err());
+            // Saving an agent under its own name is fine
+            assert!(config.upsert_agent(Some("claude"), agent("claude")).is_ok());
+        }
 
-            state.current_view = View::IssueEdit;
-            assert_eq!(state.form_field_count(), 4);
+        #[test]
+        fn test_renaming_default_agent_keeps_it_default() {
+            let mut config = LocalLlmConfig::default();
+            config.upsert_agent(None, agent("claude")).unwrap();
+            config.upsert_agent(Some("claude"), agent("work")).unwrap();
+            assert_eq!(config.default_agent, "work");
+            assert_eq!(config.agents.len(), 1);
+        }
 
-            state.current_view = View::PrCreate;
-            assert_eq!(state.form_field_count(), 5);
+        #[test]
+        fn test_removing_default_agent_picks_next() {
+            let mut config = LocalLlmConfig::default();
+            config.upsert_agent(None, agent("claude")).unwrap();
+            config.upsert_agent(None, agent("gemini")).unwrap();
+            config.remove_agent("claude");
+            assert_eq!(config.default_agent, "gemini");
+            config.remove_agent("gemini");
+            assert_eq!(config.default_agent, "");
+        }
 
-            state.current_view = View::PrEdit;
-            assert_eq!(state.form_field_count(), 6);
+        #[test]
+        fn test_agent_form_round_trip() {
+            let mut state = AppState::default();
+            let original = AgentConfig {
+                agent_type: AgentType::Codex,
+                name: "codex".to_string(),
+                command: "codex".to_string(),
+                default_args: vec!["--quiet".to_string(), "--yes".to_string()],
+                plan_template: "plan".to_string(),
+                implement_template: String::new(),
+            };
+            state.load_agent_to_form(&original);
+            assert_eq!(state.form_agent_args, "--quiet --yes");
+            assert_eq!(state.agent_from_form(), Ok(original));
+        }
 
-            state.current_view = View::DocCreate;
-            assert_eq!(state.form_field_count(), 3);
+        #[test]
+        fn test_agent_form_round_trip_keeps_spaced_args() {
+            let mut state = AppState::default();
+            let original = AgentConfig {
+                agent_type: AgentType::Custom,
+                name: "custom".to_string(),
+                command: "agent".to_string(),
+                default_args: vec![
+                    "--prompt".to_string(),
+                    "fix the bug".to_string(),
+                    "it's".to_string(),
+                ],
+                plan_template: String::new(),
+                implement_template: String::new(),
+            };
+            state.load_agent_to_form(&original);
+            assert_eq!(state.agent_from_form(), Ok(original));
+        }
 
-            state.current_view = View::Projects;
-            assert_eq!(state.form_field_count(), 1);
+        #[test]
+        fn test_agent_form_rejects_unterminated_quote() {
+            let state = AppState {
+                form_agent_args: "--prompt \"fix".to_string(),
+                ..Default::default()
+            };
+            assert!(state.agent_from_form().is_err());
         }
 
         #[test]
-        fn test_next_form_field_cycles() {
-            let mut state = create_default_state();
-            state.current_view = View::IssueCreate;
-            state.active_form_field = 3;
-            state.next_form_field();
-            assert_eq!(state.active_form_field, 0);
+        fn test_agent_type_cycle_wraps() {
+            assert_eq!(AgentType::Claude.cycle(false), AgentType::Custom);
+            assert_eq!(AgentType::Custom.cycle(true), AgentType::Claude);
+            assert_eq!(AgentType::Claude.cycle(true), AgentType::Gemini);
         }
 
         #[test]
-        fn test_prev_form_field_cycles() {
-            let mut state = create_default_state();
-            state.current_view = View::IssueCreate;
-            state.active_form_field = 0;
-            state.prev_form_field();
-            assert_eq!(state.active_form_field, 3);
+        fn test_mask_secret_hides_length() {
+            assert_eq!(mask_secret(""), "");
+            assert_eq!(mask_secret("a"), mask_secret("a-much-longer-secret"));
+        }
+    }
+
+    mod llm_work_tests {
+        use super::*;
+
+        fn session(project: &str, issue_id: &str) -> LlmWorkSession {
+            LlmWorkSession {
+                project_path: project.to_string(),
+                issue_id: issue_id.to_string(),
+                display_number: 1,
+                issue_title: "Issue".to_string(),
+                agent_name: "claude".to_string(),
+                action: LlmAction::Plan,
+                started_at: Utc::now(),
+                pid: 0,
+                pid_alive: false,
+            }
         }
 
         #[test]
-        fn test_form_input_char_appends_to_title() {
-            let mut state = create_default_state();
-            state.current_view = View::IssueCreate;
-            state.active_form_field = 0;
-            state.form_input_char('a', false);
-            state.form_input_char('b', false);
-            assert_eq!(state.form_title, "ab");
+        fn test_spawn_agent_picker_starts_with_default() {
+            let mut spawn = PendingSpawnAction::new(
+                "/p",
+                "12",
+                vec!["claude".to_string(), "gemini".to_string()],
+            );
+            assert_eq!(spawn.agent_name(), "");
+            spawn.cycle_agent(true);
+            assert_eq!(spawn.agent_name(), "claude");
+            spawn.cycle_agent(false);
+            spawn.cycle_agent(false);
+            assert_eq!(spawn.agent_name(), "gemini");
         }
 
         #[test]
-        fn test_form_input_char_with_shift() {
-            let mut state = create_default_state();
-            state.current_view = View::IssueCreate;
-            state.active_form_field = 0;
-            state.form_input_char('a', true);
-            assert_eq!(state.form_title, "A");
+        fn test_spawn_focus_skips_to_args_when_issue_known() {
+            assert_eq!(PendingSpawnAction::new("/p", "", vec![]).field, 0);
+            let mut spawn = PendingSpawnAction::new("/p", "12", vec![]);
+            assert_eq!(spawn.field, 3);
+            spawn.input_mut().unwrap().push_str("--verbose  --model x");
+            assert_eq!(spawn.extra_args(), vec!["--verbose", "--model", "x"]);
+            spawn.field = 1;
+            assert!(spawn.input_mut().is_none());
         }
 
         #[test]
-        fn test_form_backspace() {
-            let mut state = create_default_state();
-            state.current_view = View::IssueCreate;
-            state.active_form_field = 0;
-            state.form_title = "abc".to_string();
-            state.form_backspace();
-            assert_eq!(state.form_title, "ab");
+        fn test_session_lookup_and_selection_clamp() {
+            let mut state = AppState::default();
+            state.set_llm_work(vec![session("/a", "i1"), session("/b", "i2")]);
+            state.select_next_llm_work();
+            assert_eq!(state.selected_llm_work().unwrap().issue_id, "i2");
+            assert!(state.llm_session_for_issue("i1").is_some());
+
+            state.set_llm_work(vec![session("/a", "i1")]);
+            assert_eq!(state.selected_llm_work_index, 0);
+            assert!(state.llm_session_for_issue("i2").is_none());
         }
 
         #[test]
-        fn test_clear_form() {
-            let mut state = create_default_state();
-            state.form_title = "Title".to_string();
-            state.form_description = "Desc".to_string();
-            state.form_priority = 2;
-            state.active_form_field = 2;
+        fn test_llm_action_proto_round_trip() {
+            for action in [LlmAction::Plan, LlmAction::Implement] {
+                assert_eq!(LlmAction::from_proto_value(action.as_proto_value()), action);
+            }
+        }
+    }
 
-            state.clear_form();
+    mod confirm_tests {
+        use super::*;
 
-            assert_eq!(state.form_title, "");
-            assert_eq!(state.form_description, "");
-            assert_eq!(state.form_priority, 0);
-            assert_eq!(state.active_form_field, 0);
+        fn doc(slug: &str, title: &str) -> Doc {
+            Doc {
+                slug: slug.to_string(),
+                title: title.to_string(),
+                content: String::new(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }
         }
 
         #[test]
-        fn test_action_panel_up() {
-            let mut state = create_default_state();
-            state.action_panel_selected_index = 3;
-            state.action_panel_up();
-            assert_eq!(state.action_panel_selected_index, 2);
+        fn test_action_target_follows_list_selection() {
+            let mut state = AppState {
+                current_view: View::Issues,
+                issues: vec![issue("b", 2, "Second"), issue("a", 1, "First")],
+                issue_sort_field: IssueSortField::DisplayNumber,
+                selected_index: 1,
+                ..Default::default()
+            };
+            assert_eq!(
+                state.action_target(),
+                Some(("Issue", "#2 Second".to_string()))
+            );
+
+            state.selected_index = 5;
+            assert!(state.action_target().is_none());
         }
 
         #[test]
-        fn test_action_panel_up_at_zero() {
-            let mut state = create_default_state();
-            state.action_panel_selected_index = 0;
-            state.action_panel_up();
-            assert_eq!(state.action_panel_selected_index, 0);
+        fn test_action_target_in_detail_uses_open_entity() {
+            let state = AppState {
+                current_view: View::IssueDetail,
+                issues: vec![issue("a", 1, "First"), issue("b", 2, "Second")],
+                selected_issue_id: Some("b".to_string()),
+                selected_index: 0,
+                ..Default::default()
+            };
+            assert_eq!(state.action_issue().map(|i| i.id.as_str()), Some("b"));
         }
 
         #[test]
-        fn test_action_panel_down() {
-            let mut state = create_default_state();
-            state.current_actions.actions = vec![
-                EntityAction {
-                    id: "1".to_string(),
-                    label: "One".to_string(),
-                    category: ActionCategory::Crud,
-                    enabled: true,
-                    disabled_reason: String::new(),
-                    destructive: false,
-                    keyboard_shortcut: String::new(),
-                },
-                EntityAction {
-                    id: "2".to_string(),
-                    label: "Two".to_string(),
-                    category: ActionCategory::Crud,
-                    enabled: true,
-                    disabled_reason: String::new(),
-                    destructive: false,
-                    keyboard_shortcut: String::new(),
-                },
-            ];
-            state.action_panel_selected_index = 0;
-            state.action_panel_down();
-            assert_eq!(state.action_panel_selected_index, 1);
+        fn test_action_target_for_docs() {
+            let state = AppState {
+                current_view: View::DocDetail,
+                docs: vec![doc("guide", "Guide"), doc("faq", "FAQ")],
+                selected_doc_slug: Some("faq".to_string()),
+                ..Default::default()
+            };
+            assert_eq!(
+                state.action_target(),
+                Some(("Doc", "FAQ (faq)".to_string()))
+            );
+            assert!(AppState::default().action_target().is_none());
         }
+    }
 
-        #[test]
-        fn test_action_panel_down_at_max() {
-            let mut state = create_default_state();
-            state.current_actions.actions = vec![EntityAction {
-                id: "1".to_string(),
-                label: "One".to_string(),
-                category: ActionCategory::Crud,
-                enabled: true,
-                disabled_reason: String::new(),
-                destructive: false,
-                keyboard_shortcut: String::new(),
-            }];
-            state.action_panel_selected_index = 0;
-            state.action_panel_down();
-            assert_eq!(state.action_panel_selected_index, 0);
+    mod move_tests {
+        use super::*;
+
+        fn action(mode: MoveMode, entity_type: MoveEntityType) -> PendingMoveAction {
+            PendingMoveAction::new(
+                mode,
+                entity_type,
+                "/source".to_string(),
+                "id".to_string(),
+                "#1 Issue".to_string(),
+            )
         }
 
         #[test]
-        fn test_error_queue_push() {
-            let mut state = create_default_state();
-            assert!(!state.has_errors());
-            state.push_error("Error 1".to_string());
-            assert!(state.has_errors());
+        fn test_move_excludes_source_project() {
+            let action = action(MoveMode::Move, MoveEntityType::Issue);
+            assert!(!action.accepts_target(&project("/source")));
+            assert!(action.accepts_target(&project("/other")));
+            assert!(!action.accepts_target(&Project {
+                initialized: false,
+                ..project("/new")
+            }));
+            assert!(!action.accepts_target(&Project {
+                is_archived: true,
+                ..project("/old")
+            }));
         }
 
         #[test]
-        fn test_error_queue_current() {
-            let mut state = create_default_state();
-            state.push_error("Error 1".to_string());
-            state.push_error("Error 2".to_string());
-            assert_eq!(state.current_error(), Some(&"Error 1".to_string()));
+        fn test_duplicate_allows_source_project() {
+            let action = action(MoveMode::Duplicate, MoveEntityType::Issue);
+            assert!(action.accepts_target(&project("/source")));
+            assert!(!action.accepts_target(&Project {
+                initialized: false,
+                ..project("/new")
+            }));
         }
 
         #[test]
-        fn test_error_queue_dismiss() {
-            let mut state = create_default_state();
-            state.push_error("Error 1".to_string());
-            state.push_error("Error 2".to_string());
-            state.dismiss_error();
-            assert_eq!(state.current_error(), Some(&"Error 2".to_string()));
+        fn test_duplicate_inputs() {
+            let mut issue = action(MoveMode::Duplicate, MoveEntityType::Issue);
+            assert_eq!(issue.duplicate_field_count(), 1);
+            issue.active_input_mut().push_str("Copy");
+            assert_eq!(issue.new_title, "Copy");
+
+            let mut doc = action(MoveMode::Duplicate, MoveEntityType::Doc);
+            assert_eq!(doc.duplicate_field_count(), 2);
+            doc.active_field = 1;
+            doc.active_input_mut().push_str("guide-copy");
+            assert_eq!(doc.new_slug, "guide-copy");
+            assert!(doc.new_title.is_empty());
+        }
+    }
+
+    mod workspace_tests {
+        use super::*;
+
+        fn workspace(path: &str, expires_at: &str) -> TempWorkspace {
+            TempWorkspace {
+                workspace_path: path.to_string(),
+                source_project_path: "/project".to_string(),
+                issue_id: "i1".to_string(),
+                issue_display_number: 1,
+                issue_title: "Issue".to_string(),
+                agent_name: "claude".to_string(),
+                action: 1,
+                created_at: String::new(),
+                expires_at: expires_at.to_string(),
+            }
         }
 
         #[test]
-        fn test_is_action_panel_focused() {
-            let mut state = create_default_state();
+        fn test_expiry_countdown() {
+            let now = DateTime::parse_from_rfc3339("2025-01-01T10:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc);
+            assert_eq!(expiry_countdown("2025-01-01T13:05:00Z", now), "3h 05m left");
+            assert_eq!(expiry_countdown("2025-01-01T10:45:00Z", now), "45m left");
+            assert_eq!(expiry_countdown("2025-01-01T09:00:00Z", now), "expired");
+            assert_eq!(expiry_countdown("", now), "no expiry");
+
+            assert!(workspace_expired(
+                &workspace("/w", "2025-01-01T09:00:00Z"),
+                now
+            ));
+            assert!(!workspace_expired(
+                &workspace("/w", "2025-01-01T11:00:00Z"),
+                now
+            ));
+            assert!(!workspace_expired(&workspace("/w", ""), now));
+        }
 
-            state.current_view = View::Issues;
-            state.issues_list_focus = IssuesListFocus::List;
-            assert!(!state.is_action_panel_focused());
+        #[test]
+        fn test_extend_ttl_bounds() {
+            let mut confirm = WorkspaceConfirm::Extend { ttl_hours: 1 };
+            confirm.adjust_ttl(false);
+            assert_eq!(confirm, WorkspaceConfirm::Extend { ttl_hours: 1 });
+            confirm.adjust_ttl(true);
+            assert_eq!(confirm, WorkspaceConfirm::Extend { ttl_hours: 2 });
+
+            let mut close = WorkspaceConfirm::Close { force: false };
+            close.adjust_ttl(true);
+            assert_eq!(close, WorkspaceConfirm::Close { force: false });
+        }
 
-            state.issues_list_focus = IssuesListFocus::ActionPanel;
-            assert!(state.is_action_panel_focused());
+        #[test]
+        fn test_workspace_selection_clamp() {
+            let mut state = AppState::default();
+            state.set_workspaces(vec![workspace("/a", ""), workspace("/b", "")]);
+            state.select_next_workspace();
+            state.select_next_workspace();
+            assert_eq!(state.selected_workspace().unwrap().workspace_path, "/b");
+
+            state.set_workspaces(vec![workspace("/a", "")]);
+            assert_eq!(state.selected_workspace_index, 0);
         }
     }
 }
@@ -0,0 +1,543 @@
+//! Project config editor (a draft of the project config, saved via UpdateConfig)
+
+use super::{Config, CustomFieldDefinition, LinkTypeInfo};
+
+/// Colors offered for states, cycled with ←/→
+pub const STATE_COLORS: [&str; 8] = [
+    "#10b981", "#3b82f6", "#f59e0b", "#ef4444", "#8b5cf6", "#ec4899", "#14b8a6", "#6b7280",
+];
+
+/// Types a custom field can have
+pub const CUSTOM_FIELD_TYPES: [&str; 4] = ["string", "number", "boolean", "enum"];
+
+pub const MAX_PRIORITY_LEVELS: u32 = 10;
+
+/// Section of the config editor, switched with Tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigSection {
+    /// Priority levels and default state
+    #[default]
+    General,
+    States,
+    CustomFields,
+    LinkTypes,
+    Llm,
+}
+
+impl ConfigSection {
+    pub const ALL: [ConfigSection; 5] = [
+        ConfigSection::General,
+        ConfigSection::States,
+        ConfigSection::CustomFields,
+        ConfigSection::LinkTypes,
+        ConfigSection::Llm,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfigSection::General => "General",
+            ConfigSection::States => "States",
+            ConfigSection::CustomFields => "Custom Fields",
+            ConfigSection::LinkTypes => "Link Types",
+            ConfigSection::Llm => "LLM",
+        }
+    }
+
+    pub fn cycle(self, forward: bool) -> Self {
+        let idx = Self::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        let len = Self::ALL.len();
+        Self::ALL[if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        }]
+    }
+}
+
+/// Value being typed in the editor's input line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigInputTarget {
+    NewState,
+    StateName(usize),
+    NewField,
+    FieldDefault(usize),
+    /// Comma separated enum values
+    FieldEnumValues(usize),
+    NewLinkType,
+    LinkInverse(usize),
+    LinkDescription(usize),
+}
+
+impl ConfigInputTarget {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfigInputTarget::NewState => "New state",
+            ConfigInputTarget::StateName(_) => "Rename state",
+            ConfigInputTarget::NewField => "New field name",
+            ConfigInputTarget::FieldDefault(_) => "Default value",
+            ConfigInputTarget::FieldEnumValues(_) => "Enum values (comma separated)",
+            ConfigInputTarget::NewLinkType => "New link type",
+            ConfigInputTarget::LinkInverse(_) => "Inverse link type",
+            ConfigInputTarget::LinkDescription(_) => "Description",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigInput {
+    pub target: ConfigInputTarget,
+    pub value: String,
+}
+
+/// Editable copy of the project config
+#[derive(Debug, Clone)]
+pub struct ConfigEditor {
+    pub draft: Config,
+    pub section: ConfigSection,
+    pub selected: usize,
+    pub input: Option<ConfigInput>,
+    /// Draft differs from the saved config
+    pub dirty: bool,
+    /// Esc was pressed with unsaved changes, waiting for y/n
+    pub confirm_discard: bool,
+}
+
+impl ConfigEditor {
+    pub fn new(config: Config) -> Self {
+        Self {
+            draft: config,
+            section: ConfigSection::default(),
+            selected: 0,
+            input: None,
+            dirty: false,
+            confirm_discard: false,
+        }
+    }
+
+    /// Number of selectable rows in the current section
+    pub fn item_count(&self) -> usize {
+        match self.section {
+            ConfigSection::General => 2,
+            ConfigSection::States => self.draft.allowed_states.len(),
+            ConfigSection::CustomFields => self.draft.custom_fields.len(),
+            ConfigSection::LinkTypes => self.draft.custom_link_types.len(),
+            ConfigSection::Llm => 3,
+        }
+    }
+
+    pub fn cycle_section(&mut self, forward: bool) {
+        self.section = self.section.cycle(forward);
+        self.selected = 0;
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.item_count() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Move the selected state, field or link type up or down
+    pub fn move_selected(&mut self, up: bool) {
+        let idx = self.selected;
+        let target = if up {
+            idx.checked_sub(1)
+        } else {
+            Some(idx + 1).filter(|t| *t < self.item_count())
+        };
+        let Some(target) = target else {
+            return;
+        };
+        match self.section {
+            ConfigSection::States => self.draft.allowed_states.swap(idx, target),
+            ConfigSection::CustomFields => self.draft.custom_fields.swap(idx, target),
+            ConfigSection::LinkTypes => self.draft.custom_link_types.swap(idx, target),
+            ConfigSection::General | ConfigSection::Llm => return,
+        }
+        self.selected = target;
+        self.dirty = true;
+    }
+
+    /// Remove the selected state, field or link type. The last state can't be removed.
+    pub fn remove_selected(&mut self) -> Result<(), String> {
+        let idx = self.selected;
+        match self.section {
+            ConfigSection::States => {
+                if self.draft.allowed_states.len() <= 1 {
+                    return Err("At least one state is required".to_string());
+                }
+                let removed = self.draft.allowed_states.remove(idx);
+                self.draft.state_colors.remove(&removed);
+                if self.draft.default_state == removed {
+                    self.draft.default_state = self.draft.allowed_states[0].clone();
+                }
+            }
+            ConfigSection::CustomFields if idx < self.draft.custom_fields.len() => {
+                self.draft.custom_fields.remove(idx);
+            }
+            ConfigSection::LinkTypes if idx < self.draft.custom_link_types.len() => {
+                self.draft.custom_link_types.remove(idx);
+            }
+            _ => return Ok(()),
+        }
+        self.selected = self.selected.min(self.item_count().saturating_sub(1));
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// ←/→ on the selected row: priority levels, default state, state color or field type
+    pub fn adjust(&mut self, forward: bool) {
+        let idx = self.selected;
+        match (self.section, idx) {
+            (ConfigSection::General, 0) => {
+                let levels = self.draft.priority_levels;
+                self.draft.priority_levels = if forward {
+                    (levels + 1).min(MAX_PRIORITY_LEVELS)
+                } else {
+                    levels.saturating_sub(1).max(1)
+                };
+            }
+            (ConfigSection::General, _) => {
+                let states = &self.draft.allowed_states;
+                if states.is_empty() {
+                    return;
+                }
+                let current = states
+                    .iter()
+                    .position(|s| *s == self.draft.default_state)
+                    .unwrap_or(0);
+                self.draft.default_state =
+                    states[cycle_index(current, states.len(), forward)].clone();
+            }
+            (ConfigSection::States, _) => {
+                let Some(state) = self.draft.allowed_states.get(idx).cloned() else {
+                    return;
+                };
+                let current = self
+                    .draft
+                    .state_colors
+                    .get(&state)
+                    .and_then(|c| STATE_COLORS.iter().position(|p| p.eq_ignore_ascii_case(c)));
+                let next = match current {
+                    Some(pos) => cycle_index(pos, STATE_COLORS.len(), forward),
+                    None if forward => 0,
+                    None => STATE_COLORS.len() - 1,
+                };
+                self.draft
+                    .state_colors
+                    .insert(state, STATE_COLORS[next].to_string());
+            }
+            (ConfigSection::CustomFields, _) => {
+                let Some(field) = self.draft.custom_fields.get_mut(idx) else {
+                    return;
+                };
+                let current = CUSTOM_FIELD_TYPES
+                    .iter()
+                    .position(|t| *t == field.field_type)
+                    .unwrap_or(0);
+                field.field_type = CUSTOM_FIELD_TYPES
+                    [cycle_index(current, CUSTOM_FIELD_TYPES.len(), forward)]
+                .to_string();
+            }
+            (ConfigSection::LinkTypes | ConfigSection::Llm, _) => return,
+        }
+        self.dirty = true;
+    }
+
+    /// Space on the selected row: default state, required flag or LLM flag
+    pub fn toggle(&mut self) {
+        let idx = self.selected;
+        match self.section {
+            ConfigSection::States => {
+                let Some(state) = self.draft.allowed_states.get(idx) else {
+                    return;
+                };
+                self.draft.default_state = state.clone();
+            }
+            ConfigSection::CustomFields => {
+                let Some(field) = self.draft.custom_fields.get_mut(idx) else {
+                    return;
+                };
+                field.required = !field.required;
+            }
+            ConfigSection::Llm => {
+                let llm = &mut self.draft.llm;
+                let flag = match idx {
+                    0 => &mut llm.auto_close_on_complete,
+                    1 => &mut llm.update_status_on_start,
+                    _ => &mut llm.allow_direct_edits,
+                };
+                *flag = !*flag;
+            }
+            ConfigSection::General | ConfigSection::LinkTypes => return,
+        }
+        self.dirty = true;
+    }
+
+    /// Start typing, prefilled with the current value of what is being edited
+    pub fn begin_input(&mut self, target: ConfigInputTarget) {
+        let value = match target {
+            ConfigInputTarget::StateName(idx) => self
+                .draft
+                .allowed_states
+                .get(idx)
+                .cloned()
+                .unwrap_or_default(),
+            ConfigInputTarget::FieldDefault(idx) => self
+                .draft
+                .custom_fields
+                .get(idx)
+                .map(|f| f.default_value.clone())
+                .unwrap_or_default(),
+            ConfigInputTarget::FieldEnumValues(idx) => self
+                .draft
+                .custom_fields
+                .get(idx)
+                .map(|f| f.enum_values.join(", "))
+                .unwrap_or_default(),
+            ConfigInputTarget::LinkInverse(idx) => self
+                .draft
+                .custom_link_types
+                .get(idx)
+                .map(|t| t.inverse.clone())
+                .unwrap_or_default(),
+            ConfigInputTarget::LinkDescription(idx) => self
+                .draft
+                .custom_link_types
+                .get(idx)
+                .map(|t| t.description.clone())
+                .unwrap_or_default(),
+            ConfigInputTarget::NewState
+            | ConfigInputTarget::NewField
+            | ConfigInputTarget::NewLinkType => String::new(),
+        };
+        self.input = Some(ConfigInput { target, value });
+    }
+
+    /// Apply the typed value. A new link type continues with its inverse.
+    pub fn commit_input(&mut self) -> Result<(), String> {
+        let Some(input) = self.input.take() else {
+            return Ok(());
+        };
+        let value = input.value.trim().to_string();
+        let result = self.apply_input(input.target, value);
+        if result.is_err() {
+            self.input = Some(input);
+        }
+        result
+    }
+
+    fn apply_input(&mut self, target: ConfigInputTarget, value: String) -> Result<(), String> {
+        let is_name = matches!(
+            target,
+            ConfigInputTarget::NewState
+                | ConfigInputTarget::StateName(_)
+                | ConfigInputTarget::NewField
+                | ConfigInputTarget::NewLinkType
+        );
+        if is_name && value.is_empty() {
+            return Err("Name can't be empty".to_string());
+        }
+
+        match target {
+            ConfigInputTarget::NewState => {
+                if self.draft.allowed_states.contains(&value) {
+                    return Err(format!("State '{}' already exists", value));
+                }
+                self.draft.allowed_states.push(value);
+                self.selected = self.draft.allowed_states.len() - 1;
+            }
+            ConfigInputTarget::StateName(idx) => {
+                let old = self.draft.allowed_states[idx].clone();
+                if old != value && self.draft.allowed_states.contains(&value) {
+                    return Err(format!("State '{}' already exists", value));
+                }
+                if let Some(color) = self.draft.state_colors.remove(&old) {
+                    self.draft.state_colors.insert(value.clone(), color);
+                }
+                if self.draft.default_state == old {
+                    self.draft.default_state = value.clone();
+                }
+                self.draft.allowed_states[idx] = value;
+            }
+            ConfigInputTarget::NewField => {
+                if self.draft.custom_fields.iter().any(|f| f.name == value) {
+                    return Err(format!("Field '{}' already exists", value));
+                }
+                self.draft.custom_fields.push(CustomFieldDefinition {
+                    name: value,
+                    field_type: CUSTOM_FIELD_TYPES[0].to_string(),
+                    ..Default::default()
+                });
+                self.selected = self.draft.custom_fields.len() - 1;
+            }
+            ConfigInputTarget::FieldDefault(idx) => {
+                self.draft.custom_fields[idx].default_value = value;
+            }
+            ConfigInputTarget::FieldEnumValues(idx) => {
+                self.draft.custom_fields[idx].enum_values = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|v| !v.is_empty())
+                    .map(str::to_string)
+                    .collect();
+            }
+            ConfigInputTarget::NewLinkType => {
+                if self.draft.custom_link_types.iter().any(|t| t.name == value) {
+                    return Err(format!("Link type '{}' already exists", value));
+                }
+                self.draft.custom_link_types.push(LinkTypeInfo {
+                    name: value,
+                    inverse: String::new(),
+                    description: String::new(),
+                    is_builtin: false,
+                });
+                self.selected = self.draft.custom_link_types.len() - 1;
+                self.begin_input(ConfigInputTarget::LinkInverse(self.selected));
+            }
+            ConfigInputTarget::LinkInverse(idx) => {
+                self.draft.custom_link_types[idx].inverse = value;
+            }
+            ConfigInputTarget::LinkDescription(idx) => {
+                self.draft.custom_link_types[idx].description = value;
+            }
+        }
+        self.dirty = true;
+        Ok(())
+    }
+}
+
+fn cycle_index(current: usize, len: usize, forward: bool) -> usize {
+    if forward {
+        (current + 1) % len
+    } else {
+        (current + len - 1) % len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor() -> ConfigEditor {
+        ConfigEditor::new(Config {
+            priority_levels: 3,
+            allowed_states: vec![
+                "open".to_string(),
+                "in-progress".to_string(),
+                "closed".to_string(),
+            ],
+            default_state: "open".to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn type_input(editor: &mut ConfigEditor, target: ConfigInputTarget, value: &str) {
+        editor.begin_input(target);
+        editor.input.as_mut().unwrap().value = value.to_string();
+    }
+
+    #[test]
+    fn test_rename_state_carries_default_and_color() {
+        let mut editor = editor();
+        editor.section = ConfigSection::States;
+        editor.adjust(true);
+        type_input(&mut editor, ConfigInputTarget::StateName(0), "todo");
+        editor.commit_input().unwrap();
+
+        assert_eq!(editor.draft.allowed_states[0], "todo");
+        assert_eq!(editor.draft.default_state, "todo");
+        assert_eq!(editor.draft.state_colors["todo"], STATE_COLORS[0]);
+        assert!(editor.dirty);
+    }
+
+    #[test]
+    fn test_duplicate_state_keeps_input_open() {
+        let mut editor = editor();
+        type_input(&mut editor, ConfigInputTarget::NewState, "closed");
+        assert!(editor.commit_input().is_err());
+        assert!(editor.input.is_some());
+        assert_eq!(editor.draft.allowed_states.len(), 3);
+    }
+
+    #[test]
+    fn test_reorder_and_remove_states() {
+        let mut editor = editor();
+        editor.section = ConfigSection::States;
+        editor.move_selected(true);
+        assert_eq!(editor.selected, 0);
+        editor.move_selected(false);
+        assert_eq!(editor.draft.allowed_states[1], "open");
+        assert_eq!(editor.selected, 1);
+
+        editor.remove_selected().unwrap();
+        assert_eq!(editor.draft.allowed_states, vec!["in-progress", "closed"]);
+        assert_eq!(editor.draft.default_state, "in-progress");
+
+        editor.remove_selected().unwrap();
+        assert!(editor.remove_selected().is_err());
+    }
+
+    #[test]
+    fn test_priority_levels_and_default_state_bounds() {
+        let mut editor = editor();
+        for _ in 0..5 {
+            editor.adjust(false);
+        }
+        assert_eq!(editor.draft.priority_levels, 1);
+
+        editor.select_next();
+        editor.adjust(false);
+        assert_eq!(editor.draft.default_state, "closed");
+    }
+
+    #[test]
+    fn test_custom_field_type_required_and_enum_values() {
+        let mut editor = editor();
+        editor.section = ConfigSection::CustomFields;
+        type_input(&mut editor, ConfigInputTarget::NewField, "team");
+        editor.commit_input().unwrap();
+        editor.adjust(false);
+        editor.toggle();
+        type_input(
+            &mut editor,
+            ConfigInputTarget::FieldEnumValues(0),
+            "web, api,,",
+        );
+        editor.commit_input().unwrap();
+
+        let field = &editor.draft.custom_fields[0];
+        assert_eq!(field.field_type, "enum");
+        assert!(field.required);
+        assert_eq!(field.enum_values, vec!["web", "api"]);
+    }
+
+    #[test]
+    fn test_new_link_type_asks_for_inverse() {
+        let mut editor = editor();
+        editor.section = ConfigSection::LinkTypes;
+        type_input(&mut editor, ConfigInputTarget::NewLinkType, "depends-on");
+        editor.commit_input().unwrap();
+        assert_eq!(
+            editor.input.as_ref().map(|i| i.target),
+            Some(ConfigInputTarget::LinkInverse(0))
+        );
+        editor.input.as_mut().unwrap().value = "dependency-of".to_string();
+        editor.commit_input().unwrap();
+        assert_eq!(editor.draft.custom_link_types[0].inverse, "dependency-of");
+    }
+
+    #[test]
+    fn test_llm_flags_toggle() {
+        let mut editor = editor();
+        editor.cycle_section(false);
+        assert_eq!(editor.section, ConfigSection::Llm);
+        editor.select_next();
+        editor.toggle();
+        assert!(editor.draft.llm.update_status_on_start);
+        assert!(!editor.draft.llm.auto_close_on_complete);
+    }
+}
@@ -1,10 +1,30 @@
 //! Form field value objects
 
+use std::collections::BTreeSet;
+
+/// Priority levels assumed when the project config doesn't set any
+pub const DEFAULT_PRIORITY_LEVELS: u32 = 3;
+
 /// Type-safe field values
 #[derive(Debug, Clone)]
 pub enum FieldValue {
     Text(String),
+    /// 0 means the project default, otherwise 1 (highest) to the field's level count
     Priority(u32),
+    /// Kept as typed (digits, '.' and '-') and parsed on save
+    Number(String),
+    Boolean(bool),
+    /// One of `options`; an empty option stands for "unset"
+    Select {
+        options: Vec<String>,
+        selected: usize,
+    },
+    /// Any subset of `options`; `cursor` is the highlighted option
+    MultiSelect {
+        options: Vec<String>,
+        selected: BTreeSet<usize>,
+        cursor: usize,
+    },
 }
 
 impl Default for FieldValue {
@@ -20,96 +40,271 @@ pub struct FormField {
     pub label: String,
     pub value: FieldValue,
     pub is_multiline: bool,
+    /// Levels a priority field cycles through (the project's `priority_levels`)
+    pub priority_levels: u32,
+    /// Priority of the loaded item and the daemon's label for it
+    pub priority_name: Option<(u32, String)>,
+}
+
+/// Fallback label for a priority the daemon sent no label for: high/medium/low on scales
+/// of up to three levels, "P<n>" on longer ones
+pub fn priority_label(priority: u32, levels: u32) -> String {
+    if levels > DEFAULT_PRIORITY_LEVELS {
+        return format!("P{}", priority);
+    }
+    match priority {
+        1 => "high",
+        2 if levels == 3 => "medium",
+        _ => "low",
+    }
+    .to_string()
 }
 
 impl FormField {
-    /// Create a new text field
-    pub fn text(name: &str, label: &str, is_multiline: bool) -> Self {
+    fn with_value(name: &str, label: &str, value: FieldValue) -> Self {
         Self {
             name: name.to_string(),
             label: label.to_string(),
-            value: FieldValue::Text(String::new()),
-            is_multiline,
+            value,
+            is_multiline: false,
+            priority_levels: DEFAULT_PRIORITY_LEVELS,
+            priority_name: None,
         }
     }
 
+    /// Create a new text field
+    pub fn text(name: &str, label: &str, is_multiline: bool) -> Self {
+        Self::text_with_value(name, label, String::new(), is_multiline)
+    }
+
     /// Create a new text field with initial value
     pub fn text_with_value(name: &str, label: &str, value: String, is_multiline: bool) -> Self {
         Self {
-            name: name.to_string(),
-            label: label.to_string(),
-            value: FieldValue::Text(value),
             is_multiline,
+            ..Self::with_value(name, label, FieldValue::Text(value))
         }
     }
 
     /// Create a new priority field
     pub fn priority(name: &str, label: &str) -> Self {
-        Self {
-            name: name.to_string(),
-            label: label.to_string(),
-            value: FieldValue::Priority(0),
-            is_multiline: false,
-        }
+        Self::priority_with_value(name, label, 0)
     }
 
     /// Create a new priority field with initial value
     pub fn priority_with_value(name: &str, label: &str, value: u32) -> Self {
-        Self {
-            name: name.to_string(),
-            label: label.to_string(),
-            value: FieldValue::Priority(value),
-            is_multiline: false,
+        Self::with_value(name, label, FieldValue::Priority(value))
+    }
+
+    /// Create a new number field
+    pub fn number(name: &str, label: &str, value: String) -> Self {
+        Self::with_value(name, label, FieldValue::Number(value))
+    }
+
+    /// Create a new boolean toggle
+    pub fn boolean(name: &str, label: &str, checked: bool) -> Self {
+        Self::with_value(name, label, FieldValue::Boolean(checked))
+    }
+
+    /// Create a new single-select field. A value missing from `options` is kept as
+    /// the first option so loading an item never loses what it had.
+    pub fn select(name: &str, label: &str, options: Vec<String>, value: &str) -> Self {
+        let mut options = options;
+        let selected = match options.iter().position(|o| o == value) {
+            Some(idx) => idx,
+            None if value.is_empty() && !options.is_empty() => 0,
+            None => {
+                options.insert(0, value.to_string());
+                0
+            }
+        };
+        Self::with_value(name, label, FieldValue::Select { options, selected })
+    }
+
+    /// Create a new multi-select field with `values` checked (unknown values are ignored)
+    pub fn multi_select(name: &str, label: &str, options: Vec<String>, values: &[String]) -> Self {
+        let selected = options
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| values.contains(o))
+            .map(|(idx, _)| idx)
+            .collect();
+        Self::with_value(
+            name,
+            label,
+            FieldValue::MultiSelect {
+                options,
+                selected,
+                cursor: 0,
+            },
+        )
+    }
+
+    /// Set the highlighted option of a multi-select (kept within the options)
+    pub fn with_cursor(mut self, position: usize) -> Self {
+        if let FieldValue::MultiSelect {
+            options, cursor, ..
+        } = &mut self.value
+        {
+            *cursor = position.min(options.len().saturating_sub(1));
         }
+        self
+    }
+
+    /// Set how many levels a priority field offers
+    pub fn with_priority_levels(mut self, levels: u32) -> Self {
+        self.priority_levels = levels.max(1);
+        self
     }
 
-    /// Get the text value (returns empty string for priority fields)
+    /// Label priorities the way the daemon labelled the loaded item
+    pub fn with_priority_name(mut self, name: Option<(u32, String)>) -> Self {
+        self.priority_name = name;
+        self
+    }
+
+    /// Get the text value (empty for priority and multi-select fields)
     pub fn as_text(&self) -> &str {
         match &self.value {
-            FieldValue::Text(s) => s,
-            FieldValue::Priority(_) => "",
+            FieldValue::Text(s) | FieldValue::Number(s) => s,
+            FieldValue::Boolean(true) => "true",
+            FieldValue::Boolean(false) => "false",
+            FieldValue::Select { options, selected } => {
+                options.get(*selected).map_or("", String::as_str)
+            }
+            FieldValue::Priority(_) | FieldValue::MultiSelect { .. } => "",
+        }
+    }
+
+    /// Checked options of a multi-select field, in option order
+    pub fn selected_values(&self) -> Vec<String> {
+        match &self.value {
+            FieldValue::MultiSelect {
+                options, selected, ..
+            } => selected
+                .iter()
+                .filter_map(|idx| options.get(*idx).cloned())
+                .collect(),
+            _ => Vec::new(),
         }
     }
 
-    /// Push a character to the field value
+    /// Push a character to the field value. Space toggles booleans and the highlighted
+    /// multi-select option, and advances selects.
     pub fn push_char(&mut self, c: char) {
         match &mut self.value {
             FieldValue::Text(s) => s.push(c),
             FieldValue::Priority(p) => {
-                if let Some(d) = c.to_digit(10) {
+                if let Some(d) = c.to_digit(10).filter(|d| *d <= self.priority_levels) {
                     *p = d;
                 }
             }
+            FieldValue::Number(s) => {
+                if c.is_ascii_digit() || c == '.' || c == '-' {
+                    s.push(c);
+                }
+            }
+            FieldValue::Boolean(b) if c == ' ' => *b = !*b,
+            FieldValue::Select { .. } if c == ' ' => self.cycle(true),
+            FieldValue::MultiSelect {
+                options,
+                selected,
+                cursor,
+            } if c == ' ' && *cursor < options.len() => {
+                if !selected.remove(cursor) {
+                    selected.insert(*cursor);
+                }
+            }
+            FieldValue::Boolean(_) | FieldValue::Select { .. } | FieldValue::MultiSelect { .. } => {
+            }
         }
     }
 
-    /// Remove the last character from the field value
+    /// Remove the last character from the field value. Clears a select that can be unset.
     pub fn pop_char(&mut self) {
         match &mut self.value {
-            FieldValue::Text(s) => {
+            FieldValue::Text(s) | FieldValue::Number(s) => {
                 s.pop();
             }
             FieldValue::Priority(_) => {
                 // Priority fields don't support backspace (single digit)
             }
+            FieldValue::Select { options, selected } => {
+                if let Some(idx) = options.iter().position(String::is_empty) {
+                    *selected = idx;
+                }
+            }
+            FieldValue::Boolean(_) | FieldValue::MultiSelect { .. } => {}
+        }
+    }
+
+    /// ←/→: step a priority (wrapping through Default), select or toggle, or move the
+    /// multi-select highlight. Text and number fields ignore it.
+    pub fn cycle(&mut self, forward: bool) {
+        match &mut self.value {
+            FieldValue::Priority(p) => {
+                *p = cycle_index(*p as usize, self.priority_levels as usize + 1, forward) as u32
+            }
+            FieldValue::Boolean(b) => *b = !*b,
+            FieldValue::Select { options, selected } => {
+                if !options.is_empty() {
+                    *selected = cycle_index(*selected, options.len(), forward);
+                }
+            }
+            FieldValue::MultiSelect {
+                options, cursor, ..
+            } => {
+                if !options.is_empty() {
+                    *cursor = cycle_index(*cursor, options.len(), forward);
+                }
+            }
+            FieldValue::Text(_) | FieldValue::Number(_) => {}
+        }
+    }
+
+    /// Mouse click on content line `row`, counted from the first option (scrolled-off
+    /// options included): toggles booleans, advances selects and priorities, and toggles
+    /// the clicked multi-select option
+    pub fn click(&mut self, row: usize) {
+        match &mut self.value {
+            FieldValue::MultiSelect {
+                options, cursor, ..
+            } => {
+                if row < options.len() {
+                    *cursor = row;
+                    self.push_char(' ');
+                }
+            }
+            FieldValue::Text(_) | FieldValue::Number(_) => {}
+            _ => self.cycle(true),
         }
     }
 
     /// Get the display value for rendering
     pub fn display_value(&self) -> String {
         match &self.value {
-            FieldValue::Text(s) => s.clone(),
-            FieldValue::Priority(p) => match *p {
-                0 => "Default".to_string(),
-                1 => "1 (high)".to_string(),
-                2 => "2 (medium)".to_string(),
-                3 => "3 (low)".to_string(),
-                n => n.to_string(),
+            FieldValue::Text(s) | FieldValue::Number(s) => s.clone(),
+            FieldValue::Priority(0) => "Default".to_string(),
+            FieldValue::Priority(p) => match &self.priority_name {
+                Some((named, label)) if named == p => format!("{} ({})", p, label),
+                _ if *p > self.priority_levels => p.to_string(),
+                _ => format!("{} ({})", p, priority_label(*p, self.priority_levels)),
             },
+            FieldValue::Boolean(b) => if *b { "yes" } else { "no" }.to_string(),
+            FieldValue::Select { .. } if self.as_text().is_empty() => "None".to_string(),
+            FieldValue::Select { .. } => self.as_text().to_string(),
+            FieldValue::MultiSelect { .. } => self.selected_values().join(", "),
         }
     }
 }
 
+fn cycle_index(current: usize, len: usize, forward: bool) -> usize {
+    if forward {
+        (current + 1) % len
+    } else {
+        (current + len - 1) % len
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,11 +431,23 @@ mod tests {
 
         #[test]
         fn test_push_char_to_priority_replaces_value() {
+            let mut field = FormField::priority_with_value("p", "P", 1);
+            field.push_char('3');
+            if let FieldValue::Priority(p) = &field.value {
+                assert_eq!(*p, 3); // Replaced, not appended
+            }
+        }
+
+        #[test]
+        fn test_push_char_to_priority_above_levels_ignored() {
             let mut field = FormField::priority_with_value("p", "P", 1);
             field.push_char('5');
             if let FieldValue::Priority(p) = &field.value {
-                assert_eq!(*p, 5); // Replaced, not appended
+                assert_eq!(*p, 1);
             }
+            let mut field = field.with_priority_levels(5);
+            field.push_char('5');
+            assert_eq!(field.display_value(), "5 (P5)");
         }
 
         #[test]
@@ -301,5 +508,127 @@ mod tests {
             let field = FormField::priority_with_value("p", "P", 7);
             assert_eq!(field.display_value(), "7");
         }
+
+        #[test]
+        fn test_display_value_priority_honors_levels() {
+            let field = FormField::priority_with_value("p", "P", 1).with_priority_levels(4);
+            assert_eq!(field.display_value(), "1 (P1)");
+            let field = FormField::priority_with_value("p", "P", 2).with_priority_levels(2);
+            assert_eq!(field.display_value(), "2 (low)");
+        }
+    }
+
+    mod typed_fields {
+        use super::*;
+
+        fn options(values: &[&str]) -> Vec<String> {
+            values.iter().map(|v| v.to_string()).collect()
+        }
+
+        #[test]
+        fn test_priority_label_by_level_count() {
+            assert_eq!(priority_label(1, 1), "high");
+            assert_eq!(priority_label(2, 2), "low");
+            assert_eq!(priority_label(2, 3), "medium");
+            assert_eq!(priority_label(0, 3), "low");
+            assert_eq!(priority_label(3, 6), "P3");
+        }
+
+        #[test]
+        fn test_display_value_prefers_daemon_label() {
+            let field = FormField::priority_with_value("p", "P", 2)
+                .with_priority_levels(4)
+                .with_priority_name(Some((2, "urgent".to_string())));
+            assert_eq!(field.display_value(), "2 (urgent)");
+            let field = field.with_priority_name(Some((1, "urgent".to_string())));
+            assert_eq!(field.display_value(), "2 (P2)");
+        }
+
+        #[test]
+        fn test_priority_cycle_wraps_through_default() {
+            let mut field = FormField::priority("p", "P").with_priority_levels(2);
+            field.cycle(true);
+            field.cycle(true);
+            assert_eq!(field.display_value(), "2 (low)");
+            field.cycle(true);
+            assert_eq!(field.display_value(), "Default");
+            field.cycle(false);
+            assert_eq!(field.display_value(), "2 (low)");
+        }
+
+        #[test]
+        fn test_number_only_takes_numeric_characters() {
+            let mut field = FormField::number("n", "N", String::new());
+            for c in "-1a.5 ".chars() {
+                field.push_char(c);
+            }
+            assert_eq!(field.as_text(), "-1.5");
+            field.pop_char();
+            assert_eq!(field.display_value(), "-1.");
+        }
+
+        #[test]
+        fn test_boolean_toggles_with_space_arrows_and_click() {
+            let mut field = FormField::boolean("b", "B", false);
+            field.push_char('x');
+            assert_eq!(field.as_text(), "false");
+            field.push_char(' ');
+            assert_eq!(field.as_text(), "true");
+            field.cycle(false);
+            field.click(0);
+            assert_eq!(field.display_value(), "yes");
+        }
+
+        #[test]
+        fn test_select_keeps_unknown_value_and_clears_optional() {
+            let field = FormField::select("s", "S", options(&["open", "closed"]), "blocked");
+            assert_eq!(field.as_text(), "blocked");
+
+            let mut field = FormField::select("s", "S", options(&["", "web", "api"]), "api");
+            field.cycle(true);
+            assert_eq!(field.display_value(), "None");
+            field.push_char(' ');
+            assert_eq!(field.as_text(), "web");
+            field.pop_char();
+            assert_eq!(field.as_text(), "");
+        }
+
+        #[test]
+        fn test_select_without_unset_option_ignores_backspace() {
+            let mut field = FormField::select("s", "S", options(&["open", "closed"]), "");
+            assert_eq!(field.as_text(), "open");
+            field.pop_char();
+            assert_eq!(field.as_text(), "open");
+        }
+
+        #[test]
+        fn test_multi_select_toggles_highlighted_option() {
+            let mut field = FormField::multi_select(
+                "m",
+                "M",
+                options(&["alice", "bob", "carol"]),
+                &options(&["carol", "dave"]),
+            );
+            assert_eq!(field.selected_values(), vec!["carol"]);
+            field.cycle(false);
+            field.push_char(' ');
+            assert_eq!(field.selected_values(), Vec::<String>::new());
+            field.click(0);
+            field.click(9);
+            assert_eq!(field.selected_values(), vec!["alice"]);
+            field.cycle(true);
+            field.push_char(' ');
+            assert_eq!(field.display_value(), "alice, bob");
+            assert_eq!(field.as_text(), "");
+        }
+
+        #[test]
+        fn test_empty_multi_select_ignores_toggle() {
+            let mut field = FormField::multi_select("reviewers", "Reviewers", vec![], &[]);
+            field.push_char(' ');
+            field.click(0);
+            assert_eq!(field.selected_values(), Vec::<String>::new());
+            assert_eq!(field.display_value(), "");
+        }
     }
 }
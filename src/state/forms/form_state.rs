@@ -178,6 +178,8 @@ pub struct IssueEditForm {
     pub description: FormField,
     pub priority: FormField,
     pub status: FormField,
+    /// Draft checkbox (not a text field)
+    pub draft: bool,
     pub active_field_index: usize,
 }
 
@@ -202,6 +204,7 @@ impl IssueEditForm {
                 issue.metadata.status.clone(),
                 false,
             ),
+            draft: issue.metadata.draft,
             active_field_index: 0,
         }
     }
@@ -459,6 +462,7 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 custom_fields: HashMap::new(),
+                draft: false,
             },
         }
     }
@@ -658,6 +662,7 @@ mod tests {
             assert_eq!(form.title.as_text(), "Test Issue");
             assert_eq!(form.description.as_text(), "Test Description");
             assert_eq!(form.status.as_text(), "open");
+            assert!(!form.draft);
             assert_eq!(form.active_field_index, 0);
         }
 
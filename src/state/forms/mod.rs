@@ -8,7 +8,7 @@
 mod field;
 mod form_state;
 
-pub use field::FormField;
+pub use field::{priority_label, FieldValue, FormField, DEFAULT_PRIORITY_LEVELS};
 pub use form_state::{
     DocCreateForm, FormState, IssueCreateForm, IssueEditForm, PrCreateForm, PrEditForm,
 };
@@ -1,14 +1,18 @@
 //! Application state module
 
 mod app_state;
+mod config_editor;
 mod forms;
+mod path_input;
 mod screen_buffer;
 mod selection;
 mod splash_state;
 mod ui_area;
 
 pub use app_state::*;
+pub use config_editor::*;
 pub use forms::*;
+pub use path_input::*;
 pub use screen_buffer::*;
 pub use selection::*;
 pub use splash_state::*;
@@ -0,0 +1,185 @@
+//! Path input helpers (tilde expansion, tab completion and PATH lookup)
+
+use directories::BaseDirs;
+use std::path::PathBuf;
+
+/// Expand a leading `~` to the user's home directory
+pub fn expand_tilde(input: &str) -> PathBuf {
+    if input == "~" || input.starts_with("~/") {
+        if let Some(dirs) = BaseDirs::new() {
+            return dirs.home_dir().join(input[1..].trim_start_matches('/'));
+        }
+    }
+    PathBuf::from(input)
+}
+
+/// Complete the last segment of a typed path against the filesystem.
+///
+/// A single match is completed fully (directories get a trailing `/`), several
+/// matches are completed to their longest common prefix. The typed directory
+/// part (including any `~`) is kept as is. Returns None when nothing matches.
+pub fn complete_path(input: &str) -> Option<String> {
+    if input == "~" {
+        return Some("~/".to_string());
+    }
+
+    let (dir_part, prefix) = match input.rfind('/') {
+        Some(idx) => input.split_at(idx + 1),
+        None => ("", input),
+    };
+    let dir = if dir_part.is_empty() {
+        PathBuf::from(".")
+    } else {
+        expand_tilde(dir_part)
+    };
+
+    let mut candidates: Vec<(String, bool)> = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            // Hidden entries only complete when explicitly asked for
+            let hidden = name.starts_with('.') && !prefix.starts_with('.');
+            if hidden || !name.starts_with(prefix) {
+                return None;
+            }
+            Some((name, entry.path().is_dir()))
+        })
+        .collect();
+    candidates.sort();
+
+    let completed = match candidates.as_slice() {
+        [] => return None,
+        [(name, true)] => format!("{}/", name),
+        [(name, false)] => name.clone(),
+        _ => common_prefix(candidates.iter().map(|(name, _)| name.as_str())),
+    };
+
+    Some(format!("{}{}", dir_part, completed))
+}
+
+/// Whether a command can be run: an existing file when given as a path,
+/// otherwise an entry in one of the `PATH` directories
+pub fn command_on_path(command: &str) -> bool {
+    let command = command.trim();
+    if command.is_empty() {
+        return false;
+    }
+    if command.contains('/') || command.starts_with('~') {
+        return expand_tilde(command).is_file();
+    }
+
+    let Some(paths) = std::env::var_os("PATH") else {
+        return false;
+    };
+    std::env::split_paths(&paths).any(|dir| {
+        dir.join(command).is_file()
+            || (cfg!(windows) && dir.join(format!("{}.exe", command)).is_file())
+    })
+}
+
+/// Longest common prefix of a set of names
+fn common_prefix<'a>(mut names: impl Iterator<Item = &'a str>) -> String {
+    let Some(first) = names.next() else {
+        return String::new();
+    };
+    let mut prefix = first.to_string();
+    for name in names {
+        let common: usize = prefix
+            .chars()
+            .zip(name.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+        prefix.truncate(common);
+    }
+    prefix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn temp_tree(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("centy-tui-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("notes")).unwrap();
+        fs::write(root.join("report-2024.txt"), "a").unwrap();
+        fs::write(root.join("report-2025.txt"), "b").unwrap();
+        fs::write(root.join(".hidden"), "c").unwrap();
+        root
+    }
+
+    #[test]
+    fn test_expand_tilde_leaves_other_paths() {
+        assert_eq!(expand_tilde("/tmp/x"), PathBuf::from("/tmp/x"));
+        assert_eq!(expand_tilde("rel/~x"), PathBuf::from("rel/~x"));
+    }
+
+    #[test]
+    fn test_expand_tilde_home() {
+        if let Some(dirs) = BaseDirs::new() {
+            assert_eq!(expand_tilde("~"), dirs.home_dir());
+            assert_eq!(expand_tilde("~/a/b"), dirs.home_dir().join("a/b"));
+        }
+    }
+
+    #[test]
+    fn test_complete_single_directory_adds_slash() {
+        let root = temp_tree("dir");
+        let input = format!("{}/no", root.display());
+        assert_eq!(
+            complete_path(&input),
+            Some(format!("{}/notes/", root.display()))
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_complete_multiple_matches_to_common_prefix() {
+        let root = temp_tree("prefix");
+        let input = format!("{}/rep", root.display());
+        assert_eq!(
+            complete_path(&input),
+            Some(format!("{}/report-202", root.display()))
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_complete_skips_hidden_unless_requested() {
+        let root = temp_tree("hidden");
+        assert_eq!(complete_path(&format!("{}/x", root.display())), None);
+        assert_eq!(
+            complete_path(&format!("{}/.h", root.display())),
+            Some(format!("{}/.hidden", root.display()))
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_complete_lone_tilde() {
+        assert_eq!(complete_path("~"), Some("~/".to_string()));
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        assert_eq!(common_prefix(["abc", "abd", "ab"].into_iter()), "ab");
+        assert_eq!(common_prefix(["é1", "é2"].into_iter()), "é");
+        assert_eq!(common_prefix(std::iter::empty()), "");
+    }
+
+    #[test]
+    fn test_command_on_path() {
+        let root = temp_tree("command");
+        let file = root.join("report-2024.txt");
+        assert!(command_on_path(&file.display().to_string()));
+        assert!(!command_on_path(
+            &root.join("missing").display().to_string()
+        ));
+        assert!(!command_on_path("   "));
+        assert!(!command_on_path("centy-tui-no-such-command"));
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -33,8 +33,6 @@ pub enum UiArea {
     ListContent,
     /// Project grid cards (starts after context bar + outer border + inner start)
     GridContent,
-    /// Form fields (starts after context bar + border)
-    FormContent,
 }
 
 impl UiArea {
@@ -47,7 +45,6 @@ impl UiArea {
             UiArea::ActionPanel => CONTEXT_BAR_HEIGHT + 1, // +1 for outer border
             UiArea::ListContent => CONTEXT_BAR_HEIGHT + 3, // +3 for header area
             UiArea::GridContent => CONTEXT_BAR_HEIGHT + 2, // +2 for outer border + inner start
-            UiArea::FormContent => CONTEXT_BAR_HEIGHT + 1, // +1 for border
         }
     }
 
@@ -82,7 +79,6 @@ mod tests {
         assert_eq!(UiArea::ActionPanel.start_y(), 4);
         assert_eq!(UiArea::ListContent.start_y(), 6);
         assert_eq!(UiArea::GridContent.start_y(), 5);
-        assert_eq!(UiArea::FormContent.start_y(), 4);
     }
 
     #[test]
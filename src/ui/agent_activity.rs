@@ -0,0 +1,152 @@
+//! Agent activity panel (active agent sessions per project)
+
+use crate::app::App;
+use crate::state::LlmWorkSession;
+use chrono::Utc;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the agent activity panel
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let scope = if app.state.llm_work_all_projects {
+        "All Projects".to_string()
+    } else {
+        app.state
+            .selected_project_path
+            .as_ref()
+            .and_then(|p| p.split('/').next_back())
+            .unwrap_or("Project")
+            .to_string()
+    };
+
+    let mut lines = Vec::new();
+    if app.state.llm_work.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No agent is working right now. Press 's' to spawn one.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (idx, session) in app.state.llm_work.iter().enumerate() {
+        let is_selected = idx == app.state.selected_llm_work_index;
+        lines.extend(session_lines(
+            session,
+            is_selected,
+            app.state.llm_work_all_projects,
+        ));
+        lines.push(Line::from(""));
+    }
+
+    if app.state.confirm_clear_llm_work {
+        if let Some(session) = app.state.selected_llm_work() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("Clear the session on #{}? ", session.display_number),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled("y", Style::default().fg(Color::Cyan)),
+                Span::styled(" clear  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("n", Style::default().fg(Color::Cyan)),
+                Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+    }
+
+    if let Some(preview) = &app.state.spawn_prompt_preview {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Last prompt",
+            Style::default().fg(Color::Cyan),
+        )));
+        for line in preview.lines() {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", line),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(format!(" Agent Activity - {} ", scope))
+            .title_bottom(Line::from(Span::styled(
+                " refreshes every few seconds ",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Issue line and agent/process line for one session
+fn session_lines(
+    session: &LlmWorkSession,
+    is_selected: bool,
+    show_project: bool,
+) -> Vec<Line<'static>> {
+    let title_style = if is_selected {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let mut issue_line = vec![
+        Span::raw(if is_selected { "▸ " } else { "  " }),
+        Span::styled(
+            format!("#{} ", session.display_number),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(session.issue_title.clone(), title_style),
+    ];
+    if show_project {
+        let project = session
+            .project_path
+            .split('/')
+            .next_back()
+            .unwrap_or("")
+            .to_string();
+        issue_line.push(Span::styled(
+            format!("  [{}]", project),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+
+    let (process, process_color) = match (session.pid, session.pid_alive) {
+        (0, _) => ("no process".to_string(), Color::DarkGray),
+        (pid, true) => (format!("PID {} running", pid), Color::Green),
+        (pid, false) => (format!("PID {} exited (stale)", pid), Color::Red),
+    };
+    let elapsed = Utc::now().signed_duration_since(session.started_at);
+    let started = format!(
+        "started {} ({}m ago)",
+        session
+            .started_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M"),
+        elapsed.num_minutes().max(0)
+    );
+
+    vec![
+        Line::from(issue_line),
+        Line::from(vec![
+            Span::raw("    "),
+            Span::styled(session.agent_name.clone(), Style::default().fg(Color::Cyan)),
+            Span::styled(
+                format!("  {}", session.action.label()),
+                Style::default().fg(Color::Magenta),
+            ),
+            Span::styled(
+                format!("  {}  ", started),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(process, Style::default().fg(process_color)),
+        ]),
+    ]
+}
@@ -0,0 +1,210 @@
+//! Agents settings view (local LLM agents, default agent and environment variables)
+
+use crate::app::App;
+use crate::state::{mask_secret, AgentsFocus, LlmConfigScope};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Draw the agents settings view
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Percentage(60),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    draw_scope(frame, chunks[0], app);
+    draw_agents(frame, chunks[1], app);
+    draw_env_vars(frame, chunks[2], app);
+}
+
+/// Which config is being edited and which config files exist
+fn draw_scope(frame: &mut Frame, area: Rect, app: &App) {
+    let (scope, file) = match app.state.llm_config_scope {
+        LlmConfigScope::Project => ("Project", ".centy/config.local.json"),
+        LlmConfigScope::Global => ("Global", "shared by all projects"),
+    };
+    let exists = |present: bool| {
+        if present {
+            Span::styled("✓", Style::default().fg(Color::Green))
+        } else {
+            Span::styled("✗", Style::default().fg(Color::DarkGray))
+        }
+    };
+
+    let mut spans = vec![
+        Span::styled("Editing: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            scope,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!(" ({})", file), Style::default().fg(Color::DarkGray)),
+    ];
+    if let Some(settings) = &app.state.local_llm {
+        spans.push(Span::styled(
+            "   project config ",
+            Style::default().fg(Color::DarkGray),
+        ));
+        spans.push(exists(settings.has_project_config));
+        spans.push(Span::styled(
+            "  global config ",
+            Style::default().fg(Color::DarkGray),
+        ));
+        spans.push(exists(settings.has_global_config));
+    }
+
+    let paragraph = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .title(" Agents ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Configured agents, default marked with a star
+fn draw_agents(frame: &mut Frame, area: Rect, app: &App) {
+    let focused = app.state.agents_focus == AgentsFocus::Agents;
+    let default_agent = app
+        .state
+        .local_llm
+        .as_ref()
+        .map(|s| s.config.default_agent.as_str())
+        .unwrap_or("");
+
+    let mut lines = Vec::new();
+    if app.state.llm_agents().is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No agents configured. Press 'n' to add one.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (idx, agent) in app.state.llm_agents().iter().enumerate() {
+        let is_selected = focused && idx == app.state.selected_agent_index;
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let star = if agent.name == default_agent {
+            Span::styled("★ ", Style::default().fg(Color::Yellow))
+        } else {
+            Span::raw("  ")
+        };
+
+        let mut command = agent.command.clone();
+        for arg in &agent.default_args {
+            command.push(' ');
+            command.push_str(arg);
+        }
+
+        lines.push(Line::from(vec![
+            Span::raw(if is_selected { "▸ " } else { "  " }),
+            star,
+            Span::styled(agent.name.clone(), name_style),
+            Span::styled(
+                format!("  [{}]", agent.agent_type.label()),
+                Style::default().fg(Color::Magenta),
+            ),
+            Span::styled(format!("  {}", command), Style::default().fg(Color::Yellow)),
+        ]));
+        if !agent.plan_template.is_empty() || !agent.implement_template.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "      plan: {}  implement: {}",
+                    or_default(&agent.plan_template),
+                    or_default(&agent.implement_template)
+                ),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Agents ({}) ", app.state.llm_agents().len()))
+            .borders(Borders::ALL)
+            .border_style(focus_style(focused)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Environment variables passed to agents, values masked unless revealed
+fn draw_env_vars(frame: &mut Frame, area: Rect, app: &App) {
+    let focused = app.state.agents_focus == AgentsFocus::EnvVars;
+    let vars = app.state.llm_env_vars();
+
+    let mut lines = Vec::new();
+    if vars.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No environment variables.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (idx, (key, value)) in vars.iter().enumerate() {
+        let is_selected = focused && idx == app.state.selected_env_var_index;
+        let key_style = if is_selected {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let shown = if app.state.reveal_env_values {
+            (*value).clone()
+        } else {
+            mask_secret(value)
+        };
+        lines.push(Line::from(vec![
+            Span::raw(if is_selected { "▸ " } else { "  " }),
+            Span::styled((*key).clone(), key_style),
+            Span::styled(" = ", Style::default().fg(Color::DarkGray)),
+            Span::styled(shown, Style::default().fg(Color::Yellow)),
+        ]));
+    }
+
+    let reveal = if app.state.reveal_env_values {
+        " s:hide values "
+    } else {
+        " s:show values "
+    };
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Environment ({}) ", vars.len()))
+            .title_bottom(Line::from(Span::styled(
+                reveal,
+                Style::default().fg(Color::DarkGray),
+            )))
+            .borders(Borders::ALL)
+            .border_style(focus_style(focused)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+fn focus_style(focused: bool) -> Style {
+    if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    }
+}
+
+fn or_default(template: &str) -> &str {
+    if template.is_empty() {
+        "default"
+    } else {
+        template
+    }
+}
@@ -0,0 +1,129 @@
+//! Asset lists for the issue detail Assets tab and the shared assets library
+
+use crate::app::App;
+use crate::state::Asset;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the shared assets library view
+pub fn draw_shared(frame: &mut Frame, area: Rect, app: &App) {
+    let project_name = app
+        .state
+        .selected_project_path
+        .as_ref()
+        .and_then(|p| p.split('/').next_back())
+        .unwrap_or("Project");
+    let title = Line::from(format!(" Shared Assets - {} ", project_name));
+    draw_assets(frame, area, app, title, Color::Cyan);
+}
+
+/// Draw an asset list with the inline preview below it
+pub fn draw_assets(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    title: Line<'static>,
+    border_color: Color,
+) {
+    let chunks = if app.state.asset_preview.is_some() {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(40), Constraint::Min(0)])
+            .split(area)
+    } else {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0)])
+            .split(area)
+    };
+
+    let list = Paragraph::new(asset_lines(app)).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color)),
+    );
+    frame.render_widget(list, chunks[0]);
+
+    if let Some(preview) = &app.state.asset_preview {
+        let lines: Vec<Line> = preview
+            .content
+            .lines()
+            .map(|l| Line::from(l.to_string()))
+            .collect();
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(format!(" Preview - {} ", preview.filename))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::DarkGray)),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((app.state.scroll_offset as u16, 0));
+        frame.render_widget(paragraph, chunks[1]);
+    }
+}
+
+/// Build the asset list lines (hint, then one row per asset)
+fn asset_lines(app: &App) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "j/k:select  Enter:preview  a:attach  s:save  x:delete",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+    ];
+
+    if app.state.assets.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No assets. Press 'a' to attach a file.",
+            Style::default().fg(Color::DarkGray),
+        )));
+        return lines;
+    }
+
+    for (idx, asset) in app.state.assets.iter().enumerate() {
+        lines.push(asset_row(asset, idx == app.state.selected_asset_index));
+    }
+    lines
+}
+
+/// A single asset row: filename, size, MIME type and shared flag
+fn asset_row(asset: &Asset, is_selected: bool) -> Line<'static> {
+    let prefix = if is_selected { "▸ " } else { "  " };
+    let name_style = if is_selected {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let mut spans = vec![
+        Span::styled(format!("{}{}", prefix, asset.filename), name_style),
+        Span::styled(
+            format!("  {}", asset.size_label()),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            format!("  {}", asset.mime_type),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled(
+            format!("  {}", asset.created_at.format("%Y-%m-%d")),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    if asset.is_shared {
+        spans.push(Span::styled(
+            "  [shared]",
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    Line::from(spans)
+}
@@ -0,0 +1,135 @@
+//! Agents view dialogs - agent/variable removal and environment variable editor
+
+use crate::state::{mask_secret, PendingAgentAction};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the pending agents view dialog
+pub fn render_agent_dialog(frame: &mut Frame, action: &PendingAgentAction, reveal_values: bool) {
+    let (title, color, content) = match action {
+        PendingAgentAction::RemoveAgent { name } => (
+            "Remove Agent",
+            Color::Red,
+            confirm_lines("Remove agent ", name),
+        ),
+        PendingAgentAction::RemoveEnvVar { key } => (
+            "Remove Variable",
+            Color::Red,
+            confirm_lines("Remove environment variable ", key),
+        ),
+        PendingAgentAction::EnvVar {
+            original_key,
+            key,
+            value,
+            editing_value,
+            error,
+        } => {
+            let shown = if reveal_values {
+                value.clone()
+            } else {
+                mask_secret(value)
+            };
+            let mut content = Vec::new();
+            content.extend(input_lines("Name", key, !editing_value));
+            content.extend(input_lines("Value", &shown, *editing_value));
+            if let Some(error) = error {
+                content.push(Line::from(Span::styled(
+                    error.clone(),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            content.push(Line::from(""));
+            content.push(Line::from(vec![
+                Span::styled("Tab", Style::default().fg(Color::Cyan)),
+                Span::styled(" switch  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Enter", Style::default().fg(Color::Cyan)),
+                Span::styled(" save  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Esc", Style::default().fg(Color::Cyan)),
+                Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+            ]));
+            let title = if original_key.is_some() {
+                "Edit Variable"
+            } else {
+                "New Variable"
+            };
+            (title, Color::Cyan, content)
+        }
+    };
+
+    let area = frame.area();
+    let dialog_width = 60u16;
+    let dialog_height = content.len() as u16 + 4;
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            title,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(content);
+
+    let dialog = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
+
+/// Question naming the item, then the y/n hint
+fn confirm_lines(question: &str, name: &str) -> Vec<Line<'static>> {
+    vec![
+        Line::from(vec![
+            Span::styled(question.to_string(), Style::default().fg(Color::White)),
+            Span::styled(name.to_string(), Style::default().fg(Color::Yellow)),
+            Span::styled("?", Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("y/Enter", Style::default().fg(Color::Cyan)),
+            Span::styled(" remove  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("n/Esc", Style::default().fg(Color::Cyan)),
+            Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+        ]),
+    ]
+}
+
+/// Label line and input line, with a cursor on the active input
+fn input_lines(label: &str, value: &str, active: bool) -> Vec<Line<'static>> {
+    let label_style = if active {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let mut input = vec![
+        Span::raw("  "),
+        Span::styled(value.to_string(), Style::default().fg(Color::Yellow)),
+    ];
+    if active {
+        input.push(Span::styled("█", Style::default().fg(Color::Cyan)));
+    }
+    vec![
+        Line::from(Span::styled(label.to_string(), label_style)),
+        Line::from(input),
+    ]
+}
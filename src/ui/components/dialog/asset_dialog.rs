@@ -0,0 +1,116 @@
+//! Asset dialogs - attach/save path input with completion, and overwrite/delete confirmation
+
+use super::base::path_input_lines;
+use crate::state::PendingAssetAction;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the pending asset dialog
+pub fn render_asset_dialog(frame: &mut Frame, action: &PendingAssetAction) {
+    let (title, color, content) = match action {
+        PendingAssetAction::Attach {
+            path,
+            issue_id,
+            shared,
+        } => {
+            let title = if *shared {
+                "Add Shared Asset"
+            } else {
+                "Attach File"
+            };
+            let mut lines = path_input_lines("File to attach:", path);
+            // Only an issue-scoped dialog can switch targets
+            if issue_id.is_some() {
+                let target = if *shared {
+                    "shared library"
+                } else {
+                    "this issue"
+                };
+                lines.push(Line::from(vec![
+                    Span::styled("Target: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(target, Style::default().fg(Color::Yellow)),
+                    Span::styled("  Ctrl+T", Style::default().fg(Color::Cyan)),
+                    Span::styled(" switch", Style::default().fg(Color::DarkGray)),
+                ]));
+            }
+            (title, Color::Cyan, lines)
+        }
+        PendingAssetAction::Save { asset, path } => (
+            "Save Asset",
+            Color::Cyan,
+            path_input_lines(&format!("Save {} to:", asset.filename), path),
+        ),
+        PendingAssetAction::Overwrite { target, .. } => (
+            "Overwrite File",
+            Color::Red,
+            vec![
+                Line::from(vec![
+                    Span::styled(target.clone(), Style::default().fg(Color::Yellow)),
+                    Span::styled(" already exists.", Style::default().fg(Color::White)),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("y/Enter", Style::default().fg(Color::Cyan)),
+                    Span::styled(" overwrite  ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("n/Esc", Style::default().fg(Color::Cyan)),
+                    Span::styled(" choose another path", Style::default().fg(Color::DarkGray)),
+                ]),
+            ],
+        ),
+        PendingAssetAction::Delete { asset } => (
+            "Delete Asset",
+            Color::Red,
+            vec![
+                Line::from(vec![
+                    Span::styled("Delete ", Style::default().fg(Color::White)),
+                    Span::styled(asset.filename.clone(), Style::default().fg(Color::Yellow)),
+                    Span::styled("?", Style::default().fg(Color::White)),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("y/Enter", Style::default().fg(Color::Cyan)),
+                    Span::styled(" delete  ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("n/Esc", Style::default().fg(Color::Cyan)),
+                    Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+                ]),
+            ],
+        ),
+    };
+
+    let area = frame.area();
+    let dialog_width = 64u16;
+    let dialog_height = content.len() as u16 + 4;
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            title,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(content);
+
+    let dialog = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
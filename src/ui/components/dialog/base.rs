@@ -11,6 +11,9 @@ use ratatui::{
 /// Default text style for dialog content (white on black background)
 pub const TEXT_STYLE: Style = Style::new().fg(Color::White);
 
+/// Maximum number of rows shown in dialog picker lists
+const VISIBLE_ROWS: usize = 8;
+
 /// Configuration for rendering a dialog
 pub struct DialogConfig<'a> {
     /// Dialog title
@@ -109,6 +112,67 @@ pub fn render_dialog(frame: &mut Frame, config: DialogConfig) {
     frame.render_widget(dialog, dialog_area);
 }
 
+/// Push a scrolling list of labels with the selected row highlighted
+pub fn push_list(content: &mut Vec<Line<'_>>, labels: &[String], selected: usize) {
+    let visible_count = VISIBLE_ROWS.min(labels.len());
+    let start_idx = if selected >= visible_count {
+        selected - visible_count + 1
+    } else {
+        0
+    };
+
+    for (idx, label) in labels
+        .iter()
+        .enumerate()
+        .skip(start_idx)
+        .take(visible_count)
+    {
+        let is_selected = idx == selected;
+        let prefix = if is_selected { "▸ " } else { "  " };
+        let style = if is_selected {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        content.push(Line::from(Span::styled(
+            format!("{}{}", prefix, label),
+            style,
+        )));
+    }
+
+    if labels.len() > visible_count {
+        content.push(Line::from(Span::styled(
+            format!("  ({} more...)", labels.len() - visible_count),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+}
+
+/// Lines for a path input with completion hints
+pub fn path_input_lines(label: &str, path: &str) -> Vec<Line<'static>> {
+    vec![
+        Line::from(Span::styled(
+            label.to_string(),
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(vec![
+            Span::styled(path.to_string(), Style::default().fg(Color::Yellow)),
+            Span::styled("█", Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Tab", Style::default().fg(Color::Cyan)),
+            Span::styled(" complete  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::styled(" confirm  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+        ]),
+    ]
+}
+
 /// Wrap text to fit within a maximum width
 fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
     let mut lines = Vec::new();
@@ -0,0 +1,103 @@
+//! Destructive action confirmation dialog component
+
+use super::super::button::{render_button, BUTTON_HEIGHT};
+use crate::state::PendingConfirmAction;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+const DIALOG_WIDTH: u16 = 60;
+const DIALOG_HEIGHT: u16 = 11;
+const BUTTON_WIDTH: u16 = 14;
+const BUTTON_GAP: u16 = 2;
+
+/// Centered area of the confirmation dialog
+fn dialog_area(area: Rect) -> Rect {
+    Rect {
+        x: area.x + (area.width.saturating_sub(DIALOG_WIDTH)) / 2,
+        y: area.y + (area.height.saturating_sub(DIALOG_HEIGHT)) / 2,
+        width: DIALOG_WIDTH.min(area.width),
+        height: DIALOG_HEIGHT.min(area.height),
+    }
+}
+
+/// Confirm and cancel button areas, shared with mouse hit testing
+pub fn confirm_dialog_buttons(area: Rect) -> (Rect, Rect) {
+    let dialog = dialog_area(area);
+    let x = dialog.x + (dialog.width.saturating_sub(BUTTON_WIDTH * 2 + BUTTON_GAP)) / 2;
+    let y = (dialog.y + dialog.height).saturating_sub(BUTTON_HEIGHT + 1);
+    let button = |x| Rect {
+        x,
+        y,
+        width: BUTTON_WIDTH,
+        height: BUTTON_HEIGHT,
+    };
+    (button(x), button(x + BUTTON_WIDTH + BUTTON_GAP))
+}
+
+/// Render a confirmation for a destructive action, accepted only with 'y' or the button
+pub fn render_confirm_dialog(frame: &mut Frame, action: &PendingConfirmAction) {
+    let area = frame.area();
+    let dialog = dialog_area(area);
+    frame.render_widget(Clear, dialog);
+
+    let max_target = DIALOG_WIDTH.saturating_sub(4) as usize;
+    let content = vec![
+        Line::from(Span::styled(
+            format!("{}?", action.title),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate_start(&action.target, max_target),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(Span::styled(
+            action.detail.clone(),
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("y", Style::default().fg(Color::Cyan)),
+            Span::styled(" confirm  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("n/Esc", Style::default().fg(Color::Cyan)),
+            Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+    frame.render_widget(paragraph, dialog);
+
+    let (confirm, cancel) = confirm_dialog_buttons(area);
+    render_button(
+        frame,
+        confirm,
+        "Confirm",
+        false,
+        true,
+        false,
+        Some(Color::Red),
+    );
+    render_button(frame, cancel, "Cancel", false, true, false, None);
+}
+
+/// Keep the end of long values (paths), marking the cut with an ellipsis
+fn truncate_start(value: &str, max: usize) -> String {
+    let len = value.chars().count();
+    if len <= max {
+        return value.to_string();
+    }
+    let tail: String = value.chars().skip(len - max + 1).collect();
+    format!("…{}", tail)
+}
@@ -0,0 +1,112 @@
+//! Jump prompt dialog - go to an issue, PR or doc by UUID, display number or slug
+
+use super::base::push_list;
+use crate::state::PendingJumpAction;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the jump prompt (input, then disambiguation list when needed)
+pub fn render_jump_dialog(frame: &mut Frame, action: &PendingJumpAction) {
+    let dialog_width = 70u16;
+    let dialog_height = 20u16;
+
+    let area = frame.area();
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let mut content = vec![
+        Line::from(Span::styled(
+            "Go To",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::DarkGray)),
+            Span::styled(&action.input, Style::default().fg(Color::Yellow)),
+            Span::styled("█", Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+    ];
+
+    if !action.searched {
+        content.push(Line::from(Span::styled(
+            "UUID      issue or PR in any project",
+            Style::default().fg(Color::DarkGray),
+        )));
+        content.push(Line::from(Span::styled(
+            "#123      issue or PR in the current project",
+            Style::default().fg(Color::DarkGray),
+        )));
+        content.push(Line::from(Span::styled(
+            "slug      doc in any project",
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else if action.matches.is_empty() {
+        content.push(Line::from(Span::styled(
+            "No matches.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else {
+        content.push(Line::from(Span::styled(
+            format!("{} matches:", action.matches.len()),
+            Style::default().fg(Color::DarkGray),
+        )));
+        let labels: Vec<String> = action
+            .matches
+            .iter()
+            .map(|m| format!("{}  [{}] {}", m.label(), m.project_name, m.display_path))
+            .collect();
+        push_list(&mut content, &labels, action.selected_index);
+    }
+
+    // Non-fatal lookup errors (e.g. projects that couldn't be accessed)
+    for error in &action.errors {
+        content.push(Line::from(Span::styled(
+            format!("! {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    content.push(Line::from(""));
+    if action.searched && !action.matches.is_empty() {
+        content.push(Line::from(vec![
+            Span::styled("↑↓", Style::default().fg(Color::Cyan)),
+            Span::styled(" select  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::styled(" open  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::styled(" back", Style::default().fg(Color::DarkGray)),
+        ]));
+    } else {
+        content.push(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::styled(" go  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+
+    let dialog = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
@@ -0,0 +1,186 @@
+//! Link dialogs - link type picker with target search, and removal confirmation
+
+use super::base::push_list;
+use crate::state::{
+    LinkDialogStep, LinkTarget, LinkTypeInfo, PendingLinkAction, PendingUnlinkAction,
+};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the add link dialog (link type picker or target picker)
+pub fn render_link_dialog(
+    frame: &mut Frame,
+    action: &PendingLinkAction,
+    link_types: &[LinkTypeInfo],
+    targets: &[LinkTarget],
+) {
+    let dialog_width = 64u16;
+    let dialog_height = 20u16;
+
+    let area = frame.area();
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let mut content = vec![
+        Line::from(Span::styled(
+            "Add Link",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("From: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(&action.source_display, Style::default().fg(Color::White)),
+        ]),
+    ];
+
+    match action.step {
+        LinkDialogStep::SelectType => {
+            content.push(Line::from(""));
+            content.push(Line::from(Span::styled(
+                "Link type:",
+                Style::default().fg(Color::DarkGray),
+            )));
+
+            let labels: Vec<String> = link_types
+                .iter()
+                .map(|t| {
+                    let custom = if t.is_builtin { "" } else { " [custom]" };
+                    format!("{} (inverse: {}){}", t.name, t.inverse, custom)
+                })
+                .collect();
+            push_list(&mut content, &labels, action.selected_type_index);
+
+            content.push(Line::from(""));
+            content.push(Line::from(vec![
+                Span::styled("↑↓", Style::default().fg(Color::Cyan)),
+                Span::styled(" select  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Enter", Style::default().fg(Color::Cyan)),
+                Span::styled(" next  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Esc", Style::default().fg(Color::Cyan)),
+                Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+        LinkDialogStep::SelectTarget => {
+            let link_type = link_types
+                .get(action.selected_type_index)
+                .map(|t| t.name.as_str())
+                .unwrap_or("");
+            content.push(Line::from(vec![
+                Span::styled("Type: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(link_type.to_string(), Style::default().fg(Color::Yellow)),
+            ]));
+            content.push(Line::from(""));
+            content.push(Line::from(vec![
+                Span::styled("Search: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    if action.search_filter.is_empty() {
+                        "type to filter...".to_string()
+                    } else {
+                        action.search_filter.clone()
+                    },
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]));
+            content.push(Line::from(""));
+
+            if targets.is_empty() {
+                content.push(Line::from(Span::styled(
+                    "  No matching issues, PRs or docs",
+                    Style::default().fg(Color::DarkGray),
+                )));
+            } else {
+                let labels: Vec<String> = targets.iter().map(|t| t.display.clone()).collect();
+                push_list(&mut content, &labels, action.selected_target_index);
+            }
+
+            content.push(Line::from(""));
+            content.push(Line::from(vec![
+                Span::styled("↑↓", Style::default().fg(Color::Cyan)),
+                Span::styled(" select  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Enter", Style::default().fg(Color::Cyan)),
+                Span::styled(" link  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Esc", Style::default().fg(Color::Cyan)),
+                Span::styled(" back", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+    }
+
+    let dialog = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
+
+/// Render the link removal confirmation dialog
+pub fn render_unlink_dialog(frame: &mut Frame, action: &PendingUnlinkAction) {
+    let area = frame.area();
+    let dialog_width = 55u16;
+    let dialog_height = 9u16;
+
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let content = vec![
+        Line::from(Span::styled(
+            "Remove Link",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Remove ", Style::default().fg(Color::White)),
+            Span::styled(&action.link.link_type, Style::default().fg(Color::Yellow)),
+            Span::styled(" link to", Style::default().fg(Color::White)),
+        ]),
+        Line::from(Span::styled(
+            &action.target_display,
+            Style::default().fg(Color::Cyan),
+        )),
+        Line::from(Span::styled(
+            "(the inverse link is removed too)",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("y/Enter", Style::default().fg(Color::Cyan)),
+            Span::styled(" remove  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("n/Esc", Style::default().fg(Color::Cyan)),
+            Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+        ]),
+    ];
+
+    let dialog = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black));
+
+    frame.render_widget(dialog, dialog_area);
+}
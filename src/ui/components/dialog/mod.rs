@@ -1,12 +1,30 @@
 //! Dialog components for TUI
 
+mod agent_dialog;
+mod asset_dialog;
 mod base;
+mod confirm_dialog;
 mod error_dialog;
+mod jump_dialog;
+mod link_dialog;
 mod move_dialog;
+mod org_dialog;
+mod register_dialog;
+mod rename_dialog;
+mod spawn_dialog;
 mod start_work_dialog;
 mod worktree_dialog;
 
+pub use agent_dialog::render_agent_dialog;
+pub use asset_dialog::render_asset_dialog;
+pub use confirm_dialog::{confirm_dialog_buttons, render_confirm_dialog};
 pub use error_dialog::render_error_dialog;
+pub use jump_dialog::render_jump_dialog;
+pub use link_dialog::{render_link_dialog, render_unlink_dialog};
 pub use move_dialog::render_move_dialog;
+pub use org_dialog::render_org_dialog;
+pub use register_dialog::render_register_dialog;
+pub use rename_dialog::render_rename_dialog;
+pub use spawn_dialog::render_spawn_dialog;
 pub use start_work_dialog::render_start_work_dialog;
 pub use worktree_dialog::render_worktree_dialog;
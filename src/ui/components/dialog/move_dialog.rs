@@ -1,6 +1,7 @@
 //! Move entity dialog component - project picker with search and confirmation
+//! (also used to duplicate, where the confirmation edits the copy's title/slug)
 
-use crate::state::{MoveEntityType, PendingMoveAction, Project};
+use crate::state::{MoveEntityType, MoveMode, PendingMoveAction, Project};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -15,7 +16,9 @@ pub fn render_move_dialog(
     action: &PendingMoveAction,
     available_projects: &[&Project],
 ) {
-    if action.show_confirmation {
+    if action.show_confirmation && action.mode == MoveMode::Duplicate {
+        render_duplicate_confirmation(frame, action);
+    } else if action.show_confirmation {
         render_confirmation(frame, action);
     } else {
         render_project_picker(frame, action, available_projects);
@@ -47,16 +50,21 @@ fn render_project_picker(frame: &mut Frame, action: &PendingMoveAction, projects
         MoveEntityType::Doc => "Doc",
     };
 
+    let (verb, verbing) = match action.mode {
+        MoveMode::Move => ("Move", "Moving: "),
+        MoveMode::Duplicate => ("Duplicate", "Duplicating: "),
+    };
+
     let mut content = vec![
         Line::from(Span::styled(
-            format!("Move {} to Project", entity_label),
+            format!("{} {} to Project", verb, entity_label),
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Moving: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(verbing, Style::default().fg(Color::DarkGray)),
             Span::styled(&action.entity_display, Style::default().fg(Color::White)),
         ]),
         Line::from(""),
@@ -219,3 +227,124 @@ fn render_confirmation(frame: &mut Frame, action: &PendingMoveAction) {
 
     frame.render_widget(dialog, dialog_area);
 }
+
+fn render_duplicate_confirmation(frame: &mut Frame, action: &PendingMoveAction) {
+    let area = frame.area();
+    let dialog_width = 60u16;
+    let dialog_height = match action.entity_type {
+        MoveEntityType::Issue => 12u16,
+        MoveEntityType::Doc => 14u16,
+    };
+
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let target_path = action.target_project_path.as_deref().unwrap_or("Unknown");
+    let target_name = target_path.rsplit('/').next().unwrap_or(target_path);
+    let target_label = if target_path == action.source_project_path {
+        format!("{} (this project)", target_name)
+    } else {
+        target_name.to_string()
+    };
+
+    let mut content = vec![
+        Line::from(Span::styled(
+            "Confirm Duplicate",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Copy ", Style::default().fg(Color::White)),
+            Span::styled(&action.entity_display, Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(vec![
+            Span::styled("to ", Style::default().fg(Color::White)),
+            Span::styled(target_label, Style::default().fg(Color::Green)),
+        ]),
+        Line::from(""),
+    ];
+
+    content.extend(input_lines(
+        "New title",
+        &action.new_title,
+        action.active_field == 0,
+    ));
+    if action.entity_type == MoveEntityType::Doc {
+        content.extend(input_lines(
+            "New slug",
+            &action.new_slug,
+            action.active_field == 1,
+        ));
+    }
+
+    content.push(Line::from(""));
+    let mut hints = Vec::new();
+    if action.duplicate_field_count() > 1 {
+        hints.push(Span::styled("Tab", Style::default().fg(Color::Cyan)));
+        hints.push(Span::styled(
+            " switch  ",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    hints.extend([
+        Span::styled("Enter", Style::default().fg(Color::Cyan)),
+        Span::styled(" duplicate  ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::styled(" back", Style::default().fg(Color::DarkGray)),
+    ]);
+    content.push(Line::from(hints));
+
+    let dialog = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
+
+/// Label line and input line, empty values fall back to the daemon's default
+fn input_lines(label: &str, value: &str, active: bool) -> Vec<Line<'static>> {
+    let label_style = if active {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let mut input = vec![Span::raw("    ")];
+    if value.is_empty() && !active {
+        input.push(Span::styled(
+            "(default)",
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else {
+        input.push(Span::styled(
+            value.to_string(),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if active {
+        input.push(Span::styled("█", Style::default().fg(Color::Cyan)));
+    }
+
+    vec![
+        Line::from(Span::styled(
+            format!("{}{}", if active { "▸ " } else { "  " }, label),
+            label_style,
+        )),
+        Line::from(input),
+    ]
+}
@@ -0,0 +1,113 @@
+//! Organization dialogs - delete confirmation and project assignment picker
+
+use super::base::push_list;
+use crate::state::{Organization, PendingOrgAction};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the pending organization dialog
+pub fn render_org_dialog(
+    frame: &mut Frame,
+    action: &PendingOrgAction,
+    organizations: &[Organization],
+) {
+    let (title, color, content) = match action {
+        PendingOrgAction::Delete { org } => {
+            let affected = match org.project_count {
+                0 => "No projects are assigned to it.".to_string(),
+                1 => "1 project will become ungrouped.".to_string(),
+                n => format!("{} projects will become ungrouped.", n),
+            };
+            (
+                "Delete Organization",
+                Color::Red,
+                vec![
+                    Line::from(vec![
+                        Span::styled("Delete ", Style::default().fg(Color::White)),
+                        Span::styled(org.name.clone(), Style::default().fg(Color::Yellow)),
+                        Span::styled(
+                            format!(" ({})?", org.slug),
+                            Style::default().fg(Color::White),
+                        ),
+                    ]),
+                    Line::from(Span::styled(affected, Style::default().fg(Color::DarkGray))),
+                    Line::from(""),
+                    Line::from(vec![
+                        Span::styled("y/Enter", Style::default().fg(Color::Cyan)),
+                        Span::styled(" delete  ", Style::default().fg(Color::DarkGray)),
+                        Span::styled("n/Esc", Style::default().fg(Color::Cyan)),
+                        Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+                    ]),
+                ],
+            )
+        }
+        PendingOrgAction::Assign {
+            project_name,
+            selected_index,
+            ..
+        } => {
+            let mut content = vec![
+                Line::from(vec![
+                    Span::styled("Organization for ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(project_name.clone(), Style::default().fg(Color::Yellow)),
+                ]),
+                Line::from(""),
+            ];
+            let labels: Vec<String> = std::iter::once("(No organization)".to_string())
+                .chain(
+                    organizations
+                        .iter()
+                        .map(|o| format!("{}  [{}]", o.name, o.slug)),
+                )
+                .collect();
+            push_list(&mut content, &labels, *selected_index);
+            content.push(Line::from(""));
+            content.push(Line::from(vec![
+                Span::styled("↑↓", Style::default().fg(Color::Cyan)),
+                Span::styled(" select  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Enter", Style::default().fg(Color::Cyan)),
+                Span::styled(" assign  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Esc", Style::default().fg(Color::Cyan)),
+                Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+            ]));
+            ("Set Organization", Color::Cyan, content)
+        }
+    };
+
+    let area = frame.area();
+    let dialog_width = 60u16;
+    let dialog_height = content.len() as u16 + 4;
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            title,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(content);
+
+    let dialog = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
@@ -0,0 +1,81 @@
+//! Register project dialog - directory input, then optional initialization prompt
+
+use super::base::path_input_lines;
+use crate::state::PendingRegisterAction;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the pending register-project dialog
+pub fn render_register_dialog(frame: &mut Frame, action: &PendingRegisterAction) {
+    let (title, content) = match action {
+        PendingRegisterAction::Path { input, error } => {
+            let mut content = path_input_lines("Project directory:", input);
+            if let Some(error) = error {
+                content.insert(
+                    2,
+                    Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))),
+                );
+            }
+            ("Register Project", content)
+        }
+        PendingRegisterAction::ConfirmInit { project_path } => (
+            "Initialize Project",
+            vec![
+                Line::from(vec![
+                    Span::styled(project_path.clone(), Style::default().fg(Color::Yellow)),
+                    Span::styled(" has no .centy folder.", Style::default().fg(Color::White)),
+                ]),
+                Line::from(Span::styled(
+                    "Initialize it now?",
+                    Style::default().fg(Color::White),
+                )),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("y/Enter", Style::default().fg(Color::Cyan)),
+                    Span::styled(" initialize  ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("n/Esc", Style::default().fg(Color::Cyan)),
+                    Span::styled(" skip", Style::default().fg(Color::DarkGray)),
+                ]),
+            ],
+        ),
+    };
+
+    let area = frame.area();
+    let dialog_width = 64u16;
+    let dialog_height = content.len() as u16 + 4;
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(content);
+
+    let dialog = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
@@ -0,0 +1,125 @@
+//! Rename project dialog - personal (user) title or shared project title
+
+use crate::state::{PendingRenameAction, TitleScope};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the project rename dialog
+pub fn render_rename_dialog(frame: &mut Frame, action: &PendingRenameAction) {
+    let dialog_width = 64u16;
+    let dialog_height = 14u16;
+
+    let area = frame.area();
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let mut content = vec![
+        Line::from(Span::styled(
+            "Rename Project",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Shown as: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                action.effective_name().to_string(),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(
+                format!("  (folder: {})", action.name),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    content.extend(title_input(
+        "Personal title",
+        "only you, stored in ~/.centy",
+        &action.user_title,
+        action.scope == TitleScope::User,
+    ));
+    content.extend(title_input(
+        "Project title",
+        "shared, committed in .centy/project.json",
+        &action.project_title,
+        action.scope == TitleScope::Project,
+    ));
+
+    content.push(Line::from(""));
+    content.push(Line::from(vec![
+        Span::styled("Tab", Style::default().fg(Color::Cyan)),
+        Span::styled(" switch  ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Enter", Style::default().fg(Color::Cyan)),
+        Span::styled(
+            " save (empty clears)  ",
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled("^U", Style::default().fg(Color::Cyan)),
+        Span::styled(" clear  ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+    ]));
+
+    let dialog = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
+
+/// Label line and input line for one title scope
+fn title_input(label: &str, scope: &str, value: &str, active: bool) -> Vec<Line<'static>> {
+    let (prefix, label_style) = if active {
+        (
+            "▸ ",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        ("  ", Style::default().fg(Color::White))
+    };
+
+    let mut input = vec![Span::raw("    ")];
+    if value.is_empty() && !active {
+        input.push(Span::styled(
+            "(not set)",
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else {
+        input.push(Span::styled(
+            value.to_string(),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if active {
+        input.push(Span::styled("█", Style::default().fg(Color::Cyan)));
+    }
+
+    vec![
+        Line::from(vec![
+            Span::styled(format!("{}{}", prefix, label), label_style),
+            Span::styled(format!("  {}", scope), Style::default().fg(Color::DarkGray)),
+        ]),
+        Line::from(input),
+    ]
+}
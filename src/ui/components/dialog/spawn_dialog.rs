@@ -0,0 +1,101 @@
+//! Spawn agent dialog - issue, action, agent and extra CLI arguments
+
+use crate::state::PendingSpawnAction;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the spawn agent dialog
+pub fn render_spawn_dialog(frame: &mut Frame, action: &PendingSpawnAction) {
+    let agent = match action.agent_name() {
+        "" => "(default agent)",
+        name => name,
+    };
+
+    let mut content = vec![
+        Line::from(Span::styled(
+            "Spawn Agent",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    content.push(field_line("Issue", &action.issue, action.field == 0, true));
+    content.push(field_line(
+        "Action",
+        &format!("◀ {} ▶", action.action.label()),
+        action.field == 1,
+        false,
+    ));
+    content.push(field_line(
+        "Agent",
+        &format!("◀ {} ▶", agent),
+        action.field == 2,
+        false,
+    ));
+    content.push(field_line(
+        "Extra args",
+        &action.extra_args,
+        action.field == 3,
+        true,
+    ));
+    content.push(Line::from(""));
+    content.push(Line::from(vec![
+        Span::styled("Tab", Style::default().fg(Color::Cyan)),
+        Span::styled(" next  ", Style::default().fg(Color::DarkGray)),
+        Span::styled("←/→", Style::default().fg(Color::Cyan)),
+        Span::styled(" change  ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Enter", Style::default().fg(Color::Cyan)),
+        Span::styled(" spawn  ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+    ]));
+
+    let area = frame.area();
+    let dialog_width = 64u16;
+    let dialog_height = content.len() as u16 + 2;
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width.min(area.width),
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let dialog = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::new().bg(Color::Black).fg(Color::White));
+
+    frame.render_widget(dialog, dialog_area);
+}
+
+/// One labelled field; text fields get a cursor when focused
+fn field_line(label: &str, value: &str, active: bool, is_text: bool) -> Line<'static> {
+    let label_style = if active {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let mut spans = vec![
+        Span::raw(if active { "▸ " } else { "  " }),
+        Span::styled(format!("{:<11}", label), label_style),
+        Span::styled(value.to_string(), Style::default().fg(Color::Yellow)),
+    ];
+    if active && is_text {
+        spans.push(Span::styled("█", Style::default().fg(Color::Cyan)));
+    }
+    Line::from(spans)
+}
@@ -6,7 +6,10 @@ mod vertical_button_group;
 
 pub use button::{render_action_button, BUTTON_HEIGHT};
 pub use dialog::{
-    render_error_dialog, render_move_dialog, render_start_work_dialog, render_worktree_dialog,
+    confirm_dialog_buttons, render_agent_dialog, render_asset_dialog, render_confirm_dialog,
+    render_error_dialog, render_jump_dialog, render_link_dialog, render_move_dialog,
+    render_org_dialog, render_register_dialog, render_rename_dialog, render_spawn_dialog,
+    render_start_work_dialog, render_unlink_dialog, render_worktree_dialog,
 };
 pub use vertical_button_group::{
     render_vertical_button_group, ButtonGroupItem, VerticalButtonGroupConfig,
@@ -0,0 +1,296 @@
+//! Project config editor view
+
+use super::config_panel::hex_color;
+use crate::app::App;
+use crate::state::{ConfigEditor, ConfigSection};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the config editor: section tabs, the selected section and the input/help line
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(editor) = &app.state.config_editor else {
+        return;
+    };
+    let project_name = app
+        .state
+        .selected_project_path
+        .as_ref()
+        .and_then(|p| p.split('/').next_back())
+        .unwrap_or("Project");
+
+    let mut title = vec![Span::raw(format!(" Edit Config - {} ", project_name))];
+    if editor.dirty {
+        title.push(Span::styled(
+            "● unsaved ",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    let block = Block::default()
+        .title(Line::from(title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(0),
+            Constraint::Length(if editor.input.is_some() { 3 } else { 1 }),
+        ])
+        .split(inner);
+
+    frame.render_widget(Paragraph::new(section_tabs(editor.section)), chunks[0]);
+    frame.render_widget(
+        Paragraph::new(section_lines(editor)).wrap(Wrap { trim: false }),
+        chunks[1],
+    );
+
+    match &editor.input {
+        Some(input) => {
+            let field = Paragraph::new(Line::from(vec![
+                Span::styled(input.value.clone(), Style::default().fg(Color::Yellow)),
+                Span::styled("█", Style::default().fg(Color::Cyan)),
+            ]))
+            .block(
+                Block::default()
+                    .title(format!(
+                        " {} (Enter to apply, Esc to cancel) ",
+                        input.target.label()
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            );
+            frame.render_widget(field, chunks[2]);
+        }
+        None if editor.confirm_discard => {
+            let prompt = Paragraph::new(Line::from(vec![
+                Span::styled(
+                    "Discard unsaved config changes? ",
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled("y", Style::default().fg(Color::Cyan)),
+                Span::styled(" discard  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("n", Style::default().fg(Color::Cyan)),
+                Span::styled(" keep editing", Style::default().fg(Color::DarkGray)),
+            ]));
+            frame.render_widget(prompt, chunks[2]);
+        }
+        None => {
+            let help = Paragraph::new(Span::styled(
+                format!(
+                    "{}  Tab:section  {}:save  Esc:cancel",
+                    section_help(editor.section),
+                    crate::platform::SAVE_SHORTCUT
+                ),
+                Style::default().fg(Color::DarkGray),
+            ));
+            frame.render_widget(help, chunks[2]);
+        }
+    }
+}
+
+fn section_tabs(current: ConfigSection) -> Line<'static> {
+    let mut spans = Vec::new();
+    for section in ConfigSection::ALL {
+        let style = if section == current {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        spans.push(Span::styled(format!(" {} ", section.label()), style));
+        spans.push(Span::raw(" "));
+    }
+    Line::from(spans)
+}
+
+fn section_help(section: ConfigSection) -> &'static str {
+    match section {
+        ConfigSection::General => "←/→:change",
+        ConfigSection::States => "a:add  e:rename  x:remove  J/K:reorder  ←/→:color  Space:default",
+        ConfigSection::CustomFields => {
+            "a:add  ←/→:type  Space:required  e:default  v:enum values  x:remove  J/K:reorder"
+        }
+        ConfigSection::LinkTypes => "a:add  e:inverse  d:description  x:remove  J/K:reorder",
+        ConfigSection::Llm => "Space:toggle",
+    }
+}
+
+/// Row prefix and name style for the selected row
+fn row_style(selected: bool) -> (&'static str, Style) {
+    if selected {
+        (
+            "▸ ",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        ("  ", Style::default().fg(Color::White))
+    }
+}
+
+fn empty_line(text: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("  {}", text),
+        Style::default().fg(Color::DarkGray),
+    ))
+}
+
+fn section_lines(editor: &ConfigEditor) -> Vec<Line<'static>> {
+    let config = &editor.draft;
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = Vec::new();
+
+    match editor.section {
+        ConfigSection::General => {
+            let rows = [
+                ("Priority levels", config.priority_levels.to_string()),
+                ("Default state", config.default_state.clone()),
+            ];
+            for (idx, (label, value)) in rows.into_iter().enumerate() {
+                let (prefix, style) = row_style(idx == editor.selected);
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{}{:<18}", prefix, label), style),
+                    Span::styled("◂ ", dim),
+                    Span::styled(value, Style::default().fg(Color::Yellow)),
+                    Span::styled(" ▸", dim),
+                ]));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<18}", "Version"), dim),
+                Span::styled(
+                    format!("{}  (change it in the Version panel)", config.version),
+                    dim,
+                ),
+            ]));
+        }
+        ConfigSection::States => {
+            if config.allowed_states.is_empty() {
+                lines.push(empty_line("No states. Press 'a' to add one."));
+            }
+            for (idx, state) in config.allowed_states.iter().enumerate() {
+                let (prefix, style) = row_style(idx == editor.selected);
+                let color = config.state_colors.get(state);
+                let swatch = match color.and_then(|c| hex_color(c)) {
+                    Some(c) => Span::styled("● ", Style::default().fg(c)),
+                    None => Span::styled("○ ", dim),
+                };
+                let mut spans = vec![
+                    Span::raw(prefix),
+                    swatch,
+                    Span::styled(state.clone(), style),
+                ];
+                spans.push(Span::styled(
+                    format!("  {}", color.map(String::as_str).unwrap_or("no color")),
+                    dim,
+                ));
+                if *state == config.default_state {
+                    spans.push(Span::styled(
+                        "  (default)",
+                        Style::default().fg(Color::Green),
+                    ));
+                }
+                lines.push(Line::from(spans));
+            }
+        }
+        ConfigSection::CustomFields => {
+            if config.custom_fields.is_empty() {
+                lines.push(empty_line("No custom fields. Press 'a' to add one."));
+            }
+            for (idx, field) in config.custom_fields.iter().enumerate() {
+                let (prefix, style) = row_style(idx == editor.selected);
+                let mut spans = vec![
+                    Span::styled(format!("{}{}", prefix, field.name), style),
+                    Span::styled(
+                        format!("  [{}]", field.field_type),
+                        Style::default().fg(Color::Magenta),
+                    ),
+                ];
+                if field.required {
+                    spans.push(Span::styled("  required", Style::default().fg(Color::Red)));
+                }
+                if !field.default_value.is_empty() {
+                    spans.push(Span::styled(
+                        format!("  default: {}", field.default_value),
+                        dim,
+                    ));
+                }
+                lines.push(Line::from(spans));
+                if field.field_type == "enum" {
+                    let values = if field.enum_values.is_empty() {
+                        "(no values, press 'v')".to_string()
+                    } else {
+                        field.enum_values.join(", ")
+                    };
+                    lines.push(Line::from(Span::styled(
+                        format!("      values: {}", values),
+                        dim,
+                    )));
+                }
+            }
+        }
+        ConfigSection::LinkTypes => {
+            if config.custom_link_types.is_empty() {
+                lines.push(empty_line("No custom link types. Press 'a' to add one."));
+            }
+            for (idx, link_type) in config.custom_link_types.iter().enumerate() {
+                let (prefix, style) = row_style(idx == editor.selected);
+                let inverse = if link_type.inverse.is_empty() {
+                    "(no inverse)".to_string()
+                } else {
+                    link_type.inverse.clone()
+                };
+                let mut spans = vec![
+                    Span::styled(format!("{}{}", prefix, link_type.name), style),
+                    Span::styled(" ⇄ ", dim),
+                    Span::styled(inverse, Style::default().fg(Color::Yellow)),
+                ];
+                if !link_type.description.is_empty() {
+                    spans.push(Span::styled(format!("  {}", link_type.description), dim));
+                }
+                lines.push(Line::from(spans));
+            }
+        }
+        ConfigSection::Llm => {
+            let flags = [
+                (
+                    "Close issues when an agent marks them complete",
+                    config.llm.auto_close_on_complete,
+                ),
+                (
+                    "Move issues to in-progress when an agent starts",
+                    config.llm.update_status_on_start,
+                ),
+                (
+                    "Let agents edit issue files directly",
+                    config.llm.allow_direct_edits,
+                ),
+            ];
+            for (idx, (label, enabled)) in flags.into_iter().enumerate() {
+                let (prefix, style) = row_style(idx == editor.selected);
+                let checkbox = if enabled {
+                    Span::styled("[x] ", Style::default().fg(Color::Green))
+                } else {
+                    Span::styled("[ ] ", dim)
+                };
+                lines.push(Line::from(vec![
+                    Span::raw(prefix),
+                    checkbox,
+                    Span::styled(label, style),
+                ]));
+            }
+        }
+    }
+    lines
+}
@@ -48,11 +48,85 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
             Style::default().fg(Color::Cyan),
         )));
         for state in &config.allowed_states {
+            let bullet_color = config
+                .state_colors
+                .get(state)
+                .and_then(|c| hex_color(c))
+                .unwrap_or(Color::Cyan);
             content.push(Line::from(vec![
-                Span::styled("  • ", Style::default().fg(Color::Cyan)),
+                Span::styled("  • ", Style::default().fg(bullet_color)),
                 Span::raw(state),
             ]));
         }
+
+        if !config.custom_fields.is_empty() {
+            content.push(Line::from(""));
+            content.push(Line::from(Span::styled(
+                "Custom Fields",
+                Style::default().fg(Color::Cyan),
+            )));
+            for field in &config.custom_fields {
+                let mut spans = vec![
+                    Span::styled("  • ", Style::default().fg(Color::Cyan)),
+                    Span::raw(&field.name),
+                    Span::styled(
+                        format!(" [{}]", field.field_type),
+                        Style::default().fg(Color::Magenta),
+                    ),
+                ];
+                if field.required {
+                    spans.push(Span::styled(" required", Style::default().fg(Color::Red)));
+                }
+                if !field.enum_values.is_empty() {
+                    spans.push(Span::styled(
+                        format!(" ({})", field.enum_values.join(", ")),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                content.push(Line::from(spans));
+            }
+        }
+
+        if !config.custom_link_types.is_empty() {
+            content.push(Line::from(""));
+            content.push(Line::from(Span::styled(
+                "Link Types",
+                Style::default().fg(Color::Cyan),
+            )));
+            for link_type in &config.custom_link_types {
+                content.push(Line::from(vec![
+                    Span::styled("  • ", Style::default().fg(Color::Cyan)),
+                    Span::raw(&link_type.name),
+                    Span::styled(" ⇄ ", Style::default().fg(Color::DarkGray)),
+                    Span::raw(&link_type.inverse),
+                ]));
+            }
+        }
+
+        content.push(Line::from(""));
+        content.push(Line::from(Span::styled(
+            "LLM",
+            Style::default().fg(Color::Cyan),
+        )));
+        for (label, enabled) in [
+            ("Auto-close on complete", config.llm.auto_close_on_complete),
+            ("Update status on start", config.llm.update_status_on_start),
+            ("Allow direct edits", config.llm.allow_direct_edits),
+        ] {
+            content.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}: ", label),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(if enabled { "yes" } else { "no" }),
+            ]));
+        }
+
+        content.push(Line::from(""));
+        content.push(Line::from(Span::styled(
+            "Press 'e' to edit.",
+            Style::default().fg(Color::DarkGray),
+        )));
     } else {
         content.push(Line::from(Span::styled(
             "No configuration loaded.",
@@ -76,3 +150,13 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
 
     frame.render_widget(paragraph, area);
 }
+
+/// Parse a "#rrggbb" color from the project config
+pub fn hex_color(hex: &str) -> Option<Color> {
+    let hex = hex.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |range| u8::from_str_radix(hex.get(range)?, 16).ok();
+    Some(Color::Rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?))
+}
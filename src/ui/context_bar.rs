@@ -74,10 +74,21 @@ pub fn draw_context_bar(frame: &mut Frame, area: Rect, app: &mut App) {
     // Store segments for mouse handling
     app.state.context_bar_segments = segments;
 
-    // Create block with borders
-    let block = Block::default()
+    // Create block with borders; degraded mode is flagged on the bottom border
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::DarkGray));
+    if let Some(message) = app.state.degraded_mode_message() {
+        block = block
+            .border_style(Style::default().fg(Color::Red))
+            .title_bottom(
+                Line::from(Span::styled(
+                    format!(" ⚠ {} ", message),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ))
+                .centered(),
+            );
+    }
 
     // Render paragraph inside block (no Alignment::Center - we handle centering manually)
     let line = Line::from(spans);
@@ -283,12 +294,125 @@ fn build_breadcrumbs(app: &App) -> Vec<(String, View)> {
                 breadcrumbs.push((person.name, View::PersonDetail));
             }
         }
+        View::PersonCreate | View::PersonEdit | View::UserSync => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("People".to_string(), View::People));
+            let label = match &app.state.current_view {
+                View::PersonCreate => "New".to_string(),
+                View::UserSync => "Sync".to_string(),
+                _ => app
+                    .state
+                    .view_params
+                    .person_id
+                    .as_ref()
+                    .map(|id| format!("Edit {}", id))
+                    .unwrap_or_else(|| "Edit".to_string()),
+            };
+            breadcrumbs.push((label, app.state.current_view.clone()));
+        }
         View::Config => {
             if let Some(project) = project_info {
                 add_org_project_breadcrumbs(&mut breadcrumbs, project);
             }
             breadcrumbs.push(("Config".to_string(), View::Config));
         }
+        View::ConfigEdit => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("Config".to_string(), View::Config));
+            breadcrumbs.push(("Edit".to_string(), View::ConfigEdit));
+        }
+        View::Version => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("Config".to_string(), View::Config));
+            breadcrumbs.push(("Version".to_string(), View::Version));
+        }
+        View::Assets => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("Shared Assets".to_string(), View::Assets));
+        }
+        View::AgentActivity => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("Agent Activity".to_string(), View::AgentActivity));
+        }
+        View::Workspaces => {
+            breadcrumbs.push(("Projects".to_string(), View::Projects));
+            breadcrumbs.push(("Workspaces".to_string(), View::Workspaces));
+        }
+        View::Daemon => {
+            breadcrumbs.push(("Projects".to_string(), View::Projects));
+            breadcrumbs.push(("Daemon".to_string(), View::Daemon));
+        }
+        View::Agents | View::AgentCreate | View::AgentEdit => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("Config".to_string(), View::Config));
+            breadcrumbs.push(("Agents".to_string(), View::Agents));
+            match &app.state.current_view {
+                View::AgentCreate => breadcrumbs.push(("New".to_string(), View::AgentCreate)),
+                View::AgentEdit => {
+                    if let Some(name) = &app.state.view_params.agent_name {
+                        breadcrumbs.push((name.clone(), View::AgentEdit));
+                    }
+                }
+                _ => {}
+            }
+        }
+        View::Features => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("Features".to_string(), View::Features));
+        }
+        View::CompactEdit | View::MigrationCreate => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("Features".to_string(), View::Features));
+            let label = if app.state.current_view == View::CompactEdit {
+                "compact.md"
+            } else {
+                "New Migration"
+            };
+            breadcrumbs.push((label.to_string(), app.state.current_view.clone()));
+        }
+        View::Search => {
+            if let Some(project) = project_info {
+                add_org_project_breadcrumbs(&mut breadcrumbs, project);
+            }
+            breadcrumbs.push(("Search".to_string(), View::Search));
+        }
+        View::Organizations => {
+            breadcrumbs.push(("Projects".to_string(), View::Projects));
+            breadcrumbs.push(("Organizations".to_string(), View::Organizations));
+        }
+        View::OrganizationCreate => {
+            breadcrumbs.push(("Projects".to_string(), View::Projects));
+            breadcrumbs.push(("Organizations".to_string(), View::Organizations));
+            breadcrumbs.push(("New".to_string(), View::OrganizationCreate));
+        }
+        View::OrganizationEdit => {
+            breadcrumbs.push(("Projects".to_string(), View::Projects));
+            breadcrumbs.push(("Organizations".to_string(), View::Organizations));
+            if let Some(slug) = &app.state.view_params.organization_slug {
+                breadcrumbs.push((slug.clone(), View::OrganizationEdit));
+            }
+            breadcrumbs.push(("Edit".to_string(), View::OrganizationEdit));
+        }
+        View::InitWizard => {
+            breadcrumbs.push(("Projects".to_string(), View::Projects));
+            breadcrumbs.push(("Initialize".to_string(), View::InitWizard));
+        }
     }
 
     breadcrumbs
@@ -0,0 +1,34 @@
+//! Custom fields section shared by the issue and PR detail views
+
+use crate::app::App;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use std::collections::HashMap;
+
+/// Build the lines for the Custom Fields section; empty when nothing is set
+pub fn custom_field_lines(app: &App, values: &HashMap<String, String>) -> Vec<Line<'static>> {
+    let rows = app.state.custom_field_rows(values);
+    if rows.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![Line::from(Span::styled(
+        "Custom Fields",
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    for (name, value) in rows {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {}: ", name),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::raw(value),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines
+}
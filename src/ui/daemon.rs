@@ -0,0 +1,129 @@
+//! Daemon panel (connection status, daemon info, restart and shutdown)
+
+use crate::app::App;
+use crate::state::format_uptime;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the daemon panel
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let state = &app.state;
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::DarkGray));
+    let flag = |present: bool| {
+        if present {
+            Span::styled("✓", Style::default().fg(Color::Green))
+        } else {
+            Span::styled("✗", Style::default().fg(Color::DarkGray))
+        }
+    };
+
+    let status = match &state.daemon_reconnect {
+        Some(reconnect) if reconnect.attempts > 0 => Span::styled(
+            format!("Restarting... (reconnect attempt {})", reconnect.attempts),
+            Style::default().fg(Color::Yellow),
+        ),
+        Some(_) => Span::styled("Restarting...", Style::default().fg(Color::Yellow)),
+        None if state.daemon_connected => Span::styled(
+            "● Connected",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+        None => Span::styled(
+            "○ Disconnected",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+    };
+
+    let mut content = vec![
+        Line::from(vec![label("Status:     "), status]),
+        Line::from(vec![
+            label("Address:    "),
+            Span::raw(state.daemon_address.clone()),
+        ]),
+    ];
+
+    match state
+        .daemon_info
+        .as_ref()
+        .filter(|_| state.daemon_connected)
+    {
+        Some(info) => {
+            let uptime = match state.daemon_seen_since {
+                Some(since) => {
+                    let uptime = format_uptime(since.elapsed().as_secs());
+                    if state.daemon_start_observed {
+                        uptime
+                    } else {
+                        format!("at least {} (since this session connected)", uptime)
+                    }
+                }
+                None => "unknown".to_string(),
+            };
+            let binary_path = if info.binary_path.is_empty() {
+                "unknown".to_string()
+            } else {
+                info.binary_path.clone()
+            };
+            content.push(Line::from(vec![
+                label("Version:    "),
+                Span::styled(info.version.clone(), Style::default().fg(Color::Yellow)),
+            ]));
+            content.push(Line::from(vec![label("Uptime:     "), Span::raw(uptime)]));
+            content.push(Line::from(vec![
+                label("Binary:     "),
+                Span::raw(binary_path),
+            ]));
+            content.push(Line::from(vec![
+                label("VS Code:    "),
+                flag(info.vscode_available),
+            ]));
+            content.push(Line::from(vec![
+                label("Projects:   "),
+                Span::raw(info.project_count.to_string()),
+            ]));
+        }
+        None if state.daemon_connected => content.push(Line::from(Span::styled(
+            "Loading...",
+            Style::default().fg(Color::DarkGray),
+        ))),
+        None => content.push(Line::from(Span::styled(
+            "The daemon is not reachable. Start it with `centy daemon start`, then press 'r'.",
+            Style::default().fg(Color::DarkGray),
+        ))),
+    }
+
+    content.push(Line::from(""));
+    content.push(Line::from(Span::styled(
+        "Controls",
+        Style::default().fg(Color::Cyan),
+    )));
+    content.push(Line::from(vec![
+        label("Delay:      "),
+        Span::styled("◂ ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{}s", state.daemon_delay_seconds),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" ▸", Style::default().fg(Color::DarkGray)),
+        label("  applies to restart and shutdown"),
+    ]));
+
+    let paragraph = Paragraph::new(content)
+        .block(
+            Block::default()
+                .title(" Daemon ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
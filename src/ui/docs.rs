@@ -156,6 +156,12 @@ fn draw_doc_detail_content(frame: &mut Frame, area: Rect, app: &App) {
             Span::raw(doc.updated_at.format("%Y-%m-%d %H:%M").to_string()),
         ]),
         Line::from(""),
+    ];
+
+    // Links
+    content.extend(super::links::link_lines(app));
+    content.extend([
+        Line::from(""),
         // Separator
         Line::from(Span::styled(
             "─".repeat(40),
@@ -170,7 +176,7 @@ fn draw_doc_detail_content(frame: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
-    ];
+    ]);
 
     // Add content lines
     for line in doc.content.lines() {
@@ -0,0 +1,191 @@
+//! Features view (compaction workflow: status, uncompacted issues, documents)
+
+use super::render_scrollable_list;
+use crate::app::App;
+use crate::state::FeatureDocument;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the features view: status counters, uncompacted issues and the document pane
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    draw_status(frame, chunks[0], app);
+
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(chunks[1]);
+
+    draw_uncompacted(frame, panes[0], app);
+    draw_document(frame, panes[1], app);
+}
+
+/// Status counters from GetFeatureStatus
+fn draw_status(frame: &mut Frame, area: Rect, app: &App) {
+    let project_name = app
+        .state
+        .selected_project_path
+        .as_ref()
+        .and_then(|p| p.split('/').next_back())
+        .unwrap_or("Project");
+
+    let line = match &app.state.feature_status {
+        Some(status) if !status.initialized => Line::from(Span::styled(
+            "Features are not initialized for this project.",
+            Style::default().fg(Color::Yellow),
+        )),
+        Some(status) => {
+            let flag = |present: bool| {
+                if present {
+                    Span::styled("✓", Style::default().fg(Color::Green))
+                } else {
+                    Span::styled("✗", Style::default().fg(Color::Red))
+                }
+            };
+            Line::from(vec![
+                Span::styled("Uncompacted: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    status.uncompacted_count.to_string(),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled("  Migrations: ", Style::default().fg(Color::DarkGray)),
+                Span::raw(status.migration_count.to_string()),
+                Span::styled("  instruction.md ", Style::default().fg(Color::DarkGray)),
+                flag(status.has_instruction),
+                Span::styled("  compact.md ", Style::default().fg(Color::DarkGray)),
+                flag(status.has_compact),
+            ])
+        }
+        None => Line::from(Span::styled(
+            "Loading...",
+            Style::default().fg(Color::DarkGray),
+        )),
+    };
+
+    let paragraph = Paragraph::new(line).block(
+        Block::default()
+            .title(format!(" Features - {} ", project_name))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Uncompacted issues with multi-select checkboxes
+fn draw_uncompacted(frame: &mut Frame, area: Rect, app: &App) {
+    let marked = app.state.marked_uncompacted_ids.len();
+    let mut block = Block::default()
+        .title(format!(
+            " Uncompacted Issues ({}, {} selected) ",
+            app.state.uncompacted_issues.len(),
+            marked
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if app.state.confirm_mark_compacted {
+        block = block
+            .border_style(Style::default().fg(Color::Yellow))
+            .title_bottom(Line::from(vec![
+                Span::styled(
+                    format!(" Mark {} issue(s) as compacted? ", marked),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled("y", Style::default().fg(Color::Cyan)),
+                Span::styled("/", Style::default().fg(Color::DarkGray)),
+                Span::styled("n ", Style::default().fg(Color::Cyan)),
+            ]));
+    }
+
+    if app.state.uncompacted_issues.is_empty() {
+        let message = Paragraph::new("All issues are compacted.")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(message, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .state
+        .uncompacted_issues
+        .iter()
+        .enumerate()
+        .map(|(idx, issue)| {
+            let is_selected = idx == app.state.selected_uncompacted_index;
+            let checkbox = if app.state.is_uncompacted_marked(&issue.id) {
+                Span::styled("[x] ", Style::default().fg(Color::Green))
+            } else {
+                Span::styled("[ ] ", Style::default().fg(Color::DarkGray))
+            };
+            let title_style = if is_selected {
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .bg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(if is_selected { "▸ " } else { "  " }),
+                checkbox,
+                Span::styled(
+                    format!("#{} ", issue.display_number),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(issue.title.clone(), title_style),
+                Span::styled(
+                    format!("  {}", issue.metadata.status),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    render_scrollable_list(frame, area, list, app.state.selected_uncompacted_index);
+}
+
+/// instruction.md or compact.md, toggled with Tab
+fn draw_document(frame: &mut Frame, area: Rect, app: &App) {
+    let (title, content) = match app.state.feature_document {
+        FeatureDocument::Instruction => (" instruction.md ", Some(&app.state.feature_instruction)),
+        FeatureDocument::Compact => (" compact.md ", app.state.feature_compact.as_ref()),
+    };
+
+    let lines: Vec<Line> = match content {
+        Some(content) if !content.is_empty() => {
+            content.lines().map(|l| Line::from(l.to_string())).collect()
+        }
+        Some(_) => vec![Line::from(Span::styled(
+            "(empty)",
+            Style::default().fg(Color::DarkGray),
+        ))],
+        None => vec![Line::from(Span::styled(
+            "compact.md doesn't exist yet. Press 'e' to create it.",
+            Style::default().fg(Color::DarkGray),
+        ))],
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .title_bottom(Line::from(Span::styled(
+                    " Tab:switch document ",
+                    Style::default().fg(Color::DarkGray),
+                )))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((app.state.scroll_offset as u16, 0));
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,83 @@
+//! Agent form rendering (create and edit)
+
+use super::field_renderer::draw_field_with_value;
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Draw agent create form
+pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
+    draw_form(frame, area, app, " New Agent ");
+}
+
+/// Draw agent edit form
+pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
+    let title = app
+        .state
+        .view_params
+        .agent_name
+        .as_ref()
+        .map(|name| format!(" Edit Agent: {} ", name))
+        .unwrap_or_else(|| " Edit Agent ".to_string());
+    draw_form(frame, area, app, &title);
+}
+
+/// Draw the name, type, command, args and template fields
+fn draw_form(frame: &mut Frame, area: Rect, app: &App, title: &str) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Name
+            Constraint::Length(3), // Type
+            Constraint::Length(3), // Command
+            Constraint::Length(3), // Default args
+            Constraint::Length(3), // Plan template
+            Constraint::Length(3), // Implement template
+            Constraint::Min(0),
+            Constraint::Length(2), // Help text
+        ])
+        .margin(1)
+        .split(area);
+
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    frame.render_widget(block, area);
+
+    let field = app.state.active_form_field;
+    let agent_type = format!("◀ {} ▶", app.state.form_agent_type.label());
+    let fields: [(&str, &str); 6] = [
+        ("Name", &app.state.form_title),
+        ("Type (←/→ to change)", &agent_type),
+        ("Command (must be on PATH)", &app.state.form_agent_command),
+        ("Default args (shell quoting)", &app.state.form_agent_args),
+        ("Plan template (optional)", &app.state.form_plan_template),
+        (
+            "Implement template (optional)",
+            &app.state.form_implement_template,
+        ),
+    ];
+    for (idx, (label, value)) in fields.iter().enumerate() {
+        draw_field_with_value(frame, chunks[idx], label, value, field == idx, false);
+    }
+
+    let help_spans = vec![
+        Span::styled("Tab", Style::default().fg(Color::Cyan)),
+        Span::raw(": next field  "),
+        Span::styled(
+            crate::platform::SAVE_SHORTCUT,
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(": save  "),
+        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::raw(": cancel"),
+    ];
+    let help = Paragraph::new(Line::from(help_spans)).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help, chunks[7]);
+}
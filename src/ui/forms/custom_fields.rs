@@ -0,0 +1,34 @@
+//! Custom field inputs shared by the issue and PR forms (defined in the project config)
+
+use super::field_renderer::draw_typed_field;
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    Frame,
+};
+
+/// Height of the custom fields section, one row per field
+pub fn custom_fields_height(app: &App) -> u16 {
+    3 * app.state.custom_field_definitions().len() as u16
+}
+
+/// Draw one input per custom field; the first field has form index `offset`
+pub fn draw_custom_fields(frame: &mut Frame, area: Rect, app: &App, offset: usize) {
+    for (idx, row) in custom_field_areas(area, app).into_iter().enumerate() {
+        draw_typed_field(frame, row, app, offset + idx);
+    }
+}
+
+/// One area per custom field, in form order
+pub fn custom_field_areas(area: Rect, app: &App) -> Vec<Rect> {
+    let count = app.state.custom_field_definitions().len();
+    if count == 0 {
+        return Vec::new();
+    }
+
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(3); count])
+        .split(area)
+        .to_vec()
+}
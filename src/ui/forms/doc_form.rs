@@ -1,6 +1,6 @@
 //! Doc form rendering (create)
 
-use super::field_renderer::draw_field_with_value;
+use super::field_renderer::{draw_checkbox_field, draw_field_with_value};
 use crate::app::App;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -33,37 +33,9 @@ fn get_org_name(app: &App) -> Option<String> {
         })
 }
 
-/// Draw a checkbox field
-fn draw_checkbox_field(frame: &mut Frame, area: Rect, label: &str, checked: bool, focused: bool) {
-    let checkbox = if checked { "[x]" } else { "[ ]" };
-    let display = format!("{} {}", checkbox, label);
-
-    let style = if focused {
-        Style::default().fg(Color::Yellow)
-    } else {
-        Style::default().fg(Color::White)
-    };
-
-    let border_style = if focused {
-        Style::default().fg(Color::Yellow)
-    } else {
-        Style::default().fg(Color::DarkGray)
-    };
-
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_style(border_style);
-
-    let paragraph = Paragraph::new(display).style(style).block(block);
-    frame.render_widget(paragraph, area);
-}
-
-/// Draw doc create form
-pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
-    let has_org = has_organization(app);
-
-    // Build constraints based on org availability
-    let constraints: Vec<Constraint> = if has_org {
+/// Rows of the create form; the org-wide checkbox only shows for organization projects
+fn create_chunks(area: Rect, app: &App) -> Vec<Rect> {
+    let constraints: Vec<Constraint> = if has_organization(app) {
         vec![
             Constraint::Length(3), // Title
             Constraint::Min(6),    // Content
@@ -80,11 +52,25 @@ pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
         ]
     };
 
-    let chunks = Layout::default()
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints(constraints)
         .margin(1)
-        .split(area);
+        .split(area)
+        .to_vec()
+}
+
+/// Area of each create form field, by form index (the same layout `draw_create` uses)
+pub fn create_field_areas(area: Rect, app: &App) -> Vec<Rect> {
+    let mut areas = create_chunks(area, app);
+    areas.truncate(get_field_count(app));
+    areas
+}
+
+/// Draw doc create form
+pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
+    let has_org = has_organization(app);
+    let chunks = create_chunks(area, app);
 
     let block = Block::default()
         .title(" Create Doc ")
@@ -180,6 +166,11 @@ pub fn get_field_count(app: &App) -> usize {
     }
 }
 
+/// Area of each edit form field, by form index (the same layout `draw_edit` uses)
+pub fn edit_field_areas(area: Rect) -> Vec<Rect> {
+    super::edit_page::doc_field_areas(area)
+}
+
 /// Draw doc edit form with unified layout
 pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
     use super::edit_page::{draw_edit_page, EditEntityType, EditPageConfig};
@@ -12,6 +12,7 @@
 
 use super::field_renderer::draw_field_with_value;
 use crate::app::App;
+use crate::state::FormField;
 use crate::ui::render_daemon_actions;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -36,8 +37,8 @@ pub struct EditPageConfig {
     pub title: String,
 }
 
-/// Draw the unified edit page layout
-pub fn draw_edit_page(frame: &mut Frame, area: Rect, app: &App, config: &EditPageConfig) {
+/// Split the page into (main content, bottom config, action panel) areas
+fn page_areas(area: Rect) -> (Rect, Rect, Rect) {
     // Main horizontal split: Left sidebar (placeholder) | Main content | Right actions
     let h_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -60,8 +61,20 @@ pub fn draw_edit_page(frame: &mut Frame, area: Rect, app: &App, config: &EditPag
         ])
         .split(center_area);
 
-    let main_content_area = v_chunks[0];
-    let bottom_config_area = v_chunks[1];
+    (v_chunks[0], v_chunks[1], action_area)
+}
+
+/// Area of each doc edit field, by form index: title and content, then the slug
+pub fn doc_field_areas(area: Rect) -> Vec<Rect> {
+    let (main_content_area, bottom_config_area, _) = page_areas(area);
+    let mut areas = doc_content_chunks(main_content_area);
+    areas.push(doc_config_chunks(bottom_config_area)[0]);
+    areas
+}
+
+/// Draw the unified edit page layout
+pub fn draw_edit_page(frame: &mut Frame, area: Rect, app: &App, config: &EditPageConfig) {
+    let (main_content_area, bottom_config_area, action_area) = page_areas(area);
 
     // Draw sections based on entity type
     match config.entity_type {
@@ -146,18 +159,11 @@ fn draw_issue_config_section(frame: &mut Frame, area: Rect, app: &App) {
         .border_style(Style::default().fg(Color::DarkGray));
     frame.render_widget(block, area);
 
-    let priority_display = match app.state.form_priority {
-        0 => "Default".to_string(),
-        p => format!(
-            "{} ({})",
-            p,
-            match p {
-                1 => "high",
-                2 => "medium",
-                _ => "low",
-            }
-        ),
-    };
+    let priority_display =
+        FormField::priority_with_value("priority", "Priority", app.state.form_priority)
+            .with_priority_levels(app.state.priority_levels())
+            .with_priority_name(app.state.form_priority_name.clone())
+            .display_value();
     draw_field_with_value(
         frame,
         chunks[0],
@@ -270,16 +276,22 @@ fn draw_pr_config_section(frame: &mut Frame, area: Rect, app: &App) {
     );
 }
 
-/// Draw doc edit main content (title, content)
-fn draw_doc_edit_content(frame: &mut Frame, area: Rect, app: &App, title: &str) {
-    let chunks = Layout::default()
+/// Title and content rows of the doc edit main content
+fn doc_content_chunks(area: Rect) -> Vec<Rect> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
             Constraint::Min(5),    // Content
         ])
         .margin(1)
-        .split(area);
+        .split(area)
+        .to_vec()
+}
+
+/// Draw doc edit main content (title, content)
+fn draw_doc_edit_content(frame: &mut Frame, area: Rect, app: &App, title: &str) {
+    let chunks = doc_content_chunks(area);
 
     let block = Block::default()
         .title(format!(" {} ", title))
@@ -306,16 +318,22 @@ fn draw_doc_edit_content(frame: &mut Frame, area: Rect, app: &App, title: &str)
     );
 }
 
-/// Draw doc config section (slug)
-fn draw_doc_config_section(frame: &mut Frame, area: Rect, app: &App) {
-    let chunks = Layout::default()
+/// Slug and help rows of the doc config section
+fn doc_config_chunks(area: Rect) -> Vec<Rect> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Slug
             Constraint::Length(2), // Help text
         ])
         .margin(1)
-        .split(area);
+        .split(area)
+        .to_vec()
+}
+
+/// Draw doc config section (slug)
+fn draw_doc_config_section(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = doc_config_chunks(area);
 
     let block = Block::default()
         .title(" Config ")
@@ -0,0 +1,65 @@
+//! Document editors for the features workflow (compact.md and new migrations)
+
+use super::field_renderer::draw_field_with_value;
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Draw the compact.md editor
+pub fn draw_compact_edit(frame: &mut Frame, area: Rect, app: &App) {
+    draw_editor(frame, area, app, " Edit compact.md ", "compact.md");
+}
+
+/// Draw the new migration editor
+pub fn draw_migration_create(frame: &mut Frame, area: Rect, app: &App) {
+    draw_editor(
+        frame,
+        area,
+        app,
+        " New Migration ",
+        "Migration (markdown with YAML frontmatter)",
+    );
+}
+
+/// Single multiline editor backed by the form content buffer
+fn draw_editor(frame: &mut Frame, area: Rect, app: &App, title: &str, label: &str) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(2)])
+        .margin(1)
+        .split(area);
+
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    frame.render_widget(block, area);
+
+    draw_field_with_value(
+        frame,
+        chunks[0],
+        label,
+        &app.state.form_description,
+        true,
+        true,
+    );
+
+    let help_spans = vec![
+        Span::styled("Enter", Style::default().fg(Color::Cyan)),
+        Span::raw(": newline  "),
+        Span::styled(
+            crate::platform::SAVE_SHORTCUT,
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(": save  "),
+        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::raw(": cancel"),
+    ];
+    let help = Paragraph::new(Line::from(help_spans)).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help, chunks[1]);
+}
@@ -1,71 +1,149 @@
 //! Field rendering utilities for forms
 
-#[allow(unused_imports)]
-use crate::state::FormField;
+use crate::app::App;
+use crate::state::{FieldValue, FormField};
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
 
-/// Draw a form field using FormField from the domain layer
-/// Scaffolding for future form refactoring - not yet integrated
-#[allow(dead_code)]
+/// Draw a form field using FormField from the domain layer, picking the renderer for its kind
 pub fn draw_field(frame: &mut Frame, area: Rect, field: &FormField, is_active: bool) {
-    let style = if is_active {
-        Style::default().fg(Color::Cyan)
+    match &field.value {
+        FieldValue::Text(_) | FieldValue::Number(_) => draw_field_with_value(
+            frame,
+            area,
+            &field.label,
+            &field.display_value(),
+            is_active,
+            field.is_multiline,
+        ),
+        FieldValue::Priority(_) | FieldValue::Select { .. } => {
+            draw_select_field(frame, area, &field.label, &field.display_value(), is_active)
+        }
+        FieldValue::Boolean(checked) => {
+            draw_checkbox_field(frame, area, &field.label, *checked, is_active)
+        }
+        FieldValue::MultiSelect {
+            options,
+            selected,
+            cursor,
+        } => {
+            let checked: Vec<bool> = (0..options.len()).map(|i| selected.contains(&i)).collect();
+            let cursor = is_active.then_some(*cursor);
+            draw_multi_select_field(frame, area, &field.label, options, &checked, cursor);
+        }
+    }
+}
+
+/// Draw form field `index` of the current form through its typed field, if it has one
+pub fn draw_typed_field(frame: &mut Frame, area: Rect, app: &App, index: usize) {
+    if let Some(field) = app.state.typed_form_field(index) {
+        draw_field(frame, area, &field, app.state.active_form_field == index);
+    }
+}
+
+/// Draw a single-select or priority picker as "◀ value ▶"
+pub fn draw_select_field(frame: &mut Frame, area: Rect, label: &str, value: &str, is_active: bool) {
+    let (value_style, border_style) = if is_active {
+        (
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            Style::default().fg(Color::Cyan),
+        )
     } else {
-        Style::default().fg(Color::DarkGray)
+        (
+            Style::default().fg(Color::White),
+            Style::default().fg(Color::DarkGray),
+        )
     };
+    let arrow_style = Style::default().fg(Color::DarkGray);
 
-    let border_style = if is_active {
+    let block = Block::default()
+        .title(format!(" {} (←/→ to change) ", label))
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    let paragraph = Paragraph::new(Line::from(vec![
+        Span::styled("◀ ", arrow_style),
+        Span::styled(value.to_string(), value_style),
+        Span::styled(" ▶", arrow_style),
+    ]))
+    .block(block);
+    frame.render_widget(paragraph, area);
+}
+
+/// Draw a multi-select as one checkbox line per option; `cursor` is the highlighted
+/// option when the field is active
+pub fn draw_multi_select_field(
+    frame: &mut Frame,
+    area: Rect,
+    label: &str,
+    options: &[String],
+    checked: &[bool],
+    cursor: Option<usize>,
+) {
+    let border_style = if cursor.is_some() {
         Style::default().fg(Color::Cyan)
     } else {
         Style::default().fg(Color::DarkGray)
     };
+    let count = checked.iter().filter(|c| **c).count();
 
-    let display_value = field.display_value();
-    let display_str = if display_value.is_empty() && !is_active {
-        "(empty)".to_string()
-    } else {
-        display_value
-    };
-
-    let cursor = if is_active { "▌" } else { "" };
-
-    let content = if field.is_multiline {
-        let lines: Vec<Line> = display_str
-            .lines()
-            .map(|l| Line::from(l.to_string()))
-            .collect();
-        let mut lines = lines;
-        if is_active {
-            if let Some(last) = lines.last_mut() {
-                last.spans
-                    .push(Span::styled(cursor, Style::default().fg(Color::Cyan)));
+    let mut lines: Vec<Line> = options
+        .iter()
+        .zip(checked)
+        .enumerate()
+        .map(|(idx, (option, is_checked))| {
+            let highlighted = cursor == Some(idx);
+            let checkbox = if *is_checked {
+                Span::styled("[x] ", Style::default().fg(Color::Green))
             } else {
-                lines.push(Line::from(Span::styled(
-                    cursor,
-                    Style::default().fg(Color::Cyan),
-                )));
-            }
-        }
-        Paragraph::new(lines)
-    } else {
-        Paragraph::new(Line::from(vec![
-            Span::styled(&display_str, style),
-            Span::styled(cursor, Style::default().fg(Color::Cyan)),
-        ]))
-    };
+                Span::styled("[ ] ", Style::default().fg(Color::DarkGray))
+            };
+            let style = if highlighted {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::from(vec![
+                Span::raw(if highlighted { "▸ " } else { "  " }),
+                checkbox,
+                Span::styled(option.clone(), style),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "(no options)",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let scroll = multi_select_scroll(area, cursor);
 
     let block = Block::default()
-        .title(format!(" {} ", field.label))
+        .title(format!(" {} ({} selected) ", label, count))
         .borders(Borders::ALL)
         .border_style(border_style);
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((scroll as u16, 0));
+    frame.render_widget(paragraph, area);
+}
 
-    frame.render_widget(content.wrap(Wrap { trim: false }).block(block), area);
+/// Options scrolled off the top of a multi-select drawn in `area`, keeping the
+/// highlighted option visible in short areas
+pub fn multi_select_scroll(area: Rect, cursor: Option<usize>) -> usize {
+    let visible = area.height.saturating_sub(2) as usize;
+    cursor
+        .filter(|c| visible > 0 && *c >= visible)
+        .map_or(0, |c| c + 1 - visible)
 }
 
 /// Draw a form field with custom display value (for legacy compatibility)
@@ -129,3 +207,34 @@ pub fn draw_field_with_value(
 
     frame.render_widget(content.wrap(Wrap { trim: false }).block(block), area);
 }
+
+/// Draw a checkbox field
+pub fn draw_checkbox_field(
+    frame: &mut Frame,
+    area: Rect,
+    label: &str,
+    checked: bool,
+    focused: bool,
+) {
+    let checkbox = if checked { "[x]" } else { "[ ]" };
+    let display = format!("{} {}", checkbox, label);
+
+    let style = if focused {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let border_style = if focused {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    let paragraph = Paragraph::new(display).style(style).block(block);
+    frame.render_widget(paragraph, area);
+}
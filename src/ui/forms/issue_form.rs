@@ -1,6 +1,8 @@
 //! Issue form rendering (create and edit)
 
-use super::field_renderer::draw_field_with_value;
+use super::custom_fields::{custom_field_areas, custom_fields_height, draw_custom_fields};
+use super::field_renderer::{draw_field_with_value, draw_typed_field};
+use super::template_field::{description_area, draw_template_field, split_for_preview};
 use crate::app::App;
 use crate::ui::components::{render_action_button, BUTTON_HEIGHT};
 use ratatui::{
@@ -13,17 +15,7 @@ use ratatui::{
 
 /// Draw issue create form with action sidebar
 pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
-    // Split into form (left) and action panel (right)
-    let main_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Min(40),    // Form area
-            Constraint::Length(20), // Action panel
-        ])
-        .split(area);
-
-    let form_area = main_chunks[0];
-    let action_area = main_chunks[1];
+    let (form_area, action_area) = create_panels(area);
 
     // Draw form on the left
     draw_create_form(frame, form_area, app);
@@ -32,20 +24,53 @@ pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
     draw_create_action_panel(frame, action_area, app);
 }
 
-/// Draw the form fields
-fn draw_create_form(frame: &mut Frame, area: Rect, app: &App) {
+/// Split the create view into form (left) and action panel (right)
+fn create_panels(area: Rect) -> (Rect, Rect) {
     let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(40),    // Form area
+            Constraint::Length(20), // Action panel
+        ])
+        .split(area);
+    (chunks[0], chunks[1])
+}
+
+/// Rows of the create form
+fn create_form_chunks(area: Rect, app: &App) -> Vec<Rect> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
-            Constraint::Min(6),    // Description
+            Constraint::Min(6),    // Description (and template preview)
             Constraint::Length(3), // Priority
+            Constraint::Length(3), // Template
+            Constraint::Length(custom_fields_height(app)),
         ])
         .margin(1)
-        .split(area);
+        .split(area)
+        .to_vec()
+}
+
+/// Area of each create form field, by form index (the same layout `draw_create` uses)
+pub fn create_field_areas(area: Rect, app: &App) -> Vec<Rect> {
+    let chunks = create_form_chunks(create_panels(area).0, app);
+    let mut areas = vec![
+        chunks[0],
+        description_area(chunks[1], app),
+        chunks[2],
+        chunks[3],
+    ];
+    areas.extend(custom_field_areas(chunks[4], app));
+    areas
+}
 
-    // Form is focused when not on action panel (field 0-2)
-    let form_focused = app.state.active_form_field < 3;
+/// Draw the form fields
+fn draw_create_form(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = create_form_chunks(area, app);
+
+    // Form is focused when not on action panel
+    let form_focused = app.state.active_form_field < app.state.issue_create_buttons_field();
     let border_color = if form_focused {
         Color::Cyan
     } else {
@@ -67,41 +92,26 @@ fn draw_create_form(frame: &mut Frame, area: Rect, app: &App) {
         false,
     );
 
+    let description_area = split_for_preview(frame, chunks[1], app);
     draw_field_with_value(
         frame,
-        chunks[1],
+        description_area,
         "Description",
         &app.state.form_description,
         app.state.active_form_field == 1,
         true,
     );
 
-    let priority_display = match app.state.form_priority {
-        0 => "Default".to_string(),
-        p => format!(
-            "{} ({})",
-            p,
-            match p {
-                1 => "high",
-                2 => "medium",
-                _ => "low",
-            }
-        ),
-    };
-    draw_field_with_value(
-        frame,
-        chunks[2],
-        "Priority (1-3)",
-        &priority_display,
-        app.state.active_form_field == 2,
-        false,
-    );
+    draw_typed_field(frame, chunks[2], app, 2);
+
+    draw_template_field(frame, chunks[3], app, "issues");
+    draw_custom_fields(frame, chunks[4], app, 4);
 }
 
 /// Draw the action panel sidebar
 fn draw_create_action_panel(frame: &mut Frame, area: Rect, app: &App) {
-    // Action panel is focused when on field 3
-    let is_focused = app.state.active_form_field == 3;
+    // Action panel is focused when past the form fields
+    let is_focused = app.state.active_form_field == app.state.issue_create_buttons_field();
     let selected_button = app.state.form_selected_button;
 
     let border_color = if is_focused {
@@ -172,19 +182,35 @@ fn draw_create_action_panel(frame: &mut Frame, area: Rect, app: &App) {
     );
 }
 
-/// Draw issue edit form
-pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
-    let chunks = Layout::default()
+/// Rows of the edit form
+fn edit_chunks(area: Rect, app: &App) -> Vec<Rect> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
             Constraint::Min(5),    // Description
             Constraint::Length(3), // Priority
             Constraint::Length(3), // Status
+            Constraint::Length(3), // Draft
+            Constraint::Length(custom_fields_height(app)),
             Constraint::Length(2), // Help text
         ])
         .margin(1)
-        .split(area);
+        .split(area)
+        .to_vec()
+}
+
+/// Area of each edit form field, by form index (the same layout `draw_edit` uses)
+pub fn edit_field_areas(area: Rect, app: &App) -> Vec<Rect> {
+    let chunks = edit_chunks(area, app);
+    let mut areas = chunks[..5].to_vec();
+    areas.extend(custom_field_areas(chunks[5], app));
+    areas
+}
+
+/// Draw issue edit form
+pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = edit_chunks(area, app);
 
     let issue_number = app
         .state
@@ -218,37 +244,32 @@ pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
         true,
     );
 
-    let priority_display = match app.state.form_priority {
-        0 => "Default".to_string(),
-        p => format!(
-            "{} ({})",
-            p,
-            match p {
-                1 => "high",
-                2 => "medium",
-                _ => "low",
-            }
-        ),
-    };
-    draw_field_with_value(
-        frame,
-        chunks[2],
-        "Priority (1-3)",
-        &priority_display,
-        app.state.active_form_field == 2,
-        false,
-    );
+    draw_typed_field(frame, chunks[2], app, 2);
 
-    draw_field_with_value(
-        frame,
-        chunks[3],
-        "Status",
-        &app.state.form_status,
-        app.state.active_form_field == 3,
-        false,
-    );
+    // Status is a picker when the project defines its states
+    if app.state.typed_form_field(3).is_some() {
+        draw_typed_field(frame, chunks[3], app, 3);
+    } else {
+        draw_field_with_value(
+            frame,
+            chunks[3],
+            "Status",
+            &app.state.form_status,
+            app.state.active_form_field == 3,
+            false,
+        );
+    }
+
+    draw_typed_field(frame, chunks[4], app, 4);
+
+    draw_custom_fields(frame, chunks[5], app, 5);
 
-    let help = Paragraph::new(Line::from(vec![
+    let mut help_spans = Vec::new();
+    if app.state.active_form_field == 4 {
+        help_spans.push(Span::styled("Space", Style::default().fg(Color::Cyan)));
+        help_spans.push(Span::raw(": toggle  "));
+    }
+    help_spans.extend([
         Span::styled("Tab", Style::default().fg(Color::Cyan)),
         Span::raw(": next field  "),
         Span::styled(
@@ -258,7 +279,7 @@ pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
         Span::raw(": save  "),
         Span::styled("Esc", Style::default().fg(Color::Cyan)),
         Span::raw(": cancel"),
-    ]))
-    .style(Style::default().fg(Color::DarkGray));
-    frame.render_widget(help, chunks[4]);
+    ]);
+    let help = Paragraph::new(Line::from(help_spans)).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help, chunks[6]);
 }
@@ -1,18 +1,51 @@
-//! Form components for creating and editing issues, PRs, and docs
+//! Form components for creating and editing issues, PRs, docs, organizations, team members, agents and feature documents
 //!
 //! This module provides a modular DDD-based structure for form rendering.
 
+mod agent_form;
+mod custom_fields;
 mod doc_form;
 mod edit_page;
+mod feature_form;
 mod field_renderer;
 mod issue_form;
+mod org_form;
 mod pr_form;
+mod template_field;
+mod user_form;
+
+use crate::app::App;
+use crate::state::View;
+use ratatui::layout::Rect;
 
 // Re-export with original function names for API compatibility
+pub use agent_form::draw_create as draw_agent_create;
+pub use agent_form::draw_edit as draw_agent_edit;
 pub use doc_form::draw_create as draw_doc_create;
 pub use doc_form::draw_edit as draw_doc_edit;
 pub use doc_form::get_field_count as get_doc_field_count;
+pub use feature_form::draw_compact_edit;
+pub use feature_form::draw_migration_create;
+pub use field_renderer::multi_select_scroll;
 pub use issue_form::draw_create as draw_issue_create;
 pub use issue_form::draw_edit as draw_issue_edit;
+pub use org_form::draw_create as draw_org_create;
+pub use org_form::draw_edit as draw_org_edit;
 pub use pr_form::draw_create as draw_pr_create;
 pub use pr_form::draw_edit as draw_pr_edit;
+pub use user_form::draw_create as draw_person_create;
+pub use user_form::draw_edit as draw_person_edit;
+
+/// Area of each field of the current issue, PR or doc form, by form index, as drawn in
+/// `area`; other views have none
+pub fn field_areas(area: Rect, app: &App) -> Vec<Rect> {
+    match app.state.current_view {
+        View::IssueCreate => issue_form::create_field_areas(area, app),
+        View::IssueEdit => issue_form::edit_field_areas(area, app),
+        View::PrCreate => pr_form::create_field_areas(area, app),
+        View::PrEdit => pr_form::edit_field_areas(area, app),
+        View::DocCreate => doc_form::create_field_areas(area, app),
+        View::DocEdit => doc_form::edit_field_areas(area),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,95 @@
+//! Organization form rendering (create and edit)
+
+use super::field_renderer::draw_field_with_value;
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Draw organization create form
+pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
+    draw_form(
+        frame,
+        area,
+        app,
+        " New Organization ",
+        "Slug (optional, derived from name)",
+    );
+}
+
+/// Draw organization edit form
+pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
+    let title = app
+        .state
+        .view_params
+        .organization_slug
+        .as_ref()
+        .map(|slug| format!(" Edit Organization: {} ", slug))
+        .unwrap_or_else(|| " Edit Organization ".to_string());
+    draw_form(frame, area, app, &title, "Slug");
+}
+
+/// Draw the slug, name and description fields
+fn draw_form(frame: &mut Frame, area: Rect, app: &App, title: &str, slug_label: &str) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Slug
+            Constraint::Length(3), // Name
+            Constraint::Min(3),    // Description
+            Constraint::Length(2), // Help text
+        ])
+        .margin(1)
+        .split(area);
+
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    frame.render_widget(block, area);
+
+    draw_field_with_value(
+        frame,
+        chunks[0],
+        slug_label,
+        &app.state.form_slug,
+        app.state.active_form_field == 0,
+        false,
+    );
+
+    draw_field_with_value(
+        frame,
+        chunks[1],
+        "Name",
+        &app.state.form_title,
+        app.state.active_form_field == 1,
+        false,
+    );
+
+    draw_field_with_value(
+        frame,
+        chunks[2],
+        "Description",
+        &app.state.form_description,
+        app.state.active_form_field == 2,
+        true,
+    );
+
+    let help_spans = vec![
+        Span::styled("Tab", Style::default().fg(Color::Cyan)),
+        Span::raw(": next field  "),
+        Span::styled(
+            crate::platform::SAVE_SHORTCUT,
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(": save  "),
+        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::raw(": cancel"),
+    ];
+    let help = Paragraph::new(Line::from(help_spans)).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help, chunks[3]);
+}
@@ -1,6 +1,8 @@
 //! PR form rendering (create and edit)
 
-use super::field_renderer::draw_field_with_value;
+use super::custom_fields::{custom_field_areas, custom_fields_height, draw_custom_fields};
+use super::field_renderer::{draw_field_with_value, draw_typed_field};
+use super::template_field::{description_area, draw_template_field, split_for_preview};
 use crate::app::App;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -10,20 +12,43 @@ use ratatui::{
     Frame,
 };
 
-/// Draw PR create form
-pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
-    let chunks = Layout::default()
+/// Height of the reviewer picker: one line per team member, up to five
+fn reviewers_height(app: &App) -> u16 {
+    app.state.reviewer_candidates().len().clamp(1, 5) as u16 + 2
+}
+
+/// Rows of the create form
+fn create_chunks(area: Rect, app: &App) -> Vec<Rect> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
-            Constraint::Min(4),    // Description
+            Constraint::Min(4),    // Description (and template preview)
             Constraint::Length(3), // Source branch
             Constraint::Length(3), // Target branch
             Constraint::Length(3), // Priority
+            Constraint::Length(3), // Template
+            Constraint::Length(reviewers_height(app)),
+            Constraint::Length(custom_fields_height(app)),
             Constraint::Length(2), // Help text
         ])
         .margin(1)
-        .split(area);
+        .split(area)
+        .to_vec()
+}
+
+/// Area of each create form field, by form index (the same layout `draw_create` uses)
+pub fn create_field_areas(area: Rect, app: &App) -> Vec<Rect> {
+    let chunks = create_chunks(area, app);
+    let mut areas = chunks[..7].to_vec();
+    areas[1] = description_area(chunks[1], app);
+    areas.extend(custom_field_areas(chunks[7], app));
+    areas
+}
+
+/// Draw PR create form
+pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = create_chunks(area, app);
 
     let block = Block::default()
         .title(" Create Pull Request ")
@@ -40,9 +65,10 @@ pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
         false,
     );
 
+    let description_area = split_for_preview(frame, chunks[1], app);
     draw_field_with_value(
         frame,
-        chunks[1],
+        description_area,
         "Description",
         &app.state.form_description,
         app.state.active_form_field == 1,
@@ -71,37 +97,18 @@ pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
         false,
     );
 
-    let priority_display = match app.state.form_priority {
-        0 => "Default".to_string(),
-        p => format!("{}", p),
-    };
-    draw_field_with_value(
-        frame,
-        chunks[4],
-        "Priority (1-3)",
-        &priority_display,
-        app.state.active_form_field == 4,
-        false,
-    );
+    draw_typed_field(frame, chunks[4], app, 4);
 
-    let help = Paragraph::new(Line::from(vec![
-        Span::styled("Tab", Style::default().fg(Color::Cyan)),
-        Span::raw(": next  "),
-        Span::styled(
-            crate::platform::SAVE_SHORTCUT,
-            Style::default().fg(Color::Cyan),
-        ),
-        Span::raw(": save  "),
-        Span::styled("Esc", Style::default().fg(Color::Cyan)),
-        Span::raw(": cancel"),
-    ]))
-    .style(Style::default().fg(Color::DarkGray));
-    frame.render_widget(help, chunks[5]);
+    draw_template_field(frame, chunks[5], app, "prs");
+    draw_reviewers(frame, chunks[6], app);
+    draw_custom_fields(frame, chunks[7], app, 7);
+
+    draw_help(frame, chunks[8], app);
 }
 
-/// Draw PR edit form
-pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
-    let chunks = Layout::default()
+/// Rows of the edit form
+fn edit_chunks(area: Rect, app: &App) -> Vec<Rect> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
@@ -110,10 +117,26 @@ pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(3), // Target branch
             Constraint::Length(3), // Priority
             Constraint::Length(3), // Status
+            Constraint::Length(reviewers_height(app)),
+            Constraint::Length(custom_fields_height(app)),
             Constraint::Length(2), // Help text
         ])
         .margin(1)
-        .split(area);
+        .split(area)
+        .to_vec()
+}
+
+/// Area of each edit form field, by form index (the same layout `draw_edit` uses)
+pub fn edit_field_areas(area: Rect, app: &App) -> Vec<Rect> {
+    let chunks = edit_chunks(area, app);
+    let mut areas = chunks[..7].to_vec();
+    areas.extend(custom_field_areas(chunks[7], app));
+    areas
+}
+
+/// Draw PR edit form
+pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = edit_chunks(area, app);
 
     let pr_number = app
         .state
@@ -165,26 +188,46 @@ pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
         false,
     );
 
-    let priority_display = format!("{}", app.state.form_priority);
-    draw_field_with_value(
-        frame,
-        chunks[4],
-        "Priority",
-        &priority_display,
-        app.state.active_form_field == 4,
-        false,
-    );
+    draw_typed_field(frame, chunks[4], app, 4);
+    draw_typed_field(frame, chunks[5], app, 5);
+    draw_reviewers(frame, chunks[6], app);
+    draw_custom_fields(frame, chunks[7], app, 7);
 
-    draw_field_with_value(
-        frame,
-        chunks[5],
-        "Status",
-        &app.state.form_status,
-        app.state.active_form_field == 5,
-        false,
-    );
+    draw_help(frame, chunks[8], app);
+}
+
+/// Draw the reviewer picker, or a placeholder when the project has no team members
+fn draw_reviewers(frame: &mut Frame, area: Rect, app: &App) {
+    if app.state.typed_form_field(6).is_some() {
+        draw_typed_field(frame, area, app, 6);
+        return;
+    }
+    let border_color = if app.state.active_form_field == 6 {
+        Color::Cyan
+    } else {
+        Color::DarkGray
+    };
+    let placeholder = Paragraph::new("No team members")
+        .style(Style::default().fg(Color::DarkGray))
+        .block(
+            Block::default()
+                .title(" Reviewers ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border_color)),
+        );
+    frame.render_widget(placeholder, area);
+}
 
-    let help = Paragraph::new(Line::from(vec![
+/// Key help under the PR forms; the reviewer picker adds its own keys
+fn draw_help(frame: &mut Frame, area: Rect, app: &App) {
+    let mut spans = Vec::new();
+    if app.state.active_form_field == 6 && app.state.on_typed_form_field() {
+        spans.push(Span::styled("←/→", Style::default().fg(Color::Cyan)));
+        spans.push(Span::raw(": move  "));
+        spans.push(Span::styled("Space", Style::default().fg(Color::Cyan)));
+        spans.push(Span::raw(": toggle reviewer  "));
+    }
+    spans.extend([
         Span::styled("Tab", Style::default().fg(Color::Cyan)),
         Span::raw(": next  "),
         Span::styled(
@@ -194,7 +237,7 @@ pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
         Span::raw(": save  "),
         Span::styled("Esc", Style::default().fg(Color::Cyan)),
         Span::raw(": cancel"),
-    ]))
-    .style(Style::default().fg(Color::DarkGray));
-    frame.render_widget(help, chunks[6]);
+    ]);
+    let help = Paragraph::new(Line::from(spans)).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help, area);
 }
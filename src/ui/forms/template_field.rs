@@ -0,0 +1,77 @@
+//! Template selector and preview shared by the issue and PR create forms
+
+use super::field_renderer::draw_field_with_value;
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the template selector; kind is the templates subdirectory ("issues" or "prs")
+pub fn draw_template_field(frame: &mut Frame, area: Rect, app: &App, kind: &str) {
+    let value = if app.state.form_templates.is_empty() {
+        format!("None (no templates in .centy/templates/{})", kind)
+    } else if app.state.form_template.is_empty() {
+        "◀ None ▶".to_string()
+    } else {
+        format!("◀ {} ▶", app.state.form_template)
+    };
+    draw_field_with_value(
+        frame,
+        area,
+        "Template (←/→ to change)",
+        &value,
+        app.state.on_template_field(),
+        false,
+    );
+}
+
+/// Split the description area to show the selected template next to it.
+/// Returns the area left for the description.
+pub fn split_for_preview(frame: &mut Frame, area: Rect, app: &App) -> Rect {
+    let Some(template) = app.state.selected_form_template() else {
+        return area;
+    };
+
+    let (description, preview_area) = preview_chunks(area);
+
+    let lines: Vec<Line> = template
+        .body
+        .lines()
+        .map(|l| Line::from(l.to_string()))
+        .collect();
+    let preview = Paragraph::new(lines)
+        .style(Style::default().fg(Color::Gray))
+        .block(
+            Block::default()
+                .title(format!(" Template: {} ", template.name))
+                .title_bottom(Line::from(" filled in by the daemon on create "))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Magenta)),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(preview, preview_area);
+
+    description
+}
+
+/// Area of the description field, left of the template preview when one is shown
+pub fn description_area(area: Rect, app: &App) -> Rect {
+    if app.state.selected_form_template().is_some() {
+        preview_chunks(area).0
+    } else {
+        area
+    }
+}
+
+/// Description and template preview halves of the description row
+fn preview_chunks(area: Rect) -> (Rect, Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(area);
+    (chunks[0], chunks[1])
+}
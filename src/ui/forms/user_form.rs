@@ -0,0 +1,90 @@
+//! Team member form rendering (create and edit)
+
+use super::field_renderer::draw_field_with_value;
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Draw person create form
+pub fn draw_create(frame: &mut Frame, area: Rect, app: &App) {
+    draw_form(
+        frame,
+        area,
+        app,
+        " New Team Member ",
+        "ID (slug, e.g. jane-doe)",
+    );
+}
+
+/// Draw person edit form
+pub fn draw_edit(frame: &mut Frame, area: Rect, app: &App) {
+    let title = app
+        .state
+        .view_params
+        .person_id
+        .as_ref()
+        .map(|id| format!(" Edit Team Member: {} ", id))
+        .unwrap_or_else(|| " Edit Team Member ".to_string());
+    draw_form(frame, area, app, &title, "ID (read-only)");
+}
+
+/// Draw the id, name, email and git username fields
+fn draw_form(frame: &mut Frame, area: Rect, app: &App, title: &str, id_label: &str) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // ID
+            Constraint::Length(3), // Name
+            Constraint::Length(3), // Email
+            Constraint::Length(3), // Git usernames
+            Constraint::Min(0),
+            Constraint::Length(2), // Help text
+        ])
+        .margin(1)
+        .split(area);
+
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    frame.render_widget(block, area);
+
+    let fields = [
+        (id_label, &app.state.form_slug),
+        ("Name", &app.state.form_title),
+        ("Email (optional)", &app.state.form_email),
+        (
+            "Git usernames (optional, comma separated)",
+            &app.state.form_git_usernames,
+        ),
+    ];
+    for (idx, (label, value)) in fields.iter().enumerate() {
+        draw_field_with_value(
+            frame,
+            chunks[idx],
+            label,
+            value,
+            app.state.active_form_field == idx,
+            false,
+        );
+    }
+
+    let help_spans = vec![
+        Span::styled("Tab", Style::default().fg(Color::Cyan)),
+        Span::raw(": next field  "),
+        Span::styled(
+            crate::platform::SAVE_SHORTCUT,
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(": save  "),
+        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::raw(": cancel"),
+    ];
+    let help = Paragraph::new(Line::from(help_spans)).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help, chunks[5]);
+}
@@ -0,0 +1,265 @@
+//! Project initialization wizard (reconciliation plan review and init summary)
+
+use super::render_scrollable_list;
+use crate::app::App;
+use crate::state::{InitResult, InitWizard, PlanBucket};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the init wizard
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(wizard) = &app.state.init_wizard else {
+        let message = Paragraph::new("No project to initialize")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(Block::default().title(" Initialize ").borders(Borders::ALL));
+        frame.render_widget(message, area);
+        return;
+    };
+
+    match &wizard.result {
+        Some(result) => draw_result(frame, area, wizard, result),
+        None => draw_plan(frame, area, wizard),
+    }
+}
+
+/// Draw the reconciliation plan with per-file decisions and a preview pane
+fn draw_plan(frame: &mut Frame, area: Rect, wizard: &InitWizard) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let plan = &wizard.plan;
+    let mut header = vec![
+        Line::from(vec![
+            Span::styled("Initialize ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                wizard.project_path.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!(
+                "{} to create, {} to restore, {} to reset, {} up to date, {} user files",
+                plan.to_create.len(),
+                plan.to_restore.len(),
+                plan.to_reset.len(),
+                plan.up_to_date.len(),
+                plan.user_files.len()
+            ),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    if plan.needs_decisions {
+        header.push(Line::from(Span::styled(
+            "Decisions needed: Space toggles restore/reset for the selected file",
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
+    draw_plan_list(frame, body[0], wizard);
+    draw_preview(frame, body[1], wizard);
+}
+
+/// Draw the plan files grouped by bucket
+fn draw_plan_list(frame: &mut Frame, area: Rect, wizard: &InitWizard) {
+    let mut items = Vec::new();
+    let mut selected_item = 0;
+    let mut current_bucket = None;
+
+    for (idx, (bucket, file)) in wizard.rows().into_iter().enumerate() {
+        if current_bucket != Some(bucket) {
+            current_bucket = Some(bucket);
+            items.push(ListItem::new(Line::from(Span::styled(
+                bucket.label(),
+                Style::default()
+                    .fg(bucket_color(bucket))
+                    .add_modifier(Modifier::BOLD),
+            ))));
+        }
+
+        let is_selected = idx == wizard.selected_index;
+        if is_selected {
+            selected_item = items.len();
+        }
+
+        let decision = match bucket {
+            PlanBucket::Create => "[+]",
+            PlanBucket::Restore | PlanBucket::Reset => {
+                if wizard.is_chosen(bucket, &file.path) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                }
+            }
+            PlanBucket::UserFile => "   ",
+        };
+        let prefix = if is_selected { "▸ " } else { "  " };
+        let style = if is_selected {
+            Style::default().bg(Color::DarkGray)
+        } else {
+            Style::default()
+        };
+        let suffix = if file.is_directory { "/" } else { "" };
+
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(prefix, style),
+            Span::styled(decision, Style::default().fg(bucket_color(bucket))),
+            Span::raw(" "),
+            Span::styled(format!("{}{}", file.path, suffix), style),
+        ])));
+    }
+
+    let block = Block::default()
+        .title(" Plan ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if items.is_empty() {
+        let message = Paragraph::new("Everything is up to date. Press Enter to initialize.")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(message, area);
+        return;
+    }
+
+    render_scrollable_list(frame, area, List::new(items).block(block), selected_item);
+}
+
+/// Draw the content preview of the selected file
+fn draw_preview(frame: &mut Frame, area: Rect, wizard: &InitWizard) {
+    let (title, content) = match wizard.selected_row() {
+        Some((_, file)) if !file.content_preview.is_empty() => (
+            format!(" {} ", file.path),
+            file.content_preview
+                .lines()
+                .map(|l| Line::from(l.to_string()))
+                .collect(),
+        ),
+        Some((_, file)) => (
+            format!(" {} ", file.path),
+            vec![Line::from(Span::styled(
+                "No preview available.",
+                Style::default().fg(Color::DarkGray),
+            ))],
+        ),
+        None => (" Preview ".to_string(), Vec::new()),
+    };
+
+    let preview = Paragraph::new(content)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(preview, area);
+}
+
+/// Draw the init summary and organization inference result
+fn draw_result(frame: &mut Frame, area: Rect, wizard: &InitWizard, result: &InitResult) {
+    let mut content = vec![
+        Line::from(Span::styled(
+            "Project initialized",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            wizard.project_path.clone(),
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+    ];
+
+    for (label, files, color) in [
+        ("Created", &result.created, Color::Green),
+        ("Restored", &result.restored, Color::Cyan),
+        ("Reset", &result.reset, Color::Yellow),
+        ("Skipped", &result.skipped, Color::DarkGray),
+    ] {
+        content.push(Line::from(Span::styled(
+            format!("{} ({})", label, files.len()),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+        for file in files {
+            content.push(Line::from(format!("  {}", file)));
+        }
+    }
+
+    if let Some(org) = &result.org_inference {
+        content.push(Line::from(""));
+        content.push(Line::from(Span::styled(
+            "Organization",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+        if !org.message.is_empty() {
+            content.push(Line::from(format!("  {}", org.message)));
+        }
+        if !org.inferred_org_slug.is_empty() {
+            let status = if org.org_created {
+                "created from git remote"
+            } else {
+                "inferred from git remote"
+            };
+            content.push(Line::from(vec![
+                Span::raw(format!(
+                    "  {} ({}) ",
+                    org.inferred_org_name, org.inferred_org_slug
+                )),
+                Span::styled(status, Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+        if org.has_mismatch {
+            content.push(Line::from(Span::styled(
+                format!(
+                    "  Project is already in '{}', which differs from the git remote",
+                    org.existing_org_slug
+                ),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+    }
+
+    content.push(Line::from(""));
+    content.push(Line::from(Span::styled(
+        "Press Enter to continue",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let paragraph = Paragraph::new(content)
+        .block(
+            Block::default()
+                .title(" Initialize ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green)),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+/// Color for a plan bucket
+fn bucket_color(bucket: PlanBucket) -> Color {
+    match bucket {
+        PlanBucket::Create => Color::Green,
+        PlanBucket::Restore => Color::Cyan,
+        PlanBucket::Reset => Color::Yellow,
+        PlanBucket::UserFile => Color::DarkGray,
+    }
+}
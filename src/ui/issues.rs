@@ -2,7 +2,7 @@
 
 use super::render_scrollable_list;
 use crate::app::App;
-use crate::state::{IssueDetailFocus, IssuesListFocus};
+use crate::state::{IssueDetailFocus, IssueDetailTab, IssueDraftFilter, IssuesListFocus};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -68,8 +68,12 @@ fn draw_issues_list_content(frame: &mut Frame, area: Rect, app: &App) {
         String::new()
     };
 
+    let draft_filter = app.state.issue_draft_filter;
+
     if sorted_issues.is_empty() {
-        let message = if !app.state.show_closed_issues && closed_count > 0 {
+        let message = if draft_filter != IssueDraftFilter::All {
+            "No issues match the draft filter. Press 'D' to change it.\nPress 'n' to create a new issue."
+        } else if !app.state.show_closed_issues && closed_count > 0 {
             "No open issues. Press 'a' to show all issues.\nPress 'n' to create a new issue."
         } else {
             "No issues found.\nPress 'n' to create a new issue."
@@ -99,6 +103,16 @@ fn draw_issues_list_content(frame: &mut Frame, area: Rect, app: &App) {
         Span::raw(" | "),
         Span::styled(filter_label, Style::default().fg(Color::DarkGray)),
         Span::styled(" [a]toggle", Style::default().fg(Color::DarkGray)),
+        Span::raw(" | "),
+        Span::styled(
+            format!("Drafts: {}", draft_filter.label()),
+            Style::default().fg(if draft_filter == IssueDraftFilter::All {
+                Color::DarkGray
+            } else {
+                Color::Yellow
+            }),
+        ),
+        Span::styled(" [D]cycle", Style::default().fg(Color::DarkGray)),
     ]));
     frame.render_widget(header, chunks[0]);
 
@@ -126,7 +140,7 @@ fn draw_issues_list_content(frame: &mut Frame, area: Rect, app: &App) {
                 Style::default()
             };
 
-            let line = Line::from(vec![
+            let mut spans = vec![
                 Span::styled(prefix, style),
                 Span::styled(number, Style::default().fg(Color::Cyan)),
                 Span::raw(" "),
@@ -134,8 +148,18 @@ fn draw_issues_list_content(frame: &mut Frame, area: Rect, app: &App) {
                 Span::raw(" "),
                 Span::styled(status_label, Style::default().fg(Color::DarkGray)),
                 Span::raw(" "),
-                Span::styled(&issue.title, style),
-            ]);
+            ];
+            if app.state.llm_session_for_issue(&issue.id).is_some() {
+                spans.push(Span::styled("● ", Style::default().fg(Color::Green)));
+            }
+            if issue.metadata.draft {
+                spans.push(Span::styled(
+                    "[draft] ",
+                    Style::default().fg(Color::Magenta),
+                ));
+            }
+            spans.push(Span::styled(&issue.title, style));
+            let line = Line::from(spans);
 
             ListItem::new(line)
         })
@@ -196,7 +220,7 @@ pub fn draw_detail(frame: &mut Frame, area: Rect, app: &App) {
 
 /// Draw the issue content (left side)
 fn draw_issue_content(frame: &mut Frame, area: Rect, app: &App, issue: &crate::state::Issue) {
-    let title = format!(" #{} {} ", issue.display_number, issue.title);
+    let title = detail_title(app, issue);
     let priority_color = match issue.metadata.priority {
         1 => Color::Red,
         2 => Color::Yellow,
@@ -209,6 +233,11 @@ fn draw_issue_content(frame: &mut Frame, area: Rect, app: &App, issue: &crate::s
         IssueDetailFocus::ActionPanel => Color::DarkGray,
     };
 
+    if app.state.issue_detail_tab == IssueDetailTab::Assets {
+        super::assets::draw_assets(frame, area, app, title, border_color);
+        return;
+    }
+
     let mut content = vec![
         // Status and Priority
         Line::from(vec![
@@ -217,6 +246,54 @@ fn draw_issue_content(frame: &mut Frame, area: Rect, app: &App, issue: &crate::s
             Span::raw(" | Priority: "),
             Span::styled(issue.priority_label(), Style::default().fg(priority_color)),
         ]),
+    ];
+    if issue.metadata.draft {
+        content.push(Line::from(vec![
+            Span::styled(
+                "Draft",
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " - press P to publish",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+
+    // Active agent session badge
+    if let Some(session) = app.state.llm_session_for_issue(&issue.id) {
+        let (state, color) = if session.pid_alive || session.pid == 0 {
+            ("working", Color::Green)
+        } else {
+            ("stale", Color::Red)
+        };
+        content.push(Line::from(vec![
+            Span::styled("● ", Style::default().fg(color)),
+            Span::styled(
+                format!("Agent {}: ", state),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                "{} ({})",
+                session.agent_name,
+                session.action.label()
+            )),
+            Span::styled(
+                format!(
+                    "  since {}",
+                    session
+                        .started_at
+                        .with_timezone(&chrono::Local)
+                        .format("%H:%M")
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+
+    content.extend([
         Line::from(""),
         // UUID
         Line::from(vec![
@@ -246,6 +323,18 @@ fn draw_issue_content(frame: &mut Frame, area: Rect, app: &App, issue: &crate::s
             ),
         ]),
         Line::from(""),
+    ]);
+
+    // Custom fields
+    content.extend(super::custom_fields::custom_field_lines(
+        app,
+        &issue.metadata.custom_fields,
+    ));
+
+    // Links
+    content.extend(super::links::link_lines(app));
+    content.extend([
+        Line::from(""),
         // Separator
         Line::from(Span::styled(
             "─".repeat(40),
@@ -259,7 +348,7 @@ fn draw_issue_content(frame: &mut Frame, area: Rect, app: &App, issue: &crate::s
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )),
-    ];
+    ]);
 
     // Add description lines
     if issue.description.is_empty() {
@@ -286,6 +375,29 @@ fn draw_issue_content(frame: &mut Frame, area: Rect, app: &App, issue: &crate::s
     frame.render_widget(paragraph, area);
 }
 
+/// Title for the issue detail block, with the Details/Assets tab indicator
+fn detail_title(app: &App, issue: &crate::state::Issue) -> Line<'static> {
+    let tab_style = |tab: IssueDetailTab| {
+        if app.state.issue_detail_tab == tab {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    };
+    Line::from(vec![
+        Span::raw(format!(" #{} {} ", issue.display_number, issue.title)),
+        Span::styled("Details", tab_style(IssueDetailTab::Details)),
+        Span::raw(" "),
+        Span::styled(
+            format!("Assets ({})", app.state.assets.len()),
+            tab_style(IssueDetailTab::Assets),
+        ),
+        Span::styled(" [t] ", Style::default().fg(Color::DarkGray)),
+    ])
+}
+
 /// Draw the daemon actions panel (right side)
 fn draw_daemon_actions_panel(frame: &mut Frame, area: Rect, app: &App) {
     let is_focused = matches!(app.state.issue_detail_focus, IssueDetailFocus::ActionPanel);
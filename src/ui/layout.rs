@@ -164,25 +164,73 @@ const SAVE_HINT: &str = "^W:save";
 fn get_view_hints(view: &View) -> String {
     match view {
         View::Splash => "Press any key to skip".to_string(),
-        View::Projects => {
-            "h/j/k/l:nav  Enter:select  f:fav  a:archive  x:untrack  n:new".to_string()
-        }
+        View::Projects => "hjkl:nav  Enter:open  n:new  /:search  ^G:jump".to_string(),
         View::Organization => "j/k:nav  Tab:panel  Enter:open  p:projects  Esc:back".to_string(),
         View::Issues => {
-            "j/k:nav  Tab:panel  Enter:view/run  n:new  s/S:sort  a:all  y:copy".to_string()
+            "j/k:nav  Tab:panel  Enter:view/run  n:new  s/S:sort  a:all  D:drafts  y:copy  /:search"
+                .to_string()
         }
         View::IssueDetail => {
-            "Tab:panel  e:edit  p/i:mode  Enter:run  j/k:scroll  Esc:back".to_string()
-        }
-        View::IssueCreate | View::IssueEdit => format!("Tab:next  {}  Esc:cancel", SAVE_HINT),
-        View::Prs => "j/k:nav  Enter:view  n:new  s/S:sort  a:all".to_string(),
-        View::PrDetail => "e:edit  j/k:scroll  Esc:back".to_string(),
-        View::PrCreate | View::PrEdit => format!("Tab:next  {}  Esc:cancel", SAVE_HINT),
-        View::Docs => "j/k:nav  Enter:view  n:new  Esc:back".to_string(),
-        View::DocDetail => "e:edit  j/k:scroll  d/u:page  Esc:back".to_string(),
+            "Tab:panel  t:tab  e:edit  P:publish draft  p/i:mode  Enter:run/open  [/]:link  L/X:link+/-  A:agent  Esc:back"
+                .to_string()
+        }
+        View::IssueCreate | View::PrCreate => {
+            format!("Tab:next  ←/→:change  Space:toggle  {}  Esc:cancel", SAVE_HINT)
+        }
+        View::IssueEdit => format!("Tab:next  ←/→:change  Space:toggle  {}  Esc:cancel", SAVE_HINT),
+        View::Prs => "j/k:nav  Enter:view  n:new  s/S:sort  a:all  /:search".to_string(),
+        View::PrDetail => "e:edit  j/k:scroll  [/]:link  L/X:link+/-  Esc:back".to_string(),
+        View::PrEdit => format!("Tab:next  ←/→:change  Space:toggle  {}  Esc:cancel", SAVE_HINT),
+        View::Docs => "j/k:nav  Enter:view  n:new  /:search  Esc:back".to_string(),
+        View::DocDetail => {
+            "e:edit  j/k:scroll  d/u:page  [/]:link  L/X:link+/-  Esc:back".to_string()
+        }
         View::DocCreate | View::DocEdit => format!("Tab:next  {}  Esc:cancel", SAVE_HINT),
-        View::People => "j/k:nav  Tab:panel  Enter:view  s/S:sort  Esc:back".to_string(),
-        View::PersonDetail => "j/k:scroll  d/u:page  Esc:back".to_string(),
-        View::Config => "j/k:scroll  Esc:back".to_string(),
+        View::People => {
+            "j/k:nav  Tab:panel  Enter:view  n:new  e:edit  x:delete  g:sync from git  s/S:sort  Esc:back"
+                .to_string()
+        }
+        View::PersonDetail => "j/k:scroll  d/u:page  e:edit  x:delete  Esc:back".to_string(),
+        View::PersonCreate | View::PersonEdit => format!("Tab:next  {}  Esc:cancel", SAVE_HINT),
+        View::UserSync => "Enter:sync  r:dry run again  j/k:scroll  Esc:back".to_string(),
+        View::Config => "j/k:scroll  e:edit  v:version  a:agents  Esc:back".to_string(),
+        View::ConfigEdit => {
+            format!("Tab:section  j/k:nav  ←/→:change  Space:toggle  {}  Esc:cancel", SAVE_HINT)
+        }
+        View::Version => "j/k:select  Enter:migrate  Esc:back".to_string(),
+        View::Features => {
+            "j/k:nav  Space:select  a:all  Tab:document  e:edit compact  m:migration  c:mark compacted  Esc:back"
+                .to_string()
+        }
+        View::CompactEdit | View::MigrationCreate => format!("{}  Esc:cancel", SAVE_HINT),
+        View::AgentActivity => {
+            "j/k:nav  Enter:open issue  s:spawn  c:clear  a:all projects  W:workspaces  r:refresh  Esc:back"
+                .to_string()
+        }
+        View::Workspaces => {
+            "j/k:nav  o:VS Code  t:terminal  x:close  X:force close  e:extend  C:clean up expired  r:refresh  Esc:back"
+                .to_string()
+        }
+        View::Daemon => "r:refresh  R:restart  X:shutdown  +/-:delay  Esc:back".to_string(),
+        View::Agents => {
+            "j/k:nav  w:activity  Tab:agents/env  n:new  e:edit  d:default  x:remove  s:show values  g:global/project  Esc:back"
+                .to_string()
+        }
+        View::AgentCreate | View::AgentEdit => {
+            format!("Tab:next  ←/→:type  {}  Esc:cancel", SAVE_HINT)
+        }
+        View::Assets => {
+            "j/k:nav  Enter:preview  a:add  s:save  x:delete  d/u:scroll  Esc:back".to_string()
+        }
+        View::Organizations => {
+            "j/k:nav  Enter:open  n:new  e:edit  x:delete  Esc:back".to_string()
+        }
+        View::OrganizationCreate | View::OrganizationEdit => {
+            format!("Tab:next  {}  Esc:cancel", SAVE_HINT)
+        }
+        View::InitWizard => {
+            "j/k:nav  Space:restore/reset  Enter:initialize  Esc:cancel".to_string()
+        }
+        View::Search => "Enter:search/open  Tab:focus  ^A:scope  j/k:nav  Esc:back".to_string(),
     }
 }
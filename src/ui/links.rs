@@ -0,0 +1,73 @@
+//! Links section shared by issue, PR, and doc detail views
+
+use crate::app::App;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Build the lines for the Links section of a detail view
+///
+/// Links are grouped by type: outgoing groups are marked with "→",
+/// inverse groups (created from the other entity) with "←".
+pub fn link_lines(app: &App) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            "Links",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            "  [/]:select  Enter:open  L:add  X:remove",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])];
+
+    let groups = app.state.grouped_links();
+    if groups.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No links.",
+            Style::default().fg(Color::DarkGray),
+        )));
+        return lines;
+    }
+
+    let mut index = 0;
+    for group in groups {
+        let (arrow, color) = if group.is_inverse {
+            ("←", Color::Magenta)
+        } else {
+            ("→", Color::Yellow)
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{} {} ({})", arrow, group.link_type, group.links.len()),
+            Style::default().fg(color),
+        )));
+
+        for link in group.links {
+            let is_selected = index == app.state.selected_link_index;
+            let prefix = if is_selected { "  ▸ " } else { "    " };
+            let style = if is_selected {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let display = app
+                .state
+                .link_target_display(&link.target_id, link.target_type);
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}{}", prefix, display), style),
+                Span::styled(
+                    format!("  {}", link.created_at.format("%Y-%m-%d")),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            index += 1;
+        }
+    }
+
+    lines
+}
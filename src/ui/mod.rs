@@ -1,19 +1,32 @@
 //! UI module for rendering the TUI
 
+mod agent_activity;
+mod agents;
+mod assets;
 pub mod components;
+mod config_editor;
 mod config_panel;
 mod context_bar;
+mod custom_fields;
+mod daemon;
 mod docs;
+mod features;
 pub mod forms;
+mod init_wizard;
 mod issues;
 mod layout;
+mod links;
 mod organization;
+mod organizations;
 mod people;
 mod projects;
 mod prs;
+mod search;
 pub mod sidebar;
 mod splash;
+mod version;
 mod widgets;
+mod workspaces;
 
 pub use components::BUTTON_HEIGHT;
 pub use widgets::render_scrollable_list;
@@ -26,6 +39,15 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::Frame;
 
+/// Area the current view's content is drawn in, for mapping mouse clicks
+pub fn main_area(area: Rect, view: &View) -> Rect {
+    if sidebar::should_show_sidebar(view) {
+        layout::create_layout(area).2
+    } else {
+        layout::create_layout_no_sidebar(area).1
+    }
+}
+
 /// Main draw function
 pub fn draw(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
@@ -77,6 +99,26 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         View::People => people::draw_list(frame, main_area, app),
         View::PersonDetail => people::draw_detail(frame, main_area, app),
         View::Config => config_panel::draw(frame, main_area, app),
+        View::ConfigEdit => config_editor::draw(frame, main_area, app),
+        View::Search => search::draw(frame, main_area, app),
+        View::Assets => assets::draw_shared(frame, main_area, app),
+        View::InitWizard => init_wizard::draw(frame, main_area, app),
+        View::Version => version::draw(frame, main_area, app),
+        View::Features => features::draw(frame, main_area, app),
+        View::AgentActivity => agent_activity::draw(frame, main_area, app),
+        View::Workspaces => workspaces::draw(frame, main_area, app),
+        View::Daemon => daemon::draw(frame, main_area, app),
+        View::Agents => agents::draw(frame, main_area, app),
+        View::AgentCreate => forms::draw_agent_create(frame, main_area, app),
+        View::AgentEdit => forms::draw_agent_edit(frame, main_area, app),
+        View::CompactEdit => forms::draw_compact_edit(frame, main_area, app),
+        View::MigrationCreate => forms::draw_migration_create(frame, main_area, app),
+        View::Organizations => organizations::draw(frame, main_area, app),
+        View::PersonCreate => forms::draw_person_create(frame, main_area, app),
+        View::PersonEdit => forms::draw_person_edit(frame, main_area, app),
+        View::UserSync => people::draw_sync(frame, main_area, app),
+        View::OrganizationCreate => forms::draw_org_create(frame, main_area, app),
+        View::OrganizationEdit => forms::draw_org_edit(frame, main_area, app),
     }
 
     // Draw status bar
@@ -95,14 +137,19 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         components::render_worktree_dialog(frame, action);
     }
 
+    // Draw destructive confirmation (on top of everything except error)
+    if let Some(ref action) = app.state.pending_confirm_action {
+        components::render_confirm_dialog(frame, action);
+    }
+
     // Draw move dialog (on top of everything except error)
     if let Some(ref action) = app.state.pending_move_action {
-        // Get list of valid target projects (initialized, not current project)
+        // Get list of valid target projects (initialized, not current project unless duplicating)
         let targets: Vec<_> = app
             .state
             .projects
             .iter()
-            .filter(|p| p.initialized && Some(&p.path) != app.state.selected_project_path.as_ref())
+            .filter(|p| action.accepts_target(p))
             .collect();
         components::render_move_dialog(frame, action, &targets);
     }
@@ -112,6 +159,55 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         components::render_start_work_dialog(frame, action);
     }
 
+    // Draw add link dialog (on top of everything except error)
+    if let Some(ref action) = app.state.pending_link_action {
+        let link_types = app.state.link_types();
+        let targets = app
+            .state
+            .link_target_candidates(&action.source_id, &action.search_filter);
+        components::render_link_dialog(frame, action, &link_types, &targets);
+    }
+
+    // Draw unlink confirmation dialog (on top of everything except error)
+    if let Some(ref action) = app.state.pending_unlink_action {
+        components::render_unlink_dialog(frame, action);
+    }
+
+    // Draw asset dialog (on top of everything except error)
+    if let Some(ref action) = app.state.pending_asset_action {
+        components::render_asset_dialog(frame, action);
+    }
+
+    // Draw organization dialog (on top of everything except error)
+    if let Some(ref action) = app.state.pending_org_action {
+        components::render_org_dialog(frame, action, &app.state.organizations);
+    }
+
+    // Draw spawn agent dialog (on top of everything except error)
+    if let Some(ref action) = app.state.pending_spawn_action {
+        components::render_spawn_dialog(frame, action);
+    }
+
+    // Draw agents view dialog (on top of everything except error)
+    if let Some(ref action) = app.state.pending_agent_action {
+        components::render_agent_dialog(frame, action, app.state.reveal_env_values);
+    }
+
+    // Draw project rename dialog (on top of everything except error)
+    if let Some(ref action) = app.state.pending_rename_action {
+        components::render_rename_dialog(frame, action);
+    }
+
+    // Draw register project dialog (on top of everything except error)
+    if let Some(ref action) = app.state.pending_register_action {
+        components::render_register_dialog(frame, action);
+    }
+
+    // Draw jump prompt (on top of everything except error)
+    if let Some(ref action) = app.state.pending_jump_action {
+        components::render_jump_dialog(frame, action);
+    }
+
     // Draw error dialog last (on top of everything)
     if let Some(error_msg) = app.state.current_error() {
         components::render_error_dialog(frame, error_msg);
@@ -0,0 +1,76 @@
+//! Organizations list view (all organizations with project counts)
+
+use super::render_scrollable_list;
+use crate::app::App;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Draw the organizations list
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .title(format!(
+            " Organizations ({}) ",
+            app.state.organizations.len()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if app.state.organizations.is_empty() {
+        let message = Paragraph::new("No organizations. Press 'n' to create one.")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(message, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .state
+        .organizations
+        .iter()
+        .enumerate()
+        .map(|(idx, org)| {
+            let is_selected = idx == app.state.selected_organization_index;
+            let prefix = if is_selected { "▸ " } else { "  " };
+            let name_style = if is_selected {
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .bg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            let projects = if org.project_count == 1 {
+                "1 project".to_string()
+            } else {
+                format!("{} projects", org.project_count)
+            };
+
+            let mut lines = vec![Line::from(vec![
+                Span::raw(prefix),
+                Span::styled(org.name.clone(), name_style),
+                Span::styled(
+                    format!("  {}", org.slug),
+                    Style::default().fg(Color::Magenta),
+                ),
+                Span::styled(
+                    format!("  {}", projects),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])];
+            if !org.description.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", org.description),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    render_scrollable_list(frame, area, list, app.state.selected_organization_index);
+}
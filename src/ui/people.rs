@@ -2,7 +2,7 @@
 
 use super::render_scrollable_list;
 use crate::app::App;
-use crate::state::{PeopleListFocus, PersonDetailFocus, User};
+use crate::state::{GitContributor, PeopleListFocus, PersonDetailFocus, User};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -54,7 +54,7 @@ fn draw_people_list_content(frame: &mut Frame, area: Rect, app: &App) {
 
     if sorted_people.is_empty() {
         let content = Paragraph::new(
-            "No team members found.\nRun 'centy user sync' to import from git history.",
+            "No team members found.\nPress 'n' to add one or 'g' to import them from git history.",
         )
         .style(Style::default().fg(Color::DarkGray))
         .block(
@@ -119,16 +119,15 @@ fn draw_people_list_content(frame: &mut Frame, area: Rect, app: &App) {
         })
         .collect();
 
-    let list = List::new(items).block(
-        Block::default()
-            .title(format!(
-                " People - {} ({}) ",
-                project_name,
-                sorted_people.len()
-            ))
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(border_color)),
-    );
+    let block = Block::default()
+        .title(format!(
+            " People - {} ({}) ",
+            project_name,
+            sorted_people.len()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color));
+    let list = List::new(items).block(block);
 
     render_scrollable_list(frame, chunks[1], list, app.state.selected_index);
 }
@@ -269,12 +268,97 @@ fn draw_person_content(frame: &mut Frame, area: Rect, app: &App, person: &User)
         Span::raw(person.prs_created.to_string()),
     ]));
 
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color));
+
+    let paragraph = Paragraph::new(content)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.state.scroll_offset as u16, 0));
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Draw the git history sync screen (dry run preview, then the sync result)
+pub fn draw_sync(frame: &mut Frame, area: Rect, app: &App) {
+    let mut content = Vec::new();
+
+    match &app.state.user_sync {
+        Some(sync) if sync.dry_run => {
+            content.push(section("Would create", sync.would_create.len()));
+            if sync.would_create.is_empty() {
+                content.push(Line::from(Span::styled(
+                    "  Every contributor already has a user.",
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            content.extend(contributor_lines(&sync.would_create, Color::Green));
+            content.push(Line::from(""));
+            content.push(section("Would skip (already exist)", sync.would_skip.len()));
+            content.extend(contributor_lines(&sync.would_skip, Color::DarkGray));
+            content.push(Line::from(""));
+            if sync.would_create.is_empty() {
+                content.push(Line::from(Span::styled(
+                    "Nothing to sync.",
+                    Style::default().fg(Color::DarkGray),
+                )));
+            } else {
+                content.push(Line::from(vec![
+                    Span::styled("Enter", Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!(" create {} user(s)  ", sync.would_create.len()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled("Esc", Style::default().fg(Color::Cyan)),
+                    Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+                ]));
+            }
+        }
+        Some(sync) => {
+            content.push(section("Created", sync.created.len()));
+            for id in &sync.created {
+                content.push(Line::from(vec![
+                    Span::styled("  ✓ ", Style::default().fg(Color::Green)),
+                    Span::raw(id.clone()),
+                ]));
+            }
+            content.push(Line::from(""));
+            content.push(section("Skipped", sync.skipped.len()));
+            for email in &sync.skipped {
+                content.push(Line::from(Span::styled(
+                    format!("    {}", email),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            if !sync.errors.is_empty() {
+                content.push(Line::from(""));
+                content.push(section("Errors", sync.errors.len()));
+                for error in &sync.errors {
+                    content.push(Line::from(vec![
+                        Span::styled("  ✗ ", Style::default().fg(Color::Red)),
+                        Span::raw(error.clone()),
+                    ]));
+                }
+            }
+        }
+        None => content.push(Line::from(Span::styled(
+            "Scanning git history...",
+            Style::default().fg(Color::DarkGray),
+        ))),
+    }
+
+    let title = match &app.state.user_sync {
+        Some(sync) if !sync.dry_run => " Sync Users from Git - done ",
+        _ => " Sync Users from Git - dry run ",
+    };
     let paragraph = Paragraph::new(content)
         .block(
             Block::default()
                 .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(border_color)),
+                .border_style(Style::default().fg(Color::Cyan)),
         )
         .wrap(Wrap { trim: false })
         .scroll((app.state.scroll_offset as u16, 0));
@@ -282,6 +366,30 @@ fn draw_person_content(frame: &mut Frame, area: Rect, app: &App, person: &User)
     frame.render_widget(paragraph, area);
 }
 
+fn section(label: &str, count: usize) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("{} ({})", label, count),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn contributor_lines(contributors: &[GitContributor], color: Color) -> Vec<Line<'static>> {
+    contributors
+        .iter()
+        .map(|c| {
+            Line::from(vec![
+                Span::styled(format!("  {}", c.name), Style::default().fg(color)),
+                Span::styled(
+                    format!("  <{}>", c.email),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect()
+}
+
 /// Draw the person action panel (right side)
 fn draw_person_action_panel(frame: &mut Frame, area: Rect, app: &App) {
     let is_focused = matches!(
@@ -1,7 +1,7 @@
 //! Projects grid view
 
 use crate::app::App;
-use crate::state::Project;
+use crate::state::{Project, ProjectSection};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -56,9 +56,31 @@ impl GridLayout {
 pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
     let projects = &app.state.projects;
 
-    // Draw outer block first
+    // Draw outer block first, naming the extra projects being shown
+    let shown = app.state.project_filter.active_labels();
+    let title = if shown.is_empty() {
+        " Projects ".to_string()
+    } else {
+        format!(" Projects (+{}) ", shown.join(", "))
+    };
+    // Project keys live in the top border, filter keys in the bottom border (enabled ones
+    // highlighted), keeping the status bar hint short
+    let project_keys = Span::styled(
+        " f:fav a:archive i:init r:rename x:untrack o/O:org W/D:workspaces/daemon ",
+        Style::default().fg(Color::DarkGray),
+    );
+    let mut filter_keys = vec![Span::raw(" ")];
+    for (key, label, on) in app.state.project_filter.toggles() {
+        let color = if on { Color::Yellow } else { Color::DarkGray };
+        filter_keys.push(Span::styled(
+            format!("{}:{} ", key, label),
+            Style::default().fg(color),
+        ));
+    }
     let block = Block::default()
-        .title(" Projects ")
+        .title(title)
+        .title(Line::from(project_keys).right_aligned())
+        .title_bottom(Line::from(filter_keys).right_aligned())
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan));
     frame.render_widget(block, area);
@@ -119,7 +141,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
             };
 
             if header_area.height > 0 {
-                draw_section_header(frame, header_area, &section.header, section.is_favorites);
+                draw_section_header(frame, header_area, section);
             }
         }
         y_offset += SECTION_HEADER_HEIGHT;
@@ -169,13 +191,6 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
             visible_height,
         );
     }
-
-    // Draw confirmation dialog if active
-    if let Some(action) = &app.state.confirm_action {
-        if action == "untrack" {
-            draw_confirm_dialog(frame, area, app);
-        }
-    }
 }
 
 /// Draw a scroll indicator on the right side
@@ -222,11 +237,16 @@ fn draw_scroll_indicator(
 }
 
 /// Draw a section header
-fn draw_section_header(frame: &mut Frame, area: Rect, title: &str, is_favorites: bool) {
+fn draw_section_header(frame: &mut Frame, area: Rect, section: &ProjectSection) {
+    let is_favorites = section.is_favorites;
     let style = if is_favorites {
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD)
+    } else if section.is_archived {
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
             .fg(Color::White)
@@ -236,7 +256,7 @@ fn draw_section_header(frame: &mut Frame, area: Rect, title: &str, is_favorites:
     let prefix = if is_favorites { "★ " } else { "" };
     let header = Paragraph::new(Line::from(vec![
         Span::styled(prefix, Style::default().fg(Color::Yellow)),
-        Span::styled(title, style),
+        Span::styled(section.header.as_str(), style),
     ]));
 
     frame.render_widget(header, area);
@@ -292,14 +312,20 @@ fn draw_project_card(frame: &mut Frame, area: Rect, project: &Project, is_select
         name.to_string()
     };
 
+    // Archived cards are dimmed
+    let name_style = if project.is_archived {
+        Style::default().fg(Color::DarkGray)
+    } else {
+        Style::default()
+    };
     let line1 = Line::from(vec![
         Span::styled(favorite_prefix, Style::default().fg(Color::Yellow)),
         Span::styled(
             truncated_name,
             if is_selected {
-                Style::default().add_modifier(Modifier::BOLD)
+                name_style.add_modifier(Modifier::BOLD)
             } else {
-                Style::default()
+                name_style
             },
         ),
     ]);
@@ -317,6 +343,12 @@ fn draw_project_card(frame: &mut Frame, area: Rect, project: &Project, is_select
     if !project.initialized {
         stats_spans.push(Span::styled(" !", Style::default().fg(Color::Yellow)));
     }
+    if project.is_archived {
+        stats_spans.push(Span::styled(
+            " archived",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
 
     let line2 = Line::from(stats_spans);
 
@@ -325,67 +357,3 @@ fn draw_project_card(frame: &mut Frame, area: Rect, project: &Project, is_select
     let paragraph = Paragraph::new(content);
     frame.render_widget(paragraph, inner);
 }
-
-/// Draw untrack confirmation dialog
-fn draw_confirm_dialog(frame: &mut Frame, area: Rect, app: &App) {
-    let project = app
-        .state
-        .selectable_projects()
-        .get(app.state.selected_index)
-        .copied();
-    let project_name = project.map(|p| p.display_name()).unwrap_or("Unknown");
-
-    let dialog_width = 50;
-    let dialog_height = 7;
-    let dialog_x = area.x + (area.width.saturating_sub(dialog_width)) / 2;
-    let dialog_y = area.y + (area.height.saturating_sub(dialog_height)) / 2;
-
-    let dialog_area = Rect {
-        x: dialog_x,
-        y: dialog_y,
-        width: dialog_width,
-        height: dialog_height,
-    };
-
-    let content = vec![
-        Line::from(Span::styled(
-            "Remove project from tracking?",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from(""),
-        Line::from(format!("\"{}\"", project_name)),
-        Line::from(Span::styled(
-            "(This only removes it from the list, not from disk)",
-            Style::default().fg(Color::DarkGray),
-        )),
-        Line::from(""),
-        Line::from(vec![
-            Span::raw("Press "),
-            Span::styled(
-                "y",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" to confirm, "),
-            Span::styled(
-                "n",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" to cancel"),
-        ]),
-    ];
-
-    let dialog = Paragraph::new(content)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow))
-                .style(Style::default().bg(Color::Black)),
-        )
-        .style(Style::default().bg(Color::Black));
-
-    frame.render_widget(dialog, dialog_area);
-}
@@ -125,11 +125,7 @@ fn draw_prs_list_content(frame: &mut Frame, area: Rect, app: &App) {
                 _ => Color::DarkGray,
             };
 
-            let priority_label = match pr.metadata.priority {
-                1 => "high",
-                2 => "med",
-                _ => "low",
-            };
+            let priority_label = pr.priority_label();
 
             let branches = format!(
                 "({} → {})",
@@ -156,6 +152,11 @@ fn draw_prs_list_content(frame: &mut Frame, area: Rect, app: &App) {
                     Style::default().fg(status_color),
                 ),
                 Span::raw(" "),
+                Span::styled(
+                    reviewers_column(&pr.metadata.reviewers),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(" "),
                 Span::styled(&pr.title, style),
                 Span::styled(
                     format!(" {}", branches),
@@ -177,6 +178,47 @@ fn draw_prs_list_content(frame: &mut Frame, area: Rect, app: &App) {
     render_scrollable_list(frame, chunks[1], list, app.state.selected_index);
 }
 
+/// Width of the reviewers column in the PR list
+const REVIEWERS_COLUMN_WIDTH: usize = 16;
+
+/// Reviewers as a fixed-width list column, truncated with "…"
+fn reviewers_column(reviewers: &[String]) -> String {
+    let joined = if reviewers.is_empty() {
+        "-".to_string()
+    } else {
+        reviewers.join(",")
+    };
+    let shown = if joined.chars().count() > REVIEWERS_COLUMN_WIDTH {
+        let mut cut: String = joined.chars().take(REVIEWERS_COLUMN_WIDTH - 1).collect();
+        cut.push('…');
+        cut
+    } else {
+        joined
+    };
+    format!("{:<width$}", shown, width = REVIEWERS_COLUMN_WIDTH)
+}
+
+/// Reviewer IDs with team member names where known ("Alice (alice)")
+fn reviewer_names(app: &App, reviewers: &[String]) -> String {
+    let users = app
+        .state
+        .selected_project_path
+        .as_ref()
+        .and_then(|path| app.state.project_users.get(path));
+    reviewers
+        .iter()
+        .map(
+            |id| match users.and_then(|users| users.iter().find(|u| &u.id == id)) {
+                Some(user) if !user.name.is_empty() && user.name != *id => {
+                    format!("{} ({})", user.name, id)
+                }
+                _ => id.clone(),
+            },
+        )
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Draw PR detail view
 pub fn draw_detail(frame: &mut Frame, area: Rect, app: &App) {
     // Split area into content (left) and action panel (right)
@@ -254,6 +296,20 @@ fn draw_pr_detail_content(frame: &mut Frame, area: Rect, app: &App) {
                 Style::default().fg(priority_color),
             ),
         ]),
+        // Reviewers
+        Line::from(vec![
+            Span::raw("Reviewers: "),
+            if pr.metadata.reviewers.is_empty() {
+                Span::styled("none", Style::default().fg(Color::DarkGray))
+            } else {
+                Span::styled(
+                    reviewer_names(app, &pr.metadata.reviewers),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            },
+        ]),
         Line::from(""),
         // UUID
         Line::from(vec![
@@ -296,19 +352,11 @@ fn draw_pr_detail_content(frame: &mut Frame, area: Rect, app: &App) {
         content.push(Line::from(""));
     }
 
-    // Reviewers
-    if !pr.metadata.reviewers.is_empty() {
-        content.push(Line::from(Span::styled(
-            "Reviewers",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )));
-        for reviewer in &pr.metadata.reviewers {
-            content.push(Line::from(format!("  {}", reviewer)));
-        }
-        content.push(Line::from(""));
-    }
+    // Custom fields
+    content.extend(super::custom_fields::custom_field_lines(
+        app,
+        &pr.metadata.custom_fields,
+    ));
 
     // Timestamps
     content.push(Line::from(Span::styled(
@@ -338,6 +386,10 @@ fn draw_pr_detail_content(frame: &mut Frame, area: Rect, app: &App) {
         ]));
     }
 
+    // Links
+    content.push(Line::from(""));
+    content.extend(super::links::link_lines(app));
+
     // Separator and Description
     content.push(Line::from(""));
     content.push(Line::from(Span::styled(
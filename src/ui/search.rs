@@ -0,0 +1,183 @@
+//! Advanced search view (AdvancedSearch across one or all projects)
+
+use super::render_scrollable_list;
+use crate::app::App;
+use crate::state::SearchFocus;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Draw the search view: query input, scope/parsed query header, and results
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(2),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    draw_query_input(frame, chunks[0], app);
+    draw_search_header(frame, chunks[1], app);
+    draw_results(frame, chunks[2], app);
+}
+
+/// Draw the query input box
+fn draw_query_input(frame: &mut Frame, area: Rect, app: &App) {
+    let is_focused = app.state.search_focus == SearchFocus::Query;
+    let border_color = if is_focused {
+        Color::Cyan
+    } else {
+        Color::DarkGray
+    };
+
+    let mut spans = vec![Span::styled(
+        app.state.search_query.clone(),
+        Style::default().fg(Color::White),
+    )];
+    if is_focused {
+        spans.push(Span::styled("█", Style::default().fg(Color::Cyan)));
+    } else if app.state.search_query.is_empty() {
+        spans.push(Span::styled(
+            "e.g. status:open priority:1 login",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let input = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .title(" Search ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color)),
+    );
+    frame.render_widget(input, area);
+}
+
+/// Draw the scope toggle and the parsed query echo
+fn draw_search_header(frame: &mut Frame, area: Rect, app: &App) {
+    let scope_label = if app.state.search_spans_all_projects() {
+        "All projects".to_string()
+    } else {
+        let project_name = app
+            .state
+            .selected_project_path
+            .as_ref()
+            .and_then(|p| p.split('/').next_back())
+            .unwrap_or("Project");
+        format!("Project: {}", project_name)
+    };
+
+    let mut scope_line = vec![
+        Span::styled("Scope: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(scope_label, Style::default().fg(Color::Cyan)),
+    ];
+    if app.state.selected_project_path.is_some() {
+        scope_line.push(Span::styled(
+            " [^A]toggle",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let parsed_line = match &app.state.search_parsed_query {
+        Some(parsed) => Line::from(vec![
+            Span::styled("Parsed: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(parsed.clone(), Style::default().fg(Color::Yellow)),
+        ]),
+        None => Line::from(""),
+    };
+
+    let header = Paragraph::new(vec![Line::from(scope_line), parsed_line]);
+    frame.render_widget(header, area);
+}
+
+/// Draw the results list
+fn draw_results(frame: &mut Frame, area: Rect, app: &App) {
+    let is_focused = app.state.search_focus == SearchFocus::Results;
+    let border_color = if is_focused {
+        Color::Cyan
+    } else {
+        Color::DarkGray
+    };
+    let title = format!(" Results ({}) ", app.state.search_total_count);
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color));
+
+    if app.state.search_results.is_empty() {
+        let message = if app.state.search_query.is_empty() {
+            "Type a query and press Enter to search."
+        } else {
+            "No matching issues."
+        };
+        let content = Paragraph::new(message)
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(content, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .state
+        .search_results
+        .iter()
+        .enumerate()
+        .map(|(idx, result)| {
+            let is_selected = is_focused && idx == app.state.selected_search_index;
+            let issue = &result.issue;
+
+            let prefix = if is_selected { "▸" } else { " " };
+            let priority_color = match issue.metadata.priority {
+                1 => Color::Red,
+                2 => Color::Yellow,
+                _ => Color::Green,
+            };
+            let style = if is_selected {
+                Style::default().bg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+
+            let line = Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(
+                    format!("#{}", issue.display_number),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(" "),
+                Span::styled(
+                    format!("[{}]", issue.priority_label()),
+                    Style::default().fg(priority_color),
+                ),
+                Span::raw(" "),
+                Span::styled(
+                    format!("[{}]", issue.metadata.status),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(" "),
+                Span::styled(issue.title.clone(), style),
+                Span::raw("  "),
+                Span::styled(
+                    result.project_name.clone(),
+                    Style::default()
+                        .fg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(" {}", result.display_path),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]);
+
+            ListItem::new(line)
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    render_scrollable_list(frame, area, list, app.state.selected_search_index);
+}
@@ -15,7 +15,7 @@ use ratatui::{
 
 /// Draw the local actions sidebar
 pub fn draw_sidebar(frame: &mut Frame, area: Rect, app: &App) {
-    let actions = get_local_actions(&app.state.current_view);
+    let actions = get_local_actions(&app.state);
 
     if actions.is_empty() {
         // Draw empty sidebar with a subtle placeholder
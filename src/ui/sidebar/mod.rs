@@ -4,7 +4,7 @@ mod draw;
 
 pub use draw::draw_sidebar;
 
-use crate::state::View;
+use crate::state::{AppState, View};
 
 /// A local action that can be shown in the left sidebar
 #[derive(Debug, Clone)]
@@ -24,46 +24,103 @@ impl LocalAction {
     }
 }
 
-/// Get local actions for a given view
-pub fn get_local_actions(view: &View) -> Vec<LocalAction> {
-    match view {
+/// Get local actions for the current view
+pub fn get_local_actions(state: &AppState) -> Vec<LocalAction> {
+    match &state.current_view {
         View::Organization => vec![LocalAction::new("nav_projects", "All Projects", "p")],
         View::Issues => vec![
             LocalAction::new("new_issue", "New Issue", "n"),
             LocalAction::new("nav_prs", "Pull Requests", "3"),
             LocalAction::new("nav_docs", "Docs", "4"),
             LocalAction::new("nav_people", "People", "5"),
+            LocalAction::new("nav_assets", "Shared Assets", "7"),
+            LocalAction::new("nav_features", "Features", "8"),
         ],
-        View::IssueDetail => vec![LocalAction::new("edit_issue", "Edit Issue", "e")],
+        View::IssueDetail => {
+            let mut actions = vec![
+                LocalAction::new("edit_issue", "Edit Issue", "e"),
+                LocalAction::new("add_link", "Add Link", "L"),
+                LocalAction::new("spawn_agent", "Spawn Agent", "A"),
+                LocalAction::new("nav_agent_activity", "Agent Activity", "w"),
+            ];
+            if state.action_issue().is_some_and(|i| i.metadata.draft) {
+                actions.insert(1, LocalAction::new("publish_draft", "Publish Draft", "P"));
+            }
+            actions
+        }
         View::Prs => vec![
             LocalAction::new("new_pr", "New PR", "n"),
             LocalAction::new("nav_issues", "Issues", "2"),
             LocalAction::new("nav_docs", "Docs", "4"),
             LocalAction::new("nav_people", "People", "5"),
+            LocalAction::new("nav_assets", "Shared Assets", "7"),
+            LocalAction::new("nav_features", "Features", "8"),
+        ],
+        View::PrDetail => vec![
+            LocalAction::new("edit_pr", "Edit PR", "e"),
+            LocalAction::new("add_link", "Add Link", "L"),
         ],
-        View::PrDetail => vec![LocalAction::new("edit_pr", "Edit PR", "e")],
         View::Docs => vec![
             LocalAction::new("new_doc", "New Doc", "n"),
             LocalAction::new("nav_issues", "Issues", "2"),
             LocalAction::new("nav_prs", "Pull Requests", "3"),
             LocalAction::new("nav_people", "People", "5"),
+            LocalAction::new("nav_assets", "Shared Assets", "7"),
+            LocalAction::new("nav_features", "Features", "8"),
+        ],
+        View::DocDetail => vec![
+            LocalAction::new("edit_doc", "Edit Doc", "e"),
+            LocalAction::new("add_link", "Add Link", "L"),
         ],
-        View::DocDetail => vec![LocalAction::new("edit_doc", "Edit Doc", "e")],
         View::People => vec![
             LocalAction::new("nav_issues", "Issues", "2"),
             LocalAction::new("nav_prs", "Pull Requests", "3"),
             LocalAction::new("nav_docs", "Docs", "4"),
+            LocalAction::new("nav_assets", "Shared Assets", "7"),
+            LocalAction::new("nav_features", "Features", "8"),
+        ],
+        View::PersonDetail | View::UserSync => vec![],
+        View::Config => vec![
+            LocalAction::new("nav_config_edit", "Edit Config", "e"),
+            LocalAction::new("nav_version", "Version", "v"),
+            LocalAction::new("nav_agents", "Agents", "a"),
+        ],
+        View::Version => vec![LocalAction::new("nav_config", "Config", "6")],
+        View::Features => vec![LocalAction::new("nav_issues", "Issues", "2")],
+        View::Agents => vec![
+            LocalAction::new("nav_config", "Config", "6"),
+            LocalAction::new("nav_agent_activity", "Agent Activity", "w"),
+        ],
+        View::Assets => vec![
+            LocalAction::new("nav_issues", "Issues", "2"),
+            LocalAction::new("nav_prs", "Pull Requests", "3"),
+            LocalAction::new("nav_docs", "Docs", "4"),
         ],
-        View::PersonDetail => vec![],
         // Views with no local actions
-        View::Splash | View::Projects | View::Config => vec![],
+        View::Splash
+        | View::Projects
+        | View::Search
+        | View::InitWizard
+        | View::Organizations
+        | View::AgentActivity
+        | View::Workspaces
+        | View::Daemon => vec![],
         // Form views - no sidebar
         View::IssueCreate
         | View::IssueEdit
         | View::PrCreate
         | View::PrEdit
         | View::DocCreate
-        | View::DocEdit => vec![],
+        | View::DocEdit
+        | View::OrganizationCreate
+        | View::OrganizationEdit
+        | View::PersonCreate
+        | View::PersonEdit
+        | View::ConfigEdit
+        | View::CompactEdit
+        | View::MigrationCreate
+        | View::AgentCreate
+        | View::AgentEdit => vec![],
     }
 }
 
@@ -73,11 +130,25 @@ pub fn should_show_sidebar(view: &View) -> bool {
         view,
         View::Splash
             | View::Projects
+            | View::Search
+            | View::InitWizard
+            | View::Organizations
+            | View::Workspaces
+            | View::Daemon
             | View::IssueCreate
             | View::IssueEdit
             | View::PrCreate
             | View::PrEdit
             | View::DocCreate
             | View::DocEdit
+            | View::OrganizationCreate
+            | View::OrganizationEdit
+            | View::PersonCreate
+            | View::PersonEdit
+            | View::ConfigEdit
+            | View::CompactEdit
+            | View::MigrationCreate
+            | View::AgentCreate
+            | View::AgentEdit
     )
 }
@@ -0,0 +1,148 @@
+//! Version panel (project vs daemon version and migration)
+
+use crate::app::App;
+use crate::state::VersionComparison;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the version panel
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let project_name = app
+        .state
+        .selected_project_path
+        .as_ref()
+        .and_then(|p| p.split('/').next_back())
+        .unwrap_or("Project");
+
+    let mut content = vec![
+        Line::from(Span::styled(
+            "Project Version",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    match &app.state.project_version {
+        Some(version) => {
+            content.push(Line::from(vec![
+                Span::styled("Project: ", Style::default().fg(Color::DarkGray)),
+                Span::raw(version.project_version.clone()),
+            ]));
+            content.push(Line::from(vec![
+                Span::styled("Daemon:  ", Style::default().fg(Color::DarkGray)),
+                Span::raw(version.daemon_version.clone()),
+            ]));
+            let (status, color) = match version.comparison {
+                VersionComparison::Equal => ("Up to date", Color::Green),
+                VersionComparison::ProjectBehind => (
+                    "Project is behind the daemon, migration available",
+                    Color::Yellow,
+                ),
+                VersionComparison::ProjectAhead => ("Project is ahead of the daemon", Color::Red),
+            };
+            content.push(Line::from(vec![
+                Span::styled("Status:  ", Style::default().fg(Color::DarkGray)),
+                Span::styled(status, Style::default().fg(color)),
+            ]));
+            if version.degraded_mode {
+                content.push(Line::from(Span::styled(
+                    "Degraded mode: update the daemon or migrate the project to a supported version.",
+                    Style::default().fg(Color::Red),
+                )));
+            }
+        }
+        None => content.push(Line::from(Span::styled(
+            "Version information unavailable.",
+            Style::default().fg(Color::DarkGray),
+        ))),
+    }
+
+    content.push(Line::from(""));
+    content.push(Line::from(Span::styled(
+        "Migrate To",
+        Style::default().fg(Color::Cyan),
+    )));
+
+    let versions = app.state.available_versions();
+    if versions.is_empty() {
+        content.push(Line::from(Span::styled(
+            "  No versions available.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    let current = app
+        .state
+        .project_version
+        .as_ref()
+        .map(|v| v.project_version.as_str());
+    let daemon = app
+        .state
+        .project_version
+        .as_ref()
+        .map(|v| v.daemon_version.as_str());
+    for (idx, version) in versions.iter().enumerate() {
+        let is_selected = idx == app.state.selected_version_index;
+        let prefix = if is_selected { "▸ " } else { "  " };
+        let style = if is_selected {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let mut spans = vec![Span::styled(format!("{}{}", prefix, version), style)];
+        if Some(version.as_str()) == current {
+            spans.push(Span::styled(
+                "  (current)",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        if Some(version.as_str()) == daemon {
+            spans.push(Span::styled(
+                "  (daemon)",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        content.push(Line::from(spans));
+    }
+
+    if let Some(result) = &app.state.migration_result {
+        content.push(Line::from(""));
+        content.push(Line::from(Span::styled(
+            format!("Migrated {} → {}", result.from_version, result.to_version),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )));
+        if result.migrations_applied.is_empty() {
+            content.push(Line::from(Span::styled(
+                "  No migrations were needed.",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for migration in &result.migrations_applied {
+            content.push(Line::from(vec![
+                Span::styled("  • ", Style::default().fg(Color::Green)),
+                Span::raw(migration.clone()),
+            ]));
+        }
+    }
+
+    let paragraph = Paragraph::new(content)
+        .block(
+            Block::default()
+                .title(format!(" Version - {} ", project_name))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,242 @@
+//! Workspaces view (temporary workspaces across all projects)
+
+use super::render_scrollable_list;
+use crate::app::App;
+use crate::daemon::TempWorkspace;
+use crate::state::{expiry_countdown, workspace_expired, LlmAction, WorkspaceConfirm};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draw the workspaces list, with the pending confirmation and last cleanup report below
+pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
+    let footer = footer_lines(app);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(if footer.is_empty() {
+                0
+            } else {
+                (footer.len() as u16 + 2).min(area.height / 2)
+            }),
+        ])
+        .split(area);
+
+    draw_list(frame, chunks[0], app);
+
+    if !footer.is_empty() {
+        let paragraph = Paragraph::new(footer).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        frame.render_widget(paragraph, chunks[1]);
+    }
+}
+
+fn draw_list(frame: &mut Frame, area: Rect, app: &App) {
+    let now = Utc::now();
+    let expired = app
+        .state
+        .workspaces
+        .iter()
+        .filter(|w| workspace_expired(w, now))
+        .count();
+    let block = Block::default()
+        .title(format!(
+            " Workspaces ({}, {} expired) ",
+            app.state.workspaces.len(),
+            expired
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if app.state.workspaces.is_empty() {
+        let message = Paragraph::new("No temporary workspaces.")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(message, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .state
+        .workspaces
+        .iter()
+        .enumerate()
+        .map(|(idx, workspace)| {
+            workspace_item(workspace, idx == app.state.selected_workspace_index, now)
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    render_scrollable_list(frame, area, list, app.state.selected_workspace_index);
+}
+
+/// Issue line, agent/expiry line and path line for one workspace
+fn workspace_item(
+    workspace: &TempWorkspace,
+    is_selected: bool,
+    now: DateTime<Utc>,
+) -> ListItem<'static> {
+    let title_style = if is_selected {
+        Style::default()
+            .add_modifier(Modifier::BOLD)
+            .bg(Color::DarkGray)
+    } else {
+        Style::default()
+    };
+    let project = workspace
+        .source_project_path
+        .split('/')
+        .next_back()
+        .unwrap_or("")
+        .to_string();
+
+    let countdown = expiry_countdown(&workspace.expires_at, now);
+    let countdown_color = if workspace_expired(workspace, now) {
+        Color::Red
+    } else {
+        Color::Green
+    };
+    let created = DateTime::parse_from_rfc3339(&workspace.created_at)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|_| "unknown".to_string());
+    let agent = if workspace.agent_name.is_empty() {
+        "default agent".to_string()
+    } else {
+        workspace.agent_name.clone()
+    };
+
+    ListItem::new(vec![
+        Line::from(vec![
+            Span::raw(if is_selected { "▸ " } else { "  " }),
+            Span::styled(
+                format!("#{} ", workspace.issue_display_number),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(workspace.issue_title.clone(), title_style),
+            Span::styled(
+                format!("  [{}]", project),
+                Style::default().fg(Color::Magenta),
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw("    "),
+            Span::styled(agent, Style::default().fg(Color::Cyan)),
+            Span::styled(
+                format!(
+                    "  {}",
+                    LlmAction::from_proto_value(workspace.action).label()
+                ),
+                Style::default().fg(Color::Magenta),
+            ),
+            Span::styled(
+                format!("  created {}  ", created),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(countdown, Style::default().fg(countdown_color)),
+        ]),
+        Line::from(Span::styled(
+            format!("    {}", workspace.workspace_path),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ])
+}
+
+/// Pending confirmation prompt and the last cleanup report
+fn footer_lines(app: &App) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    if let Some(confirm) = app.state.workspace_confirm {
+        let number = app
+            .state
+            .selected_workspace()
+            .map(|w| w.issue_display_number)
+            .unwrap_or_default();
+        let (question, extra) = match confirm {
+            WorkspaceConfirm::Close { force: false } => {
+                (format!("Close the workspace for #{}? ", number), None)
+            }
+            WorkspaceConfirm::Close { force: true } => (
+                format!(
+                    "Force close the workspace for #{}? Uncommitted changes are lost. ",
+                    number
+                ),
+                None,
+            ),
+            WorkspaceConfirm::Extend { ttl_hours } => (
+                format!(
+                    "Replace the workspace for #{} with a new one ({}h TTL)? ",
+                    number, ttl_hours
+                ),
+                Some("+/-"),
+            ),
+            WorkspaceConfirm::CleanupExpired => {
+                ("Remove all expired workspaces? ".to_string(), None)
+            }
+        };
+        let mut spans = vec![
+            Span::styled(question, Style::default().fg(Color::Yellow)),
+            Span::styled("y", Style::default().fg(Color::Cyan)),
+            Span::styled(" confirm  ", Style::default().fg(Color::DarkGray)),
+        ];
+        if let Some(keys) = extra {
+            spans.push(Span::styled(keys, Style::default().fg(Color::Cyan)));
+            spans.push(Span::styled(
+                " hours  ",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        spans.push(Span::styled("n", Style::default().fg(Color::Cyan)));
+        spans.push(Span::styled(
+            " cancel",
+            Style::default().fg(Color::DarkGray),
+        ));
+        lines.push(Line::from(spans));
+        if let WorkspaceConfirm::Extend { .. } = confirm {
+            lines.push(Line::from(Span::styled(
+                "Unpushed local commits are discarded and the workspace path changes.",
+                Style::default().fg(Color::Red),
+            )));
+        }
+    }
+
+    if let Some(cleanup) = &app.state.workspace_cleanup {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Last cleanup: removed {}, failed {}",
+                cleanup.cleaned_paths.len(),
+                cleanup.failed_paths.len()
+            ),
+            Style::default().fg(Color::Cyan),
+        )));
+        for path in &cleanup.cleaned_paths {
+            lines.push(Line::from(vec![
+                Span::styled("  ✓ ", Style::default().fg(Color::Green)),
+                Span::raw(path.clone()),
+            ]));
+        }
+        for path in &cleanup.failed_paths {
+            lines.push(Line::from(vec![
+                Span::styled("  ✗ ", Style::default().fg(Color::Red)),
+                Span::raw(path.clone()),
+            ]));
+        }
+    }
+
+    lines
+}